@@ -0,0 +1,152 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use typed_path::{
+    UnixEncoding, UnixPath, UnixPathBuf, Utf8UnixPath, Utf8WindowsPath, WindowsEncoding,
+    WindowsPath, WindowsPathBuf, WindowsPrefixComponent,
+};
+
+const UNIX_PATHS: &[(&str, &str)] = &[
+    ("short", "/usr/bin"),
+    (
+        "long",
+        "/home/user/projects/typed-path/src/common/non_utf8/path.rs",
+    ),
+    (
+        "dots",
+        "/home/./user/../user/projects/./typed-path/../typed-path/src",
+    ),
+];
+
+const WINDOWS_PATHS: &[(&str, &str)] = &[
+    ("disk", r"C:\Users\user\projects\typed-path\src\lib.rs"),
+    ("unc", r"\\server\share\projects\typed-path\src\lib.rs"),
+    (
+        "verbatim",
+        r"\\?\C:\Users\user\projects\typed-path\src\lib.rs",
+    ),
+    (
+        "dots",
+        r"C:\Users\.\user\..\user\projects\typed-path\..\typed-path\src",
+    ),
+];
+
+fn components(c: &mut Criterion) {
+    let mut group = c.benchmark_group("components");
+    for (name, path) in UNIX_PATHS {
+        group.bench_with_input(BenchmarkId::new("unix", name), path, |b, path| {
+            b.iter(|| UnixPath::new(black_box(path)).components().count())
+        });
+        group.bench_with_input(BenchmarkId::new("unix_rev", name), path, |b, path| {
+            b.iter(|| UnixPath::new(black_box(path)).components().rev().count())
+        });
+    }
+    for (name, path) in WINDOWS_PATHS {
+        group.bench_with_input(BenchmarkId::new("windows", name), path, |b, path| {
+            b.iter(|| WindowsPath::new(black_box(path)).components().count())
+        });
+        group.bench_with_input(BenchmarkId::new("windows_rev", name), path, |b, path| {
+            b.iter(|| WindowsPath::new(black_box(path)).components().rev().count())
+        });
+        group.bench_with_input(BenchmarkId::new("windows_utf8", name), path, |b, path| {
+            b.iter(|| Utf8WindowsPath::new(black_box(path)).components().count())
+        });
+    }
+    group.finish();
+}
+
+fn push_and_join(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_and_join");
+    group.bench_function("unix_push", |b| {
+        b.iter(|| {
+            let mut path = UnixPathBuf::from("/home/user");
+            path.push(black_box("projects"));
+            path.push(black_box("typed-path/src"));
+            path
+        })
+    });
+    group.bench_function("unix_join", |b| {
+        b.iter(|| UnixPath::new(black_box("/home/user")).join(black_box("projects/typed-path")))
+    });
+    group.bench_function("windows_push", |b| {
+        b.iter(|| {
+            let mut path = WindowsPathBuf::from(r"C:\Users\user");
+            path.push(black_box("projects"));
+            path.push(black_box(r"typed-path\src"));
+            path
+        })
+    });
+    group.bench_function("windows_push_checked", |b| {
+        b.iter(|| {
+            let mut path = WindowsPathBuf::from(r"C:\Users\user");
+            path.push_checked(black_box(r"projects\typed-path\src"))
+        })
+    });
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize");
+    for (name, path) in UNIX_PATHS {
+        group.bench_with_input(BenchmarkId::new("unix", name), path, |b, path| {
+            b.iter(|| UnixPath::new(black_box(path)).normalize())
+        });
+        group.bench_with_input(BenchmarkId::new("unix_utf8", name), path, |b, path| {
+            b.iter(|| Utf8UnixPath::new(black_box(path)).normalize())
+        });
+    }
+    for (name, path) in WINDOWS_PATHS {
+        group.bench_with_input(BenchmarkId::new("windows", name), path, |b, path| {
+            b.iter(|| WindowsPath::new(black_box(path)).normalize())
+        });
+    }
+    group.finish();
+}
+
+fn conversions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("conversions");
+    for (name, path) in UNIX_PATHS {
+        group.bench_with_input(
+            BenchmarkId::new("unix_to_windows", name),
+            path,
+            |b, path| b.iter(|| UnixPath::new(black_box(path)).with_encoding::<WindowsEncoding>()),
+        );
+        group.bench_with_input(BenchmarkId::new("unix_to_utf8", name), path, |b, path| {
+            b.iter(|| Utf8UnixPath::from_bytes_path(UnixPath::new(black_box(path))).is_ok())
+        });
+    }
+    for (name, path) in WINDOWS_PATHS {
+        group.bench_with_input(
+            BenchmarkId::new("windows_to_unix", name),
+            path,
+            |b, path| b.iter(|| WindowsPath::new(black_box(path)).with_encoding::<UnixEncoding>()),
+        );
+    }
+    group.finish();
+}
+
+fn windows_prefix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("windows_prefix");
+    for (name, path) in WINDOWS_PATHS {
+        group.bench_with_input(BenchmarkId::new("parse", name), path, |b, path| {
+            b.iter(|| WindowsPrefixComponent::parse(black_box(path.as_bytes())))
+        });
+        group.bench_with_input(BenchmarkId::new("components", name), path, |b, path| {
+            b.iter(|| {
+                WindowsPath::new(black_box(path))
+                    .components()
+                    .prefix_kind()
+                    .is_some()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    components,
+    push_and_join,
+    normalize,
+    conversions,
+    windows_prefix
+);
+criterion_main!(benches);
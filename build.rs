@@ -0,0 +1,21 @@
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(typed_path_os_str_encoded_bytes)");
+
+    // `OsStr::as_encoded_bytes` was stabilized in Rust 1.74, which is newer than our minimum
+    // supported version, so we only rely on it when the compiler provides it
+    if rustc_minor_version().map_or(false, |minor| minor >= 74) {
+        println!("cargo:rustc-cfg=typed_path_os_str_encoded_bytes");
+    }
+}
+
+/// Returns the minor version of the compiler, e.g. `58` for `rustc 1.58.1`.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    version.split('.').nth(1)?.parse().ok()
+}
@@ -1,13 +1,33 @@
+mod archive;
+mod checkpoint;
 mod errors;
 #[macro_use]
-mod non_utf8;
+pub(crate) mod non_utf8;
+mod normalize;
+mod path_id;
+mod pathset;
+mod root_kind;
+mod template;
 mod utf8;
+mod vfs;
 
 /// Interface to try to perform a cheap reference-to-reference conversion.
+///
+/// Unlike [`AsRef`], the conversion can fail, in which case `None` is returned. Each
+/// implementation documents the conditions under which it fails.
 pub trait TryAsRef<T: ?Sized> {
+    /// Performs the conversion, returning `None` if `self` cannot be viewed as `T`.
     fn try_as_ref(&self) -> Option<&T>;
 }
 
+pub use archive::*;
+pub use checkpoint::*;
 pub use errors::*;
 pub use non_utf8::*;
+pub use normalize::*;
+pub use path_id::*;
+pub use pathset::*;
+pub use root_kind::*;
+pub use template::*;
 pub use utf8::*;
+pub use vfs::*;
@@ -0,0 +1,100 @@
+use crate::common::non_utf8::helpers;
+use crate::common::{ArchiveEntryError, Encoding, PathBuf};
+
+/// Converts the name of an entry from an archive such as a zip or tar file into a relative
+/// [`PathBuf`] that cannot escape the directory it is extracted into.
+///
+/// Archive formats store entry names as raw bytes that are expected to use `/` as the
+/// separator, but archives created on Windows frequently use `\` instead. Both are treated as
+/// separators, regardless of the encoding `T` of the returned path, so that an entry like
+/// `..\..\evil.exe` is rejected on every platform rather than only on Windows.
+///
+/// Empty and `.` components are dropped, and every remaining component is pushed onto the
+/// returned path using the separator of `T`.
+///
+/// # Errors
+///
+/// Returns an [`ArchiveEntryError`] if the entry name:
+///
+/// * has no components left once empty and `.` components are dropped
+/// * begins with a separator, making it absolute or a UNC path
+/// * begins with a drive letter, such as `C:`
+/// * contains a `..` component anywhere, even if it would not escape the extraction directory
+/// * contains a component that is not valid for `T`, such as one containing `\0`
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{sanitize_archive_entry, ArchiveEntryError, UnixPathBuf, WindowsPathBuf};
+///
+/// let path: UnixPathBuf = sanitize_archive_entry(b"docs/./guide.md").unwrap();
+/// assert_eq!(path, UnixPathBuf::from("docs/guide.md"));
+///
+/// // Backslashes are separators no matter the target encoding
+/// let path: UnixPathBuf = sanitize_archive_entry(br"docs\guide.md").unwrap();
+/// assert_eq!(path, UnixPathBuf::from("docs/guide.md"));
+///
+/// let path: WindowsPathBuf = sanitize_archive_entry(b"docs/guide.md").unwrap();
+/// assert_eq!(path, WindowsPathBuf::from(r"docs\guide.md"));
+///
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::UnixEncoding>(b"../etc/passwd"),
+///     Err(ArchiveEntryError::ParentDir),
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::UnixEncoding>(br"a\..\..\b"),
+///     Err(ArchiveEntryError::ParentDir),
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::UnixEncoding>(b"/etc/passwd"),
+///     Err(ArchiveEntryError::Absolute),
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::UnixEncoding>(br"\\server\share\file"),
+///     Err(ArchiveEntryError::Absolute),
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::UnixEncoding>(br"C:\Windows\evil.dll"),
+///     Err(ArchiveEntryError::DriveLetter),
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::UnixEncoding>(b"./"),
+///     Err(ArchiveEntryError::Empty),
+/// );
+/// assert_eq!(
+///     sanitize_archive_entry::<typed_path::WindowsEncoding>(b"file.txt:stream"),
+///     Err(ArchiveEntryError::InvalidComponent),
+/// );
+/// ```
+pub fn sanitize_archive_entry<T>(entry: &[u8]) -> Result<PathBuf<T>, ArchiveEntryError>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let is_separator = |b: &u8| *b == b'/' || *b == b'\\';
+
+    if entry.first().map_or(false, is_separator) {
+        return Err(ArchiveEntryError::Absolute);
+    }
+
+    if let [drive, b':', ..] = entry {
+        if drive.is_ascii_alphabetic() {
+            return Err(ArchiveEntryError::DriveLetter);
+        }
+    }
+
+    let mut path = PathBuf::new();
+    for component in entry.split(is_separator) {
+        match component {
+            b"" | b"." => continue,
+            b".." => return Err(ArchiveEntryError::ParentDir),
+            _ if helpers::is_single_normal_component::<T>(component) => path.push(component),
+            _ => return Err(ArchiveEntryError::InvalidComponent),
+        }
+    }
+
+    if path.as_bytes().is_empty() {
+        return Err(ArchiveEntryError::Empty);
+    }
+
+    Ok(path)
+}
@@ -0,0 +1,67 @@
+/// A saved length of a [`PathBuf`] or [`Utf8PathBuf`] that it can later be truncated back to.
+///
+/// Restoring a checkpoint with [`PathBuf::truncate_to`] only shortens the buffer, which lets
+/// recursive algorithms such as directory walks push and pop components on a single buffer
+/// instead of cloning the path at every level.
+///
+/// Alongside the length, a checkpoint records a hash of the bytes up to that length, so that
+/// restoring it fails rather than keeping a meaningless prefix if the buffer was replaced since,
+/// e.g. by pushing an absolute path, [`set_file_name`], or [`clear`].
+///
+/// [`PathBuf`]: crate::PathBuf
+/// [`PathBuf::truncate_to`]: crate::PathBuf::truncate_to
+/// [`Utf8PathBuf`]: crate::Utf8PathBuf
+/// [`set_file_name`]: crate::PathBuf::set_file_name
+/// [`clear`]: crate::PathBuf::clear
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::UnixPathBuf;
+///
+/// let mut path = UnixPathBuf::from("/srv");
+/// let checkpoint = path.checkpoint();
+///
+/// path.push("app/logs");
+/// assert_eq!(path.as_bytes(), b"/srv/app/logs");
+///
+/// assert!(path.truncate_to(checkpoint));
+/// assert_eq!(path.as_bytes(), b"/srv");
+///
+/// // The checkpoint no longer applies once the buffer has been replaced
+/// path.push("/tmp/cache");
+/// assert!(!path.truncate_to(checkpoint));
+/// assert_eq!(path.as_bytes(), b"/tmp/cache");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathCheckpoint {
+    len: usize,
+    hash: u64,
+}
+
+impl PathCheckpoint {
+    pub(crate) fn new(bytes: &[u8]) -> Self {
+        Self {
+            len: bytes.len(),
+            hash: hash_bytes(bytes),
+        }
+    }
+
+    /// Returns the length to truncate `bytes` to, or [`None`] if they no longer start with the
+    /// bytes this checkpoint was taken from.
+    pub(crate) fn len_in(self, bytes: &[u8]) -> Option<usize> {
+        let prefix = bytes.get(..self.len)?;
+        if hash_bytes(prefix) == self.hash {
+            Some(self.len)
+        } else {
+            None
+        }
+    }
+}
+
+/// 64-bit FNV-1a, which needs no state and is cheap for the short inputs paths tend to be.
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -1,5 +1,8 @@
 use core::fmt;
 
+use crate::no_std_compat::*;
+use crate::PathType;
+
 /// An error returned if the prefix was not found.
 ///
 /// This `struct` is created by the [`strip_prefix`] method on [`Path`].
@@ -19,6 +22,9 @@ impl fmt::Display for StripPrefixError {
 #[cfg(feature = "std")]
 impl std::error::Error for StripPrefixError {}
 
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for StripPrefixError {}
+
 /// An error returned when a path violates checked criteria.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CheckedPathError {
@@ -49,3 +55,583 @@ impl fmt::Display for CheckedPathError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for CheckedPathError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for CheckedPathError {}
+
+/// An error returned when a typed path does not have the expected flavor, carrying the original
+/// path so that it is not lost.
+///
+/// This `struct` is created by methods such as [`TypedPathBuf::into_unix`]. See their
+/// documentation for more.
+///
+/// [`TypedPathBuf::into_unix`]: crate::TypedPathBuf::into_unix
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlavorMismatchError<P> {
+    pub(crate) expected: PathType,
+    pub(crate) actual: PathType,
+    pub(crate) path: P,
+}
+
+impl<P> FlavorMismatchError<P> {
+    /// Returns the flavor that the path was expected to have.
+    pub fn expected(&self) -> PathType {
+        self.expected
+    }
+
+    /// Returns the flavor that the path actually has.
+    pub fn actual(&self) -> PathType {
+        self.actual
+    }
+
+    /// Returns a reference to the original path.
+    pub fn path(&self) -> &P {
+        &self.path
+    }
+
+    /// Consumes the error, returning the original path.
+    pub fn into_path(self) -> P {
+        self.path
+    }
+}
+
+impl<P> fmt::Display for FlavorMismatchError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = |t: PathType| match t {
+            PathType::Unix => "unix",
+            PathType::Windows => "windows",
+        };
+
+        write!(
+            f,
+            "expected {} path, found {} path",
+            label(self.expected),
+            label(self.actual)
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: fmt::Debug> std::error::Error for FlavorMismatchError<P> {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl<P: fmt::Debug> core::error::Error for FlavorMismatchError<P> {}
+
+/// An error returned when a path is not valid UTF-8, reporting where the first invalid byte is.
+///
+/// This `struct` is created by [`Utf8Path::from_bytes_path`] and the other conversions from byte
+/// paths to UTF-8 paths. See its documentation for more.
+///
+/// [`Utf8Path::from_bytes_path`]: crate::Utf8Path::from_bytes_path
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Utf8PathError {
+    valid_up_to: usize,
+    error_len: Option<usize>,
+}
+
+impl Utf8PathError {
+    /// Returns the offset of the first byte of the path that is not part of valid UTF-8, which
+    /// is also the length of the longest valid prefix of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{UnixPath, Utf8UnixPath};
+    ///
+    /// let error = Utf8UnixPath::from_bytes_path(UnixPath::new(b"/tmp/\xff.txt")).unwrap_err();
+    /// assert_eq!(error.valid_up_to(), 5);
+    /// ```
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the length of the invalid byte sequence at [`valid_up_to`], or [`None`] if the
+    /// path ends with an incomplete sequence, as with [`core::str::Utf8Error::error_len`].
+    ///
+    /// [`valid_up_to`]: Utf8PathError::valid_up_to
+    #[inline]
+    pub fn error_len(&self) -> Option<usize> {
+        self.error_len
+    }
+}
+
+impl From<core::str::Utf8Error> for Utf8PathError {
+    fn from(error: core::str::Utf8Error) -> Self {
+        Self {
+            valid_up_to: error.valid_up_to(),
+            error_len: error.error_len(),
+        }
+    }
+}
+
+impl fmt::Display for Utf8PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "path is not valid UTF-8: invalid byte at offset {}",
+            self.valid_up_to
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8PathError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for Utf8PathError {}
+
+/// An error returned when a path is not valid UTF-8, carrying the original path so that its
+/// allocation is not lost.
+///
+/// This `struct` is created by [`Utf8PathBuf::from_bytes_path_buf`]. See its documentation for
+/// more.
+///
+/// [`Utf8PathBuf::from_bytes_path_buf`]: crate::Utf8PathBuf::from_bytes_path_buf
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FromUtf8PathError<P> {
+    pub(crate) path: P,
+    pub(crate) error: Utf8PathError,
+}
+
+impl<P> FromUtf8PathError<P> {
+    /// Returns the details of why the path is not valid UTF-8, including where the first invalid
+    /// byte is.
+    pub fn utf8_error(&self) -> Utf8PathError {
+        self.error
+    }
+
+    /// Returns a reference to the original path.
+    pub fn path(&self) -> &P {
+        &self.path
+    }
+
+    /// Consumes the error, returning the original path.
+    pub fn into_path(self) -> P {
+        self.path
+    }
+}
+
+impl<P> fmt::Display for FromUtf8PathError<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: fmt::Debug> std::error::Error for FromUtf8PathError<P> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl<P: fmt::Debug> core::error::Error for FromUtf8PathError<P> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error returned when an archive entry name cannot be safely extracted.
+///
+/// This `enum` is created by [`sanitize_archive_entry`]. See its documentation for more.
+///
+/// [`sanitize_archive_entry`]: crate::sanitize_archive_entry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveEntryError {
+    /// When the entry name has no components once `.` and empty components are removed.
+    Empty,
+
+    /// When the entry name begins with a separator, e.g. `/etc/passwd` or `\\server\share`.
+    Absolute,
+
+    /// When the entry name begins with a drive letter, e.g. `C:\Windows` or `C:file`.
+    DriveLetter,
+
+    /// When the entry name contains a `..` component.
+    ParentDir,
+
+    /// When a component contains characters that are invalid for the target encoding,
+    /// e.g. `\0`, or `:` for Windows.
+    InvalidComponent,
+}
+
+impl fmt::Display for ArchiveEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "archive entry has an empty name"),
+            Self::Absolute => write!(f, "archive entry has an absolute path"),
+            Self::DriveLetter => write!(f, "archive entry has a drive letter"),
+            Self::ParentDir => write!(f, "archive entry contains a parent directory"),
+            Self::InvalidComponent => write!(f, "archive entry contains an invalid component"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArchiveEntryError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for ArchiveEntryError {}
+
+/// An error returned when bytes cannot be decoded by a [`PathSetDecoder`].
+///
+/// [`PathSetDecoder`]: crate::PathSetDecoder
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSetDecodeError {
+    /// When the data ends in the middle of an entry.
+    Truncated,
+
+    /// When an entry claims to share more bytes with the previous path than it has.
+    InvalidPrefixLength,
+
+    /// When a length does not fit within a `usize`.
+    Overflow,
+}
+
+impl fmt::Display for PathSetDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "path set data is truncated"),
+            Self::InvalidPrefixLength => {
+                write!(f, "path set entry shares more bytes than the previous path")
+            }
+            Self::Overflow => write!(f, "path set entry length overflows"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathSetDecodeError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for PathSetDecodeError {}
+
+/// An error returned when a [`PathTemplate`] could not be parsed or rendered.
+///
+/// [`PathTemplate`]: crate::PathTemplate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathTemplateError {
+    /// When a `{` is not followed by a matching `}`.
+    UnclosedPlaceholder,
+
+    /// When a `}` appears outside of a placeholder without being escaped as `}}`.
+    UnmatchedBrace,
+
+    /// When a placeholder has no name, e.g. `{}`.
+    EmptyPlaceholder,
+
+    /// When no value was provided for the named placeholder.
+    MissingValue(String),
+
+    /// When the value for the named placeholder is not a single normal component, such as when
+    /// it contains a separator or is `..`.
+    InvalidValue(String),
+}
+
+impl fmt::Display for PathTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedPlaceholder => write!(f, "path template has an unclosed placeholder"),
+            Self::UnmatchedBrace => write!(f, "path template has an unmatched closing brace"),
+            Self::EmptyPlaceholder => write!(f, "path template has an unnamed placeholder"),
+            Self::MissingValue(name) => write!(f, "no value for placeholder {name:?}"),
+            Self::InvalidValue(name) => {
+                write!(
+                    f,
+                    "value for placeholder {name:?} is not a single component"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathTemplateError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for PathTemplateError {}
+
+/// An error returned when a Windows prefix could not be used to build a path.
+///
+/// This `enum` is created by the [`validate`] method on [`WindowsPrefix`]. See its documentation
+/// for more.
+///
+/// [`validate`]: crate::WindowsPrefix::validate
+/// [`WindowsPrefix`]: crate::WindowsPrefix
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WindowsPrefixError {
+    /// When a disk prefix has a drive that is not an ASCII letter.
+    InvalidDrive,
+
+    /// When a UNC prefix has no server name.
+    EmptyServer,
+
+    /// When a UNC server name contains a character that is not allowed in a hostname.
+    InvalidServer,
+
+    /// When a UNC server name ends with an `@` suffix other than `@SSL` followed by an optional
+    /// `@port`, or `@port` on its own.
+    InvalidServerSuffix,
+
+    /// When a UNC prefix has no share name.
+    EmptyShare,
+
+    /// When a UNC share name contains a character that is not allowed in a share name.
+    InvalidShare,
+}
+
+impl fmt::Display for WindowsPrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDrive => write!(f, "prefix drive is not an ASCII letter"),
+            Self::EmptyServer => write!(f, "prefix has an empty server name"),
+            Self::InvalidServer => write!(f, "prefix server name contains invalid characters"),
+            Self::InvalidServerSuffix => write!(f, "prefix server name has an invalid @ suffix"),
+            Self::EmptyShare => write!(f, "prefix has an empty share name"),
+            Self::InvalidShare => write!(f, "prefix share name contains invalid characters"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WindowsPrefixError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for WindowsPrefixError {}
+
+/// An error returned when a UNC path could not be assembled.
+///
+/// This `enum` is created by [`WindowsPathBuf::unc`] and [`Utf8WindowsPathBuf::unc`]. See their
+/// documentation for more.
+///
+/// [`WindowsPathBuf::unc`]: crate::WindowsPathBuf::unc
+/// [`Utf8WindowsPathBuf::unc`]: crate::Utf8WindowsPathBuf::unc
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UncPathError {
+    /// When the server or share name is invalid.
+    InvalidPrefix(WindowsPrefixError),
+
+    /// When the path relative to the share cannot be pushed onto it.
+    InvalidPath(CheckedPathError),
+}
+
+impl From<WindowsPrefixError> for UncPathError {
+    fn from(error: WindowsPrefixError) -> Self {
+        Self::InvalidPrefix(error)
+    }
+}
+
+impl From<CheckedPathError> for UncPathError {
+    fn from(error: CheckedPathError) -> Self {
+        Self::InvalidPath(error)
+    }
+}
+
+impl fmt::Display for UncPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPrefix(error) => write!(f, "{error}"),
+            Self::InvalidPath(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UncPathError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidPrefix(error) => Some(error),
+            Self::InvalidPath(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for UncPathError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidPrefix(error) => Some(error),
+            Self::InvalidPath(error) => Some(error),
+        }
+    }
+}
+
+/// An error returned when one of a collection of paths being rebased does not start with the base
+/// path, identifying which path failed.
+///
+/// This `struct` is created by the [`RebaseAll`] iterator. See its documentation for more.
+///
+/// [`RebaseAll`]: crate::convert::RebaseAll
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RebaseError {
+    pub(crate) index: usize,
+}
+
+impl RebaseError {
+    /// Returns the position of the failing path within the collection, starting at zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for RebaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "path at index {} is not under the base path", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RebaseError {}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl core::error::Error for RebaseError {}
+
+/// An error returned when a path cannot be resolved through a [`Vfs`].
+///
+/// This `enum` is created by [`Path::realpath_with`]. See its documentation for more.
+///
+/// [`Vfs`]: crate::Vfs
+/// [`Path::realpath_with`]: crate::Path::realpath_with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RealpathError<E> {
+    /// When the path to resolve, or the target of a symlink followed while resolving it, is not
+    /// absolute.
+    NotAbsolute,
+
+    /// When a component other than the last one is neither a directory nor a symlink.
+    NotADirectory,
+
+    /// When more symlinks were followed than [`MAX_SYMLINKS`] allows, which usually means that
+    /// they form a loop. This is the equivalent of `ELOOP`.
+    ///
+    /// [`MAX_SYMLINKS`]: crate::MAX_SYMLINKS
+    TooManyLinks,
+
+    /// When the [`Vfs`] itself failed to look up a path.
+    ///
+    /// [`Vfs`]: crate::Vfs
+    Vfs(E),
+}
+
+impl<E: fmt::Display> fmt::Display for RealpathError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAbsolute => write!(f, "path is not absolute"),
+            Self::NotADirectory => write!(f, "path component is not a directory"),
+            Self::TooManyLinks => write!(f, "too many levels of symbolic links"),
+            Self::Vfs(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for RealpathError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Vfs(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(feature = "core-error", not(feature = "std")))]
+impl<E: core::error::Error + 'static> core::error::Error for RealpathError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Vfs(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// An error returned when a path does not fit within the capacity of a [`FixedPathBuf`].
+///
+/// [`FixedPathBuf`]: crate::FixedPathBuf
+#[cfg(feature = "heapless")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError(pub(crate) ());
+
+#[cfg(feature = "heapless")]
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "path exceeds fixed capacity")
+    }
+}
+
+#[cfg(all(feature = "heapless", feature = "std"))]
+impl std::error::Error for CapacityError {}
+
+#[cfg(all(feature = "heapless", feature = "core-error", not(feature = "std")))]
+impl core::error::Error for CapacityError {}
+
+/// An error returned when a spec cannot be parsed as a [`RemotePath`].
+///
+/// [`RemotePath`]: crate::RemotePath
+#[cfg(feature = "remote")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RemotePathError {
+    /// When the spec refers to a local path rather than one on a remote host.
+    NotRemote,
+
+    /// When the spec has a separator but no host, e.g. `user@:path`.
+    EmptyHost,
+
+    /// When a `[` starting an IPv6 address is never closed.
+    UnclosedBracket,
+}
+
+#[cfg(feature = "remote")]
+impl fmt::Display for RemotePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotRemote => write!(f, "spec does not refer to a remote host"),
+            Self::EmptyHost => write!(f, "remote spec has an empty host"),
+            Self::UnclosedBracket => write!(f, "remote spec has an unclosed bracket"),
+        }
+    }
+}
+
+#[cfg(all(feature = "remote", feature = "std"))]
+impl std::error::Error for RemotePathError {}
+
+#[cfg(all(feature = "remote", feature = "core-error", not(feature = "std")))]
+impl core::error::Error for RemotePathError {}
+
+/// An error returned when converting between a Windows path and a wide (UTF-16) string.
+///
+/// This `enum` is created by [`WindowsPath::to_wide_null`] and
+/// [`Utf8WindowsPathBuf::from_pcwstr`]. See their documentation for more.
+///
+/// [`WindowsPath::to_wide_null`]: crate::WindowsPath::to_wide_null
+/// [`Utf8WindowsPathBuf::from_pcwstr`]: crate::Utf8WindowsPathBuf::from_pcwstr
+#[cfg(feature = "windows-interop")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WideStringError {
+    /// When the path is not valid WTF-8, or the wide string has an unpaired surrogate that a
+    /// UTF-8 path cannot hold, so it cannot be converted without changing it.
+    InvalidUnicode,
+
+    /// When the path contains a NUL character, which would end the wide string early.
+    InteriorNul,
+}
+
+#[cfg(feature = "windows-interop")]
+impl fmt::Display for WideStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUnicode => write!(f, "path is not valid unicode"),
+            Self::InteriorNul => write!(f, "path contains a NUL character"),
+        }
+    }
+}
+
+#[cfg(all(feature = "windows-interop", feature = "std"))]
+impl std::error::Error for WideStringError {}
+
+#[cfg(all(
+    feature = "windows-interop",
+    feature = "core-error",
+    not(feature = "std")
+))]
+impl core::error::Error for WideStringError {}
@@ -1,20 +1,40 @@
+mod buffer;
+mod comparable;
 mod components;
+mod diff;
+#[cfg(feature = "heapless")]
+mod fixed;
 mod iter;
+mod parsed;
 mod path;
 mod pathbuf;
+mod rel;
+mod trie;
 
 #[macro_use]
 pub(crate) mod parser;
 
+use core::cmp::Ordering;
 use core::hash::Hasher;
 
+pub use comparable::*;
 pub use components::*;
+pub use diff::*;
+#[cfg(feature = "heapless")]
+pub use fixed::*;
 pub use iter::*;
+pub use parsed::*;
 pub use parser::ParseError;
 pub use path::*;
 pub use pathbuf::*;
+pub use rel::*;
+pub use trie::*;
+
+pub(crate) use buffer::{replace_tail, PushBuffer};
+pub(crate) use path::helpers;
 
 use crate::common::errors::CheckedPathError;
+use crate::common::RootKind;
 use crate::no_std_compat::*;
 use crate::private;
 
@@ -32,9 +52,20 @@ pub trait Encoding<'a>: private::Sealed {
     /// Hashes a byte slice (`path`)
     fn hash<H: Hasher>(path: &[u8], h: &mut H);
 
+    /// Compares two byte slices (`path` and `other`) as paths.
+    ///
+    /// This is guaranteed to produce the same [`Ordering`] as comparing the [`Components`] of
+    /// each path, but may skip parsing the bytes that both paths have in common.
+    fn cmp(path: &[u8], other: &[u8]) -> Ordering;
+
     /// Pushes a byte slice (`path`) onto the an existing path (`current_path`)
     fn push(current_path: &mut Vec<u8>, path: &[u8]);
 
+    /// Like [`Encoding::push`], but onto storage that may have a fixed capacity, returning false
+    /// and leaving `current_path` unchanged if the result would not fit.
+    #[doc(hidden)]
+    fn try_push<B: PushBuffer>(current_path: &mut B, path: &[u8]) -> bool;
+
     /// Like [`Encoding::push`], but enforces several new rules:
     ///
     /// 1. `path` cannot contain a prefix component.
@@ -42,4 +73,15 @@ pub trait Encoding<'a>: private::Sealed {
     /// 3. `path` cannot contain invalid filename bytes.
     /// 4. `path` cannot contain parent components such that the current path would be escaped.
     fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError>;
+
+    /// Attempts to determine whether `base` is a prefix of `path` using only byte comparisons,
+    /// without parsing either into components.
+    ///
+    /// Returns `Some(true)` if `base` is known to be a component-wise prefix of `path`, and
+    /// `None` if a cheap answer is not possible, in which case callers must fall back to
+    /// comparing components.
+    fn starts_with_fast(path: &[u8], base: &[u8]) -> Option<bool>;
+
+    /// Describes how a byte slice (`path`) is anchored.
+    fn root_kind(path: &[u8]) -> RootKind;
 }
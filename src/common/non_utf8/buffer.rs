@@ -0,0 +1,65 @@
+use crate::no_std_compat::*;
+
+/// Storage for the bytes of a path that [`Encoding::try_push`] can write into, which may have a
+/// fixed capacity.
+///
+/// This is only nameable within the crate, letting fixed-capacity paths share the push logic of
+/// each encoding without allocating.
+///
+/// [`Encoding::try_push`]: crate::Encoding::try_push
+pub trait PushBuffer: Default {
+    /// Returns the bytes currently held.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Returns true if the buffer can hold `len` bytes in total.
+    fn fits(&self, len: usize) -> bool;
+
+    /// Shortens the buffer to `len` bytes.
+    fn truncate(&mut self, len: usize);
+
+    /// Appends `bytes`, which must fit as checked with [`PushBuffer::fits`].
+    fn extend(&mut self, bytes: &[u8]);
+}
+
+impl PushBuffer for Vec<u8> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn fits(&self, _len: usize) -> bool {
+        true
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len)
+    }
+
+    #[inline]
+    fn extend(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes)
+    }
+}
+
+/// Replaces everything in `buffer` after the first `keep` bytes with `separator`, if any,
+/// followed by `path`, returning false without changing `buffer` if the result would not fit.
+pub(crate) fn replace_tail<B: PushBuffer>(
+    buffer: &mut B,
+    keep: usize,
+    separator: Option<u8>,
+    path: &[u8],
+) -> bool {
+    let len = keep + usize::from(separator.is_some()) + path.len();
+    if !buffer.fits(len) {
+        return false;
+    }
+
+    buffer.truncate(keep);
+    if let Some(separator) = separator {
+        buffer.extend(&[separator]);
+    }
+    buffer.extend(path);
+    true
+}
@@ -0,0 +1,380 @@
+use alloc::borrow::Cow;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::{Component, Components, Encoding, Path, PathBuf};
+
+/// Rules deciding when two paths are considered the same by a [`ComparablePath`].
+///
+/// Paths are always compared component by component, so repeated separators and `.` components
+/// never matter (see [`Path::components`]). On top of that, the rules decide how each component
+/// is folded before being compared and whether a trailing separator is significant.
+///
+/// Custom rules can be written to apply folding that this crate does not provide, such as
+/// Unicode normalization:
+///
+/// ```
+/// use std::borrow::Cow;
+/// use typed_path::{ComparablePath, ComparisonRules, UnixEncoding};
+///
+/// /// Treats `-` and `_` as the same character
+/// struct DashInsensitive;
+///
+/// impl ComparisonRules for DashInsensitive {
+///     const TRAILING_SEPARATOR_SIGNIFICANT: bool = false;
+///
+///     fn fold_component(component: &[u8]) -> Cow<'_, [u8]> {
+///         if component.contains(&b'-') {
+///             Cow::Owned(component.iter().map(|b| if *b == b'-' { b'_' } else { *b }).collect())
+///         } else {
+///             Cow::Borrowed(component)
+///         }
+///     }
+/// }
+///
+/// let a = ComparablePath::<UnixEncoding, DashInsensitive>::new("my-crate/src");
+/// let b = ComparablePath::<UnixEncoding, DashInsensitive>::new("my_crate/src/");
+/// assert_eq!(a, b);
+/// ```
+pub trait ComparisonRules {
+    /// Whether `foo/` and `foo` are considered different paths.
+    const TRAILING_SEPARATOR_SIGNIFICANT: bool;
+
+    /// Folds a single component into the form used for hashing and comparison.
+    fn fold_component(component: &[u8]) -> Cow<'_, [u8]>;
+}
+
+/// Compares components byte for byte, with a trailing separator being significant.
+///
+/// This is the strictest of the provided rules, but like every [`ComparisonRules`] it still
+/// compares the parsed [`components`](Path::components) rather than the raw bytes. Repeated
+/// separators, `.` components other than a leading one, and on Windows the choice between `/`
+/// and `\` are ignored, so `a//./b` and `a/b` are equal. Wrap the path in a [`PathBuf`] to
+/// compare its bytes exactly.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ExactRules;
+
+impl ComparisonRules for ExactRules {
+    const TRAILING_SEPARATOR_SIGNIFICANT: bool = true;
+
+    #[inline]
+    fn fold_component(component: &[u8]) -> Cow<'_, [u8]> {
+        Cow::Borrowed(component)
+    }
+}
+
+/// Compares components byte for byte, ignoring a trailing separator.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnixRules;
+
+impl ComparisonRules for UnixRules {
+    const TRAILING_SEPARATOR_SIGNIFICANT: bool = false;
+
+    #[inline]
+    fn fold_component(component: &[u8]) -> Cow<'_, [u8]> {
+        Cow::Borrowed(component)
+    }
+}
+
+/// Compares components ignoring ASCII case, ignoring a trailing separator.
+///
+/// This approximates how Windows file systems identify paths. ASCII letters are folded to
+/// uppercase, so paths are ordered the same way as by [`WindowsPath::cmp_ignore_case`] and
+/// [`WindowsPathKey`]. Non-ASCII characters are compared as-is.
+///
+/// [`WindowsPath::cmp_ignore_case`]: crate::WindowsPath::cmp_ignore_case
+/// [`WindowsPathKey`]: crate::WindowsPathKey
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WindowsRules;
+
+impl ComparisonRules for WindowsRules {
+    const TRAILING_SEPARATOR_SIGNIFICANT: bool = false;
+
+    fn fold_component(component: &[u8]) -> Cow<'_, [u8]> {
+        if component.iter().any(u8::is_ascii_lowercase) {
+            Cow::Owned(component.to_ascii_uppercase())
+        } else {
+            Cow::Borrowed(component)
+        }
+    }
+}
+
+/// An owned path whose [`Hash`], [`Eq`], and [`Ord`] implementations follow the
+/// [`ComparisonRules`] `R` instead of comparing components exactly.
+///
+/// This is useful as a key in maps and sets that need to deduplicate paths the way a particular
+/// platform would, without writing a wrapper for each set of semantics.
+///
+/// `ComparablePath` implements [`Deref`] to [`Path`], meaning that all methods on [`Path`]
+/// slices are available on `ComparablePath` values as well.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use typed_path::{ComparablePath, ExactRules, WindowsEncoding, WindowsRules};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let mut set = HashSet::new();
+/// assert!(set.insert(ComparablePath::<WindowsEncoding, WindowsRules>::new(r"C:\Temp\Log.txt")));
+/// assert!(!set.insert(ComparablePath::new(r"c:\temp\log.TXT")));
+/// assert!(!set.insert(ComparablePath::new(r"C:\\Temp\.\log.txt")));
+///
+/// // Exact rules keep case and trailing separators significant
+/// let a = ComparablePath::<WindowsEncoding, ExactRules>::new(r"C:\Temp");
+/// assert_ne!(a, ComparablePath::new(r"C:\temp"));
+/// assert_ne!(a, ComparablePath::new(r"C:\Temp\"));
+/// ```
+pub struct ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    inner: PathBuf<T>,
+    _rules: PhantomData<fn() -> R>,
+}
+
+impl<T, R> ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    /// Wraps a path so that it is compared using the rules `R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ComparablePath, UnixEncoding, UnixRules};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = ComparablePath::<UnixEncoding, UnixRules>::new("/tmp/");
+    /// assert_eq!(path, ComparablePath::new("/tmp"));
+    /// ```
+    pub fn new(path: impl Into<PathBuf<T>>) -> Self {
+        Self {
+            inner: path.into(),
+            _rules: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped path.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        &self.inner
+    }
+
+    /// Consumes the `ComparablePath`, returning the wrapped [`PathBuf`].
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.inner
+    }
+
+    /// Returns the folded components of the path, followed by an empty marker if the path has
+    /// a significant trailing separator.
+    fn keys(&self) -> impl Iterator<Item = Cow<'_, [u8]>> {
+        let trailing = if R::TRAILING_SEPARATOR_SIGNIFICANT && has_trailing_separator(&self.inner) {
+            Some(Cow::Borrowed(&[][..]))
+        } else {
+            None
+        };
+
+        self.inner
+            .components()
+            .map(|component| R::fold_component(component.as_bytes()))
+            .chain(trailing)
+    }
+}
+
+/// Returns true if the path ends in a separator that follows a non-root component.
+fn has_trailing_separator<T>(path: &Path<T>) -> bool
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let root = <<<T as Encoding>::Components as Components>::Component as Component>::root();
+    let ends_with_separator = match path.as_bytes().last() {
+        Some(b'/') => true,
+        Some(b) => root.as_bytes() == [*b],
+        None => false,
+    };
+
+    ends_with_separator
+        && path
+            .components()
+            .next_back()
+            .map_or(false, |c| c.is_normal() || c.is_current() || c.is_parent())
+}
+
+impl<T, R> Clone for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<T, R> fmt::Debug for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComparablePath")
+            .field("encoding", &T::label())
+            .field("path", &self.inner.to_string_lossy())
+            .finish()
+    }
+}
+
+impl<T, R> Deref for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    type Target = Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, R> AsRef<Path<T>> for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        &self.inner
+    }
+}
+
+impl<T, R> From<PathBuf<T>> for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    #[inline]
+    fn from(path: PathBuf<T>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl<T, R> PartialEq for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.keys().eq(other.keys())
+    }
+}
+
+impl<T, R> Eq for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+}
+
+impl<T, R> Hash for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for key in self.keys() {
+            state.write_usize(key.len());
+            state.write(&key);
+        }
+    }
+}
+
+impl<T, R> PartialOrd for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, R> Ord for ComparablePath<T, R>
+where
+    T: for<'enc> Encoding<'enc>,
+    R: ComparisonRules,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.keys().cmp(other.keys())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    #[test]
+    fn trailing_separator_should_only_matter_for_non_root_paths() {
+        type Exact = ComparablePath<UnixEncoding, ExactRules>;
+
+        assert_ne!(Exact::new("foo"), Exact::new("foo/"));
+        assert_eq!(Exact::new("foo//"), Exact::new("foo/"));
+        assert_eq!(Exact::new("/"), Exact::new("//"));
+
+        type Windows = ComparablePath<WindowsEncoding, ExactRules>;
+        assert_ne!(Windows::new(r"C:\foo"), Windows::new(r"C:\foo\"));
+        assert_eq!(Windows::new(r"C:\foo/"), Windows::new(r"C:\foo\"));
+        assert_eq!(Windows::new(r"C:\"), Windows::new(r"C:\"));
+    }
+
+    #[test]
+    fn ordering_should_be_consistent_with_equality() {
+        type Windows = ComparablePath<WindowsEncoding, WindowsRules>;
+
+        assert_eq!(
+            Windows::new(r"C:\FOO").cmp(&Windows::new(r"c:\foo\")),
+            cmp::Ordering::Equal
+        );
+        assert!(Windows::new(r"C:\a\b") < Windows::new(r"C:\B"));
+        assert!(Windows::new(r"C:\a") < Windows::new(r"C:\a\b"));
+    }
+
+    #[test]
+    fn windows_rules_should_order_like_cmp_ignore_case() {
+        type Windows = ComparablePath<WindowsEncoding, WindowsRules>;
+
+        let paths = [r"C:\_", r"C:\a", r"C:\B", r"C:\[x]", r"C:\z~", r"C:\Z`"];
+        for a in paths {
+            for b in paths {
+                assert_eq!(
+                    Windows::new(a).cmp(&Windows::new(b)),
+                    crate::WindowsPath::new(a).cmp_ignore_case(crate::WindowsPath::new(b)),
+                    "{a} vs {b}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hash_should_be_consistent_with_equality() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        type Windows = ComparablePath<WindowsEncoding, WindowsRules>;
+        assert_eq!(
+            hash_of(&Windows::new(r"C:\Program Files\App")),
+            hash_of(&Windows::new(r"c:/program files/APP/")),
+        );
+    }
+}
@@ -9,6 +9,25 @@ pub trait Component<'a>:
     AsRef<[u8]> + Clone + fmt::Debug + PartialEq + Eq + PartialOrd + Ord + private::Sealed
 {
     /// Extracts the underlying [`[u8]`] slice
+    ///
+    /// For a prefix, this is the raw slice as it appeared in the path (e.g. a lowercase drive
+    /// letter is preserved). Pushing the slice of every component of a path, in order, onto an
+    /// empty [`PathBuf`] reproduces a path equal to the original.
+    ///
+    /// [`PathBuf`]: crate::PathBuf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, WindowsEncoding, WindowsPath, WindowsPathBuf};
+    ///
+    /// let path = WindowsPath::new(r"\\?\UNC\server\share\dir\file.txt");
+    /// let mut rebuilt = WindowsPathBuf::new();
+    /// for component in path.components() {
+    ///     rebuilt.push(component.as_bytes());
+    /// }
+    /// assert_eq!(rebuilt, path);
+    /// ```
     fn as_bytes(&self) -> &'a [u8];
 
     /// Returns true if this component is the root component, meaning
@@ -0,0 +1,174 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::no_std_compat::*;
+use crate::{ComparablePath, ComparisonRules, Encoding, PathBuf};
+
+/// The differences between two sets of paths, such as two snapshots of a directory tree.
+///
+/// Paths are matched using the [`ComparisonRules`] given to [`PathDiff::new`]. A path that
+/// appears on only one side is reported as added or removed, unless its file name matches
+/// exactly one other such path on the opposite side, in which case the pair is reported as
+/// moved instead. Matching by name is only a heuristic: callers that can compare file contents
+/// should confirm each candidate before treating it as a move.
+///
+/// Each list is sorted in the order defined by the rules, and duplicate paths are only
+/// reported once.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathDiff, UnixEncoding, UnixPathBuf, UnixRules, WindowsEncoding, WindowsRules};
+///
+/// let old = ["src/lib.rs", "src/util.rs", "README.md"];
+/// let new = ["src/lib.rs", "src/common/util.rs", "CHANGELOG.md"];
+/// let diff = PathDiff::<UnixEncoding>::new(old, new, UnixRules);
+///
+/// assert_eq!(diff.added(), [UnixPathBuf::from("CHANGELOG.md")]);
+/// assert_eq!(diff.removed(), [UnixPathBuf::from("README.md")]);
+/// assert_eq!(
+///     diff.moved(),
+///     [(UnixPathBuf::from("src/util.rs"), UnixPathBuf::from("src/common/util.rs"))]
+/// );
+///
+/// // Windows rules treat paths differing only in case as the same path
+/// let old = [r"C:\Temp\A.txt"];
+/// let new = [r"c:\temp\a.TXT"];
+/// let diff = PathDiff::<WindowsEncoding>::new(old, new, WindowsRules);
+/// assert!(diff.is_empty());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathDiff<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    added: Vec<PathBuf<T>>,
+    removed: Vec<PathBuf<T>>,
+    moved: Vec<(PathBuf<T>, PathBuf<T>)>,
+}
+
+impl<T> PathDiff<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Computes the differences going from the `old` paths to the `new` paths, comparing paths
+    /// and file names using `rules`.
+    pub fn new<R, I, J>(old: I, new: J, _rules: R) -> Self
+    where
+        R: ComparisonRules,
+        I: IntoIterator,
+        I::Item: Into<PathBuf<T>>,
+        J: IntoIterator,
+        J::Item: Into<PathBuf<T>>,
+    {
+        let old: BTreeSet<ComparablePath<T, R>> =
+            old.into_iter().map(ComparablePath::new).collect();
+        let new: BTreeSet<ComparablePath<T, R>> =
+            new.into_iter().map(ComparablePath::new).collect();
+
+        let mut removed: Vec<Option<&ComparablePath<T, R>>> =
+            old.difference(&new).map(Some).collect();
+        let mut added: Vec<Option<&ComparablePath<T, R>>> =
+            new.difference(&old).map(Some).collect();
+
+        // Group the indexes of removed and added paths by their file name
+        let mut by_name: BTreeMap<ComparablePath<T, R>, (Vec<usize>, Vec<usize>)> = BTreeMap::new();
+        for (i, path) in removed.iter().enumerate() {
+            if let Some(name) = path.and_then(|p| p.file_name()) {
+                by_name
+                    .entry(ComparablePath::new(name))
+                    .or_default()
+                    .0
+                    .push(i);
+            }
+        }
+        for (i, path) in added.iter().enumerate() {
+            if let Some(name) = path.and_then(|p| p.file_name()) {
+                by_name
+                    .entry(ComparablePath::new(name))
+                    .or_default()
+                    .1
+                    .push(i);
+            }
+        }
+
+        let mut moved = Vec::new();
+        for (from, to) in by_name.into_values() {
+            if let ([from], [to]) = (from.as_slice(), to.as_slice()) {
+                if let (Some(from), Some(to)) = (removed[*from].take(), added[*to].take()) {
+                    moved.push((from, to));
+                }
+            }
+        }
+        moved.sort();
+
+        Self {
+            added: added
+                .into_iter()
+                .flatten()
+                .map(|p| p.to_path_buf())
+                .collect(),
+            removed: removed
+                .into_iter()
+                .flatten()
+                .map(|p| p.to_path_buf())
+                .collect(),
+            moved: moved
+                .into_iter()
+                .map(|(from, to)| (from.to_path_buf(), to.to_path_buf()))
+                .collect(),
+        }
+    }
+
+    /// Returns the paths only present in the new set that were not matched as a move.
+    #[inline]
+    pub fn added(&self) -> &[PathBuf<T>] {
+        &self.added
+    }
+
+    /// Returns the paths only present in the old set that were not matched as a move.
+    #[inline]
+    pub fn removed(&self) -> &[PathBuf<T>] {
+        &self.removed
+    }
+
+    /// Returns pairs of old and new paths that share a file name and are likely to be the same
+    /// file having moved.
+    #[inline]
+    pub fn moved(&self) -> &[(PathBuf<T>, PathBuf<T>)] {
+        &self.moved
+    }
+
+    /// Returns true if both sets contained the same paths.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExactRules, UnixEncoding, UnixPathBuf, UnixRules};
+
+    #[test]
+    fn ambiguous_file_names_should_not_be_reported_as_moved() {
+        let old = ["a/mod.rs", "b/mod.rs"];
+        let new = ["c/mod.rs", "d/mod.rs"];
+        let diff = PathDiff::<UnixEncoding>::new(old, new, ExactRules);
+
+        assert!(diff.moved().is_empty());
+        assert_eq!(diff.removed().len(), 2);
+        assert_eq!(diff.added().len(), 2);
+    }
+
+    #[test]
+    fn rules_should_decide_whether_paths_are_the_same() {
+        let diff = PathDiff::<UnixEncoding>::new(["a/b/"], ["a/b"], ExactRules);
+        assert_eq!(
+            diff.moved(),
+            [(UnixPathBuf::from("a/b/"), UnixPathBuf::from("a/b"))]
+        );
+
+        let diff = PathDiff::<UnixEncoding>::new(["a/b/", "a//b"], ["a/b"], UnixRules);
+        assert!(diff.is_empty());
+    }
+}
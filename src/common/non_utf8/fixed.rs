@@ -0,0 +1,422 @@
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::common::errors::CapacityError;
+use crate::common::PushBuffer;
+use crate::{Encoding, Path, PathBuf};
+
+/// An owned, mutable path whose bytes are stored inline with a fixed capacity of `N` bytes.
+///
+/// Unlike [`PathBuf`], this type never grows beyond `N` bytes. Operations that would exceed the
+/// capacity fail with a [`CapacityError`] and leave the path untouched. The bytes are held in a
+/// [`heapless::Vec`], which can be retrieved with [`into_inner`].
+///
+/// `FixedPathBuf` implements [`Deref`] to [`Path`], meaning that all methods on [`Path`] slices
+/// are available on `FixedPathBuf` values as well.
+///
+/// [`into_inner`]: FixedPathBuf::into_inner
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{FixedPathBuf, UnixEncoding, UnixPath};
+///
+/// let mut path = FixedPathBuf::<UnixEncoding, 16>::new();
+/// path.try_push("/tmp").unwrap();
+/// path.try_push("foo.txt").unwrap();
+/// assert_eq!(path.as_path(), UnixPath::new("/tmp/foo.txt"));
+///
+/// // Pushing beyond the capacity fails without modifying the path
+/// assert!(path.try_push("some/much/longer/path").is_err());
+/// assert_eq!(path.as_path(), UnixPath::new("/tmp/foo.txt"));
+/// ```
+pub struct FixedPathBuf<T, const N: usize>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Encoding associated with path buf
+    _encoding: PhantomData<T>,
+
+    /// Path as an unparsed collection of bytes
+    inner: heapless::Vec<u8, N>,
+}
+
+impl<T, const N: usize> FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a new, empty `FixedPathBuf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FixedPathBuf, UnixEncoding};
+    ///
+    /// let path = FixedPathBuf::<UnixEncoding, 32>::new();
+    /// assert!(path.as_bytes().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: heapless::Vec::new(),
+        }
+    }
+
+    /// Creates a new `FixedPathBuf` holding the bytes of `path`, failing if they do not fit
+    /// within `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FixedPathBuf, WindowsEncoding};
+    ///
+    /// assert!(FixedPathBuf::<WindowsEncoding, 8>::try_from_path(r"C:\temp").is_ok());
+    /// assert!(FixedPathBuf::<WindowsEncoding, 8>::try_from_path(r"C:\windows").is_err());
+    /// ```
+    pub fn try_from_path<P: AsRef<Path<T>>>(path: P) -> Result<Self, CapacityError> {
+        let mut inner = heapless::Vec::new();
+        inner
+            .extend_from_slice(path.as_ref().as_bytes())
+            .map_err(|_| CapacityError(()))?;
+
+        Ok(Self {
+            _encoding: PhantomData,
+            inner,
+        })
+    }
+
+    /// Coerces to a [`Path`] slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FixedPathBuf, UnixEncoding, UnixPath};
+    ///
+    /// let p = FixedPathBuf::<UnixEncoding, 16>::try_from_path("/test").unwrap();
+    /// assert_eq!(UnixPath::new("/test"), p.as_path());
+    /// ```
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        Path::new(self.inner.as_slice())
+    }
+
+    /// Returns the maximum number of bytes that the path can hold, which is always `N`.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Extends `self` with `path`, following the same rules as [`PathBuf::push`], failing if the
+    /// resulting path would not fit within `N` bytes.
+    ///
+    /// On failure, `self` is left unchanged. The path is updated in place without allocating,
+    /// except when pushing onto a Windows verbatim path, which is rebuilt in a second inline
+    /// buffer of the same capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FixedPathBuf, UnixEncoding, UnixPath};
+    ///
+    /// let mut path = FixedPathBuf::<UnixEncoding, 10>::try_from_path("/tmp").unwrap();
+    /// path.try_push("file").unwrap();
+    /// assert_eq!(path.as_path(), UnixPath::new("/tmp/file"));
+    ///
+    /// // Pushing an absolute path replaces the current one
+    /// path.try_push("/etc").unwrap();
+    /// assert_eq!(path.as_path(), UnixPath::new("/etc"));
+    ///
+    /// assert!(path.try_push("passwd").is_err());
+    /// assert_eq!(path.as_path(), UnixPath::new("/etc"));
+    /// ```
+    pub fn try_push<P: AsRef<Path<T>>>(&mut self, path: P) -> Result<(), CapacityError> {
+        if T::try_push(&mut self.inner, path.as_ref().as_bytes()) {
+            Ok(())
+        } else {
+            Err(CapacityError(()))
+        }
+    }
+
+    /// Truncates `self` to [`self.parent`].
+    ///
+    /// Returns `false` and does nothing if [`self.parent`] is [`None`].
+    /// Otherwise, returns `true`.
+    ///
+    /// [`self.parent`]: Path::parent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FixedPathBuf, UnixEncoding, UnixPath};
+    ///
+    /// let mut p = FixedPathBuf::<UnixEncoding, 32>::try_from_path("/spirited/away.rs").unwrap();
+    ///
+    /// p.pop();
+    /// assert_eq!(UnixPath::new("/spirited"), p.as_path());
+    /// p.pop();
+    /// assert_eq!(UnixPath::new("/"), p.as_path());
+    /// ```
+    pub fn pop(&mut self) -> bool {
+        match self.parent().map(|p| p.as_bytes().len()) {
+            Some(len) => {
+                self.inner.truncate(len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Truncates the path to be empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear()
+    }
+
+    /// Consumes the `FixedPathBuf`, yielding its internal [`heapless::Vec`] storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{FixedPathBuf, UnixEncoding};
+    ///
+    /// let p = FixedPathBuf::<UnixEncoding, 8>::try_from_path("/etc").unwrap();
+    /// let bytes: heapless::Vec<u8, 8> = p.into_inner();
+    /// assert_eq!(bytes, b"/etc");
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> heapless::Vec<u8, N> {
+        self.inner
+    }
+
+    /// Converts this `FixedPathBuf` into a heap-allocated [`PathBuf`].
+    #[inline]
+    pub fn to_path_buf(&self) -> PathBuf<T> {
+        self.as_path().to_path_buf()
+    }
+}
+
+impl<const N: usize> PushBuffer for heapless::Vec<u8, N> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline]
+    fn fits(&self, len: usize) -> bool {
+        len <= N
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        heapless::Vec::truncate(self, len)
+    }
+
+    #[inline]
+    fn extend(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes)
+            .expect("checked that the bytes fit");
+    }
+}
+
+impl<T, const N: usize> Clone for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _encoding: PhantomData,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedPathBuf")
+            .field("encoding", &T::label())
+            .field("path", &self.as_path().to_string_lossy())
+            .finish()
+    }
+}
+
+impl<T, const N: usize> AsRef<[u8]> for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<Path<T>> for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T, const N: usize> Default for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Target = Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl<T, const N: usize> From<FixedPathBuf<T, N>> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: FixedPathBuf<T, N>) -> Self {
+        path.to_path_buf()
+    }
+}
+
+impl<T, const N: usize> PartialEq for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl<T, const N: usize> Eq for FixedPathBuf<T, N> where T: for<'enc> Encoding<'enc> {}
+
+impl<T, const N: usize> PartialEq<Path<T>> for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Path<T>) -> bool {
+        self.as_path() == other
+    }
+}
+
+impl<T, const N: usize> PartialEq<PathBuf<T>> for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &PathBuf<T>) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl<T, const N: usize> Hash for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.as_path().hash(h)
+    }
+}
+
+impl<T, const N: usize> cmp::PartialOrd for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, const N: usize> cmp::Ord for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_path().cmp(other.as_path())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, const N: usize> defmt::Format for FixedPathBuf<T, N>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_path(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WindowsEncoding, WindowsPathBuf};
+
+    #[test]
+    fn try_push_should_match_pathbuf_push() {
+        let cases: &[(&str, &str)] = &[
+            (r"C:\dir", "file.txt"),
+            (r"C:", "file.txt"),
+            (r"C:\dir", r"\other"),
+            (r"\\?\C:\dir", r"..\file.txt"),
+            (r"\\?\C:\a\b", r"..\..\..\c\.\d"),
+            (r"\\?\UNC\server\share\a", r"\x\y"),
+            (r"dir", r"D:\abs"),
+        ];
+
+        for (base, pushed) in cases {
+            let mut expected = WindowsPathBuf::from(*base);
+            expected.push(pushed);
+
+            let mut path = FixedPathBuf::<WindowsEncoding, 64>::try_from_path(base).unwrap();
+            path.try_push(pushed).unwrap();
+
+            assert_eq!(
+                path.as_bytes(),
+                expected.as_bytes(),
+                "{base:?} + {pushed:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn try_push_should_fail_without_modifying_when_exceeding_capacity() {
+        let mut path = FixedPathBuf::<WindowsEncoding, 6>::try_from_path(r"C:\dir").unwrap();
+        assert_eq!(path.try_push("file"), Err(CapacityError(())));
+        assert_eq!(path.as_bytes(), br"C:\dir");
+
+        let mut path = FixedPathBuf::<WindowsEncoding, 12>::try_from_path(r"\\?\C:\dir").unwrap();
+        assert_eq!(path.try_push(r"..\longer"), Err(CapacityError(())));
+        assert_eq!(path.as_bytes(), br"\\?\C:\dir");
+    }
+
+    #[test]
+    fn debug_should_include_encoding_label_and_lossy_path() {
+        let path =
+            FixedPathBuf::<WindowsEncoding, 16>::try_from_path(Path::new(b"C:\\\xFFfile")).unwrap();
+        assert_eq!(
+            format!("{path:?}"),
+            "FixedPathBuf { encoding: \"windows\", path: \"C:\\\\\u{FFFD}file\" }"
+        );
+    }
+}
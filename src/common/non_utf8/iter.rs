@@ -1,6 +1,7 @@
 use core::fmt;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
+use core::ops::Range;
 
 use crate::{Component, Components, Encoding, Path};
 
@@ -123,6 +124,141 @@ where
 
 impl<'a, T> FusedIterator for Iter<'a, T> where T: for<'enc> Encoding<'enc> + 'a {}
 
+/// An iterator over the [`Component`]s of a [`Path`] alongside the byte range that each component
+/// occupies within the original path.
+///
+/// This `struct` is created by the [`components_with_spans`] method on [`Path`].
+/// See its documentation for more.
+///
+/// [`components_with_spans`]: Path::components_with_spans
+#[derive(Clone)]
+pub struct ComponentsWithSpans<'a, T>
+where
+    T: Encoding<'a>,
+{
+    _encoding: PhantomData<T>,
+    inner: <T as Encoding<'a>>::Components,
+    len: usize,
+}
+
+impl<'a, T> ComponentsWithSpans<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(inner: <T as Encoding<'a>>::Components) -> Self {
+        let len = inner.as_bytes().len();
+        Self {
+            _encoding: PhantomData,
+            inner,
+            len,
+        }
+    }
+
+    /// Returns the byte offset within the original path at which iteration will resume.
+    #[inline]
+    fn offset(&self) -> usize {
+        self.len - self.inner.as_bytes().len()
+    }
+}
+
+impl<'a, T> fmt::Debug for ComponentsWithSpans<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(ComponentsWithSpans))
+            .field("remaining", &self.inner.as_bytes())
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for ComponentsWithSpans<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    type Item = (
+        <<T as Encoding<'a>>::Components as Components<'a>>::Component,
+        Range<usize>,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Each component begins exactly where the remaining path did before it was parsed; any
+        // separators or skipped current-directory components that follow it are consumed along
+        // with it. The component's own length is the same as it is in the original path, even
+        // for the root directory, whose separator may be written in a different style.
+        let start = self.offset();
+        let component = self.inner.next()?;
+        let end = start + component.len();
+        Some((component, start..end))
+    }
+}
+
+impl<'a, T> FusedIterator for ComponentsWithSpans<'a, T> where T: for<'enc> Encoding<'enc> + 'a {}
+
+/// An iterator over the [`Component`]s of a [`Path`], each paired with the portion of the path
+/// that precedes it.
+///
+/// This `struct` is created by the [`component_pairs`] method on [`Path`].
+/// See its documentation for more.
+///
+/// [`component_pairs`]: Path::component_pairs
+#[derive(Clone)]
+pub struct ComponentPairs<'a, T>
+where
+    T: Encoding<'a>,
+{
+    path: &'a [u8],
+    inner: ComponentsWithSpans<'a, T>,
+    end: usize,
+}
+
+impl<'a, T> ComponentPairs<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(path: &'a Path<T>) -> Self {
+        Self {
+            path: path.as_bytes(),
+            inner: path.components_with_spans(),
+            end: 0,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for ComponentPairs<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(ComponentPairs))
+            .field("parent", &Path::<T>::new(&self.path[..self.end]))
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for ComponentPairs<'a, T>
+where
+    T: for<'enc> Encoding<'enc> + 'a,
+{
+    type Item = (
+        &'a Path<T>,
+        <<T as Encoding<'a>>::Components as Components<'a>>::Component,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The parent ends where the previous component did, which excludes the separators and
+        // skipped current-directory components between the two
+        let (component, span) = self.inner.next()?;
+        let parent = Path::new(&self.path[..self.end]);
+        self.end = span.end;
+        Some((parent, component))
+    }
+}
+
+impl<'a, T> FusedIterator for ComponentPairs<'a, T> where T: for<'enc> Encoding<'enc> + 'a {}
+
 /// An iterator over [`Path`] and its ancestors.
 ///
 /// This `struct` is created by the [`ancestors`] method on [`Path`].
@@ -0,0 +1,623 @@
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::{Deref, Range};
+use core::{cmp, fmt};
+
+use super::path::helpers;
+use crate::no_std_compat::*;
+use crate::{Component, Components, Encoding, Path, PathBuf};
+
+/// An owned, immutable path that parses its components once when created, caching where each of
+/// them starts and ends.
+///
+/// Methods like [`file_name`], [`parent`], and [`extension`] on [`Path`] parse the underlying bytes
+/// every time that they are called. For long-lived paths that are interrogated repeatedly, this
+/// type performs that work up front so these methods, alongside [`iter`], read from the cached
+/// boundaries instead.
+///
+/// `ParsedPathBuf` implements [`Deref`] to [`Path`], meaning that all other methods on [`Path`]
+/// slices are still available, but will parse the path as usual.
+///
+/// [`file_name`]: ParsedPathBuf::file_name
+/// [`parent`]: ParsedPathBuf::parent
+/// [`extension`]: ParsedPathBuf::extension
+/// [`iter`]: ParsedPathBuf::iter
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{ParsedPathBuf, UnixPath, UnixPathBuf};
+///
+/// let path = ParsedPathBuf::new(UnixPathBuf::from("/tmp/foo.tar.gz"));
+///
+/// assert_eq!(path.file_name(), Some(b"foo.tar.gz".as_slice()));
+/// assert_eq!(path.file_stem(), Some(b"foo.tar".as_slice()));
+/// assert_eq!(path.extension(), Some(b"gz".as_slice()));
+/// assert_eq!(path.parent(), Some(UnixPath::new("/tmp")));
+/// assert_eq!(path.iter().collect::<Vec<_>>(), [b"/".as_slice(), b"tmp", b"foo.tar.gz"]);
+/// ```
+pub struct ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Path whose components have been parsed
+    path: PathBuf<T>,
+
+    /// Information about each component of the path, in order
+    spans: Vec<Span>,
+}
+
+/// Cached information about a single component within a [`ParsedPathBuf`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Span {
+    /// Kind of component, including where its bytes live if they come from the path
+    kind: SpanKind,
+
+    /// Whether the component is considered a root, meaning nothing can come before it
+    is_root: bool,
+
+    /// Length of the path that remains when this component and all following are removed
+    parent_len: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SpanKind {
+    /// Root directory component
+    Root,
+
+    /// Prefix component (e.g. `C:` on Windows) located at the given bytes of the path
+    Prefix(Range<usize>),
+
+    /// Current directory component
+    Current,
+
+    /// Parent directory component
+    Parent,
+
+    /// Normal component located at the given bytes of the path
+    Normal(Range<usize>),
+}
+
+impl<T> ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Parses the components of `path`, caching their boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, WindowsPathBuf};
+    ///
+    /// let path = ParsedPathBuf::new(WindowsPathBuf::from(r"C:\foo\bar.txt"));
+    /// assert_eq!(path.components_len(), 4);
+    /// ```
+    pub fn new(path: PathBuf<T>) -> Self {
+        let spans = Self::parse_spans(path.as_bytes());
+        Self { path, spans }
+    }
+
+    /// Parses `bytes` from the back, recording information about each component in order.
+    fn parse_spans(bytes: &[u8]) -> Vec<Span> {
+        let offset_of = |slice: &[u8]| {
+            let start = slice.as_ptr() as usize - bytes.as_ptr() as usize;
+            start..(start + slice.len())
+        };
+
+        let mut spans = Vec::new();
+        let mut components = T::components(bytes);
+        while let Some(component) = components.next_back() {
+            let kind = if component.is_normal() {
+                SpanKind::Normal(offset_of(component.as_bytes()))
+            } else if component.is_current() {
+                SpanKind::Current
+            } else if component.is_parent() {
+                SpanKind::Parent
+            } else if component == Component::root() {
+                SpanKind::Root
+            } else {
+                SpanKind::Prefix(offset_of(component.as_bytes()))
+            };
+
+            spans.push(Span {
+                kind,
+                is_root: component.is_root(),
+                parent_len: components.as_bytes().len(),
+            });
+        }
+        spans.reverse();
+        spans
+    }
+
+    /// Coerces to a [`Path`] slice.
+    #[inline]
+    pub fn as_path(&self) -> &Path<T> {
+        self.path.as_path()
+    }
+
+    /// Consumes the `ParsedPathBuf`, yielding its internal [`PathBuf`] and discarding the cached
+    /// component boundaries.
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.path
+    }
+
+    /// Returns the total number of components within the path without parsing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, UnixPathBuf};
+    ///
+    /// assert_eq!(ParsedPathBuf::new(UnixPathBuf::from("/tmp/foo.txt")).components_len(), 3);
+    /// assert_eq!(ParsedPathBuf::new(UnixPathBuf::from("")).components_len(), 0);
+    /// ```
+    #[inline]
+    pub fn components_len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns the path without its final component, if there is one, without parsing it.
+    ///
+    /// See [`Path::parent`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, UnixPath, UnixPathBuf};
+    ///
+    /// let path = ParsedPathBuf::new(UnixPathBuf::from("/foo/bar"));
+    /// assert_eq!(path.parent(), Some(UnixPath::new("/foo")));
+    ///
+    /// let path = ParsedPathBuf::new(UnixPathBuf::from("/"));
+    /// assert_eq!(path.parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<&Path<T>> {
+        match self.spans.last() {
+            Some(span) if !span.is_root => {
+                Some(Path::new(&self.path.as_bytes()[..span.parent_len]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the final component of the path, if it is a normal component, without parsing it.
+    ///
+    /// See [`Path::file_name`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, UnixPathBuf};
+    ///
+    /// let path = ParsedPathBuf::new(UnixPathBuf::from("/usr/bin/"));
+    /// assert_eq!(path.file_name(), Some(b"bin".as_slice()));
+    ///
+    /// let path = ParsedPathBuf::new(UnixPathBuf::from("foo.txt/.."));
+    /// assert_eq!(path.file_name(), None);
+    /// ```
+    pub fn file_name(&self) -> Option<&[u8]> {
+        match self.spans.last() {
+            Some(Span {
+                kind: SpanKind::Normal(range),
+                ..
+            }) => Some(&self.path.as_bytes()[range.clone()]),
+            _ => None,
+        }
+    }
+
+    /// Extracts the stem (non-extension) portion of [`file_name`] without parsing the path.
+    ///
+    /// See [`Path::file_stem`] for more details.
+    ///
+    /// [`file_name`]: ParsedPathBuf::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, UnixPathBuf};
+    ///
+    /// let path = ParsedPathBuf::new(UnixPathBuf::from("foo.tar.gz"));
+    /// assert_eq!(path.file_stem(), Some(b"foo.tar".as_slice()));
+    /// ```
+    pub fn file_stem(&self) -> Option<&[u8]> {
+        self.file_name()
+            .map(helpers::rsplit_file_at_dot)
+            .and_then(|(before, after)| before.or(after))
+    }
+
+    /// Extracts the extension of [`file_name`], if possible, without parsing the path.
+    ///
+    /// See [`Path::extension`] for more details.
+    ///
+    /// [`file_name`]: ParsedPathBuf::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, UnixPathBuf};
+    ///
+    /// let path = ParsedPathBuf::new(UnixPathBuf::from("foo.tar.gz"));
+    /// assert_eq!(path.extension(), Some(b"gz".as_slice()));
+    /// ```
+    pub fn extension(&self) -> Option<&[u8]> {
+        self.file_name()
+            .map(helpers::rsplit_file_at_dot)
+            .and_then(|(before, after)| before.and(after))
+    }
+
+    /// Produces an iterator over the path's components viewed as [`[u8]`] slices, reading from
+    /// the cached component boundaries instead of parsing the path.
+    ///
+    /// Yields the same slices as [`Path::iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{ParsedPathBuf, WindowsPathBuf};
+    ///
+    /// let path = ParsedPathBuf::new(WindowsPathBuf::from(r"C:/foo/./bar.txt"));
+    /// let mut it = path.iter();
+    ///
+    /// assert_eq!(it.next(), Some(b"C:".as_slice()));
+    /// assert_eq!(it.next(), Some(br"\".as_slice()));
+    /// assert_eq!(it.next(), Some(b"foo".as_slice()));
+    /// assert_eq!(it.next_back(), Some(b"bar.txt".as_slice()));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> ParsedIter<'_, T> {
+        ParsedIter {
+            path: self,
+            front: 0,
+            back: self.spans.len(),
+        }
+    }
+
+    /// Returns the bytes of the component tracked by `span`.
+    fn span_bytes(&self, span: &Span) -> &[u8] {
+        type StaticComponent<T> =
+            <<T as Encoding<'static>>::Components as Components<'static>>::Component;
+
+        match &span.kind {
+            SpanKind::Root => StaticComponent::<T>::root().as_bytes(),
+            SpanKind::Current => StaticComponent::<T>::current().as_bytes(),
+            SpanKind::Parent => StaticComponent::<T>::parent().as_bytes(),
+            SpanKind::Prefix(range) | SpanKind::Normal(range) => {
+                &self.path.as_bytes()[range.clone()]
+            }
+        }
+    }
+}
+
+impl<T> Clone for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            spans: self.spans.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParsedPathBuf")
+            .field("encoding", &T::label())
+            .field("path", &self.path.to_string_lossy())
+            .finish()
+    }
+}
+
+impl<T> AsRef<[u8]> for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.path.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        self.as_path()
+    }
+}
+
+impl<T> Deref for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Target = Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl<T> From<PathBuf<T>> for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: PathBuf<T>) -> Self {
+        Self::new(path)
+    }
+}
+
+impl<T> From<&Path<T>> for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: &Path<T>) -> Self {
+        Self::new(path.to_path_buf())
+    }
+}
+
+impl<T> From<ParsedPathBuf<T>> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn from(path: ParsedPathBuf<T>) -> Self {
+        path.into_path_buf()
+    }
+}
+
+impl<T> PartialEq for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+impl<T> Eq for ParsedPathBuf<T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> PartialEq<Path<T>> for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &Path<T>) -> bool {
+        self.as_path() == other
+    }
+}
+
+impl<T> PartialEq<PathBuf<T>> for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn eq(&self, other: &PathBuf<T>) -> bool {
+        self.as_path() == other.as_path()
+    }
+}
+
+impl<T> Hash for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.path.hash(h)
+    }
+}
+
+impl<T> cmp::PartialOrd for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> cmp::Ord for ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.path.cmp(&other.path)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ParsedPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type IntoIter = ParsedIter<'a, T>;
+    type Item = &'a [u8];
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the cached components of a [`ParsedPathBuf`], as [`[u8]`] slices.
+///
+/// This `struct` is created by the [`iter`] method on [`ParsedPathBuf`].
+/// See its documentation for more.
+///
+/// [`iter`]: ParsedPathBuf::iter
+pub struct ParsedIter<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    path: &'a ParsedPathBuf<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<T> Clone for ParsedIter<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ParsedIter<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for ParsedIter<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let span = &self.path.spans[self.front];
+            self.front += 1;
+            Some(self.path.span_bytes(span))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ParsedIter<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.path.span_bytes(&self.path.spans[self.back]))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for ParsedIter<'_, T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> FusedIterator for ParsedIter<'_, T> where T: for<'enc> Encoding<'enc> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    fn assert_matches_path<T>(path: &str)
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        let expected = Path::<T>::new(path);
+        let parsed = ParsedPathBuf::new(expected.to_path_buf());
+
+        assert_eq!(parsed.parent(), expected.parent(), "parent of {path:?}");
+        assert_eq!(
+            parsed.file_name(),
+            expected.file_name(),
+            "file name of {path:?}"
+        );
+        assert_eq!(
+            parsed.file_stem(),
+            expected.file_stem(),
+            "file stem of {path:?}"
+        );
+        assert_eq!(
+            parsed.extension(),
+            expected.extension(),
+            "extension of {path:?}"
+        );
+        assert_eq!(
+            parsed.iter().collect::<Vec<_>>(),
+            expected.iter().collect::<Vec<_>>(),
+            "components of {path:?}"
+        );
+        assert_eq!(
+            parsed.iter().rev().collect::<Vec<_>>(),
+            expected.iter().rev().collect::<Vec<_>>(),
+            "reversed components of {path:?}"
+        );
+    }
+
+    #[test]
+    fn should_match_parsing_the_path_for_unix_encoding() {
+        for path in [
+            "",
+            ".",
+            "..",
+            "/",
+            "//",
+            "foo",
+            "foo/",
+            "./foo",
+            "../foo",
+            "foo/..",
+            "foo/.",
+            "/foo/bar.txt",
+            "/foo//bar/./baz.tar.gz/",
+            ".hidden",
+            "a/b/c/d",
+        ] {
+            assert_matches_path::<UnixEncoding>(path);
+        }
+    }
+
+    #[test]
+    fn should_match_parsing_the_path_for_windows_encoding() {
+        for path in [
+            "",
+            ".",
+            "..",
+            r"\",
+            "C:",
+            r"C:\",
+            "C:foo",
+            r"C:\foo\bar.txt",
+            r"C:/foo/./bar/",
+            r"\\server\share",
+            r"\\server\share\foo.txt",
+            r"\\?\C:\foo\.\bar",
+            r"\\.\COM1\foo",
+            r"..\foo\..",
+        ] {
+            assert_matches_path::<WindowsEncoding>(path);
+        }
+    }
+
+    #[test]
+    fn debug_should_include_encoding_label_and_lossy_path() {
+        let path = ParsedPathBuf::new(Path::<UnixEncoding>::new(b"/tmp/\xFFfile").to_path_buf());
+        assert_eq!(
+            format!("{path:?}"),
+            "ParsedPathBuf { encoding: \"unix\", path: \"/tmp/\u{FFFD}file\" }"
+        );
+    }
+}
@@ -5,12 +5,14 @@ use alloc::rc::Rc;
 use alloc::sync::Arc;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+use core::ops::Range;
 use core::{cmp, fmt};
 
 pub use display::Display;
 
 use crate::common::{
-    Ancestors, CheckedPathError, Component, Components, Encoding, Iter, PathBuf, StripPrefixError,
+    Ancestors, CheckedPathError, Component, ComponentPairs, Components, ComponentsWithSpans,
+    Encoding, Iter, NormalizeOptions, PathBuf, PathId, RootKind, StripPrefixError, TryAsRef,
 };
 use crate::no_std_compat::*;
 
@@ -84,6 +86,59 @@ where
     pub(crate) inner: [u8],
 }
 
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc> + 'static,
+{
+    /// An empty [`Path`], which can be used wherever a borrowed path is needed without
+    /// allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::EMPTY;
+    /// assert_eq!(path, Path::<UnixEncoding>::new(""));
+    /// assert_eq!(path, <&Path<UnixEncoding>>::default());
+    /// ```
+    pub const EMPTY: &'static Self = unsafe { &*(b"" as *const [u8] as *const Self) };
+}
+
+/// Implements `const fn` constructors for paths with a concrete encoding, as `const fn` cannot
+/// have trait bounds on their generic parameters on the minimum supported Rust version.
+macro_rules! impl_path_new_const {
+    ($($encoding:ty),+ $(,)?) => {$(
+        impl Path<$encoding> {
+            /// Directly wraps a byte slice as a path slice in a `const` context.
+            ///
+            /// This is equivalent to [`Path::new`], but can be used to define constants and
+            /// statics.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use typed_path::UnixPath;
+            ///
+            /// static ROOTS: &[&UnixPath] =
+            ///     &[UnixPath::new_const(b"/etc"), UnixPath::new_const(b"/usr")];
+            /// assert_eq!(ROOTS[0], UnixPath::new(b"/etc"));
+            /// ```
+            #[inline]
+            pub const fn new_const(s: &[u8]) -> &Self {
+                unsafe { &*(s as *const [u8] as *const Self) }
+            }
+        }
+    )+};
+}
+
+impl_path_new_const!(
+    crate::UnixEncoding,
+    crate::WindowsEncoding,
+    crate::PlatformEncoding,
+);
+
 impl<T> Path<T>
 where
     T: for<'enc> Encoding<'enc>,
@@ -143,6 +198,100 @@ where
         &self.inner
     }
 
+    /// Returns `true` if the path contains no bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// assert!(Path::<UnixEncoding>::new("").is_empty());
+    /// assert!(!Path::<UnixEncoding>::new("/tmp/foo.txt").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns `true` if the path refers to the current directory without naming it, meaning it
+    /// is empty or consists only of `.` components, such as `""`, `.`, or `./.`.
+    ///
+    /// The empty path and `.` refer to the same directory but are not interchangeable: `.` has
+    /// the empty path as its [`parent`] while the empty path has none, and [`join`]ing the empty
+    /// path adds nothing while `.` is kept as written. Both [`normalize`] to the empty path.
+    ///
+    /// [`parent`]: Path::parent
+    /// [`join`]: Path::join
+    /// [`normalize`]: Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("").is_cur_dir_like());
+    /// assert!(Path::<UnixEncoding>::new(".").is_cur_dir_like());
+    /// assert!(Path::<UnixEncoding>::new("././/").is_cur_dir_like());
+    /// assert!(!Path::<UnixEncoding>::new("./foo/..").is_cur_dir_like());
+    /// assert!(!Path::<UnixEncoding>::new("/").is_cur_dir_like());
+    /// ```
+    pub fn is_cur_dir_like(&self) -> bool {
+        self.components().all(|c| c.is_current())
+    }
+
+    /// Returns `true` if the path is non-empty and consists only of `.` components, such as `.`
+    /// or `./.`.
+    ///
+    /// See [`is_cur_dir_like`] for how this differs from the empty path.
+    ///
+    /// [`is_cur_dir_like`]: Path::is_cur_dir_like
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new(".").is_cur_dir());
+    /// assert!(Path::<UnixEncoding>::new("./.").is_cur_dir());
+    /// assert!(!Path::<UnixEncoding>::new("").is_cur_dir());
+    /// assert!(!Path::<UnixEncoding>::new("..").is_cur_dir());
+    /// ```
+    pub fn is_cur_dir(&self) -> bool {
+        !self.is_empty() && self.is_cur_dir_like()
+    }
+
+    /// Returns the length of the path in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// assert_eq!(Path::<UnixEncoding>::new("/tmp/foo.txt").len(), 12);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns the number of [`components`](Path::components) in the path.
+    ///
+    /// This parses the path each time that it is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// assert_eq!(Path::<UnixEncoding>::new("/tmp/foo.txt").components_len(), 3);
+    /// assert_eq!(Path::<UnixEncoding>::new("").components_len(), 0);
+    /// ```
+    pub fn components_len(&self) -> usize {
+        self.components().count()
+    }
+
     /// Yields a [`&str`] slice if the `Path` is valid unicode.
     ///
     /// This conversion may entail doing a check for UTF-8 validity.
@@ -191,6 +340,49 @@ where
         String::from_utf8_lossy(&self.inner)
     }
 
+    /// Converts a `Path` to a [`std::path::PathBuf`], which always succeeds.
+    ///
+    /// On Unix-like platforms, the bytes are passed through unchanged. Elsewhere, any
+    /// non-Unicode sequences are replaced with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// Use `TryFrom` when the conversion must not lose information.
+    ///
+    /// [U+FFFD]: std::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/tmp/foo.txt");
+    /// assert_eq!(path.to_std_path_lossy(), std::path::PathBuf::from("/tmp/foo.txt"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_std_path_lossy(&self) -> std::path::PathBuf {
+        #[cfg(any(
+            unix,
+            all(target_vendor = "fortanix", target_env = "sgx"),
+            target_os = "solid_asp3",
+            target_os = "hermit",
+            target_os = "wasi"
+        ))]
+        {
+            std::path::PathBuf::from(AsRef::<std::ffi::OsStr>::as_ref(self))
+        }
+
+        #[cfg(not(any(
+            unix,
+            all(target_vendor = "fortanix", target_env = "sgx"),
+            target_os = "solid_asp3",
+            target_os = "hermit",
+            target_os = "wasi"
+        )))]
+        {
+            std::path::PathBuf::from(self.to_string_lossy().into_owned())
+        }
+    }
+
     /// Converts a `Path` to an owned [`PathBuf`].
     ///
     /// # Examples
@@ -298,6 +490,61 @@ where
         self.components().has_root()
     }
 
+    /// Classifies how the `Path` is anchored, distinguishing the Windows-specific forms that
+    /// [`Path::is_absolute`] and [`Path::has_root`] collapse together. See [`RootKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, RootKind, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("/etc/passwd").root_kind(), RootKind::Absolute);
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"C:foo").root_kind(), RootKind::DriveRelative);
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"\foo").root_kind(), RootKind::RootRelative);
+    /// ```
+    #[inline]
+    pub fn root_kind(&self) -> RootKind {
+        T::root_kind(&self.inner)
+    }
+
+    /// Returns the label of the encoding backing this `Path`, such as `"unix"` or `"windows"`.
+    ///
+    /// This is the same label that appears in the [`Debug`] output of the path.
+    ///
+    /// [`Debug`]: fmt::Debug
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("/tmp").encoding_label(), "unix");
+    /// assert_eq!(Path::<WindowsEncoding>::new(r"C:\tmp").encoding_label(), "windows");
+    /// ```
+    #[inline]
+    pub fn encoding_label(&self) -> &'static str {
+        T::label()
+    }
+
+    /// Returns a compact identifier for this `Path` that is shared by every path with the same
+    /// encoding and normalized components. See [`PathId`] for its stability guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let id = Path::<UnixEncoding>::new("/var/log/app.log").stable_id();
+    /// assert_eq!(id, Path::<UnixEncoding>::new("/var/log/./app.log").stable_id());
+    /// assert_ne!(id, Path::<UnixEncoding>::new("/var/log/app.log.1").stable_id());
+    /// ```
+    pub fn stable_id(&self) -> PathId {
+        PathId::of(self)
+    }
+
     /// Returns the `Path` without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -359,10 +606,61 @@ where
     ///
     /// [`parent`]: Path::parent
     #[inline]
-    pub fn ancestors(&self) -> Ancestors<T> {
+    pub fn ancestors(&self) -> Ancestors<'_, T> {
         Ancestors { next: Some(self) }
     }
 
+    /// Returns the ancestor `n` levels above the `Path`, or [`None`] if that would go past the
+    /// root or the start of a relative path.
+    ///
+    /// `ancestor(0)` is `self` and `ancestor(1)` is the same as [`parent`]. This is equivalent to
+    /// `self.ancestors().nth(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/foo/bar/baz");
+    /// assert_eq!(path.ancestor(0), Some(path));
+    /// assert_eq!(path.ancestor(2), Some(Path::new("/foo")));
+    /// assert_eq!(path.ancestor(3), Some(Path::new("/")));
+    /// assert_eq!(path.ancestor(4), None);
+    /// ```
+    ///
+    /// [`parent`]: Path::parent
+    #[inline]
+    pub fn ancestor(&self, n: usize) -> Option<&Self> {
+        self.ancestors().nth(n)
+    }
+
+    /// Returns the first of the `Path` and its [`ancestors`] for which `predicate` returns true.
+    ///
+    /// This is the building block for discovering a project root from a nested path, where the
+    /// predicate checks for some marker such as a `Cargo.toml` or `.git` entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/work/project/src/bin/main.rs");
+    /// let root = path.find_ancestor_with(|p| p.file_name() == Some("project".as_ref()));
+    /// assert_eq!(root, Some(Path::new("/work/project")));
+    ///
+    /// assert_eq!(path.find_ancestor_with(|p| p.ends_with("missing")), None);
+    /// ```
+    ///
+    /// [`ancestors`]: Path::ancestors
+    pub fn find_ancestor_with<F>(&self, mut predicate: F) -> Option<&Self>
+    where
+        F: FnMut(&Self) -> bool,
+    {
+        self.ancestors().find(|p| predicate(p))
+    }
+
     /// Returns the final component of the `Path`, if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -396,6 +694,67 @@ where
         }
     }
 
+    /// Returns both the [`parent`] and the [`file_name`] of the `Path`, parsing the final
+    /// component once rather than once for each.
+    ///
+    /// [`parent`]: Path::parent
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let (parent, file_name) = Path::<UnixEncoding>::new("/usr/bin/rustc").split_file_name();
+    /// assert_eq!(parent, Some(Path::new("/usr/bin")));
+    /// assert_eq!(file_name, Some(b"rustc".as_slice()));
+    ///
+    /// let (parent, file_name) = Path::<UnixEncoding>::new("foo/..").split_file_name();
+    /// assert_eq!(parent, Some(Path::new("foo")));
+    /// assert_eq!(file_name, None);
+    ///
+    /// assert_eq!(Path::<UnixEncoding>::new("/").split_file_name(), (None, None));
+    /// ```
+    pub fn split_file_name(&self) -> (Option<&Self>, Option<&[u8]>) {
+        let mut comps = self.components();
+        match comps.next_back() {
+            Some(p) if p.is_root() => (None, None),
+            Some(p) => {
+                let file_name = if p.is_normal() {
+                    Some(p.as_bytes())
+                } else {
+                    None
+                };
+                (Some(Self::new(comps.as_bytes())), file_name)
+            }
+            None => (None, None),
+        }
+    }
+
+    /// Returns true if the [`file_name`] begins with a `.`, which is how Unix-style tools mark
+    /// a file or directory as hidden.
+    ///
+    /// Returns false if there is no [`file_name`].
+    ///
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("/home/user/.bashrc").file_name_is_dotfile());
+    /// assert!(Path::<UnixEncoding>::new(".config/").file_name_is_dotfile());
+    /// assert!(!Path::<UnixEncoding>::new(".config/nvim").file_name_is_dotfile());
+    /// assert!(!Path::<UnixEncoding>::new("foo/..").file_name_is_dotfile());
+    /// ```
+    pub fn file_name_is_dotfile(&self) -> bool {
+        self.file_name()
+            .map_or(false, |name| name.first() == Some(&b'.'))
+    }
+
     /// Returns a path that, when joined onto `base`, yields `self`.
     ///
     /// # Errors
@@ -433,10 +792,60 @@ where
     }
 
     fn _strip_prefix(&self, base: &Path<T>) -> Result<&Path<T>, StripPrefixError> {
-        match helpers::iter_after(self.components(), base.components()) {
-            Some(c) => Ok(Path::new(c.as_bytes())),
-            None => Err(StripPrefixError(())),
+        let range = Self::try_strip_prefix_bytes(self.as_bytes(), base.as_bytes())?;
+        Ok(Path::new(&self.as_bytes()[range]))
+    }
+
+    /// Strips the path in `base` from the front of the path in `path`, returning the range of
+    /// bytes within `path` that remain.
+    ///
+    /// This is the primitive behind [`strip_prefix`], and is meant for callers such as FFI
+    /// layers that hold paths in buffers they do not own. Both sides are parsed in place using
+    /// encoding `T`, and nothing is copied or allocated.
+    ///
+    /// [`strip_prefix`]: Path::strip_prefix
+    ///
+    /// # Errors
+    ///
+    /// If `base` is not a prefix of `path` when comparing whole components, returns [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{UnixPath, WindowsPath};
+    ///
+    /// let buf = b"/usr/local/lib/libfoo.so";
+    /// let range = UnixPath::try_strip_prefix_bytes(buf, b"/usr/local/").unwrap();
+    /// assert_eq!(range, 11..buf.len());
+    /// assert_eq!(&buf[range], b"lib/libfoo.so");
+    ///
+    /// // Fully consumed paths leave an empty range at the end
+    /// assert_eq!(UnixPath::try_strip_prefix_bytes(buf, buf), Ok(buf.len()..buf.len()));
+    ///
+    /// // Components are compared with the rules of the encoding
+    /// let buf = br"C:\Users\alice";
+    /// let range = WindowsPath::try_strip_prefix_bytes(buf, b"C:/Users").unwrap();
+    /// assert_eq!(&buf[range], b"alice");
+    ///
+    /// assert!(UnixPath::try_strip_prefix_bytes(b"/usr/lib", b"/usr/li").is_err());
+    /// ```
+    pub fn try_strip_prefix_bytes(
+        path: &[u8],
+        base: &[u8],
+    ) -> Result<Range<usize>, StripPrefixError> {
+        let rest = helpers::iter_after(
+            Path::<T>::new(path).components(),
+            Path::<T>::new(base).components(),
+        )
+        .ok_or(StripPrefixError(()))?
+        .as_bytes();
+
+        // Components always borrow from `path`, so the remainder is a subslice of it
+        if rest.is_empty() {
+            return Ok(path.len()..path.len());
         }
+        let start = rest.as_ptr() as usize - path.as_ptr() as usize;
+        Ok(start..start + rest.len())
     }
 
     /// Determines whether `base` is a prefix of `self`.
@@ -470,6 +879,11 @@ where
     }
 
     fn _starts_with(&self, base: &Path<T>) -> bool {
+        // Try a cheap byte comparison first, only parsing components when it is inconclusive
+        if let Some(result) = T::starts_with_fast(&self.inner, &base.inner) {
+            return result;
+        }
+
         helpers::iter_after(self.components(), base.components()).is_some()
     }
 
@@ -503,6 +917,102 @@ where
         helpers::iter_after(self.components().rev(), child.components().rev()).is_some()
     }
 
+    /// Like [`Path::starts_with`], but compares against an iterator of individual components
+    /// rather than a path, avoiding the need to build a path from dynamic segments first.
+    ///
+    /// Each item is compared to exactly one component of `self`, so an item containing a
+    /// separator never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/etc/ssh/sshd_config");
+    ///
+    /// assert!(path.starts_with_components(["/", "etc", "ssh"]));
+    /// assert!(path.starts_with_components(path.components()));
+    /// assert!(path.starts_with_components(Vec::<&str>::new()));
+    ///
+    /// assert!(!path.starts_with_components(["etc"]));
+    /// assert!(!path.starts_with_components(["/", "etc/ssh"]));
+    /// ```
+    pub fn starts_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut this = self.components();
+        components.into_iter().all(|component| {
+            this.next()
+                .map_or(false, |c| c.as_bytes() == component.as_ref())
+        })
+    }
+
+    /// Like [`Path::ends_with`], but compares against an iterator of individual components
+    /// rather than a path, avoiding the need to build a path from dynamic segments first.
+    ///
+    /// Each item is compared to exactly one component of `self`, so an item containing a
+    /// separator never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/etc/ssh/sshd_config");
+    ///
+    /// assert!(path.ends_with_components(["ssh", "sshd_config"]));
+    /// assert!(path.ends_with_components(["/", "etc", "ssh", "sshd_config"]));
+    ///
+    /// assert!(!path.ends_with_components(["ssh"]));
+    /// assert!(!path.ends_with_components(["ssh/sshd_config"]));
+    /// ```
+    pub fn ends_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut this = self.components().rev();
+        components.into_iter().rev().all(|component| {
+            this.next()
+                .map_or(false, |c| c.as_bytes() == component.as_ref())
+        })
+    }
+
+    /// Returns true if `patterns`, read in order like the lines of a [`.gitignore`] file, ignore
+    /// the path, which refers to a directory if `is_dir` is true.
+    ///
+    /// Each call tries the patterns one by one. To filter many paths with the same patterns,
+    /// build a [`PatternSet`] once instead.
+    ///
+    /// [`.gitignore`]: https://git-scm.com/docs/gitignore
+    /// [`PatternSet`]: crate::PatternSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, Pattern, UnixEncoding};
+    ///
+    /// let patterns: Vec<Pattern> = ["target/", "*.log", "!keep.log"]
+    ///     .iter()
+    ///     .filter_map(Pattern::new)
+    ///     .collect();
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Path::<UnixEncoding>::new("target/debug/app").matches_any(&patterns, false));
+    /// assert!(Path::<UnixEncoding>::new("logs/today.log").matches_any(&patterns, false));
+    /// assert!(!Path::<UnixEncoding>::new("logs/keep.log").matches_any(&patterns, false));
+    /// assert!(!Path::<UnixEncoding>::new("target").matches_any(&patterns, false));
+    /// ```
+    #[cfg(feature = "gitignore")]
+    pub fn matches_any(&self, patterns: &[crate::Pattern], is_dir: bool) -> bool {
+        crate::gitignore::matches_any(self, patterns, is_dir)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: Path::file_name
@@ -627,6 +1137,91 @@ where
         path
     }
 
+    /// Like [`normalize`], but with additional behavior controlled by `options`.
+    ///
+    /// [`normalize`]: Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{NormalizeOptions, Path, UnixEncoding};
+    ///
+    /// let options = NormalizeOptions::new().empty_as_cur_dir(true);
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Path::<UnixEncoding>::new("foo/..").normalize().as_bytes(), b"");
+    /// assert_eq!(Path::<UnixEncoding>::new("foo/..").normalize_with(options).as_bytes(), b".");
+    /// assert_eq!(Path::<UnixEncoding>::new("").normalize_with(options).as_bytes(), b".");
+    /// assert_eq!(Path::<UnixEncoding>::new("./foo/.").normalize_with(options).as_bytes(), b"foo");
+    /// ```
+    pub fn normalize_with(&self, options: NormalizeOptions) -> PathBuf<T> {
+        let mut path = self.normalize();
+        if options.is_empty_as_cur_dir() && path.is_empty() {
+            path.push(".");
+        }
+        path
+    }
+
+    /// Returns a cleaned version of the path, following the semantics of Go's `path.Clean`.
+    ///
+    /// This differs from [`normalize`] in how `..` is handled:
+    ///
+    /// * A `..` is resolved by removing the preceding normal component.
+    /// * A `..` directly following a root directory is removed.
+    /// * Any other `..`, such as those leading a relative path, is kept.
+    ///
+    /// Repeated separators and `.` components are removed, and an empty result becomes `.`.
+    ///
+    /// [`normalize`]: Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("foo//bar/./baz/..").clean(),
+    ///     PathBuf::from("foo/bar"),
+    /// );
+    /// assert_eq!(Path::<UnixEncoding>::new("../foo/../..").clean(), PathBuf::from("../.."));
+    /// assert_eq!(Path::<UnixEncoding>::new("/../foo").clean(), PathBuf::from("/foo"));
+    /// assert_eq!(Path::<UnixEncoding>::new("foo/..").clean(), PathBuf::from("."));
+    ///
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::new(r"C:..\foo\..").clean(),
+    ///     PathBuf::from(r"C:.."),
+    /// );
+    /// ```
+    pub fn clean(&self) -> PathBuf<T> {
+        let mut components = Vec::new();
+        for component in self.components() {
+            if !component.is_current() && !component.is_parent() {
+                components.push(component);
+            } else if component.is_parent() {
+                match components.last() {
+                    Some(last) if last.is_normal() => {
+                        components.pop();
+                    }
+                    Some(last) if last.is_root() => {}
+                    _ => components.push(component),
+                }
+            }
+        }
+
+        if components.is_empty() {
+            return PathBuf::from(".");
+        }
+
+        let mut path = PathBuf::<T>::new();
+
+        for component in components {
+            path.push(component.as_bytes());
+        }
+
+        path
+    }
+
     /// Converts a path to an absolute form by [`normalizing`] the path, returning a
     /// [`PathBuf`].
     ///
@@ -650,7 +1245,7 @@ where
     /// let path = cwd.join(Path::new("a/b/../c/./d"));
     /// assert_eq!(path.absolutize().unwrap(), cwd.join(Path::new("a/c/d")));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     pub fn absolutize(&self) -> std::io::Result<PathBuf<T>> {
         if self.is_absolute() {
             Ok(self.normalize())
@@ -687,6 +1282,44 @@ where
         buf
     }
 
+    /// Creates an owned [`PathBuf`] with each of `paths` adjoined to `self` in order.
+    ///
+    /// This is equivalent to calling [`join`] for each path, but `paths` is walked twice, first
+    /// to size the result and then to join each path, so that it is allocated once rather than
+    /// per path.
+    ///
+    /// [`join`]: Path::join
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("/root").join_iter(["a", "b", "c", "d"]),
+    ///     PathBuf::from("/root/a/b/c/d"),
+    /// );
+    ///
+    /// // Like with join, an absolute path replaces everything before it
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("/root").join_iter(["a", "/etc", "passwd"]),
+    ///     PathBuf::from("/etc/passwd"),
+    /// );
+    /// ```
+    pub fn join_iter<I, P>(&self, paths: I) -> PathBuf<T>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Clone,
+        P: AsRef<Path<T>>,
+    {
+        let paths = paths.into_iter();
+        let mut buf = PathBuf::with_capacity(self.inner.len() + helpers::pushed_len(paths.clone()));
+        buf.inner.extend_from_slice(&self.inner);
+        paths.for_each(|p| buf.push(p.as_ref()));
+        buf
+    }
+
     /// Creates an owned [`PathBuf`] with `path` adjoined to `self`, checking the `path` to ensure
     /// it is safe to join. _When dealing with user-provided paths, this is the preferred method._
     ///
@@ -716,6 +1349,54 @@ where
         Ok(buf)
     }
 
+    /// Resolves the `target` of a symbolic link located in the directory `self`, following the
+    /// same rules that the operating system uses when traversing the link.
+    ///
+    /// * An absolute `target` replaces `self` entirely.
+    /// * A relative `target` is adjoined to `self`.
+    /// * On Windows, a `target` with a root but no prefix, such as `\foo`, keeps the prefix of
+    ///   `self`, and a `target` with a prefix, such as `D:foo`, replaces `self` entirely.
+    ///
+    /// No `..` components are resolved, as `self` may itself traverse symbolic links, which makes
+    /// removing them lexically incorrect. Use [`Path::normalize`] if that is acceptable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] if `target` is empty, which is never a valid
+    /// link target, or if any of its components are invalid for the encoding (see
+    /// [`Path::is_valid`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Path, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let dir = Path::<UnixEncoding>::new("/usr/lib");
+    /// assert_eq!(
+    ///     dir.resolve_link_target("../lib64/libc.so"),
+    ///     Ok(PathBuf::from("/usr/lib/../lib64/libc.so"))
+    /// );
+    /// assert_eq!(dir.resolve_link_target("/opt/libc.so"), Ok(PathBuf::from("/opt/libc.so")));
+    /// assert_eq!(dir.resolve_link_target(""), Err(CheckedPathError::InvalidFilename));
+    ///
+    /// let dir = Path::<WindowsEncoding>::new(r"C:\Users\Public");
+    /// assert_eq!(dir.resolve_link_target(r"\Shared"), Ok(PathBuf::from(r"C:\Shared")));
+    /// assert_eq!(dir.resolve_link_target(r"Docs"), Ok(PathBuf::from(r"C:\Users\Public\Docs")));
+    /// assert_eq!(dir.resolve_link_target("a|b"), Err(CheckedPathError::InvalidFilename));
+    /// ```
+    pub fn resolve_link_target<P: AsRef<Path<T>>>(
+        &self,
+        target: P,
+    ) -> Result<PathBuf<T>, CheckedPathError> {
+        let target = target.as_ref();
+        if target.as_bytes().is_empty() || !target.is_valid() {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        Ok(self.join(target))
+    }
+
     /// Creates an owned [`PathBuf`] like `self` but with the given file name.
     ///
     /// See [`PathBuf::set_file_name`] for more details.
@@ -772,6 +1453,25 @@ where
         buf
     }
 
+    /// Creates an owned [`PathBuf`] like `self` but with its extension converted to lowercase.
+    ///
+    /// See [`PathBuf::lowercase_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("IMG_0001.JPG");
+    /// assert_eq!(path.with_lowercase_extension(), PathBuf::from("IMG_0001.jpg"));
+    /// ```
+    pub fn with_lowercase_extension(&self) -> PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.lowercase_extension();
+        buf
+    }
+
     /// Produces an iterator over the [`Component`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -779,16 +1479,142 @@ where
     /// * Repeated separators are ignored, so `a/b` and `a//b` both have
     ///   `a` and `b` as components.
     ///
-    /// * Occurrences of `.` are normalized away, except if they are at the
-    ///   beginning of the path. For example, `a/./b`, `a/b/`, `a/b/.` and
-    ///   `a/b` all have `a` and `b` as components, but `./a/b` starts with
-    ///   an additional [`CurDir`] component.
+    /// * Occurrences of `.` are normalized away, except if they are at the
+    ///   beginning of the path. For example, `a/./b`, `a/b/`, `a/b/.` and
+    ///   `a/b` all have `a` and `b` as components, but `./a/b` starts with
+    ///   an additional [`CurDir`] component.
+    ///
+    /// * A trailing slash is normalized away, `/a/b` and `/a/b/` are equivalent.
+    ///
+    /// Note that no other normalization takes place; in particular, `a/c`
+    /// and `a/b/../c` are distinct, to account for the possibility that `b`
+    /// is a symbolic link (so its parent isn't `a`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixComponent, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut components = Path::<UnixEncoding>::new("/tmp/foo.txt").components();
+    ///
+    /// assert_eq!(components.next(), Some(UnixComponent::RootDir));
+    /// assert_eq!(components.next(), Some(UnixComponent::Normal(b"tmp")));
+    /// assert_eq!(components.next(), Some(UnixComponent::Normal(b"foo.txt")));
+    /// assert_eq!(components.next(), None)
+    /// ```
+    ///
+    /// [`CurDir`]: crate::unix::UnixComponent::CurDir
+    pub fn components(&self) -> <T as Encoding<'_>>::Components {
+        T::components(&self.inner)
+    }
+
+    /// Produces an iterator over the path's components viewed as [`[u8]`] slices.
+    ///
+    /// For more information about the particulars of how the path is separated
+    /// into components, see [`components`].
+    ///
+    /// [`components`]: Path::components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let mut it = Path::<UnixEncoding>::new("/tmp/foo.txt").iter();
+    ///
+    /// assert_eq!(it.next(), Some(typed_path::constants::unix::SEPARATOR_STR.as_bytes()));
+    /// assert_eq!(it.next(), Some(b"tmp".as_slice()));
+    /// assert_eq!(it.next(), Some(b"foo.txt".as_slice()));
+    /// assert_eq!(it.next(), None)
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.components())
+    }
+
+    /// Produces an iterator over the [`Component`]s of the path, each paired with the range of
+    /// bytes it occupies within the path.
+    ///
+    /// Components are identical to those produced by [`components`]. The ranges index into
+    /// [`as_bytes`] and are useful for pointing at a specific component when reporting
+    /// diagnostics. A root directory component covers a single separator, even when the path
+    /// begins with several.
+    ///
+    /// [`Component`]: crate::Component
+    /// [`components`]: Path::components
+    /// [`as_bytes`]: Path::as_bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixComponent, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/tmp//./foo/../bar.txt");
+    /// let mut it = path.components_with_spans();
+    ///
+    /// assert_eq!(it.next(), Some((UnixComponent::RootDir, 0..1)));
+    /// assert_eq!(it.next(), Some((UnixComponent::Normal(b"tmp"), 1..4)));
+    /// assert_eq!(it.next(), Some((UnixComponent::Normal(b"foo"), 8..11)));
+    /// assert_eq!(it.next(), Some((UnixComponent::ParentDir, 12..14)));
+    /// assert_eq!(it.next(), Some((UnixComponent::Normal(b"bar.txt"), 15..22)));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    pub fn components_with_spans(&self) -> ComponentsWithSpans<'_, T> {
+        ComponentsWithSpans::new(self.components())
+    }
+
+    /// Produces an iterator over the [`Component`]s of the path, each paired with the path that
+    /// precedes it, so that every ancestor can be visited alongside the component that extends
+    /// it in a single pass.
+    ///
+    /// The preceding path of the first component is empty. Joining a preceding path with its
+    /// component produces the next preceding path.
+    ///
+    /// [`Component`]: crate::Component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixComponent, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/srv//app/./logs");
+    /// let mut it = path.component_pairs();
+    ///
+    /// assert_eq!(it.next(), Some((Path::new(""), UnixComponent::RootDir)));
+    /// assert_eq!(it.next(), Some((Path::new("/"), UnixComponent::Normal(b"srv"))));
+    /// assert_eq!(it.next(), Some((Path::new("/srv"), UnixComponent::Normal(b"app"))));
+    /// assert_eq!(it.next(), Some((Path::new("/srv//app"), UnixComponent::Normal(b"logs"))));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    ///
+    /// Creating each missing directory in turn, like `mkdir -p`:
+    ///
+    /// ```
+    /// use typed_path::{Component, Path, UnixEncoding};
+    ///
+    /// let mut created = Vec::new();
+    /// for (parent, component) in Path::<UnixEncoding>::new("/a/b/c").component_pairs() {
+    ///     if component.is_normal() {
+    ///         created.push(parent.join(component.as_bytes()));
+    ///     }
+    /// }
+    /// assert_eq!(created, ["/a", "/a/b", "/a/b/c"].map(Path::<UnixEncoding>::new));
+    /// ```
+    #[inline]
+    pub fn component_pairs(&self) -> ComponentPairs<'_, T> {
+        ComponentPairs::new(self)
+    }
+
+    /// Returns the first [`Component`] of the path, if there is one.
     ///
-    /// * A trailing slash is normalized away, `/a/b` and `/a/b/` are equivalent.
+    /// This is equivalent to `path.components().next()`.
     ///
-    /// Note that no other normalization takes place; in particular, `a/c`
-    /// and `a/b/../c` are distinct, to account for the possibility that `b`
-    /// is a symbolic link (so its parent isn't `a`).
+    /// [`Component`]: crate::Component
     ///
     /// # Examples
     ///
@@ -796,42 +1622,60 @@ where
     /// use typed_path::{Path, UnixComponent, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let mut components = Path::<UnixEncoding>::new("/tmp/foo.txt").components();
-    ///
-    /// assert_eq!(components.next(), Some(UnixComponent::RootDir));
-    /// assert_eq!(components.next(), Some(UnixComponent::Normal(b"tmp")));
-    /// assert_eq!(components.next(), Some(UnixComponent::Normal(b"foo.txt")));
-    /// assert_eq!(components.next(), None)
+    /// let path = Path::<UnixEncoding>::new("../foo.txt");
+    /// assert_eq!(path.first_component(), Some(UnixComponent::ParentDir));
+    /// assert_eq!(Path::<UnixEncoding>::new("").first_component(), None);
     /// ```
-    ///
-    /// [`CurDir`]: crate::unix::UnixComponent::CurDir
-    pub fn components(&self) -> <T as Encoding<'_>>::Components {
-        T::components(&self.inner)
+    #[inline]
+    pub fn first_component<'a>(
+        &'a self,
+    ) -> Option<<<T as Encoding<'a>>::Components as Components<'a>>::Component> {
+        self.components().next()
     }
 
-    /// Produces an iterator over the path's components viewed as [`[u8]`] slices.
+    /// Returns the last [`Component`] of the path, if there is one.
     ///
-    /// For more information about the particulars of how the path is separated
-    /// into components, see [`components`].
+    /// This is equivalent to `path.components().next_back()`.
     ///
-    /// [`components`]: Path::components
+    /// [`Component`]: crate::Component
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{Path, UnixEncoding};
+    /// use typed_path::{Path, UnixComponent, UnixEncoding};
     ///
     /// // NOTE: A path cannot be created on its own without a defined encoding
-    /// let mut it = Path::<UnixEncoding>::new("/tmp/foo.txt").iter();
+    /// let path = Path::<UnixEncoding>::new("/tmp/foo.txt");
+    /// assert_eq!(path.last_component(), Some(UnixComponent::Normal(b"foo.txt")));
+    /// assert_eq!(Path::<UnixEncoding>::new("/").last_component(), Some(UnixComponent::RootDir));
+    /// ```
+    #[inline]
+    pub fn last_component<'a>(
+        &'a self,
+    ) -> Option<<<T as Encoding<'a>>::Components as Components<'a>>::Component> {
+        self.components().next_back()
+    }
+
+    /// Returns `true` if the first [`Component`] of the path is `component`.
     ///
-    /// assert_eq!(it.next(), Some(typed_path::constants::unix::SEPARATOR_STR.as_bytes()));
-    /// assert_eq!(it.next(), Some(b"tmp".as_slice()));
-    /// assert_eq!(it.next(), Some(b"foo.txt".as_slice()));
-    /// assert_eq!(it.next(), None)
+    /// [`Component`]: crate::Component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixComponent, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("../foo.txt");
+    /// assert!(path.starts_with_component(UnixComponent::ParentDir));
+    /// assert!(!path.starts_with_component(UnixComponent::CurDir));
     /// ```
     #[inline]
-    pub fn iter(&self) -> Iter<T> {
-        Iter::new(self.components())
+    pub fn starts_with_component<'a>(
+        &'a self,
+        component: <<T as Encoding<'a>>::Components as Components<'a>>::Component,
+    ) -> bool {
+        self.first_component().map_or(false, |c| c == component)
     }
 
     /// Returns an object that implements [`Display`] for safely printing paths
@@ -853,10 +1697,37 @@ where
     /// println!("{}", path.display());
     /// ```
     #[inline]
-    pub fn display(&self) -> Display<T> {
+    pub fn display(&self) -> Display<'_, T> {
         Display { path: self }
     }
 
+    /// Like [`display`], but strips `base` from the front of the path when it is a prefix,
+    /// displaying the full path otherwise.
+    ///
+    /// This is the same as `path.strip_prefix(base).unwrap_or(path).display()`. Note that when
+    /// `base` is the path itself, the displayed path is empty.
+    ///
+    /// [`display`]: Path::display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/repo/src/main.rs");
+    ///
+    /// assert_eq!(path.display_relative_to("/repo").to_string(), "src/main.rs");
+    /// assert_eq!(path.display_relative_to("/other").to_string(), "/repo/src/main.rs");
+    /// ```
+    #[inline]
+    pub fn display_relative_to<P>(&self, base: P) -> impl fmt::Display + '_
+    where
+        P: AsRef<Path<T>>,
+    {
+        self._strip_prefix(base.as_ref()).unwrap_or(self).display()
+    }
+
     /// Creates an owned [`PathBuf`] like `self` but with a different encoding.
     ///
     /// # Note
@@ -1003,6 +1874,107 @@ where
         Ok(path)
     }
 
+    /// Creates an owned [`PathBuf`] like `self` but with a different encoding, rewriting only
+    /// separators while leaving every other byte untouched.
+    ///
+    /// Unlike [`with_encoding`], which rebuilds the path from its [`components`], this never
+    /// resolves or removes `.` and `..`, never collapses repeated separators, and preserves
+    /// trailing separators. It is meant for tools that need to keep the original intent of a path
+    /// when moving it between platforms.
+    ///
+    /// Every separator of the current encoding, including `/` on Windows, is replaced by the
+    /// primary separator of the new encoding. A Windows path with a root but no prefix, such as
+    /// `\tmp`, becomes absolute on Unix.
+    ///
+    /// [`components`]: Path::components
+    /// [`with_encoding`]: Path::with_encoding
+    ///
+    /// # Errors
+    ///
+    /// Fails rather than change what the path refers to:
+    ///
+    /// * [`CheckedPathError::UnexpectedPrefix`] if the path has a prefix, such as `C:` or
+    ///   `\\server\share` on Windows, as no other encoding can represent it.
+    /// * [`CheckedPathError::InvalidFilename`] if the new encoding would read the components
+    ///   differently, such as a Unix file name containing `\`, which Windows splits in two, or
+    ///   one starting with `C:`, which Windows reads as a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Path, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let windows_path = Path::<WindowsEncoding>::new(r"a\..\b/.\\c\");
+    /// assert_eq!(windows_path.transcode::<UnixEncoding>().unwrap().as_bytes(), b"a/../b/.//c/");
+    ///
+    /// // Compare with rebuilding the path from its components
+    /// assert_eq!(windows_path.with_encoding::<UnixEncoding>().as_bytes(), b"a/../b/c");
+    ///
+    /// let unix_path = Path::<UnixEncoding>::new("./tmp/../foo.txt");
+    /// assert_eq!(
+    ///     unix_path.transcode::<WindowsEncoding>().unwrap().as_bytes(),
+    ///     br".\tmp\..\foo.txt",
+    /// );
+    ///
+    /// // Paths that cannot be represented in the new encoding are rejected
+    /// assert_eq!(
+    ///     Path::<WindowsEncoding>::new(r"C:\tmp").transcode::<UnixEncoding>(),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new(r"/tmp/a\b").transcode::<WindowsEncoding>(),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// assert_eq!(
+    ///     Path::<UnixEncoding>::new("c:file").transcode::<WindowsEncoding>(),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// ```
+    pub fn transcode<U>(&self) -> Result<PathBuf<U>, CheckedPathError>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        if T::label() == U::label() {
+            return Ok(Path::new(self.as_bytes()).to_path_buf());
+        }
+
+        let from = <<<T as Encoding>::Components as Components>::Component as Component>::root()
+            .as_bytes();
+        let to = <<<U as Encoding>::Components as Components>::Component as Component>::root()
+            .as_bytes();
+
+        // Any special component that is not the root directory is a prefix
+        if let Some(component) = self.components().next() {
+            let is_prefix = !component.is_normal()
+                && !component.is_current()
+                && !component.is_parent()
+                && component.as_bytes() != from;
+            if is_prefix {
+                return Err(CheckedPathError::UnexpectedPrefix);
+            }
+        }
+
+        let mut separators = [None; 128];
+        let mut bytes = Vec::with_capacity(self.inner.len());
+        for &b in self.as_bytes() {
+            let is_separator = b.is_ascii()
+                && *separators[usize::from(b)].get_or_insert_with(|| helpers::is_separator::<T>(b));
+            if is_separator {
+                bytes.extend_from_slice(to);
+            } else {
+                bytes.push(b);
+            }
+        }
+
+        let path = PathBuf::from(bytes);
+        if helpers::same_components(self, &path) {
+            Ok(path)
+        } else {
+            Err(CheckedPathError::InvalidFilename)
+        }
+    }
+
     /// Converts a [`Box<Path>`](Box) into a
     /// [`PathBuf`] without copying or allocating.
     pub fn into_path_buf(self: Box<Path<T>>) -> PathBuf<T> {
@@ -1034,6 +2006,59 @@ where
     }
 }
 
+impl<T> TryAsRef<str> for Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Views the path as a [`str`], failing if the path is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, TryAsRef, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/tmp/foo.txt");
+    /// assert_eq!(TryAsRef::<str>::try_as_ref(path), Some("/tmp/foo.txt"));
+    ///
+    /// let path = Path::<UnixEncoding>::new(b"/tmp/\xff.txt");
+    /// assert_eq!(TryAsRef::<str>::try_as_ref(path), None);
+    /// ```
+    #[inline]
+    fn try_as_ref(&self) -> Option<&str> {
+        self.to_str()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> TryAsRef<std::path::Path> for Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Views the path as a [`std::path::Path`], failing if the path is not valid UTF-8.
+    ///
+    /// This mirrors the conversion performed by `TryFrom<PathBuf<T>>` for
+    /// [`std::path::PathBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, TryAsRef, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Path::<UnixEncoding>::new("/tmp/foo.txt");
+    /// let std_path: &std::path::Path = path.try_as_ref().unwrap();
+    /// assert_eq!(std_path, std::path::Path::new("/tmp/foo.txt"));
+    ///
+    /// let path = Path::<UnixEncoding>::new(b"/tmp/\xff.txt");
+    /// assert_eq!(TryAsRef::<std::path::Path>::try_as_ref(path), None);
+    /// ```
+    #[inline]
+    fn try_as_ref(&self) -> Option<&std::path::Path> {
+        self.to_str().map(std::path::Path::new)
+    }
+}
+
 impl<T> AsRef<Path<T>> for Path<T>
 where
     T: for<'enc> Encoding<'enc>,
@@ -1100,8 +2125,8 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Path")
-            .field("_encoding", &T::label())
-            .field("inner", &&self.inner)
+            .field("encoding", &T::label())
+            .field("path", &self.to_string_lossy())
             .finish()
     }
 }
@@ -1132,6 +2157,20 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Formats the path for `defmt`, replacing invalid UTF-8 sequences with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD]. Does not allocate.
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    fn format(&self, f: defmt::Formatter<'_>) {
+        helpers::defmt_write_lossy(f, self.as_bytes())
+    }
+}
+
 impl<T> cmp::PartialEq for Path<T>
 where
     T: for<'enc> Encoding<'enc>,
@@ -1169,7 +2208,7 @@ where
 {
     #[inline]
     fn cmp(&self, other: &Path<T>) -> cmp::Ordering {
-        self.components().cmp(other.components())
+        T::cmp(&self.inner, &other.inner)
     }
 }
 
@@ -1217,6 +2256,34 @@ where
     }
 }
 
+impl<T> Default for &Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates an empty [`Path`] without allocating.
+    ///
+    /// A [`Cow<Path>`](Cow) can likewise default to an empty path without allocating as it
+    /// defaults to an empty owned path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typed_path::{Path, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path: &Path<UnixEncoding> = Default::default();
+    /// assert_eq!(path, Path::<UnixEncoding>::EMPTY);
+    ///
+    /// let cow: Cow<'_, Path<UnixEncoding>> = Default::default();
+    /// assert_eq!(cow, Path::<UnixEncoding>::EMPTY);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Path::new(b"")
+    }
+}
+
 impl<'a, T> From<&'a Path<T>> for Cow<'a, Path<T>>
 where
     T: for<'enc> Encoding<'enc>,
@@ -1440,9 +2507,99 @@ impl_cmp_bytes!('a; &'a Path<T>, [u8]);
 impl_cmp_bytes!('a, 'b; &'a Path<T>, Cow<'b, [u8]>);
 impl_cmp_bytes!('a; &'a Path<T>, Vec<u8>);
 
-mod helpers {
+pub(crate) mod helpers {
     use super::*;
 
+    /// Writes `bytes` to a [`defmt::Formatter`], replacing each invalid UTF-8 sequence with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD] without allocating.
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    #[cfg(feature = "defmt")]
+    pub fn defmt_write_lossy(f: defmt::Formatter<'_>, mut bytes: &[u8]) {
+        loop {
+            match core::str::from_utf8(bytes) {
+                Ok(s) => {
+                    defmt::write!(f, "{=str}", s);
+                    return;
+                }
+                Err(x) => {
+                    let (valid, rest) = bytes.split_at(x.valid_up_to());
+
+                    // SAFETY: from_utf8 verified that everything up to valid_up_to is UTF-8
+                    let valid = unsafe { core::str::from_utf8_unchecked(valid) };
+                    defmt::write!(f, "{=str}\u{FFFD}", valid);
+
+                    match x.error_len() {
+                        Some(len) => bytes = &rest[len..],
+                        None => return,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the maximum number of bytes that [`PathBuf::push`]ing each of `paths` could add,
+    /// accounting for a separator being inserted before each one.
+    pub fn pushed_len<T, P>(paths: impl Iterator<Item = P>) -> usize
+    where
+        T: for<'enc> Encoding<'enc>,
+        P: AsRef<Path<T>>,
+    {
+        paths.map(|p| p.as_ref().as_bytes().len() + 1).sum()
+    }
+
+    /// Returns true if the ASCII `byte` separates components in encoding `T`.
+    pub fn is_separator<T>(byte: u8) -> bool
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        // Digits are never separators, nor do they form a prefix such as `C:` with what follows
+        !byte.is_ascii_digit() && Path::<T>::new(&[b'0', byte, b'0']).components().count() == 2
+    }
+
+    /// Returns true if `a` and `b` have the same kinds of components in the same order, with
+    /// normal components having the same bytes, even though their encodings differ.
+    pub fn same_components<T, U>(a: &Path<T>, b: &Path<U>) -> bool
+    where
+        T: for<'enc> Encoding<'enc>,
+        U: for<'enc> Encoding<'enc>,
+    {
+        let mut b = b.components();
+        for x in a.components() {
+            let same = match b.next() {
+                Some(y) if x.is_normal() => y.is_normal() && x.as_bytes() == y.as_bytes(),
+                Some(y) => {
+                    x.is_root() == y.is_root()
+                        && x.is_current() == y.is_current()
+                        && x.is_parent() == y.is_parent()
+                        && !y.is_normal()
+                }
+                None => false,
+            };
+            if !same {
+                return false;
+            }
+        }
+        b.next().is_none()
+    }
+
+    /// Returns true if `name` parses as exactly one valid [`Component::Normal`] spanning all of
+    /// `name`, meaning it can be used as a file name without introducing other components.
+    ///
+    /// [`Component::Normal`]: Component::is_normal
+    pub fn is_single_normal_component<T>(name: &[u8]) -> bool
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        let mut components = Path::<T>::new(name).components();
+        match (components.next(), components.next()) {
+            (Some(component), None) => {
+                component.is_normal() && component.is_valid() && component.as_bytes() == name
+            }
+            _ => false,
+        }
+    }
+
     pub fn rsplit_file_at_dot(file: &[u8]) -> (Option<&[u8]>, Option<&[u8]>) {
         if file == b".." {
             return (Some(file), None);
@@ -1542,4 +2699,184 @@ mod std_conversions {
             OsStrExt::from_bytes(self.as_bytes())
         }
     }
+
+    #[cfg(unix)]
+    impl Path<crate::NativeEncoding> {
+        /// Views a [`NativePath`](crate::NativePath) as a [`std::path::Path`] without checking
+        /// for valid UTF-8.
+        ///
+        /// This is useful where a concrete [`std::path::Path`] is needed rather than a generic
+        /// `impl AsRef<std::path::Path>`, such as when working with `tokio::fs` or `fs_err`.
+        ///
+        /// Only available on Unix, as the bytes of a [`NativePath`](crate::NativePath) on Windows
+        /// are not guaranteed to be a valid `OsStr`. Use
+        /// [`Utf8NativePath::as_std_path`](crate::Utf8NativePath::as_std_path) or
+        /// [`TryAsRef`](crate::TryAsRef) there instead.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use typed_path::NativePath;
+        ///
+        /// let path = NativePath::new("/tmp/foo.txt");
+        /// assert_eq!(path.as_std_path(), std::path::Path::new("/tmp/foo.txt"));
+        /// ```
+        #[inline]
+        pub fn as_std_path(&self) -> &std::path::Path {
+            std::path::Path::new(AsRef::<OsStr>::as_ref(self))
+        }
+
+        /// Walks the [`NativePath`](crate::NativePath) and its [`ancestors`](Path::ancestors),
+        /// returning the first directory containing an entry named `marker` on the file system.
+        ///
+        /// Returns `None` without touching the file system if `marker` is not a single normal
+        /// component, such as `../Cargo.toml` or `/etc`, as joining it would look outside of
+        /// each directory.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use typed_path::NativePath;
+        ///
+        /// let manifest_dir = NativePath::new(env!("CARGO_MANIFEST_DIR"));
+        /// let src_dir = manifest_dir.join("src");
+        /// assert_eq!(src_dir.find_up("Cargo.toml"), Some(manifest_dir));
+        /// assert_eq!(src_dir.find_up("../Cargo.toml"), None);
+        /// ```
+        pub fn find_up(&self, marker: impl AsRef<[u8]>) -> Option<&Self> {
+            let marker = marker.as_ref();
+            if !helpers::is_single_normal_component::<crate::NativeEncoding>(marker) {
+                return None;
+            }
+
+            self.find_ancestor_with(|dir| dir.join(marker).as_std_path().exists())
+        }
+
+        /// Creates the directory at this [`NativePath`](crate::NativePath) along with any
+        /// missing [`ancestors`](Path::ancestors), like [`std::fs::create_dir_all`], returning
+        /// the directories that were created from the outermost inward.
+        ///
+        /// Directories that already exist, including those created concurrently by another
+        /// process, are not included. If an error occurs, the directories created before it
+        /// remain on the file system.
+        ///
+        /// # Errors
+        ///
+        /// Returns an [`Err`] under the same conditions as [`std::fs::create_dir_all`], including
+        /// an [`AlreadyExists`] error if the path already exists but is not a directory.
+        ///
+        /// [`AlreadyExists`]: std::io::ErrorKind::AlreadyExists
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use typed_path::NativePathBuf;
+        ///
+        /// let root = NativePathBuf::from(std::env::temp_dir().to_str().unwrap())
+        ///     .join(format!("typed-path-doc-{}", std::process::id()));
+        /// let path = root.join("a/b");
+        ///
+        /// let created = path.create_dir_all_verbose().unwrap();
+        /// assert_eq!(created, [root.clone(), root.join("a"), root.join("a/b")]);
+        /// assert!(path.create_dir_all_verbose().unwrap().is_empty());
+        ///
+        /// // The created list makes it easy to roll back, innermost first
+        /// for dir in created.iter().rev() {
+        ///     std::fs::remove_dir(dir).unwrap();
+        /// }
+        /// ```
+        pub fn create_dir_all_verbose(
+            &self,
+        ) -> std::io::Result<Vec<PathBuf<crate::NativeEncoding>>> {
+            let missing: Vec<&Self> = self
+                .ancestors()
+                .filter(|dir| !dir.as_bytes().is_empty())
+                .take_while(|dir| !dir.as_std_path().exists())
+                .collect();
+
+            if missing.is_empty() && !self.as_bytes().is_empty() && !self.as_std_path().is_dir() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    "path exists and is not a directory",
+                ));
+            }
+
+            let mut created = Vec::with_capacity(missing.len());
+            for dir in missing.into_iter().rev() {
+                match std::fs::create_dir(dir) {
+                    Ok(()) => created.push(dir.to_path_buf()),
+                    Err(x)
+                        if x.kind() == std::io::ErrorKind::AlreadyExists
+                            && dir.as_std_path().is_dir() => {}
+                    Err(x) => return Err(x),
+                }
+            }
+            Ok(created)
+        }
+    }
+
+    #[cfg(unix)]
+    impl AsRef<std::path::Path> for Path<crate::NativeEncoding> {
+        /// Views a [`NativePath`](crate::NativePath) as a [`std::path::Path`] without checking
+        /// for valid UTF-8, allowing it to be passed directly to functions like
+        /// [`File::open`](std::fs::File::open).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use typed_path::NativePath;
+        ///
+        /// let path = NativePath::new(b"/tmp/\xff.txt");
+        /// let std_path: &std::path::Path = path.as_ref();
+        /// assert_eq!(
+        ///     std::os::unix::ffi::OsStrExt::as_bytes(std_path.as_os_str()),
+        ///     path.as_bytes()
+        /// );
+        /// ```
+        #[inline]
+        fn as_ref(&self) -> &std::path::Path {
+            self.as_std_path()
+        }
+    }
+}
+
+/// Conversions for platforms whose [`OsStrExt`] does not expose raw bytes, such as Windows. These
+/// rely on [`OsStr::as_encoded_bytes`], which yields the platform's internal encoding (WTF-8 on
+/// Windows), so only the direction from [`OsStr`] into [`Path`] is provided.
+///
+/// [`OsStrExt`]: https://doc.rust-lang.org/std/os/unix/ffi/trait.OsStrExt.html
+/// [`OsStr`]: std::ffi::OsStr
+/// [`OsStr::as_encoded_bytes`]: std::ffi::OsStr::as_encoded_bytes
+#[cfg(not(any(
+    unix,
+    all(target_vendor = "fortanix", target_env = "sgx"),
+    target_os = "solid_asp3",
+    target_os = "hermit",
+    target_os = "wasi"
+)))]
+#[cfg(all(feature = "std", typed_path_os_str_encoded_bytes))]
+mod std_encoded_bytes_conversions {
+    use std::ffi::{OsStr, OsString};
+
+    use super::*;
+
+    impl<T> AsRef<Path<T>> for OsStr
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        #[inline]
+        fn as_ref(&self) -> &Path<T> {
+            Path::new(self.as_encoded_bytes())
+        }
+    }
+
+    impl<T> AsRef<Path<T>> for OsString
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        #[inline]
+        fn as_ref(&self) -> &Path<T> {
+            Path::new(self.as_encoded_bytes())
+        }
+    }
 }
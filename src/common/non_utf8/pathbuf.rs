@@ -8,8 +8,9 @@ use core::ops::Deref;
 use core::str::FromStr;
 use core::{cmp, fmt};
 
+use super::helpers;
 use crate::no_std_compat::*;
-use crate::{CheckedPathError, Encoding, Iter, Path};
+use crate::{CheckedPathError, Component, Encoding, Iter, Path, PathCheckpoint, TryAsRef};
 
 /// An owned, mutable path that mirrors [`std::path::PathBuf`], but operatings using an
 /// [`Encoding`] to determine how to parse the underlying bytes.
@@ -117,6 +118,75 @@ where
         }
     }
 
+    /// Parses, validates, and [`normalizes`] `input` in a single pass, allocating only the
+    /// resulting path.
+    ///
+    /// This produces the same path as `PathBuf::from(input).normalize()`, but fails instead of
+    /// silently repairing input that cannot be normalized safely:
+    ///
+    /// * [`CheckedPathError::InvalidFilename`] if a component contains bytes that are not allowed
+    ///   by the encoding.
+    /// * [`CheckedPathError::PathTraversalAttack`] if a `..` component would escape a path that
+    ///   has no root. Leading `..` components of rooted paths are dropped as with [`normalize`].
+    ///
+    /// [`normalize`]: Path::normalize
+    /// [`normalizes`]: Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     PathBuf::<UnixEncoding>::normalized_from("/tmp//foo/./bar/.."),
+    ///     Ok(PathBuf::from("/tmp/foo")),
+    /// );
+    /// assert_eq!(
+    ///     PathBuf::<UnixEncoding>::normalized_from("/../etc"),
+    ///     Ok(PathBuf::from("/etc")),
+    /// );
+    /// assert_eq!(
+    ///     PathBuf::<UnixEncoding>::normalized_from("foo/../../etc"),
+    ///     Err(CheckedPathError::PathTraversalAttack),
+    /// );
+    /// assert_eq!(
+    ///     PathBuf::<WindowsEncoding>::normalized_from(r"C:\dir\file?.txt"),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// ```
+    pub fn normalized_from<P: AsRef<[u8]>>(input: P) -> Result<Self, CheckedPathError> {
+        let input = input.as_ref();
+        let mut path = Self::with_capacity(input.len());
+
+        for component in T::components(input) {
+            if !component.is_valid() {
+                return Err(CheckedPathError::InvalidFilename);
+            }
+
+            if component.is_current() {
+                continue;
+            }
+
+            if component.is_parent() {
+                let after_normal = path
+                    .components()
+                    .next_back()
+                    .map_or(false, |c| c.is_normal());
+                if after_normal {
+                    path.pop();
+                } else if !path.has_root() {
+                    return Err(CheckedPathError::PathTraversalAttack);
+                }
+                continue;
+            }
+
+            T::push(&mut path.inner, component.as_bytes());
+        }
+
+        Ok(path)
+    }
+
     /// Coerces to a [`Path`] slice.
     ///
     /// # Examples
@@ -175,6 +245,24 @@ where
         T::push(&mut self.inner, path.as_ref().as_bytes());
     }
 
+    /// Like [`PathBuf::push`], extends `self` with `path`, but returns `self` so that further
+    /// changes can be chained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<UnixEncoding>::from("/tmp");
+    /// path.push_chained("a").push_chained("b").set_extension_chained("rs");
+    /// assert_eq!(path, PathBuf::from("/tmp/a/b.rs"));
+    /// ```
+    pub fn push_chained<P: AsRef<Path<T>>>(&mut self, path: P) -> &mut Self {
+        self.push(path);
+        self
+    }
+
     /// Like [`PathBuf::push`], extends `self` with `path`, but also checks to ensure that `path`
     /// abides by a set of rules.
     ///
@@ -262,6 +350,65 @@ where
         }
     }
 
+    /// Returns a [`PathCheckpoint`] recording the current length and contents of the buffer,
+    /// which [`truncate_to`] can later restore.
+    ///
+    /// [`truncate_to`]: PathBuf::truncate_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<UnixEncoding>::from("/home");
+    /// let checkpoint = p.checkpoint();
+    ///
+    /// p.push("ferris/projects");
+    /// assert!(p.truncate_to(checkpoint));
+    /// assert_eq!(p.as_bytes(), b"/home");
+    /// ```
+    #[inline]
+    pub fn checkpoint(&self) -> PathCheckpoint {
+        PathCheckpoint::new(&self.inner)
+    }
+
+    /// Truncates the buffer back to the length recorded by `checkpoint`, discarding everything
+    /// pushed since it was taken, and returns true.
+    ///
+    /// If the buffer no longer starts with the bytes it held when the checkpoint was taken, such
+    /// as after pushing an absolute path or calling [`clear`], the buffer is left unchanged and
+    /// false is returned.
+    ///
+    /// [`clear`]: PathBuf::clear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<WindowsEncoding>::from(r"C:");
+    /// for dir in ["Users", "Windows"] {
+    ///     let checkpoint = p.checkpoint();
+    ///     p.push(dir);
+    ///     p.push("Temp");
+    ///     assert!(p.ends_with("Temp"));
+    ///     assert!(p.truncate_to(checkpoint));
+    /// }
+    /// assert_eq!(p.as_bytes(), br"C:");
+    /// ```
+    #[inline]
+    pub fn truncate_to(&mut self, checkpoint: PathCheckpoint) -> bool {
+        match checkpoint.len_in(&self.inner) {
+            Some(len) => {
+                self.inner.truncate(len);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Updates [`self.file_name`] to `file_name`.
     ///
     /// If [`self.file_name`] was [`None`], this is equivalent to pushing
@@ -300,6 +447,79 @@ where
         self.push(file_name);
     }
 
+    /// Like [`PathBuf::set_file_name`], updates [`self.file_name`] to `file_name`, but returns
+    /// `self` so that further changes can be chained.
+    ///
+    /// [`self.file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = PathBuf::<UnixEncoding>::from("/tmp/foo.txt");
+    /// buf.set_file_name_chained("bar").push_chained("baz.txt");
+    /// assert_eq!(buf, PathBuf::from("/tmp/bar/baz.txt"));
+    /// ```
+    pub fn set_file_name_chained<S: AsRef<[u8]>>(&mut self, file_name: S) -> &mut Self {
+        self.set_file_name(file_name);
+        self
+    }
+
+    /// Like [`PathBuf::set_file_name`], updates [`self.file_name`] to `file_name`, but first
+    /// checks that `file_name` is a single, valid file name for the encoding.
+    ///
+    /// [`PathBuf::set_file_name`] pushes `file_name` as a path, so a value containing separators
+    /// silently introduces additional components. This should be used instead when `file_name`
+    /// comes from an untrusted source.
+    ///
+    /// [`self.file_name`]: Path::file_name
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] and leaves the path unchanged if
+    /// `file_name`:
+    ///
+    /// * is empty, `.`, or `..`
+    /// * contains a separator or any other character disallowed by the encoding, such as `\0`
+    ///   on Unix or `|` on Windows
+    /// * is a Windows prefix such as `C:`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PathBuf, UnixEncoding, WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = PathBuf::<UnixEncoding>::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_file_name("bar.txt"), Ok(()));
+    /// assert_eq!(buf, PathBuf::from("/tmp/bar.txt"));
+    ///
+    /// assert_eq!(buf.try_set_file_name("../etc/passwd"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name(".."), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name("a\0b"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, PathBuf::from("/tmp/bar.txt"));
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = PathBuf::<WindowsEncoding>::from(r"C:\tmp\foo.txt");
+    /// assert_eq!(buf.try_set_file_name(r"bar\baz.txt"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name("bar|baz.txt"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name("D:"), Err(CheckedPathError::InvalidFilename));
+    /// ```
+    pub fn try_set_file_name<S: AsRef<[u8]>>(
+        &mut self,
+        file_name: S,
+    ) -> Result<(), CheckedPathError> {
+        let file_name = file_name.as_ref();
+        if !helpers::is_single_normal_component::<T>(file_name) {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        self._set_file_name(file_name);
+        Ok(())
+    }
+
     /// Updates [`self.extension`] to `extension`.
     ///
     /// Returns `false` and does nothing if [`self.file_name`] is [`None`],
@@ -358,6 +578,105 @@ where
         true
     }
 
+    /// Like [`PathBuf::set_extension`], updates [`self.extension`] to `extension`, but returns
+    /// `self` so that further changes can be chained.
+    ///
+    /// As with [`PathBuf::set_extension`], nothing happens if [`self.file_name`] is [`None`].
+    ///
+    /// [`self.file_name`]: Path::file_name
+    /// [`self.extension`]: Path::extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = PathBuf::<UnixEncoding>::from("/tmp/foo.txt");
+    /// buf.set_extension_chained("rs").push_chained("..");
+    /// assert_eq!(buf, PathBuf::from("/tmp/foo.rs/.."));
+    /// ```
+    pub fn set_extension_chained<S: AsRef<[u8]>>(&mut self, extension: S) -> &mut Self {
+        self.set_extension(extension);
+        self
+    }
+
+    /// Like [`PathBuf::set_extension`], updates [`self.extension`] to `extension`, but first
+    /// checks that the resulting file name would still be a single, valid file name.
+    ///
+    /// An empty `extension` is always accepted and removes the current extension.
+    ///
+    /// [`self.extension`]: Path::extension
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] and leaves the path unchanged if
+    /// `extension` contains a separator or any other character disallowed by the encoding, or
+    /// is `.` or `..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = PathBuf::<UnixEncoding>::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_extension("tar.gz"), Ok(true));
+    /// assert_eq!(buf, PathBuf::from("/tmp/foo.tar.gz"));
+    ///
+    /// assert_eq!(buf.try_set_extension("gz/evil"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, PathBuf::from("/tmp/foo.tar.gz"));
+    ///
+    /// let mut buf = PathBuf::<UnixEncoding>::from("/");
+    /// assert_eq!(buf.try_set_extension("txt"), Ok(false));
+    /// ```
+    pub fn try_set_extension<S: AsRef<[u8]>>(
+        &mut self,
+        extension: S,
+    ) -> Result<bool, CheckedPathError> {
+        let extension = extension.as_ref();
+        if !extension.is_empty() && !helpers::is_single_normal_component::<T>(extension) {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        Ok(self._set_extension(extension))
+    }
+
+    /// Converts [`self.extension`] to lowercase in place, so that extensions such as `JPG` and
+    /// `jpg` compare equal.
+    ///
+    /// Only ASCII characters are converted, as the underlying bytes are not guaranteed to be
+    /// UTF-8. See [`Utf8PathBuf::lowercase_extension`] for full Unicode lowercasing.
+    ///
+    /// Returns `false` and does nothing if [`self.extension`] is [`None`], returns `true`
+    /// otherwise.
+    ///
+    /// [`self.extension`]: Path::extension
+    /// [`Utf8PathBuf::lowercase_extension`]: crate::Utf8PathBuf::lowercase_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = PathBuf::<UnixEncoding>::from("/photos/IMG_0001.JPG");
+    /// assert!(p.lowercase_extension());
+    /// assert_eq!(Path::new("/photos/IMG_0001.jpg"), p.as_path());
+    ///
+    /// let mut p = PathBuf::<UnixEncoding>::from("/photos/README");
+    /// assert!(!p.lowercase_extension());
+    /// ```
+    pub fn lowercase_extension(&mut self) -> bool {
+        match self.extension() {
+            Some(extension) => {
+                let extension = extension.to_ascii_lowercase();
+                self._set_extension(&extension)
+            }
+            None => false,
+        }
+    }
+
     /// Consumes the `PathBuf`, yielding its internal [`Vec<u8>`] storage.
     ///
     /// # Examples
@@ -373,6 +692,28 @@ where
         self.inner
     }
 
+    /// Converts this [`PathBuf`] into a [`std::path::PathBuf`], returning the original path if
+    /// it is not valid UTF-8.
+    ///
+    /// This is equivalent to `TryFrom<PathBuf<T>>` for [`std::path::PathBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// let p = PathBuf::<UnixEncoding>::from("/the/head");
+    /// assert_eq!(p.try_into_std_pathbuf().unwrap(), std::path::PathBuf::from("/the/head"));
+    ///
+    /// let p = PathBuf::<UnixEncoding>::from(b"/the/\xff");
+    /// assert!(p.try_into_std_pathbuf().is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn try_into_std_pathbuf(self) -> Result<std::path::PathBuf, Self> {
+        std::path::PathBuf::try_from(self)
+    }
+
     /// Converts this [`PathBuf`] into a [boxed](Box) [`Path`].
     #[inline]
     pub fn into_boxed_path(self) -> Box<Path<T>> {
@@ -464,12 +805,22 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PathBuf")
-            .field("_encoding", &T::label())
-            .field("inner", &self.inner)
+            .field("encoding", &T::label())
+            .field("path", &self.to_string_lossy())
             .finish()
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_path(), f)
+    }
+}
+
 impl<T> AsRef<[u8]> for PathBuf<T>
 where
     T: for<'enc> Encoding<'enc>,
@@ -480,6 +831,29 @@ where
     }
 }
 
+impl<T> TryAsRef<str> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Views the path as a [`str`], failing if the path is not valid UTF-8.
+    #[inline]
+    fn try_as_ref(&self) -> Option<&str> {
+        self.to_str()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> TryAsRef<std::path::Path> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Views the path as a [`std::path::Path`], failing if the path is not valid UTF-8.
+    #[inline]
+    fn try_as_ref(&self) -> Option<&std::path::Path> {
+        self.as_path().try_as_ref()
+    }
+}
+
 impl<T> AsRef<Path<T>> for PathBuf<T>
 where
     T: for<'enc> Encoding<'enc>,
@@ -538,8 +912,31 @@ where
     T: for<'enc> Encoding<'enc>,
     P: AsRef<Path<T>>,
 {
+    /// Extends `self` by [`pushing`] each path from `iter` in order.
+    ///
+    /// Before pushing, a byte is reserved for the separator of each path promised by the lower
+    /// bound of the iterator's [`size_hint`]. Paths are then pushed as they are produced, so the
+    /// buffer may still grow more than once. Use [`reserve`] beforehand when the total length is
+    /// known.
+    ///
+    /// [`pushing`]: PathBuf::push
+    /// [`reserve`]: PathBuf::reserve
+    /// [`size_hint`]: Iterator::size_hint
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = PathBuf::<UnixEncoding>::from("/root");
+    /// path.extend(["a", "b", "c"]);
+    /// assert_eq!(path, PathBuf::from("/root/a/b/c"));
+    /// ```
     fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
-        iter.into_iter().for_each(move |p| self.push(p.as_ref()));
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(move |p| self.push(p.as_ref()));
     }
 }
 
@@ -776,6 +1173,27 @@ mod std_conversions {
         }
     }
 
+    impl<T> From<OsString> for PathBuf<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        /// Converts an [`OsString`] into a [`PathBuf`] without copying or checking its bytes.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsString;
+        /// use typed_path::NativePathBuf;
+        ///
+        /// let path = NativePathBuf::from(OsString::from("foo.txt"));
+        /// assert_eq!(path, NativePathBuf::from("foo.txt"));
+        /// ```
+        #[inline]
+        fn from(s: OsString) -> Self {
+            PathBuf::from(s.into_vec())
+        }
+    }
+
     impl<T> AsRef<OsStr> for PathBuf<T>
     where
         T: for<'enc> Encoding<'enc>,
@@ -785,4 +1203,58 @@ mod std_conversions {
             OsStrExt::from_bytes(self.as_bytes())
         }
     }
+
+    #[cfg(unix)]
+    impl AsRef<std::path::Path> for PathBuf<crate::NativeEncoding> {
+        /// Views a [`NativePathBuf`](crate::NativePathBuf) as a [`std::path::Path`] without
+        /// checking for valid UTF-8, allowing it to be passed directly to functions like
+        /// [`File::open`](std::fs::File::open).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use typed_path::NativePathBuf;
+        ///
+        /// let path = NativePathBuf::from(b"/tmp/\xff.txt");
+        /// let std_path: &std::path::Path = path.as_ref();
+        /// assert_eq!(
+        ///     std::os::unix::ffi::OsStrExt::as_bytes(std_path.as_os_str()),
+        ///     path.as_bytes()
+        /// );
+        /// ```
+        #[inline]
+        fn as_ref(&self) -> &std::path::Path {
+            self.as_path().as_ref()
+        }
+    }
+}
+
+/// Conversions for platforms whose `OsStringExt` does not expose raw bytes, such as Windows,
+/// relying on [`OsString::into_encoded_bytes`] instead.
+///
+/// [`OsString::into_encoded_bytes`]: std::ffi::OsString::into_encoded_bytes
+#[cfg(not(any(
+    unix,
+    all(target_vendor = "fortanix", target_env = "sgx"),
+    target_os = "solid_asp3",
+    target_os = "hermit",
+    target_os = "wasi"
+)))]
+#[cfg(all(feature = "std", typed_path_os_str_encoded_bytes))]
+mod std_encoded_bytes_conversions {
+    use std::ffi::OsString;
+
+    use super::*;
+
+    impl<T> From<OsString> for PathBuf<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        /// Converts an [`OsString`] into a [`PathBuf`] using the platform's internal encoding of
+        /// the string (WTF-8 on Windows) without copying.
+        #[inline]
+        fn from(s: OsString) -> Self {
+            PathBuf::from(s.into_encoded_bytes())
+        }
+    }
 }
@@ -0,0 +1,544 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{cmp, fmt};
+
+use crate::{Encoding, Path, PathBuf, RootKind};
+
+/// A slice of a path that is guaranteed to be relative, meaning it has neither a root nor a
+/// prefix.
+///
+/// Functions that take a `RelPath` encode "must be relative" in their signature, rather than
+/// checking at runtime. Joining two relative paths with [`RelPath::join`] yields another
+/// [`RelPathBuf`], so relativity is preserved without rechecking.
+///
+/// Being relative says nothing about containment: `../secret` is a valid `RelPath`. See
+/// [`PathBuf::push_checked`] for that guarantee.
+///
+/// `RelPath` implements [`Deref`] to [`Path`], meaning that all methods on [`Path`] slices are
+/// available on `RelPath` values as well.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{RelPath, UnixEncoding, WindowsEncoding};
+///
+/// // NOTE: A path cannot be created on its own without a defined encoding
+/// let path = RelPath::<UnixEncoding>::new("assets/textures").unwrap();
+/// assert_eq!(
+///     path.join(RelPath::new("hero.png").unwrap()),
+///     RelPath::new("assets/textures/hero.png").unwrap()
+/// );
+///
+/// assert!(RelPath::<UnixEncoding>::new("/etc/passwd").is_none());
+/// assert!(RelPath::<WindowsEncoding>::new(r"\Windows").is_none());
+/// assert!(RelPath::<WindowsEncoding>::new(r"C:Windows").is_none());
+/// ```
+#[repr(transparent)]
+pub struct RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Path known to have a [`RootKind::Relative`] root kind
+    inner: Path<T>,
+}
+
+impl<T> RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Wraps a byte slice as a `RelPath`, returning [`None`] if it has a root or a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RelPath, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(RelPath::<UnixEncoding>::new("foo/bar").is_some());
+    /// assert!(RelPath::<UnixEncoding>::new("../foo").is_some());
+    /// assert!(RelPath::<UnixEncoding>::new("").is_some());
+    /// assert!(RelPath::<UnixEncoding>::new("/foo").is_none());
+    /// ```
+    pub fn new<S: AsRef<[u8]> + ?Sized>(s: &S) -> Option<&Self> {
+        Self::from_path(Path::new(s))
+    }
+
+    /// Converts a [`Path`] into a `RelPath`, returning [`None`] if it has a root or a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RelPath, WindowsPath};
+    ///
+    /// assert!(RelPath::from_path(WindowsPath::new(r"foo\bar")).is_some());
+    /// assert!(RelPath::from_path(WindowsPath::new(r"\\server\share\foo")).is_none());
+    /// ```
+    pub fn from_path(path: &Path<T>) -> Option<&Self> {
+        if path.root_kind() == RootKind::Relative {
+            Some(unsafe { &*(path as *const Path<T> as *const Self) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the underlying [`Path`].
+    pub fn as_path(&self) -> &Path<T> {
+        &self.inner
+    }
+
+    /// Creates an owned [`RelPathBuf`] with `path` adjoined to `self`.
+    ///
+    /// Unlike [`Path::join`], `path` must itself be relative, so the result is guaranteed to be
+    /// relative as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RelPath, WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let dir = RelPath::<WindowsEncoding>::new("assets").unwrap();
+    /// let file = RelPath::new(r"textures\hero.png").unwrap();
+    /// assert_eq!(dir.join(file).as_bytes(), br"assets\textures\hero.png");
+    /// ```
+    pub fn join<P: AsRef<RelPath<T>>>(&self, path: P) -> RelPathBuf<T> {
+        let mut buf = self.to_rel_path_buf();
+        buf.push(path);
+        buf
+    }
+
+    /// Returns the `RelPath` without its final component, if there is one.
+    ///
+    /// See [`Path::parent`] for more details. The parent of a relative path is always relative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RelPath, UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = RelPath::<UnixEncoding>::new("foo/bar").unwrap();
+    /// assert_eq!(path.parent(), RelPath::new("foo"));
+    /// assert_eq!(path.parent().and_then(RelPath::parent), RelPath::new(""));
+    /// ```
+    pub fn parent(&self) -> Option<&RelPath<T>> {
+        self.inner
+            .parent()
+            .map(|path| unsafe { &*(path as *const Path<T> as *const Self) })
+    }
+
+    /// Converts `self` into an owned [`RelPathBuf`].
+    pub fn to_rel_path_buf(&self) -> RelPathBuf<T> {
+        RelPathBuf {
+            inner: self.inner.to_path_buf(),
+        }
+    }
+}
+
+impl<T> AsRef<[u8]> for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        &self.inner
+    }
+}
+
+impl<T> AsRef<RelPath<T>> for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &RelPath<T> {
+        self
+    }
+}
+
+impl<T> fmt::Debug for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelPath")
+            .field("encoding", &T::label())
+            .field("path", &self.inner.to_string_lossy())
+            .finish()
+    }
+}
+
+impl<T> Deref for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Target = Path<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> PartialEq for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T> Eq for RelPath<T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> Hash for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
+impl<T> cmp::PartialOrd for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> cmp::Ord for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T> ToOwned for RelPath<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Owned = RelPathBuf<T>;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.to_rel_path_buf()
+    }
+}
+
+/// An owned, mutable path that is guaranteed to be relative, akin to [`PathBuf`].
+///
+/// See [`RelPath`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{RelPath, RelPathBuf, UnixPathBuf};
+///
+/// let mut path = RelPathBuf::try_from(UnixPathBuf::from("assets")).unwrap();
+/// path.push(RelPath::new("hero.png").unwrap());
+/// assert_eq!(path.as_bytes(), b"assets/hero.png");
+///
+/// assert!(RelPathBuf::try_from(UnixPathBuf::from("/assets")).is_err());
+/// ```
+pub struct RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Path known to have a [`RootKind::Relative`] root kind
+    inner: PathBuf<T>,
+}
+
+impl<T> RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Allocates an empty `RelPathBuf`.
+    pub fn new() -> Self {
+        Self {
+            inner: PathBuf::new(),
+        }
+    }
+
+    /// Converts a [`PathBuf`] into a `RelPathBuf`, returning the original path if it has a root
+    /// or a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RelPathBuf, WindowsPathBuf};
+    ///
+    /// assert!(RelPathBuf::from_path_buf(WindowsPathBuf::from(r"foo\bar")).is_ok());
+    /// assert_eq!(
+    ///     RelPathBuf::from_path_buf(WindowsPathBuf::from(r"C:\foo")),
+    ///     Err(WindowsPathBuf::from(r"C:\foo")),
+    /// );
+    /// ```
+    pub fn from_path_buf(path: PathBuf<T>) -> Result<Self, PathBuf<T>> {
+        if path.root_kind() == RootKind::Relative {
+            Ok(Self { inner: path })
+        } else {
+            Err(path)
+        }
+    }
+
+    /// Coerces to a [`RelPath`] slice.
+    pub fn as_rel_path(&self) -> &RelPath<T> {
+        unsafe { &*(self.inner.as_path() as *const Path<T> as *const RelPath<T>) }
+    }
+
+    /// Consumes the `RelPathBuf`, yielding the underlying [`PathBuf`].
+    pub fn into_path_buf(self) -> PathBuf<T> {
+        self.inner
+    }
+
+    /// Extends `self` with `path`, which must be relative so that `self` stays relative.
+    ///
+    /// See [`PathBuf::push`] for more details.
+    pub fn push<P: AsRef<RelPath<T>>>(&mut self, path: P) {
+        self.inner.push(path.as_ref().as_path());
+    }
+
+    /// Truncates `self` to [`self.parent`].
+    ///
+    /// Returns `false` and does nothing if [`self.parent`] is [`None`]. Otherwise, returns
+    /// `true`.
+    ///
+    /// [`self.parent`]: RelPath::parent
+    pub fn pop(&mut self) -> bool {
+        self.inner.pop()
+    }
+}
+
+impl<T> AsRef<[u8]> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.inner.as_bytes()
+    }
+}
+
+impl<T> AsRef<Path<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        self.inner.as_path()
+    }
+}
+
+impl<T> AsRef<RelPath<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &RelPath<T> {
+        self.as_rel_path()
+    }
+}
+
+impl<T> Borrow<RelPath<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn borrow(&self) -> &RelPath<T> {
+        self.as_rel_path()
+    }
+}
+
+impl<T> Clone for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelPathBuf")
+            .field("encoding", &T::label())
+            .field("path", &self.inner.to_string_lossy())
+            .finish()
+    }
+}
+
+impl<T> Default for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Target = RelPath<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_rel_path()
+    }
+}
+
+impl<T> From<&RelPath<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn from(path: &RelPath<T>) -> Self {
+        path.to_rel_path_buf()
+    }
+}
+
+impl<T> From<RelPathBuf<T>> for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn from(path: RelPathBuf<T>) -> Self {
+        path.into_path_buf()
+    }
+}
+
+impl<T> TryFrom<PathBuf<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Error = PathBuf<T>;
+
+    fn try_from(path: PathBuf<T>) -> Result<Self, Self::Error> {
+        Self::from_path_buf(path)
+    }
+}
+
+impl<T> PartialEq for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T> PartialEq<RelPath<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn eq(&self, other: &RelPath<T>) -> bool {
+        self.as_rel_path() == other
+    }
+}
+
+impl<T> PartialEq<&RelPath<T>> for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn eq(&self, other: &&RelPath<T>) -> bool {
+        self.as_rel_path() == *other
+    }
+}
+
+impl<T> Eq for RelPathBuf<T> where T: for<'enc> Encoding<'enc> {}
+
+impl<T> Hash for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_rel_path().hash(state)
+    }
+}
+
+impl<T> cmp::PartialOrd for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> cmp::Ord for RelPathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_rel_path().cmp(other.as_rel_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    #[test]
+    fn should_only_accept_relative_paths() {
+        for path in ["", ".", "..", "foo", "foo/bar", "./foo", "../foo"] {
+            assert!(RelPath::<UnixEncoding>::new(path).is_some(), "{path:?}");
+        }
+        for path in ["/", "/foo", "//foo"] {
+            assert!(RelPath::<UnixEncoding>::new(path).is_none(), "{path:?}");
+        }
+
+        for path in ["", "foo", r"foo\bar", "foo/bar", r"..\foo"] {
+            assert!(RelPath::<WindowsEncoding>::new(path).is_some(), "{path:?}");
+        }
+        for path in [
+            r"\foo",
+            "/foo",
+            "C:",
+            "C:foo",
+            r"C:\foo",
+            r"\\server\share",
+            r"\\?\C:\foo",
+            r"\\.\COM1",
+        ] {
+            assert!(RelPath::<WindowsEncoding>::new(path).is_none(), "{path:?}");
+        }
+    }
+
+    #[test]
+    fn join_and_push_should_stay_relative() {
+        let mut buf = RelPathBuf::<WindowsEncoding>::new();
+        buf.push(RelPath::new("a").unwrap());
+        buf.push(RelPath::new(r"b\c").unwrap());
+        assert_eq!(buf.as_bytes(), br"a\b\c");
+        assert_eq!(buf.root_kind(), RootKind::Relative);
+
+        let joined = buf.join(RelPath::new("..").unwrap());
+        assert_eq!(joined.as_bytes(), br"a\b\c\..");
+        assert_eq!(joined.root_kind(), RootKind::Relative);
+
+        assert!(buf.pop());
+        assert_eq!(buf, RelPath::new(r"a\b").unwrap());
+    }
+}
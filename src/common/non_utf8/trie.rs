@@ -0,0 +1,449 @@
+use core::marker::PhantomData;
+use core::{fmt, slice};
+
+use crate::no_std_compat::*;
+use crate::{Component, Components, Encoding, Path, PathBuf};
+
+/// A map keyed by paths that is stored as a tree of their [`components`], supporting
+/// longest-prefix lookups.
+///
+/// Keys are split into components before being stored, so `a/b`, `a//b`, and `a/./b` all refer
+/// to the same entry, and components are compared using the rules of the encoding `T`. Iterating
+/// yields entries in the same order as sorting their paths, with every path coming before the
+/// paths beneath it.
+///
+/// [`components`]: Path::components
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathTrie, UnixEncoding, UnixPath};
+///
+/// let mut mounts = PathTrie::<UnixEncoding, &str>::new();
+/// mounts.insert("/", "rootfs");
+/// mounts.insert("/home", "homefs");
+/// mounts.insert("/home/user/cache", "tmpfs");
+///
+/// let (prefix, fs) = mounts.longest_prefix("/home/user/docs/notes.txt").unwrap();
+/// assert_eq!((prefix, *fs), (UnixPath::new("/home"), "homefs"));
+///
+/// let (prefix, fs) = mounts.longest_prefix("/etc/hosts").unwrap();
+/// assert_eq!((prefix, *fs), (UnixPath::new("/"), "rootfs"));
+///
+/// let paths: Vec<_> = mounts.iter().map(|(path, _)| path).collect();
+/// assert_eq!(paths, ["/", "/home", "/home/user/cache"].map(UnixPath::new));
+/// ```
+pub struct PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    root: Node<V>,
+    len: usize,
+    _encoding: PhantomData<T>,
+}
+
+/// Component of a path paired with the node it leads to
+type Child<V> = (Vec<u8>, Node<V>);
+
+struct Node<V> {
+    value: Option<V>,
+
+    /// Children sorted by comparing their component as a [`Path`] of the trie's encoding
+    children: Vec<Child<V>>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            children: Vec::new(),
+        }
+    }
+
+    fn search<T>(&self, name: &[u8]) -> Result<usize, usize>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        self.children
+            .binary_search_by(|(child, _)| Path::<T>::new(child).cmp(Path::new(name)))
+    }
+
+    fn child<T>(&self, name: &[u8]) -> Option<&Self>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        self.search::<T>(name).ok().map(|i| &self.children[i].1)
+    }
+
+    fn child_mut<T>(&mut self, name: &[u8]) -> Option<&mut Self>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        match self.search::<T>(name) {
+            Ok(i) => Some(&mut self.children[i].1),
+            Err(_) => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.is_empty()
+    }
+}
+
+impl<V: Clone> Clone for Node<V> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T, V> PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates an empty `PathTrie`.
+    pub fn new() -> Self {
+        Self {
+            root: Node::new(),
+            len: 0,
+            _encoding: PhantomData,
+        }
+    }
+
+    /// Returns the number of paths in the trie.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the trie contains no paths.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts a value for `path`, returning the previous value for the path if there was one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTrie, WindowsEncoding};
+    ///
+    /// let mut trie = PathTrie::<WindowsEncoding, u32>::new();
+    /// assert_eq!(trie.insert(r"C:\Users", 1), None);
+    ///
+    /// // Separators and drive letter case are compared using the Windows rules
+    /// assert_eq!(trie.insert("c:/Users/", 2), Some(1));
+    /// assert_eq!(trie.len(), 1);
+    /// ```
+    pub fn insert<P: AsRef<Path<T>>>(&mut self, path: P, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for component in path.as_ref().components() {
+            let name = component.as_bytes();
+            let i = match node.search::<T>(name) {
+                Ok(i) => i,
+                Err(i) => {
+                    node.children.insert(i, (name.to_vec(), Node::new()));
+                    i
+                }
+            };
+            node = &mut node.children[i].1;
+        }
+
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns a reference to the value for exactly `path`, if there is one.
+    pub fn get<P: AsRef<Path<T>>>(&self, path: P) -> Option<&V> {
+        let mut node = &self.root;
+        for component in path.as_ref().components() {
+            node = node.child::<T>(component.as_bytes())?;
+        }
+        node.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value for exactly `path`, if there is one.
+    pub fn get_mut<P: AsRef<Path<T>>>(&mut self, path: P) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for component in path.as_ref().components() {
+            node = node.child_mut::<T>(component.as_bytes())?;
+        }
+        node.value.as_mut()
+    }
+
+    /// Returns true if the trie contains a value for exactly `path`.
+    #[inline]
+    pub fn contains_path<P: AsRef<Path<T>>>(&self, path: P) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// Removes the value for exactly `path`, returning it if there was one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTrie, UnixEncoding};
+    ///
+    /// let mut trie = PathTrie::<UnixEncoding, u32>::new();
+    /// trie.insert("/a", 1);
+    /// trie.insert("/a/b", 2);
+    ///
+    /// assert_eq!(trie.remove("/a"), Some(1));
+    /// assert_eq!(trie.remove("/a"), None);
+    /// assert_eq!(trie.get("/a/b"), Some(&2));
+    /// assert_eq!(trie.len(), 1);
+    /// ```
+    pub fn remove<P: AsRef<Path<T>>>(&mut self, path: P) -> Option<V> {
+        fn remove_from<'a, T, V>(
+            node: &mut Node<V>,
+            mut components: impl Iterator<Item = &'a [u8]>,
+        ) -> Option<V>
+        where
+            T: for<'enc> Encoding<'enc>,
+        {
+            let name = match components.next() {
+                Some(name) => name,
+                None => return node.value.take(),
+            };
+
+            let i = node.search::<T>(name).ok()?;
+            let value = remove_from::<T, V>(&mut node.children[i].1, components);
+
+            // Prune branches that no longer lead to any value
+            if node.children[i].1.is_empty() {
+                node.children.remove(i);
+            }
+            value
+        }
+
+        let value = remove_from::<T, V>(
+            &mut self.root,
+            path.as_ref().components().map(|c| c.as_bytes()),
+        );
+        if value.is_some() {
+            self.len -= 1;
+        }
+        value
+    }
+
+    /// Returns the longest path in the trie that `path` starts with, along with its value.
+    ///
+    /// The returned prefix is a slice of `path` rather than the key that was inserted, which
+    /// makes it suitable for use with [`Path::strip_prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTrie, UnixEncoding, UnixPath};
+    ///
+    /// let mut routes = PathTrie::<UnixEncoding, &str>::new();
+    /// routes.insert("assets", "static");
+    /// routes.insert("assets/generated", "build");
+    ///
+    /// let (prefix, route) = routes.longest_prefix("assets//generated/app.js").unwrap();
+    /// assert_eq!(*route, "build");
+    /// assert_eq!(prefix, UnixPath::new("assets//generated"));
+    ///
+    /// // Matching is by component, not by bytes
+    /// assert_eq!(routes.longest_prefix("assets-old/app.js"), None);
+    /// ```
+    pub fn longest_prefix<'p, P>(&self, path: &'p P) -> Option<(&'p Path<T>, &V)>
+    where
+        P: AsRef<Path<T>> + ?Sized,
+    {
+        let path = path.as_ref();
+        let mut node = &self.root;
+        let mut best = node.value.as_ref().map(|value| (0, value));
+
+        let mut total = 0;
+        for component in path.components() {
+            node = match node.child::<T>(component.as_bytes()) {
+                Some(node) => node,
+                None => break,
+            };
+            total += 1;
+
+            if let Some(value) = node.value.as_ref() {
+                best = Some((total, value));
+            }
+        }
+
+        let (depth, value) = best?;
+        let mut components = path.components();
+        let remaining = components.clone().count() - depth;
+        for _ in 0..remaining {
+            components.next_back();
+        }
+
+        Some((Path::new(components.as_bytes()), value))
+    }
+
+    /// Returns an iterator over the paths and values in the trie, in path order.
+    pub fn iter(&self) -> PathTrieIter<'_, T, V> {
+        PathTrieIter {
+            root: self.root.value.as_ref(),
+            stack: vec![(PathBuf::new(), self.root.children.iter())],
+        }
+    }
+}
+
+impl<T, V> Clone for PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            _encoding: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Default for PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V> fmt::Debug for PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, P, V> Extend<(P, V)> for PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+    P: AsRef<Path<T>>,
+{
+    fn extend<I: IntoIterator<Item = (P, V)>>(&mut self, iter: I) {
+        for (path, value) in iter {
+            self.insert(path, value);
+        }
+    }
+}
+
+impl<T, P, V> FromIterator<(P, V)> for PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+    P: AsRef<Path<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = (P, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<'a, T, V> IntoIterator for &'a PathTrie<T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Item = (PathBuf<T>, &'a V);
+    type IntoIter = PathTrieIter<'a, T, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`PathTrie`], in path order.
+///
+/// This `struct` is created by the [`iter`] method on [`PathTrie`]. See its documentation for
+/// more.
+///
+/// [`iter`]: PathTrie::iter
+pub struct PathTrieIter<'a, T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    root: Option<&'a V>,
+    stack: Vec<(PathBuf<T>, slice::Iter<'a, Child<V>>)>,
+}
+
+impl<'a, T, V> Iterator for PathTrieIter<'a, T, V>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Item = (PathBuf<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(value) = self.root.take() {
+            return Some((PathBuf::new(), value));
+        }
+
+        loop {
+            let (prefix, children) = self.stack.last_mut()?;
+            match children.next() {
+                Some((name, node)) => {
+                    let path = prefix.join(name);
+                    self.stack.push((path.clone(), node.children.iter()));
+                    if let Some(value) = node.value.as_ref() {
+                        return Some((path, value));
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding, WindowsPath};
+
+    #[test]
+    fn iter_should_yield_paths_in_path_order() {
+        let paths = ["a/b/c", "", "a", "b", "a/b", "a-b", "/", "/a"];
+        let trie: PathTrie<UnixEncoding, ()> = paths.iter().map(|p| (p, ())).collect();
+        assert_eq!(trie.len(), paths.len());
+
+        let mut expected: Vec<_> = paths.iter().map(Path::<UnixEncoding>::new).collect();
+        expected.sort();
+
+        let actual: Vec<_> = trie.iter().map(|(p, _)| p).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn should_rebuild_windows_paths_from_components() {
+        let paths = [r"C:\foo", r"\\server\share\bar", r"C:baz", r"\qux"];
+        let trie: PathTrie<WindowsEncoding, ()> = paths.iter().map(|p| (p, ())).collect();
+        assert_eq!(trie.iter().count(), paths.len());
+
+        for (path, _) in trie.iter() {
+            assert!(
+                paths.iter().any(|p| WindowsPath::new(p) == path),
+                "{path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn remove_should_prune_empty_branches() {
+        let mut trie = PathTrie::<UnixEncoding, u8>::new();
+        trie.insert("a/b/c", 1);
+        assert_eq!(trie.remove("a/b/c"), Some(1));
+        assert!(trie.root.is_empty());
+        assert!(trie.is_empty());
+        assert_eq!(trie.longest_prefix("a/b/c"), None);
+    }
+}
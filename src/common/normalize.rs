@@ -0,0 +1,87 @@
+/// Options controlling how [`Path::normalize_with`] and [`Utf8Path::normalize_with`] build the
+/// normalized path.
+///
+/// [`Path::normalize_with`]: crate::Path::normalize_with
+/// [`Utf8Path::normalize_with`]: crate::Utf8Path::normalize_with
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::NormalizeOptions;
+///
+/// let options = NormalizeOptions::new().empty_as_cur_dir(true);
+/// assert!(options.is_empty_as_cur_dir());
+/// assert!(!NormalizeOptions::default().is_empty_as_cur_dir());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NormalizeOptions {
+    empty_as_cur_dir: bool,
+}
+
+impl NormalizeOptions {
+    /// Creates a new set of options, with everything disabled, matching [`Path::normalize`].
+    ///
+    /// [`Path::normalize`]: crate::Path::normalize
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether a path that normalizes to nothing, such as `.`, `a/..`, or the empty path,
+    /// is returned as `.` rather than as the empty path.
+    ///
+    /// This is useful for tools that pass the result to programs where an empty argument is an
+    /// error or means something else. A path that keeps a prefix or root, such as `C:` or `/`, is
+    /// never empty and so is unaffected.
+    #[inline]
+    pub fn empty_as_cur_dir(mut self, yes: bool) -> Self {
+        self.empty_as_cur_dir = yes;
+        self
+    }
+
+    /// Returns true if an empty result will be replaced by `.`.
+    #[inline]
+    pub fn is_empty_as_cur_dir(&self) -> bool {
+        self.empty_as_cur_dir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UnixPath, Utf8WindowsPath, WindowsPath};
+
+    /// Pins down how the empty path and `.` behave, as both refer to the current directory but
+    /// are distinguished by some operations, just as with `std::path`
+    #[test]
+    fn empty_and_cur_dir_should_behave_consistently() {
+        for (empty, cur) in [
+            (UnixPath::new(""), UnixPath::new(".")),
+            (UnixPath::new(""), UnixPath::new("./.")),
+        ] {
+            assert!(empty.is_cur_dir_like() && !empty.is_cur_dir());
+            assert!(cur.is_cur_dir_like() && cur.is_cur_dir());
+
+            // Both normalize to the empty path, and have no file name
+            assert_eq!(empty.normalize().as_bytes(), b"");
+            assert_eq!(cur.normalize().as_bytes(), b"");
+            assert_eq!(empty.file_name(), None);
+            assert_eq!(cur.file_name(), None);
+
+            // Only `.` has a parent, which is the empty path
+            assert_eq!(empty.parent(), None);
+            assert_eq!(cur.parent().map(UnixPath::as_bytes), Some(b"".as_slice()));
+
+            // Joining the empty path adds nothing, while `.` is kept as written
+            assert_eq!(empty.join("a").as_bytes(), b"a");
+            assert_eq!(UnixPath::new("a").join(empty).as_bytes(), b"a");
+            assert_eq!(cur.join("a").normalize().as_bytes(), b"a");
+            assert_eq!(UnixPath::new("a").join(cur), UnixPath::new("a"));
+        }
+
+        assert!(!WindowsPath::new("C:").is_cur_dir_like());
+        assert!(!WindowsPath::new("C:.").is_cur_dir());
+        assert!(WindowsPath::new(r".\.").is_cur_dir());
+        assert!(Utf8WindowsPath::new(r".\").is_cur_dir());
+        assert!(!Utf8WindowsPath::new(r"..").is_cur_dir_like());
+    }
+}
@@ -0,0 +1,128 @@
+use core::fmt;
+use core::hash::Hasher;
+
+use crate::common::{Encoding, Path, Utf8Encoding, Utf8Path};
+
+/// A compact identifier for a path, derived from the encoding of the path and its
+/// [normalized](Path::normalize) components.
+///
+/// Two paths with the same encoding whose normalized forms are equal always have the same
+/// `PathId`, so `a/./b`, `a/b/`, and `a/c/../b` share an identifier. A [`Utf8Path`] has the same
+/// identifier as the equivalent [`Path`], while the same bytes under different encodings do not.
+///
+/// Identifiers are stable for the lifetime of a process and across runs of the same build, which
+/// makes them suitable as map keys for events about paths. They are not guaranteed to be stable
+/// across versions of this crate or across platforms with different pointer widths, so they
+/// should not be persisted. Like any hash, different paths can collide, so a match should be
+/// confirmed against the paths themselves when that matters.
+///
+/// [`Utf8Path`]: crate::Utf8Path
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{UnixPath, Utf8UnixPath, WindowsPath};
+///
+/// let id = UnixPath::new("/srv/app/config.toml").stable_id();
+/// assert_eq!(id, UnixPath::new("/srv/./app/logs/../config.toml").stable_id());
+/// assert_eq!(id, Utf8UnixPath::new("/srv/app/config.toml").stable_id());
+///
+/// assert_ne!(id, UnixPath::new("/srv/app/config.json").stable_id());
+/// assert_ne!(id, WindowsPath::new("/srv/app/config.toml").stable_id());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathId(u64);
+
+impl PathId {
+    /// Computes the identifier of `path`.
+    pub(crate) fn of<T>(path: &Path<T>) -> Self
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        let mut hasher = StableHasher::with_label(T::label());
+        T::hash(path.normalize().as_bytes(), &mut hasher);
+        Self(hasher.finish())
+    }
+
+    /// Computes the identifier of `path`, which matches that of the equivalent [`Path`] as the
+    /// UTF-8 encodings hash the same way as their byte counterparts.
+    pub(crate) fn of_utf8<T>(path: &Utf8Path<T>) -> Self
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        let mut hasher = StableHasher::with_label(T::label());
+        T::hash(path.normalize().as_str(), &mut hasher);
+        Self(hasher.finish())
+    }
+
+    /// Returns the identifier as an integer.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Debug for PathId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PathId({:016x})", self.0)
+    }
+}
+
+impl fmt::Display for PathId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// 64-bit FNV-1a, which unlike the hashers in `std` does not vary between processes
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn with_label(label: &str) -> Self {
+        let mut hasher = Self(Self::OFFSET_BASIS);
+        hasher.write(label.as_bytes());
+        hasher.write_u8(0xff);
+        hasher
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixPath, WindowsPath};
+
+    #[test]
+    fn stable_hasher_should_match_fnv_1a() {
+        let mut hasher = StableHasher(StableHasher::OFFSET_BASIS);
+        assert_eq!(hasher.finish(), 0xcbf2_9ce4_8422_2325);
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn stable_id_should_match_normalized_paths() {
+        assert_eq!(
+            WindowsPath::new(r"C:\a\..\b").stable_id(),
+            WindowsPath::new("C:/b").stable_id()
+        );
+        assert_ne!(
+            UnixPath::new("/a/b").stable_id(),
+            UnixPath::new("/b/a").stable_id()
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::common::{Encoding, Path, PathBuf, PathSetDecodeError};
+use crate::no_std_compat::*;
+
+/// Serializes a sequence of paths using front coding, where each path is stored as the length
+/// of the prefix it shares with the previous path followed by the remaining suffix.
+///
+/// Paths are not sorted by the encoder. Sorting them first, such as with [`Path::cmp`] or by
+/// bytes, places paths from the same directory next to each other and is what makes the
+/// encoding compact. The paths come back from [`PathSetDecoder`] in the order they were pushed.
+///
+/// Each entry is written as two unsigned LEB128 integers, the shared prefix length and the
+/// suffix length, followed by the suffix bytes. Only the bytes of each path are stored, so the
+/// decoder must be given the same encoding `T` that was used to encode.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathSetDecoder, PathSetEncoder, UnixEncoding, UnixPathBuf};
+///
+/// let mut encoder = PathSetEncoder::<UnixEncoding>::new();
+/// encoder.push("/usr/lib/libc.so");
+/// encoder.push("/usr/lib/libm.so");
+/// encoder.push("/usr/lib/libz.so");
+/// let bytes = encoder.finish();
+/// assert!(bytes.len() < "/usr/lib/libc.so".len() * 3);
+///
+/// let paths: Vec<UnixPathBuf> = PathSetDecoder::new(&bytes).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(paths[0], UnixPathBuf::from("/usr/lib/libc.so"));
+/// assert_eq!(paths[2], UnixPathBuf::from("/usr/lib/libz.so"));
+/// ```
+pub struct PathSetEncoder<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    buf: Vec<u8>,
+    prev: Vec<u8>,
+    _encoding: PhantomData<T>,
+}
+
+impl<T> PathSetEncoder<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a new encoder with an empty output buffer.
+    pub fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            prev: Vec::new(),
+            _encoding: PhantomData,
+        }
+    }
+
+    /// Appends `path` to the encoded output.
+    pub fn push<P: AsRef<Path<T>>>(&mut self, path: P) {
+        let bytes = path.as_ref().as_bytes();
+        let shared = self
+            .prev
+            .iter()
+            .zip(bytes)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let suffix = &bytes[shared..];
+
+        write_varint(&mut self.buf, shared);
+        write_varint(&mut self.buf, suffix.len());
+        self.buf.extend_from_slice(suffix);
+
+        self.prev.truncate(shared);
+        self.prev.extend_from_slice(suffix);
+    }
+
+    /// Consumes the encoder, returning the encoded bytes.
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+impl<T> Default for PathSetEncoder<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P> Extend<P> for PathSetEncoder<T>
+where
+    T: for<'enc> Encoding<'enc>,
+    P: AsRef<Path<T>>,
+{
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for path in iter {
+            self.push(path);
+        }
+    }
+}
+
+/// Streams [`PathBuf`]s back out of bytes produced by a [`PathSetEncoder`].
+///
+/// Each item is decoded on demand. Once an error is returned, the decoder yields nothing more.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{
+///     PathSetDecodeError, PathSetDecoder, PathSetEncoder, WindowsEncoding, WindowsPath,
+/// };
+///
+/// let mut encoder = PathSetEncoder::<WindowsEncoding>::new();
+/// encoder.extend([r"C:\src\main.rs", r"C:\src\lib.rs"]);
+/// let bytes = encoder.finish();
+///
+/// let mut decoder = PathSetDecoder::<WindowsEncoding>::new(&bytes);
+/// assert_eq!(decoder.next().unwrap().unwrap(), WindowsPath::new(r"C:\src\main.rs"));
+/// assert_eq!(decoder.next().unwrap().unwrap(), WindowsPath::new(r"C:\src\lib.rs"));
+/// assert!(decoder.next().is_none());
+///
+/// // Truncated input is reported rather than silently dropped
+/// let mut decoder = PathSetDecoder::<WindowsEncoding>::new(&bytes[..bytes.len() - 1]);
+/// assert!(decoder.next().unwrap().is_ok());
+/// assert_eq!(decoder.next().unwrap(), Err(PathSetDecodeError::Truncated));
+/// assert!(decoder.next().is_none());
+/// ```
+pub struct PathSetDecoder<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    data: &'a [u8],
+    prev: Vec<u8>,
+    failed: bool,
+    _encoding: PhantomData<T>,
+}
+
+impl<'a, T> PathSetDecoder<'a, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a decoder over bytes produced by [`PathSetEncoder::finish`].
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            prev: Vec::new(),
+            failed: false,
+            _encoding: PhantomData,
+        }
+    }
+
+    fn decode_next(&mut self) -> Result<PathBuf<T>, PathSetDecodeError> {
+        let shared = read_varint(&mut self.data)?;
+        let len = read_varint(&mut self.data)?;
+
+        if shared > self.prev.len() {
+            return Err(PathSetDecodeError::InvalidPrefixLength);
+        }
+        if len > self.data.len() {
+            return Err(PathSetDecodeError::Truncated);
+        }
+
+        let (suffix, rest) = self.data.split_at(len);
+        self.data = rest;
+        self.prev.truncate(shared);
+        self.prev.extend_from_slice(suffix);
+
+        Ok(PathBuf::from(self.prev.clone()))
+    }
+}
+
+impl<T> Iterator for PathSetDecoder<'_, T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    type Item = Result<PathBuf<T>, PathSetDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.data.is_empty() {
+            return None;
+        }
+
+        let result = self.decode_next();
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+impl<T> FusedIterator for PathSetDecoder<'_, T> where T: for<'enc> Encoding<'enc> {}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn read_varint(data: &mut &[u8]) -> Result<usize, PathSetDecodeError> {
+    let mut value = 0usize;
+    let mut shift = 0u32;
+
+    loop {
+        let (&byte, rest) = data.split_first().ok_or(PathSetDecodeError::Truncated)?;
+        *data = rest;
+
+        let bits = usize::from(byte & 0x7f);
+        if shift >= usize::BITS || (bits << shift) >> shift != bits {
+            return Err(PathSetDecodeError::Overflow);
+        }
+        value |= bits << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, UnixPath};
+
+    #[test]
+    fn should_round_trip_paths_in_any_order() {
+        let paths = ["b/c", "", "b/c", "a", "b/c/d/e", "b"];
+
+        let mut encoder = PathSetEncoder::<UnixEncoding>::new();
+        encoder.extend(paths);
+        let bytes = encoder.finish();
+
+        let decoded = PathSetDecoder::<UnixEncoding>::new(&bytes)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(decoded.len(), paths.len());
+        for (decoded, path) in decoded.iter().zip(paths) {
+            assert_eq!(decoded, UnixPath::new(path));
+        }
+    }
+
+    #[test]
+    fn should_round_trip_long_suffixes() {
+        let long = "x".repeat(300);
+
+        let mut encoder = PathSetEncoder::<UnixEncoding>::new();
+        encoder.push(long.as_str());
+        let bytes = encoder.finish();
+        assert_eq!(&bytes[..3], &[0x00, 0xac, 0x02]);
+
+        let mut decoder = PathSetDecoder::<UnixEncoding>::new(&bytes);
+        assert_eq!(decoder.next().unwrap().unwrap(), UnixPath::new(&long));
+        assert!(decoder.next().is_none());
+    }
+
+    #[test]
+    fn should_reject_malformed_input() {
+        // Shared prefix longer than the previous path
+        let mut decoder = PathSetDecoder::<UnixEncoding>::new(&[0x01, 0x00]);
+        assert_eq!(
+            decoder.next().unwrap(),
+            Err(PathSetDecodeError::InvalidPrefixLength)
+        );
+        assert!(decoder.next().is_none());
+
+        // Varint that never terminates within the data
+        let mut decoder = PathSetDecoder::<UnixEncoding>::new(&[0x80]);
+        assert_eq!(decoder.next().unwrap(), Err(PathSetDecodeError::Truncated));
+
+        // Varint too large for usize
+        let mut decoder = PathSetDecoder::<UnixEncoding>::new(&[0xff; 16]);
+        assert_eq!(decoder.next().unwrap(), Err(PathSetDecodeError::Overflow));
+    }
+}
@@ -0,0 +1,49 @@
+/// Describes how a path is anchored, combining what would otherwise require checking
+/// [`is_absolute`], [`has_root`], and the kind of any Windows prefix together.
+///
+/// [`is_absolute`]: crate::Path::is_absolute
+/// [`has_root`]: crate::Path::has_root
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{RootKind, UnixPath, WindowsPath};
+///
+/// assert_eq!(UnixPath::new("/etc/passwd").root_kind(), RootKind::Absolute);
+/// assert_eq!(UnixPath::new("etc/passwd").root_kind(), RootKind::Relative);
+///
+/// assert_eq!(WindowsPath::new(r"C:\Windows").root_kind(), RootKind::Absolute);
+/// assert_eq!(WindowsPath::new(r"C:Windows").root_kind(), RootKind::DriveRelative);
+/// assert_eq!(WindowsPath::new(r"\Windows").root_kind(), RootKind::RootRelative);
+/// assert_eq!(WindowsPath::new(r"\\server\share\file").root_kind(), RootKind::UNC);
+/// assert_eq!(WindowsPath::new(r"\\?\C:\Windows").root_kind(), RootKind::Verbatim);
+/// assert_eq!(WindowsPath::new(r"\\.\COM1").root_kind(), RootKind::Device);
+/// assert_eq!(WindowsPath::new(r"Windows").root_kind(), RootKind::Relative);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RootKind {
+    /// Path has neither a root nor a prefix, e.g. `foo/bar` or `foo\bar`.
+    Relative,
+
+    /// Path is fully qualified by a root (Unix) or a drive and root (Windows), e.g. `/foo` or
+    /// `C:\foo`.
+    Absolute,
+
+    /// Path has a drive but no root, and is resolved against the current directory of that
+    /// drive, e.g. `C:foo`.
+    DriveRelative,
+
+    /// Path has a root but no drive, and is resolved against the root of the current drive,
+    /// e.g. `\foo`.
+    RootRelative,
+
+    /// Path begins with a Windows _**U**niform **N**aming **C**onvention_ prefix, e.g.
+    /// `\\server\share\foo`.
+    UNC,
+
+    /// Path begins with a Windows verbatim prefix, e.g. `\\?\C:\foo` or `\\?\UNC\server\share`.
+    Verbatim,
+
+    /// Path begins with a Windows device namespace prefix, e.g. `\\.\COM1`.
+    Device,
+}
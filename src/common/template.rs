@@ -0,0 +1,220 @@
+use core::marker::PhantomData;
+
+use crate::common::non_utf8::helpers;
+use crate::common::{Encoding, PathBuf, PathTemplateError};
+use crate::no_std_compat::*;
+
+/// A path containing named placeholders, such as `reports/{year}/{name}.{ext}`, that are
+/// replaced with values to produce a [`PathBuf`].
+///
+/// Each value must form exactly one normal component on its own for the encoding `T`, so values
+/// cannot introduce separators, roots, prefixes, `.`, or `..` into the rendered path. Text
+/// outside of placeholders is used as written and may contain separators. Write `{{` or `}}` for
+/// a literal brace.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathTemplate, PathTemplateError, UnixEncoding, WindowsEncoding};
+///
+/// let template = PathTemplate::<UnixEncoding>::new("reports/{year}/{name}.{ext}").unwrap();
+/// let path = template
+///     .render(&[("year", "2024"), ("name", "summary"), ("ext", "pdf")])
+///     .unwrap();
+/// assert_eq!(path.as_bytes(), b"reports/2024/summary.pdf");
+///
+/// // Values cannot escape the directory the template describes
+/// assert_eq!(
+///     template.render(&[("year", ".."), ("name", "summary"), ("ext", "pdf")]),
+///     Err(PathTemplateError::InvalidValue(String::from("year"))),
+/// );
+/// assert_eq!(
+///     template.render(&[("year", "2024"), ("name", "a/b"), ("ext", "pdf")]),
+///     Err(PathTemplateError::InvalidValue(String::from("name"))),
+/// );
+///
+/// // Whether a value is valid depends on the encoding
+/// let template = PathTemplate::<WindowsEncoding>::new(r"C:\logs\{name}.log").unwrap();
+/// assert!(template.render(&[("name", "a:b")]).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PathTemplate<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    segments: Vec<Segment>,
+    _encoding: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Placeholder(String),
+}
+
+impl<T> PathTemplate<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Parses `template`, finding each `{name}` placeholder within it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a placeholder is unclosed or unnamed, or if a `}` appears outside of a
+    /// placeholder without being escaped.
+    pub fn new(template: &str) -> Result<Self, PathTemplateError> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or(PathTemplateError::UnclosedPlaceholder)?;
+                    let name = &rest[..end];
+                    if name.is_empty() {
+                        return Err(PathTemplateError::EmptyPlaceholder);
+                    } else if name.contains('{') {
+                        return Err(PathTemplateError::UnclosedPlaceholder);
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(core::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Placeholder(name.to_string()));
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => return Err(PathTemplateError::UnmatchedBrace),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Self {
+            segments,
+            _encoding: PhantomData,
+        })
+    }
+
+    /// Returns an iterator over the names of the placeholders in the order they appear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathTemplate, UnixEncoding};
+    ///
+    /// let template = PathTemplate::<UnixEncoding>::new("{root}/{name}.{ext}").unwrap();
+    /// assert_eq!(template.placeholders().collect::<Vec<_>>(), ["root", "name", "ext"]);
+    /// ```
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Placeholder(name) => Some(name.as_str()),
+            Segment::Literal(_) => None,
+        })
+    }
+
+    /// Renders the template into a [`PathBuf`], replacing each placeholder with the value paired
+    /// with its name in `values`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathTemplateError::MissingValue`] if a placeholder has no value, or
+    /// [`PathTemplateError::InvalidValue`] if a value is not a single normal component that is
+    /// valid for the encoding `T`.
+    pub fn render<V: AsRef<[u8]>>(
+        &self,
+        values: &[(&str, V)],
+    ) -> Result<PathBuf<T>, PathTemplateError> {
+        let mut bytes = Vec::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => bytes.extend_from_slice(literal.as_bytes()),
+                Segment::Placeholder(name) => {
+                    let value = values
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value.as_ref())
+                        .ok_or_else(|| PathTemplateError::MissingValue(name.clone()))?;
+                    if !helpers::is_single_normal_component::<T>(value) {
+                        return Err(PathTemplateError::InvalidValue(name.clone()));
+                    }
+                    bytes.extend_from_slice(value);
+                }
+            }
+        }
+
+        Ok(PathBuf::from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, WindowsEncoding};
+
+    #[test]
+    fn new_should_parse_escaped_braces() {
+        let template = PathTemplate::<UnixEncoding>::new("{{a}}/{b}").unwrap();
+        assert_eq!(
+            template.segments,
+            [
+                Segment::Literal(String::from("{a}/")),
+                Segment::Placeholder(String::from("b")),
+            ]
+        );
+        let path = template.render(&[("b", "c")]).unwrap();
+        assert_eq!(path.as_bytes(), b"{a}/c");
+    }
+
+    #[test]
+    fn new_should_fail_for_malformed_placeholders() {
+        let parse = PathTemplate::<UnixEncoding>::new;
+        assert_eq!(
+            parse("a/{b").err(),
+            Some(PathTemplateError::UnclosedPlaceholder)
+        );
+        assert_eq!(
+            parse("a/{b{c}").err(),
+            Some(PathTemplateError::UnclosedPlaceholder)
+        );
+        assert_eq!(parse("a/b}").err(), Some(PathTemplateError::UnmatchedBrace));
+        assert_eq!(
+            parse("a/{}").err(),
+            Some(PathTemplateError::EmptyPlaceholder)
+        );
+    }
+
+    #[test]
+    fn render_should_reject_values_that_are_not_a_single_normal_component() {
+        let template = PathTemplate::<WindowsEncoding>::new(r"out\{name}").unwrap();
+        for value in ["", ".", "..", "a/", r"a\b", "C:", r"\\server\share"] {
+            assert_eq!(
+                template.render(&[("name", value)]),
+                Err(PathTemplateError::InvalidValue(String::from("name"))),
+                "{value:?} should be rejected"
+            );
+        }
+        assert_eq!(
+            template.render::<&str>(&[]),
+            Err(PathTemplateError::MissingValue(String::from("name")))
+        );
+        assert_eq!(
+            template.render(&[("name", "...")]).unwrap().as_bytes(),
+            br"out\..."
+        );
+    }
+}
@@ -3,6 +3,7 @@ mod iter;
 mod path;
 mod pathbuf;
 
+use core::cmp::Ordering;
 use core::hash::Hasher;
 
 pub use components::*;
@@ -10,7 +11,10 @@ pub use iter::*;
 pub use path::*;
 pub use pathbuf::*;
 
+pub(crate) use path::helpers;
+
 use crate::common::errors::CheckedPathError;
+use crate::common::RootKind;
 use crate::no_std_compat::*;
 use crate::private;
 
@@ -28,6 +32,12 @@ pub trait Utf8Encoding<'a>: private::Sealed {
     /// Hashes a utf8 str (`path`)
     fn hash<H: Hasher>(path: &str, h: &mut H);
 
+    /// Compares two utf8 strs (`path` and `other`) as paths.
+    ///
+    /// This is guaranteed to produce the same [`Ordering`] as comparing the [`Utf8Components`] of
+    /// each path, but may skip parsing the bytes that both paths have in common.
+    fn cmp(path: &str, other: &str) -> Ordering;
+
     /// Pushes a utf8 str (`path`) onto the an existing path (`current_path`)
     fn push(current_path: &mut String, path: &str);
 
@@ -38,4 +48,15 @@ pub trait Utf8Encoding<'a>: private::Sealed {
     /// 3. `path` cannot contain invalid filename characters.
     /// 4. `path` cannot contain parent components such that the current path would be escaped.
     fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError>;
+
+    /// Attempts to determine whether `base` is a prefix of `path` using only byte comparisons,
+    /// without parsing either into components.
+    ///
+    /// Returns `Some(true)` if `base` is known to be a component-wise prefix of `path`, and
+    /// `None` if a cheap answer is not possible, in which case callers must fall back to
+    /// comparing components.
+    fn starts_with_fast(path: &str, base: &str) -> Option<bool>;
+
+    /// Describes how a utf8 str (`path`) is anchored.
+    fn root_kind(path: &str) -> RootKind;
 }
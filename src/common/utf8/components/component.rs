@@ -9,6 +9,25 @@ pub trait Utf8Component<'a>:
     AsRef<str> + Clone + fmt::Debug + PartialEq + Eq + PartialOrd + Ord + private::Sealed
 {
     /// Extracts the underlying [`str`] slice
+    ///
+    /// For a prefix, this is the raw slice as it appeared in the path (e.g. a lowercase drive
+    /// letter is preserved). Pushing the slice of every component of a path, in order, onto an
+    /// empty [`Utf8PathBuf`] reproduces a path equal to the original.
+    ///
+    /// [`Utf8PathBuf`]: crate::Utf8PathBuf
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Component, Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8WindowsPath::new(r"c:\dir\file.txt");
+    /// let mut rebuilt = Utf8WindowsPathBuf::new();
+    /// for component in path.components() {
+    ///     rebuilt.push(component.as_str());
+    /// }
+    /// assert_eq!(rebuilt, path);
+    /// ```
     fn as_str(&self) -> &'a str;
 
     /// Returns true if this component is the root component, meaning
@@ -1,6 +1,7 @@
 use core::fmt;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
+use core::ops::Range;
 
 use crate::{Utf8Component, Utf8Components, Utf8Encoding, Utf8Path};
 
@@ -133,6 +134,144 @@ where
 
 impl<'a, T> FusedIterator for Utf8Iter<'a, T> where T: for<'enc> Utf8Encoding<'enc> + 'a {}
 
+/// An iterator over the [`Utf8Component`]s of a [`Utf8Path`] alongside the byte range that each
+/// component occupies within the original path.
+///
+/// This `struct` is created by the [`components_with_spans`] method on [`Utf8Path`].
+/// See its documentation for more.
+///
+/// [`components_with_spans`]: Utf8Path::components_with_spans
+#[derive(Clone)]
+pub struct Utf8ComponentsWithSpans<'a, T>
+where
+    T: Utf8Encoding<'a>,
+{
+    _encoding: PhantomData<T>,
+    inner: <T as Utf8Encoding<'a>>::Components,
+    len: usize,
+}
+
+impl<'a, T> Utf8ComponentsWithSpans<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(inner: <T as Utf8Encoding<'a>>::Components) -> Self {
+        let len = inner.as_str().len();
+        Self {
+            _encoding: PhantomData,
+            inner,
+            len,
+        }
+    }
+
+    /// Returns the byte offset within the original path at which iteration will resume.
+    #[inline]
+    fn offset(&self) -> usize {
+        self.len - self.inner.as_str().len()
+    }
+}
+
+impl<'a, T> fmt::Debug for Utf8ComponentsWithSpans<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Utf8ComponentsWithSpans))
+            .field("remaining", &self.inner.as_str())
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for Utf8ComponentsWithSpans<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    type Item = (
+        <<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component,
+        Range<usize>,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Each component begins exactly where the remaining path did before it was parsed; any
+        // separators or skipped current-directory components that follow it are consumed along
+        // with it. The component's own length is the same as it is in the original path, even
+        // for the root directory, whose separator may be written in a different style.
+        let start = self.offset();
+        let component = self.inner.next()?;
+        let end = start + component.len();
+        Some((component, start..end))
+    }
+}
+
+impl<'a, T> FusedIterator for Utf8ComponentsWithSpans<'a, T> where
+    T: for<'enc> Utf8Encoding<'enc> + 'a
+{
+}
+
+/// An iterator over the [`Utf8Component`]s of a [`Utf8Path`], each paired with the portion of the
+/// path that precedes it.
+///
+/// This `struct` is created by the [`component_pairs`] method on [`Utf8Path`].
+/// See its documentation for more.
+///
+/// [`component_pairs`]: Utf8Path::component_pairs
+#[derive(Clone)]
+pub struct Utf8ComponentPairs<'a, T>
+where
+    T: Utf8Encoding<'a>,
+{
+    path: &'a str,
+    inner: Utf8ComponentsWithSpans<'a, T>,
+    end: usize,
+}
+
+impl<'a, T> Utf8ComponentPairs<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    pub(crate) fn new(path: &'a Utf8Path<T>) -> Self {
+        Self {
+            path: path.as_str(),
+            inner: path.components_with_spans(),
+            end: 0,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for Utf8ComponentPairs<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Utf8ComponentPairs))
+            .field("parent", &Utf8Path::<T>::new(&self.path[..self.end]))
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<'a, T> Iterator for Utf8ComponentPairs<'a, T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'a,
+{
+    type Item = (
+        &'a Utf8Path<T>,
+        <<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component,
+    );
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The parent ends where the previous component did, which excludes the separators and
+        // skipped current-directory components between the two
+        let (component, span) = self.inner.next()?;
+        let parent = Utf8Path::new(&self.path[..self.end]);
+        self.end = span.end;
+        Some((parent, component))
+    }
+}
+
+impl<'a, T> FusedIterator for Utf8ComponentPairs<'a, T> where T: for<'enc> Utf8Encoding<'enc> + 'a {}
+
 /// An iterator over [`Utf8Path`] and its ancestors.
 ///
 /// This `struct` is created by the [`ancestors`] method on [`Utf8Path`].
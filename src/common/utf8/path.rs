@@ -1,15 +1,16 @@
 use alloc::borrow::{Cow, ToOwned};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
+use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::str::Utf8Error;
 use core::{cmp, fmt};
 
 use crate::no_std_compat::*;
 use crate::{
-    CheckedPathError, Encoding, Path, StripPrefixError, Utf8Ancestors, Utf8Component,
-    Utf8Components, Utf8Encoding, Utf8Iter, Utf8PathBuf,
+    CheckedPathError, Encoding, NormalizeOptions, Path, PathId, RootKind, StripPrefixError,
+    Utf8Ancestors, Utf8Component, Utf8ComponentPairs, Utf8Components, Utf8ComponentsWithSpans,
+    Utf8Encoding, Utf8Iter, Utf8PathBuf, Utf8PathError,
 };
 
 /// A slice of a path (akin to [`str`]).
@@ -82,6 +83,59 @@ where
     pub(crate) inner: str,
 }
 
+impl<T> Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc> + 'static,
+{
+    /// An empty [`Utf8Path`], which can be used wherever a borrowed path is needed without
+    /// allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::EMPTY;
+    /// assert_eq!(path, Utf8Path::<Utf8UnixEncoding>::new(""));
+    /// assert_eq!(path, <&Utf8Path<Utf8UnixEncoding>>::default());
+    /// ```
+    pub const EMPTY: &'static Self = unsafe { &*("" as *const str as *const Self) };
+}
+
+/// Implements `const fn` constructors for paths with a concrete encoding, as `const fn` cannot
+/// have trait bounds on their generic parameters on the minimum supported Rust version.
+macro_rules! impl_utf8path_new_const {
+    ($($encoding:ty),+ $(,)?) => {$(
+        impl Utf8Path<$encoding> {
+            /// Directly wraps a str slice as a path slice in a `const` context.
+            ///
+            /// This is equivalent to [`Utf8Path::new`], but can be used to define constants and
+            /// statics.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use typed_path::Utf8UnixPath;
+            ///
+            /// static ROOTS: &[&Utf8UnixPath] =
+            ///     &[Utf8UnixPath::new_const("/etc"), Utf8UnixPath::new_const("/usr")];
+            /// assert_eq!(ROOTS[0], Utf8UnixPath::new("/etc"));
+            /// ```
+            #[inline]
+            pub const fn new_const(s: &str) -> &Self {
+                unsafe { &*(s as *const str as *const Self) }
+            }
+        }
+    )+};
+}
+
+impl_utf8path_new_const!(
+    crate::Utf8UnixEncoding,
+    crate::Utf8WindowsEncoding,
+    crate::Utf8PlatformEncoding,
+);
+
 impl<T> Utf8Path<T>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -141,6 +195,148 @@ where
         &self.inner
     }
 
+    /// Returns `true` if the path contains no bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("").is_empty());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns `true` if the path refers to the current directory without naming it, meaning it
+    /// is empty or consists only of `.` components, such as `""`, `.`, or `./.`.
+    ///
+    /// The empty path and `.` refer to the same directory but are not interchangeable: `.` has
+    /// the empty path as its [`parent`] while the empty path has none, and [`join`]ing the empty
+    /// path adds nothing while `.` is kept as written. Both [`normalize`] to the empty path.
+    ///
+    /// [`parent`]: Utf8Path::parent
+    /// [`join`]: Utf8Path::join
+    /// [`normalize`]: Utf8Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("").is_cur_dir_like());
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new(".").is_cur_dir_like());
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("././/").is_cur_dir_like());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("./foo/..").is_cur_dir_like());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("/").is_cur_dir_like());
+    /// ```
+    pub fn is_cur_dir_like(&self) -> bool {
+        self.components().all(|c| c.is_current())
+    }
+
+    /// Returns `true` if the path is non-empty and consists only of `.` components, such as `.`
+    /// or `./.`.
+    ///
+    /// See [`is_cur_dir_like`] for how this differs from the empty path.
+    ///
+    /// [`is_cur_dir_like`]: Utf8Path::is_cur_dir_like
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new(".").is_cur_dir());
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("./.").is_cur_dir());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("").is_cur_dir());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("..").is_cur_dir());
+    /// ```
+    pub fn is_cur_dir(&self) -> bool {
+        !self.is_empty() && self.is_cur_dir_like()
+    }
+
+    /// Returns the length of the path in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt").len(), 12);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns the number of [`components`](Utf8Path::components) in the path.
+    ///
+    /// This parses the path each time that it is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt").components_len(), 3);
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("").components_len(), 0);
+    /// ```
+    pub fn components_len(&self) -> usize {
+        self.components().count()
+    }
+
+    /// Returns the number of terminal columns needed to display the path.
+    ///
+    /// Unlike [`len`](Utf8Path::len), which counts bytes, this accounts for characters that are
+    /// zero-width or that occupy two columns, such as many CJK characters and emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/日本.txt");
+    /// assert_eq!(path.len(), 15);
+    /// assert_eq!(path.display_width(), 13);
+    /// ```
+    #[cfg(feature = "unicode-width")]
+    pub fn display_width(&self) -> usize {
+        unicode_width::UnicodeWidthStr::width(self.as_str())
+    }
+
+    /// Returns the longest leading portion of the path that fits within `cols` terminal columns.
+    ///
+    /// The path is never split in the middle of a character, so the returned [`str`] may be
+    /// narrower than `cols` when the next character is wide. Characters with no defined width,
+    /// such as control characters, are treated as zero columns wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/日本.txt");
+    /// assert_eq!(path.truncate_to_width(7), "/tmp/日");
+    /// assert_eq!(path.truncate_to_width(8), "/tmp/日");
+    /// assert_eq!(path.truncate_to_width(100), "/tmp/日本.txt");
+    /// ```
+    #[cfg(feature = "unicode-width")]
+    pub fn truncate_to_width(&self, cols: usize) -> &str {
+        let mut width = 0;
+        for (i, c) in self.inner.char_indices() {
+            width += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            if width > cols {
+                return &self.inner[..i];
+            }
+        }
+
+        &self.inner
+    }
+
     /// Converts a `Utf8Path` to an owned [`Utf8PathBuf`].
     ///
     /// # Examples
@@ -248,6 +444,71 @@ where
         self.components().has_root()
     }
 
+    /// Classifies how the `Utf8Path` is anchored, distinguishing the Windows-specific forms that
+    /// [`Utf8Path::is_absolute`] and [`Utf8Path::has_root`] collapse together. See [`RootKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RootKind, Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/etc/passwd").root_kind(),
+    ///     RootKind::Absolute
+    /// );
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"C:foo").root_kind(),
+    ///     RootKind::DriveRelative
+    /// );
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"\\server\share").root_kind(),
+    ///     RootKind::UNC
+    /// );
+    /// ```
+    #[inline]
+    pub fn root_kind(&self) -> RootKind {
+        T::root_kind(&self.inner)
+    }
+
+    /// Returns the label of the encoding backing this `Utf8Path`, such as `"unix"` or `"windows"`.
+    ///
+    /// This is the same label that appears in the [`Debug`] output of the path.
+    ///
+    /// [`Debug`]: fmt::Debug
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("/tmp").encoding_label(), "unix");
+    /// assert_eq!(Utf8Path::<Utf8WindowsEncoding>::new(r"C:\tmp").encoding_label(), "windows");
+    /// ```
+    #[inline]
+    pub fn encoding_label(&self) -> &'static str {
+        T::label()
+    }
+
+    /// Returns a compact identifier for this `Utf8Path` that is shared by every path with the
+    /// same encoding and normalized components, including the equivalent [`Path`]. See
+    /// [`PathId`] for its stability guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8WindowsEncoding, WindowsPath};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let id = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Logs\app.log").stable_id();
+    /// assert_eq!(id, Utf8Path::<Utf8WindowsEncoding>::new("C:/Logs/app.log").stable_id());
+    /// assert_eq!(id, WindowsPath::new(r"C:\Logs\app.log").stable_id());
+    /// ```
+    pub fn stable_id(&self) -> PathId {
+        PathId::of_utf8(self)
+    }
+
     /// Returns the `Utf8Path` without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -309,10 +570,62 @@ where
     ///
     /// [`parent`]: Utf8Path::parent
     #[inline]
-    pub fn ancestors(&self) -> Utf8Ancestors<T> {
+    pub fn ancestors(&self) -> Utf8Ancestors<'_, T> {
         Utf8Ancestors { next: Some(self) }
     }
 
+    /// Returns the ancestor `n` levels above the `Utf8Path`, or [`None`] if that would go past the
+    /// root or the start of a relative path.
+    ///
+    /// `ancestor(0)` is `self` and `ancestor(1)` is the same as [`parent`]. This is equivalent to
+    /// `self.ancestors().nth(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/foo/bar/baz");
+    /// assert_eq!(path.ancestor(0), Some(path));
+    /// assert_eq!(path.ancestor(2), Some(Utf8Path::new("/foo")));
+    /// assert_eq!(path.ancestor(3), Some(Utf8Path::new("/")));
+    /// assert_eq!(path.ancestor(4), None);
+    /// ```
+    ///
+    /// [`parent`]: Utf8Path::parent
+    #[inline]
+    pub fn ancestor(&self, n: usize) -> Option<&Self> {
+        self.ancestors().nth(n)
+    }
+
+    /// Returns the first of the `Utf8Path` and its [`ancestors`] for which `predicate` returns
+    /// true.
+    ///
+    /// This is the building block for discovering a project root from a nested path, where the
+    /// predicate checks for some marker such as a `Cargo.toml` or `.git` entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/work/project/src/bin/main.rs");
+    /// let root = path.find_ancestor_with(|p| p.file_name() == Some("project".as_ref()));
+    /// assert_eq!(root, Some(Utf8Path::new("/work/project")));
+    ///
+    /// assert_eq!(path.find_ancestor_with(|p| p.ends_with("missing")), None);
+    /// ```
+    ///
+    /// [`ancestors`]: Utf8Path::ancestors
+    pub fn find_ancestor_with<F>(&self, mut predicate: F) -> Option<&Self>
+    where
+        F: FnMut(&Self) -> bool,
+    {
+        self.ancestors().find(|p| predicate(p))
+    }
+
     /// Returns the final component of the `Utf8Path`, if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -346,6 +659,67 @@ where
         }
     }
 
+    /// Returns both the [`parent`] and the [`file_name`] of the `Utf8Path`, parsing the final
+    /// component once rather than once for each.
+    ///
+    /// [`parent`]: Utf8Path::parent
+    /// [`file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let (parent, file_name) =
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/usr/bin/rustc").split_file_name();
+    /// assert_eq!(parent, Some(Utf8Path::new("/usr/bin")));
+    /// assert_eq!(file_name, Some("rustc"));
+    ///
+    /// let (parent, file_name) = Utf8Path::<Utf8UnixEncoding>::new("foo/..").split_file_name();
+    /// assert_eq!(parent, Some(Utf8Path::new("foo")));
+    /// assert_eq!(file_name, None);
+    ///
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("/").split_file_name(), (None, None));
+    /// ```
+    pub fn split_file_name(&self) -> (Option<&Self>, Option<&str>) {
+        let mut comps = self.components();
+        match comps.next_back() {
+            Some(p) if p.is_root() => (None, None),
+            Some(p) => {
+                let file_name = if p.is_normal() {
+                    Some(p.as_str())
+                } else {
+                    None
+                };
+                (Some(Self::new(comps.as_str())), file_name)
+            }
+            None => (None, None),
+        }
+    }
+
+    /// Returns true if the [`file_name`] begins with a `.`, which is how Unix-style tools mark
+    /// a file or directory as hidden.
+    ///
+    /// Returns false if there is no [`file_name`].
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new("/home/user/.bashrc").file_name_is_dotfile());
+    /// assert!(Utf8Path::<Utf8UnixEncoding>::new(".config/").file_name_is_dotfile());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new(".config/nvim").file_name_is_dotfile());
+    /// assert!(!Utf8Path::<Utf8UnixEncoding>::new("foo/..").file_name_is_dotfile());
+    /// ```
+    pub fn file_name_is_dotfile(&self) -> bool {
+        self.file_name().map_or(false, |name| name.starts_with('.'))
+    }
+
     /// Returns a path that, when joined onto `base`, yields `self`.
     ///
     /// # Errors
@@ -389,6 +763,31 @@ where
         }
     }
 
+    /// Returns an object that displays the path with `base` stripped from the front when it is a
+    /// prefix, displaying the full path otherwise.
+    ///
+    /// This is the same as displaying `path.strip_prefix(base).unwrap_or(path)`. Note that when
+    /// `base` is the path itself, the displayed path is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/repo/src/main.rs");
+    ///
+    /// assert_eq!(path.display_relative_to("/repo").to_string(), "src/main.rs");
+    /// assert_eq!(path.display_relative_to("/other").to_string(), "/repo/src/main.rs");
+    /// ```
+    #[inline]
+    pub fn display_relative_to<P>(&self, base: P) -> impl fmt::Display + '_
+    where
+        P: AsRef<Utf8Path<T>>,
+    {
+        self._strip_prefix(base.as_ref()).unwrap_or(self)
+    }
+
     /// Determines whether `base` is a prefix of `self`.
     ///
     /// Only considers whole path components to match.
@@ -420,6 +819,11 @@ where
     }
 
     fn _starts_with(&self, base: &Utf8Path<T>) -> bool {
+        // Try a cheap byte comparison first, only parsing components when it is inconclusive
+        if let Some(result) = T::starts_with_fast(&self.inner, &base.inner) {
+            return result;
+        }
+
         helpers::iter_after(self.components(), base.components()).is_some()
     }
 
@@ -453,6 +857,72 @@ where
         helpers::iter_after(self.components().rev(), child.components().rev()).is_some()
     }
 
+    /// Like [`Utf8Path::starts_with`], but compares against an iterator of individual components
+    /// rather than a path, avoiding the need to build a path from dynamic segments first.
+    ///
+    /// Each item is compared to exactly one component of `self`, so an item containing a
+    /// separator never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/ssh/sshd_config");
+    ///
+    /// assert!(path.starts_with_components(["/", "etc", "ssh"]));
+    /// assert!(path.starts_with_components(path.components()));
+    /// assert!(path.starts_with_components(Vec::<&str>::new()));
+    ///
+    /// assert!(!path.starts_with_components(["etc"]));
+    /// assert!(!path.starts_with_components(["/", "etc/ssh"]));
+    /// ```
+    pub fn starts_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut this = self.components();
+        components.into_iter().all(|component| {
+            this.next()
+                .map_or(false, |c| c.as_str() == component.as_ref())
+        })
+    }
+
+    /// Like [`Utf8Path::ends_with`], but compares against an iterator of individual components
+    /// rather than a path, avoiding the need to build a path from dynamic segments first.
+    ///
+    /// Each item is compared to exactly one component of `self`, so an item containing a
+    /// separator never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/etc/ssh/sshd_config");
+    ///
+    /// assert!(path.ends_with_components(["ssh", "sshd_config"]));
+    /// assert!(path.ends_with_components(["/", "etc", "ssh", "sshd_config"]));
+    ///
+    /// assert!(!path.ends_with_components(["ssh"]));
+    /// assert!(!path.ends_with_components(["ssh/sshd_config"]));
+    /// ```
+    pub fn ends_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut this = self.components().rev();
+        components.into_iter().rev().all(|component| {
+            this.next()
+                .map_or(false, |c| c.as_str() == component.as_ref())
+        })
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: Utf8Path::file_name
@@ -577,21 +1047,115 @@ where
         path
     }
 
-    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
-    /// [`Utf8PathBuf`].
-    ///
-    /// In the case that the path is relative, the current working directory is prepended prior to
-    /// normalizing.
+    /// Like [`normalize`], but with additional behavior controlled by `options`.
     ///
-    /// [`normalizing`]: Utf8Path::normalize
+    /// [`normalize`]: Utf8Path::normalize
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_path::{utils, Utf8Path, Utf8UnixEncoding};
+    /// use typed_path::{NormalizeOptions, Utf8Path, Utf8UnixEncoding};
     ///
-    /// // With an absolute path, it is just normalized
-    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/a/b/../c/./d");
+    /// let options = NormalizeOptions::new().empty_as_cur_dir(true);
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("foo/..").normalize().as_str(), "");
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("foo/..").normalize_with(options).as_str(),
+    ///     "."
+    /// );
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("").normalize_with(options).as_str(), ".");
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("./foo/.").normalize_with(options).as_str(),
+    ///     "foo"
+    /// );
+    /// ```
+    pub fn normalize_with(&self, options: NormalizeOptions) -> Utf8PathBuf<T> {
+        let mut path = self.normalize();
+        if options.is_empty_as_cur_dir() && path.is_empty() {
+            path.push(".");
+        }
+        path
+    }
+
+    /// Returns a cleaned version of the path, following the semantics of Go's `path.Clean`.
+    ///
+    /// This differs from [`normalize`] in how `..` is handled:
+    ///
+    /// * A `..` is resolved by removing the preceding normal component.
+    /// * A `..` directly following a root directory is removed.
+    /// * Any other `..`, such as those leading a relative path, is kept.
+    ///
+    /// Repeated separators and `.` components are removed, and an empty result becomes `.`.
+    ///
+    /// [`normalize`]: Utf8Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("foo//bar/./baz/..").clean(),
+    ///     Utf8PathBuf::from("foo/bar"),
+    /// );
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("../foo/../..").clean(),
+    ///     Utf8PathBuf::from("../.."),
+    /// );
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("/../foo").clean(), Utf8PathBuf::from("/foo"));
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("foo/..").clean(), Utf8PathBuf::from("."));
+    ///
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"C:..\foo\..").clean(),
+    ///     Utf8PathBuf::from(r"C:.."),
+    /// );
+    /// ```
+    pub fn clean(&self) -> Utf8PathBuf<T> {
+        let mut components = Vec::new();
+        for component in self.components() {
+            if !component.is_current() && !component.is_parent() {
+                components.push(component);
+            } else if component.is_parent() {
+                match components.last() {
+                    Some(last) if last.is_normal() => {
+                        components.pop();
+                    }
+                    Some(last) if last.is_root() => {}
+                    _ => components.push(component),
+                }
+            }
+        }
+
+        if components.is_empty() {
+            return Utf8PathBuf::from(".");
+        }
+
+        let mut path = Utf8PathBuf::<T>::new();
+
+        for component in components {
+            path.push(component.as_str());
+        }
+
+        path
+    }
+
+    /// Converts a path to an absolute form by [`normalizing`] the path, returning a
+    /// [`Utf8PathBuf`].
+    ///
+    /// In the case that the path is relative, the current working directory is prepended prior to
+    /// normalizing.
+    ///
+    /// [`normalizing`]: Utf8Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{utils, Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // With an absolute path, it is just normalized
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/a/b/../c/./d");
     /// assert_eq!(path.absolutize().unwrap(), Utf8Path::new("/a/c/d"));
     ///
     /// // With a relative path, it is first joined with the current working directory
@@ -600,7 +1164,7 @@ where
     /// let path = cwd.join(Utf8Path::new("a/b/../c/./d"));
     /// assert_eq!(path.absolutize().unwrap(), cwd.join(Utf8Path::new("a/c/d")));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     pub fn absolutize(&self) -> std::io::Result<Utf8PathBuf<T>> {
         if self.is_absolute() {
             Ok(self.normalize())
@@ -637,6 +1201,45 @@ where
         buf
     }
 
+    /// Creates an owned [`Utf8PathBuf`] with each of `paths` adjoined to `self` in order.
+    ///
+    /// This is equivalent to calling [`join`] for each path, but `paths` is walked twice, first
+    /// to size the result and then to join each path, so that it is allocated once rather than
+    /// per path.
+    ///
+    /// [`join`]: Utf8Path::join
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/root").join_iter(["a", "b", "c", "d"]),
+    ///     Utf8PathBuf::from("/root/a/b/c/d"),
+    /// );
+    ///
+    /// // Like with join, an absolute path replaces everything before it
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/root").join_iter(["a", "/etc", "passwd"]),
+    ///     Utf8PathBuf::from("/etc/passwd"),
+    /// );
+    /// ```
+    pub fn join_iter<I, P>(&self, paths: I) -> Utf8PathBuf<T>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Clone,
+        P: AsRef<Utf8Path<T>>,
+    {
+        let paths = paths.into_iter();
+        let mut buf =
+            Utf8PathBuf::with_capacity(self.inner.len() + helpers::pushed_len(paths.clone()));
+        buf.inner.push_str(&self.inner);
+        paths.for_each(|p| buf.push(p.as_ref()));
+        buf
+    }
+
     /// Creates an owned [`Utf8PathBuf`] with `path` adjoined to `self`, checking the `path` to
     /// ensure it is safe to join. _When dealing with user-provided paths, this is the preferred
     /// method._
@@ -671,6 +1274,59 @@ where
         Ok(buf)
     }
 
+    /// Resolves the `target` of a symbolic link located in the directory `self`, following the
+    /// same rules that the operating system uses when traversing the link.
+    ///
+    /// * An absolute `target` replaces `self` entirely.
+    /// * A relative `target` is adjoined to `self`.
+    /// * On Windows, a `target` with a root but no prefix, such as `\foo`, keeps the prefix of
+    ///   `self`, and a `target` with a prefix, such as `D:foo`, replaces `self` entirely.
+    ///
+    /// No `..` components are resolved, as `self` may itself traverse symbolic links, which makes
+    /// removing them lexically incorrect. Use [`Utf8Path::normalize`] if that is acceptable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] if `target` is empty, which is never a valid
+    /// link target, or if any of its components are invalid for the encoding (see
+    /// [`Utf8Path::is_valid`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{
+    ///     CheckedPathError, Utf8Path, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding,
+    /// };
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let dir = Utf8Path::<Utf8UnixEncoding>::new("/usr/lib");
+    /// assert_eq!(
+    ///     dir.resolve_link_target("../lib64/libc.so"),
+    ///     Ok(Utf8PathBuf::from("/usr/lib/../lib64/libc.so"))
+    /// );
+    /// assert_eq!(dir.resolve_link_target("/opt/libc.so"), Ok(Utf8PathBuf::from("/opt/libc.so")));
+    /// assert_eq!(dir.resolve_link_target(""), Err(CheckedPathError::InvalidFilename));
+    ///
+    /// let dir = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\Public");
+    /// assert_eq!(dir.resolve_link_target(r"\Shared"), Ok(Utf8PathBuf::from(r"C:\Shared")));
+    /// assert_eq!(
+    ///     dir.resolve_link_target(r"Docs"),
+    ///     Ok(Utf8PathBuf::from(r"C:\Users\Public\Docs"))
+    /// );
+    /// assert_eq!(dir.resolve_link_target("a|b"), Err(CheckedPathError::InvalidFilename));
+    /// ```
+    pub fn resolve_link_target<P: AsRef<Utf8Path<T>>>(
+        &self,
+        target: P,
+    ) -> Result<Utf8PathBuf<T>, CheckedPathError> {
+        let target = target.as_ref();
+        if target.as_str().is_empty() || !target.is_valid() {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        Ok(self.join(target))
+    }
+
     /// Creates an owned [`Utf8PathBuf`] like `self` but with the given file name.
     ///
     /// See [`Utf8PathBuf::set_file_name`] for more details.
@@ -727,6 +1383,26 @@ where
         buf
     }
 
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with its extension converted to
+    /// lowercase.
+    ///
+    /// See [`Utf8PathBuf::lowercase_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("IMG_0001.JPG");
+    /// assert_eq!(path.with_lowercase_extension(), Utf8PathBuf::from("IMG_0001.jpg"));
+    /// ```
+    pub fn with_lowercase_extension(&self) -> Utf8PathBuf<T> {
+        let mut buf = self.to_path_buf();
+        buf.lowercase_extension();
+        buf
+    }
+
     /// Produces an iterator over the [`Utf8Component`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -785,10 +1461,142 @@ where
     /// assert_eq!(it.next(), None)
     /// ```
     #[inline]
-    pub fn iter(&self) -> Utf8Iter<T> {
+    pub fn iter(&self) -> Utf8Iter<'_, T> {
         Utf8Iter::new(self.components())
     }
 
+    /// Produces an iterator over the [`Utf8Component`]s of the path, each paired with the range of
+    /// bytes it occupies within the path.
+    ///
+    /// Components are identical to those produced by [`components`]. The ranges index into
+    /// [`as_str`] and are useful for pointing at a specific component when reporting
+    /// diagnostics. A root directory component covers a single separator, even when the path
+    /// begins with several.
+    ///
+    /// [`Utf8Component`]: crate::Utf8Component
+    /// [`components`]: Utf8Path::components
+    /// [`as_str`]: Utf8Path::as_str
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixComponent, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp//./foo/../bar.txt");
+    /// let mut it = path.components_with_spans();
+    ///
+    /// assert_eq!(it.next(), Some((Utf8UnixComponent::RootDir, 0..1)));
+    /// assert_eq!(it.next(), Some((Utf8UnixComponent::Normal("tmp"), 1..4)));
+    /// assert_eq!(it.next(), Some((Utf8UnixComponent::Normal("foo"), 8..11)));
+    /// assert_eq!(it.next(), Some((Utf8UnixComponent::ParentDir, 12..14)));
+    /// assert_eq!(it.next(), Some((Utf8UnixComponent::Normal("bar.txt"), 15..22)));
+    /// assert_eq!(it.next(), None);
+    /// ```
+    #[inline]
+    pub fn components_with_spans(&self) -> Utf8ComponentsWithSpans<'_, T> {
+        Utf8ComponentsWithSpans::new(self.components())
+    }
+
+    /// Produces an iterator over the [`Utf8Component`]s of the path, each paired with the path
+    /// that precedes it, so that every ancestor can be visited alongside the component that
+    /// extends it in a single pass.
+    ///
+    /// The preceding path of the first component is empty. Joining a preceding path with its
+    /// component produces the next preceding path.
+    ///
+    /// [`Utf8Component`]: crate::Utf8Component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8WindowsComponent, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8WindowsEncoding>::new(r"C:\Users\me");
+    /// let parents: Vec<_> = path.component_pairs().map(|(parent, _)| parent.as_str()).collect();
+    /// assert_eq!(parents, ["", "C:", r"C:\", r"C:\Users"]);
+    ///
+    /// let (parent, component) = path.component_pairs().last().unwrap();
+    /// assert_eq!(parent, Utf8Path::new(r"C:\Users"));
+    /// assert_eq!(component, Utf8WindowsComponent::Normal("me"));
+    /// ```
+    #[inline]
+    pub fn component_pairs(&self) -> Utf8ComponentPairs<'_, T> {
+        Utf8ComponentPairs::new(self)
+    }
+
+    /// Returns the first [`Component`] of the path, if there is one.
+    ///
+    /// This is equivalent to `path.components().next()`.
+    ///
+    /// [`Component`]: crate::Utf8Component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixComponent, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("../foo.txt");
+    /// assert_eq!(path.first_component(), Some(Utf8UnixComponent::ParentDir));
+    /// assert_eq!(Utf8Path::<Utf8UnixEncoding>::new("").first_component(), None);
+    /// ```
+    #[inline]
+    pub fn first_component<'a>(
+        &'a self,
+    ) -> Option<<<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component> {
+        self.components().next()
+    }
+
+    /// Returns the last [`Component`] of the path, if there is one.
+    ///
+    /// This is equivalent to `path.components().next_back()`.
+    ///
+    /// [`Component`]: crate::Utf8Component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixComponent, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/foo.txt");
+    /// assert_eq!(path.last_component(), Some(Utf8UnixComponent::Normal("foo.txt")));
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new("/").last_component(),
+    ///     Some(Utf8UnixComponent::RootDir)
+    /// );
+    /// ```
+    #[inline]
+    pub fn last_component<'a>(
+        &'a self,
+    ) -> Option<<<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component> {
+        self.components().next_back()
+    }
+
+    /// Returns `true` if the first [`Component`] of the path is `component`.
+    ///
+    /// [`Component`]: crate::Utf8Component
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8UnixComponent, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path = Utf8Path::<Utf8UnixEncoding>::new("../foo.txt");
+    /// assert!(path.starts_with_component(Utf8UnixComponent::ParentDir));
+    /// assert!(!path.starts_with_component(Utf8UnixComponent::CurDir));
+    /// ```
+    #[inline]
+    pub fn starts_with_component<'a>(
+        &'a self,
+        component: <<T as Utf8Encoding<'a>>::Components as Utf8Components<'a>>::Component,
+    ) -> bool {
+        self.first_component().map_or(false, |c| c == component)
+    }
+
     /// Creates an owned [`Utf8PathBuf`] like `self` but with a different encoding.
     ///
     /// # Note
@@ -934,6 +1742,87 @@ where
         Ok(path)
     }
 
+    /// Creates an owned [`Utf8PathBuf`] like `self` but with a different encoding, rewriting
+    /// only separators while leaving everything else untouched.
+    ///
+    /// See [`Path::transcode`] for the rules of the conversion. Unlike [`with_encoding`], `.` and
+    /// `..` are never resolved or removed, and repeated or trailing separators are kept.
+    ///
+    /// [`Path::transcode`]: crate::Path::transcode
+    /// [`with_encoding`]: Utf8Path::with_encoding
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::UnexpectedPrefix`] if the path has a prefix, and
+    /// [`CheckedPathError::InvalidFilename`] if the new encoding would read its components
+    /// differently, exactly as [`Path::transcode`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8Path, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let windows_path = Utf8Path::<Utf8WindowsEncoding>::new(r"a\..\b\.\\c\");
+    /// assert_eq!(windows_path.transcode::<Utf8UnixEncoding>().unwrap().as_str(), "a/../b/.//c/");
+    ///
+    /// let unix_path = Utf8Path::<Utf8UnixEncoding>::new("/tmp/../foo.txt");
+    /// assert_eq!(
+    ///     unix_path.transcode::<Utf8WindowsEncoding>().unwrap().as_str(),
+    ///     r"\tmp\..\foo.txt"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8WindowsEncoding>::new(r"C:a").transcode::<Utf8UnixEncoding>(),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// assert_eq!(
+    ///     Utf8Path::<Utf8UnixEncoding>::new(r"a\b").transcode::<Utf8WindowsEncoding>(),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// ```
+    pub fn transcode<U>(&self) -> Result<Utf8PathBuf<U>, CheckedPathError>
+    where
+        U: for<'enc> Utf8Encoding<'enc>,
+    {
+        if T::label() == U::label() {
+            return Ok(Utf8Path::new(self.as_str()).to_path_buf());
+        }
+
+        let from = <<<T as Utf8Encoding>::Components as Utf8Components>::Component as Utf8Component>::root().as_str();
+        let to = <<<U as Utf8Encoding>::Components as Utf8Components>::Component as Utf8Component>::root().as_str();
+
+        // Any special component that is not the root directory is a prefix
+        if let Some(component) = self.components().next() {
+            let is_prefix = !component.is_normal()
+                && !component.is_current()
+                && !component.is_parent()
+                && component.as_str() != from;
+            if is_prefix {
+                return Err(CheckedPathError::UnexpectedPrefix);
+            }
+        }
+
+        let mut separators = [None; 128];
+        let mut s = String::with_capacity(self.inner.len());
+        for c in self.as_str().chars() {
+            let is_separator = c.is_ascii()
+                && *separators[c as usize].get_or_insert_with(|| helpers::is_separator::<T>(c));
+            if is_separator {
+                s.push_str(to);
+            } else {
+                s.push(c);
+            }
+        }
+
+        let path = Utf8PathBuf::from(s);
+        if helpers::same_components(self, &path) {
+            Ok(path)
+        } else {
+            Err(CheckedPathError::InvalidFilename)
+        }
+    }
+
     /// Converts a [`Box<Utf8Path>`](Box) into a
     /// [`Utf8PathBuf`] without copying or allocating.
     pub fn into_path_buf(self: Box<Utf8Path<T>>) -> Utf8PathBuf<T> {
@@ -945,75 +1834,246 @@ where
         }
     }
 
-    /// Converts a non-UTF-8 [`Path`] to a UTF-8 [`Utf8PathBuf`] by checking that the path contains
-    /// valid UTF-8.
+    /// Converts a non-UTF-8 [`Path`] to a UTF-8 [`Utf8PathBuf`] by checking that the path contains
+    /// valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8PathError`] reporting the offset of the first invalid byte if the path is
+    /// not UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let path = Path::<UnixEncoding>::new(&[0xf0, 0x9f, 0x92, 0x96]);
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path(&path).unwrap();
+    /// assert_eq!(utf8_path.as_str(), "💖");
+    ///
+    /// let path = Path::<UnixEncoding>::new(b"/tmp/\xff");
+    /// let error = Utf8Path::<Utf8UnixEncoding>::from_bytes_path(&path).unwrap_err();
+    /// assert_eq!(error.valid_up_to(), 5);
+    /// ```
+    pub fn from_bytes_path<U>(path: &Path<U>) -> Result<&Self, Utf8PathError>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        Ok(Self::new(core::str::from_utf8(path.as_bytes())?))
+    }
+
+    /// Converts a non-UTF-8 [`Path`] to a UTF-8 [`Utf8Path`], replacing any invalid UTF-8
+    /// sequences with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// The path is borrowed when it is already valid UTF-8, and allocated otherwise.
+    ///
+    /// [U+FFFD]: std::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let path = Path::<UnixEncoding>::new(&[0xf0, 0x9f, 0x92, 0x96]);
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path_lossy(&path);
+    /// assert!(matches!(utf8_path, Cow::Borrowed(_)));
+    /// assert_eq!(utf8_path.as_str(), "💖");
+    ///
+    /// let path = Path::<UnixEncoding>::new(b"/tmp/\xff.txt");
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path_lossy(&path);
+    /// assert_eq!(utf8_path.as_str(), "/tmp/\u{FFFD}.txt");
+    /// ```
+    pub fn from_bytes_path_lossy<U>(path: &Path<U>) -> Cow<'_, Self>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        match String::from_utf8_lossy(path.as_bytes()) {
+            Cow::Borrowed(s) => Cow::Borrowed(Self::new(s)),
+            Cow::Owned(s) => Cow::Owned(Utf8PathBuf::from(s)),
+        }
+    }
+
+    /// Converts a non-UTF-8 [`Path`] to a UTF-8 [`Utf8Path`] without checking that the path
+    /// contains valid UTF-8.
+    ///
+    /// See the safe version, [`from_bytes_path`], for more information.
+    ///
+    /// [`from_bytes_path`]: Utf8Path::from_bytes_path
+    ///
+    /// # Safety
+    ///
+    /// The path passed in must be valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let path = Path::<UnixEncoding>::new(&[0xf0, 0x9f, 0x92, 0x96]);
+    /// let utf8_path = unsafe {
+    ///     Utf8Path::<Utf8UnixEncoding>::from_bytes_path_unchecked(&path)
+    /// };
+    /// assert_eq!(utf8_path.as_str(), "💖");
+    /// ```
+    pub unsafe fn from_bytes_path_unchecked<U>(path: &Path<U>) -> &Self
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        Self::new(core::str::from_utf8_unchecked(path.as_bytes()))
+    }
+
+    /// Converts a UTF-8 [`Utf8Path`] to a non-UTF-8 [`Path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::new("💖");
+    /// let path = utf8_path.as_bytes_path::<UnixEncoding>();
+    /// assert_eq!(path.as_bytes(), &[0xf0, 0x9f, 0x92, 0x96]);
+    /// ```
+    pub fn as_bytes_path<U>(&self) -> &Path<U>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.as_str())
+    }
+
+    /// Converts a [`Box<Utf8Path>`](Box) into a [`Box<Path>`](Box) without copying or
+    /// allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let utf8_path: Box<Utf8Path<Utf8UnixEncoding>> = Box::from(Utf8Path::new("/tmp/💖"));
+    /// let path: Box<Path<UnixEncoding>> = utf8_path.into_bytes_path_box();
+    /// assert_eq!(path.as_bytes(), "/tmp/💖".as_bytes());
+    /// ```
+    pub fn into_bytes_path_box<U>(self: Box<Self>) -> Box<Path<U>>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut Path<U>) }
+    }
+
+    /// Converts an [`Arc<Utf8Path>`](Arc) into an [`Arc<Path>`](Arc) without copying or
+    /// allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let utf8_path: Arc<Utf8Path<Utf8UnixEncoding>> = Arc::from(Utf8Path::new("/tmp/💖"));
+    /// let path: Arc<Path<UnixEncoding>> = utf8_path.into_bytes_path_arc();
+    /// assert_eq!(path.as_bytes(), "/tmp/💖".as_bytes());
+    /// ```
+    pub fn into_bytes_path_arc<U>(self: Arc<Self>) -> Arc<Path<U>>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        unsafe { Arc::from_raw(Arc::into_raw(self) as *const Path<U>) }
+    }
+
+    /// Converts an [`Rc<Utf8Path>`](Rc) into an [`Rc<Path>`](Rc) without copying or allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let utf8_path: Rc<Utf8Path<Utf8UnixEncoding>> = Rc::from(Utf8Path::new("/tmp/💖"));
+    /// let path: Rc<Path<UnixEncoding>> = utf8_path.into_bytes_path_rc();
+    /// assert_eq!(path.as_bytes(), "/tmp/💖".as_bytes());
+    /// ```
+    pub fn into_bytes_path_rc<U>(self: Rc<Self>) -> Rc<Path<U>>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        unsafe { Rc::from_raw(Rc::into_raw(self) as *const Path<U>) }
+    }
+
+    /// Converts a [`Box<Path>`](Box) into a [`Box<Utf8Path>`](Box) by checking that the path
+    /// contains valid UTF-8. No copying or allocating is performed.
     ///
     /// # Errors
     ///
-    /// Returns `Err` if the path is not UTF-8 with a description as to why the
-    /// provided component is not UTF-8.
+    /// Returns [`Utf8PathError`] reporting the offset of the first invalid byte if the path is
+    /// not UTF-8.
     ///
     /// # Examples
     ///
     /// ```
     /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
     ///
-    /// let path = Path::<UnixEncoding>::new(&[0xf0, 0x9f, 0x92, 0x96]);
-    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path(&path).unwrap();
+    /// let path: Box<Path<UnixEncoding>> = Box::from(Path::new(&[0xf0, 0x9f, 0x92, 0x96]));
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path_box(path).unwrap();
     /// assert_eq!(utf8_path.as_str(), "💖");
     /// ```
-    pub fn from_bytes_path<U>(path: &Path<U>) -> Result<&Self, Utf8Error>
+    pub fn from_bytes_path_box<U>(path: Box<Path<U>>) -> Result<Box<Self>, Utf8PathError>
     where
         U: for<'enc> Encoding<'enc>,
     {
-        Ok(Self::new(core::str::from_utf8(path.as_bytes())?))
+        core::str::from_utf8(path.as_bytes())?;
+        Ok(unsafe { Box::from_raw(Box::into_raw(path) as *mut Self) })
     }
 
-    /// Converts a non-UTF-8 [`Path`] to a UTF-8 [`Utf8Path`] without checking that the path
-    /// contains valid UTF-8.
-    ///
-    /// See the safe version, [`from_bytes_path`], for more information.
-    ///
-    /// [`from_bytes_path`]: Utf8Path::from_bytes_path
+    /// Converts an [`Arc<Path>`](Arc) into an [`Arc<Utf8Path>`](Arc) by checking that the path
+    /// contains valid UTF-8. No copying or allocating is performed.
     ///
-    /// # Safety
+    /// # Errors
     ///
-    /// The path passed in must be valid UTF-8.
+    /// Returns [`Utf8PathError`] reporting the offset of the first invalid byte if the path is
+    /// not UTF-8.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::sync::Arc;
     /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
     ///
-    /// let path = Path::<UnixEncoding>::new(&[0xf0, 0x9f, 0x92, 0x96]);
-    /// let utf8_path = unsafe {
-    ///     Utf8Path::<Utf8UnixEncoding>::from_bytes_path_unchecked(&path)
-    /// };
+    /// let path: Arc<Path<UnixEncoding>> = Arc::from(Path::new(&[0xf0, 0x9f, 0x92, 0x96]));
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path_arc(path).unwrap();
     /// assert_eq!(utf8_path.as_str(), "💖");
     /// ```
-    pub unsafe fn from_bytes_path_unchecked<U>(path: &Path<U>) -> &Self
+    pub fn from_bytes_path_arc<U>(path: Arc<Path<U>>) -> Result<Arc<Self>, Utf8PathError>
     where
         U: for<'enc> Encoding<'enc>,
     {
-        Self::new(core::str::from_utf8_unchecked(path.as_bytes()))
+        core::str::from_utf8(path.as_bytes())?;
+        Ok(unsafe { Arc::from_raw(Arc::into_raw(path) as *const Self) })
     }
 
-    /// Converts a UTF-8 [`Utf8Path`] to a non-UTF-8 [`Path`].
+    /// Converts an [`Rc<Path>`](Rc) into an [`Rc<Utf8Path>`](Rc) by checking that the path
+    /// contains valid UTF-8. No copying or allocating is performed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8PathError`] reporting the offset of the first invalid byte if the path is
+    /// not UTF-8.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::rc::Rc;
     /// use typed_path::{Path, Utf8Path, UnixEncoding, Utf8UnixEncoding};
     ///
-    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::new("💖");
-    /// let path = utf8_path.as_bytes_path::<UnixEncoding>();
-    /// assert_eq!(path.as_bytes(), &[0xf0, 0x9f, 0x92, 0x96]);
+    /// let path: Rc<Path<UnixEncoding>> = Rc::from(Path::new(&[0xf0, 0x9f, 0x92, 0x96]));
+    /// let utf8_path = Utf8Path::<Utf8UnixEncoding>::from_bytes_path_rc(path).unwrap();
+    /// assert_eq!(utf8_path.as_str(), "💖");
     /// ```
-    pub fn as_bytes_path<U>(&self) -> &Path<U>
+    pub fn from_bytes_path_rc<U>(path: Rc<Path<U>>) -> Result<Rc<Self>, Utf8PathError>
     where
         U: for<'enc> Encoding<'enc>,
     {
-        Path::new(self.as_str())
+        core::str::from_utf8(path.as_bytes())?;
+        Ok(unsafe { Rc::from_raw(Rc::into_raw(path) as *const Self) })
     }
 }
 
@@ -1092,8 +2152,8 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Utf8Path")
-            .field("_encoding", &T::label())
-            .field("inner", &&self.inner)
+            .field("encoding", &T::label())
+            .field("path", &self.as_str())
             .finish()
     }
 }
@@ -1118,6 +2178,16 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
+
 impl<T> cmp::PartialEq for Utf8Path<T>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -1155,7 +2225,7 @@ where
 {
     #[inline]
     fn cmp(&self, other: &Utf8Path<T>) -> cmp::Ordering {
-        self.components().cmp(other.components())
+        T::cmp(&self.inner, &other.inner)
     }
 }
 
@@ -1203,6 +2273,34 @@ where
     }
 }
 
+impl<T> Default for &Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    /// Creates an empty [`Utf8Path`] without allocating.
+    ///
+    /// A [`Cow<Utf8Path>`](Cow) can likewise default to an empty path without allocating as it
+    /// defaults to an empty owned path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typed_path::{Utf8Path, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A path cannot be created on its own without a defined encoding
+    /// let path: &Utf8Path<Utf8UnixEncoding> = Default::default();
+    /// assert_eq!(path, Utf8Path::<Utf8UnixEncoding>::EMPTY);
+    ///
+    /// let cow: Cow<'_, Utf8Path<Utf8UnixEncoding>> = Default::default();
+    /// assert_eq!(cow, Utf8Path::<Utf8UnixEncoding>::EMPTY);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Utf8Path::new("")
+    }
+}
+
 impl<'a, T> From<&'a Utf8Path<T>> for Cow<'a, Utf8Path<T>>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -1295,6 +2393,118 @@ where
     }
 }
 
+impl<T, U> From<Box<Utf8Path<T>>> for Box<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Converts a <code>[Box]&lt;[Utf8Path]&gt;</code> into a <code>[Box]&lt;[Path]&gt;</code>.
+    ///
+    /// This conversion does not clone or allocate.
+    #[inline]
+    fn from(path: Box<Utf8Path<T>>) -> Self {
+        path.into_bytes_path_box()
+    }
+}
+
+impl<T, U> TryFrom<Box<Path<U>>> for Box<Utf8Path<T>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    type Error = Utf8PathError;
+
+    /// Converts a <code>[Box]&lt;[Path]&gt;</code> into a <code>[Box]&lt;[Utf8Path]&gt;</code>
+    /// if the path is valid UTF-8.
+    ///
+    /// This conversion does not clone or allocate.
+    #[inline]
+    fn try_from(path: Box<Path<U>>) -> Result<Self, Self::Error> {
+        Utf8Path::from_bytes_path_box(path)
+    }
+}
+
+impl<T, U> From<&Utf8Path<T>> for Box<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Creates a boxed [`Path`] from a reference to a [`Utf8Path`].
+    ///
+    /// This will allocate and clone `path` to it.
+    #[inline]
+    fn from(path: &Utf8Path<T>) -> Self {
+        Box::from(path.as_bytes_path::<U>())
+    }
+}
+
+impl<T, U> From<Utf8PathBuf<T>> for Box<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Converts a [`Utf8PathBuf`] into a <code>[Box]&lt;[Path]&gt;</code>.
+    ///
+    /// This conversion currently should not allocate memory,
+    /// but this behavior is not guaranteed on all platforms or in all future versions.
+    #[inline]
+    fn from(path_buf: Utf8PathBuf<T>) -> Self {
+        path_buf.into_boxed_path().into_bytes_path_box()
+    }
+}
+
+impl<T, U> From<&Utf8Path<T>> for Arc<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Converts a [`Utf8Path`] into an <code>[Arc]<[Path]></code> by copying the [`Utf8Path`]
+    /// data into a new [`Arc`] buffer.
+    #[inline]
+    fn from(path: &Utf8Path<T>) -> Self {
+        Arc::from(path.as_bytes_path::<U>())
+    }
+}
+
+impl<T, U> From<Utf8PathBuf<T>> for Arc<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Converts a [`Utf8PathBuf`] into an <code>[Arc]<[Path]></code> by moving the
+    /// [`Utf8PathBuf`] data into a new [`Arc`] buffer.
+    #[inline]
+    fn from(path_buf: Utf8PathBuf<T>) -> Self {
+        Arc::<Utf8Path<T>>::from(path_buf).into_bytes_path_arc()
+    }
+}
+
+impl<T, U> From<&Utf8Path<T>> for Rc<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Converts a [`Utf8Path`] into an <code>[Rc]<[Path]></code> by copying the [`Utf8Path`]
+    /// data into a new [`Rc`] buffer.
+    #[inline]
+    fn from(path: &Utf8Path<T>) -> Self {
+        Rc::from(path.as_bytes_path::<U>())
+    }
+}
+
+impl<T, U> From<Utf8PathBuf<T>> for Rc<Path<U>>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+    U: for<'enc> Encoding<'enc>,
+{
+    /// Converts a [`Utf8PathBuf`] into an <code>[Rc]<[Path]></code> by moving the
+    /// [`Utf8PathBuf`] data into a new [`Rc`] buffer.
+    #[inline]
+    fn from(path_buf: Utf8PathBuf<T>) -> Self {
+        Rc::<Utf8Path<T>>::from(path_buf).into_bytes_path_rc()
+    }
+}
+
 impl<'a, T> IntoIterator for &'a Utf8Path<T>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -1426,21 +2636,81 @@ impl_cmp_bytes!('a; &'a Utf8Path<T>, str);
 impl_cmp_bytes!('a, 'b; &'a Utf8Path<T>, Cow<'b, str>);
 impl_cmp_bytes!('a; &'a Utf8Path<T>, String);
 
-mod helpers {
+pub(crate) mod helpers {
     use super::*;
 
-    pub fn rsplit_file_at_dot(file: &str) -> (Option<&str>, Option<&str>) {
-        if file == ".." {
-            return (Some(file), None);
+    /// Returns the maximum number of bytes that [`Utf8PathBuf::push`]ing each of `paths` could
+    /// add, accounting for a separator being inserted before each one.
+    pub fn pushed_len<T, P>(paths: impl Iterator<Item = P>) -> usize
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+        P: AsRef<Utf8Path<T>>,
+    {
+        paths.map(|p| p.as_ref().as_str().len() + 1).sum()
+    }
+
+    /// Returns true if the ASCII `c` separates components in encoding `T`.
+    pub fn is_separator<T>(c: char) -> bool
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        // Digits are never separators, nor do they form a prefix such as `C:` with what follows
+        let mut buf = [b'0'; 3];
+        c.encode_utf8(&mut buf[1..2]);
+        let s = core::str::from_utf8(&buf).expect("ASCII is valid UTF-8");
+        !c.is_ascii_digit() && Utf8Path::<T>::new(s).components().count() == 2
+    }
+
+    /// Returns true if `a` and `b` have the same kinds of components in the same order, with
+    /// normal components having the same text, even though their encodings differ.
+    pub fn same_components<T, U>(a: &Utf8Path<T>, b: &Utf8Path<U>) -> bool
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+        U: for<'enc> Utf8Encoding<'enc>,
+    {
+        let mut b = b.components();
+        for x in a.components() {
+            let same = match b.next() {
+                Some(y) if x.is_normal() => y.is_normal() && x.as_str() == y.as_str(),
+                Some(y) => {
+                    x.is_root() == y.is_root()
+                        && x.is_current() == y.is_current()
+                        && x.is_parent() == y.is_parent()
+                        && !y.is_normal()
+                }
+                None => false,
+            };
+            if !same {
+                return false;
+            }
         }
+        b.next().is_none()
+    }
 
-        let mut iter = file.rsplitn(2, '.');
-        let after = iter.next();
-        let before = iter.next();
-        if before == Some("") {
-            (Some(file), None)
-        } else {
-            (before, after)
+    /// Returns true if `name` parses as exactly one valid normal [`Utf8Component`] spanning all
+    /// of `name`, meaning it can be used as a file name without introducing other components.
+    pub fn is_single_normal_component<T>(name: &str) -> bool
+    where
+        T: for<'enc> Utf8Encoding<'enc>,
+    {
+        let mut components = Utf8Path::<T>::new(name).components();
+        match (components.next(), components.next()) {
+            (Some(component), None) => {
+                component.is_normal() && component.is_valid() && component.as_str() == name
+            }
+            _ => false,
+        }
+    }
+
+    pub fn rsplit_file_at_dot(file: &str) -> (Option<&str>, Option<&str>) {
+        // NOTE: We defer to the byte implementation so the two cannot diverge. Splitting only
+        //       ever happens at an ASCII `.`, so both halves remain valid UTF-8.
+        let (before, after) = crate::common::non_utf8::helpers::rsplit_file_at_dot(file.as_bytes());
+        unsafe {
+            (
+                before.map(|s| core::str::from_utf8_unchecked(s)),
+                after.map(|s| core::str::from_utf8_unchecked(s)),
+            )
         }
     }
 
@@ -1476,57 +2746,222 @@ mod helpers {
     }
 }
 
-#[cfg(any(
-    unix,
-    all(target_vendor = "fortanix", target_env = "sgx"),
-    target_os = "solid_asp3",
-    target_os = "hermit",
-    target_os = "wasi"
-))]
 #[cfg(feature = "std")]
-mod std_conversions {
-    use std::ffi::{OsStr, OsString};
-    #[cfg(all(target_vendor = "fortanix", target_env = "sgx"))]
-    use std::os::fortanix_sgx as os;
-    #[cfg(target_os = "solid_asp3")]
-    use std::os::solid as os;
-    #[cfg(any(target_os = "hermit", unix))]
-    use std::os::unix as os;
-    #[cfg(target_os = "wasi")]
-    use std::os::wasi as os;
-
-    use os::ffi::OsStrExt;
+impl<T> crate::common::TryAsRef<Utf8Path<T>> for std::ffi::OsStr
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn try_as_ref(&self) -> Option<&Utf8Path<T>> {
+        self.to_str().map(Utf8Path::new)
+    }
+}
 
-    use super::*;
-    use crate::common::TryAsRef;
+#[cfg(feature = "std")]
+impl<T> crate::common::TryAsRef<Utf8Path<T>> for std::ffi::OsString
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn try_as_ref(&self) -> Option<&Utf8Path<T>> {
+        self.to_str().map(Utf8Path::new)
+    }
+}
 
-    impl<T> TryAsRef<Utf8Path<T>> for OsStr
-    where
-        T: for<'enc> Utf8Encoding<'enc>,
-    {
-        #[inline]
-        fn try_as_ref(&self) -> Option<&Utf8Path<T>> {
-            std::str::from_utf8(self.as_bytes()).ok().map(Utf8Path::new)
+#[cfg(feature = "std")]
+impl<T> AsRef<std::ffi::OsStr> for Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &std::ffi::OsStr {
+        std::ffi::OsStr::new(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "std", any(unix, windows, target_os = "wasi")))]
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
+impl Utf8Path<crate::Utf8NativeEncoding> {
+    /// Views a [`Utf8NativePath`](crate::Utf8NativePath) as a [`std::path::Path`].
+    ///
+    /// This is useful where a concrete [`std::path::Path`] is needed rather than a generic
+    /// `impl AsRef<std::path::Path>`, such as when working with `tokio::fs` or `fs_err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8NativePath;
+    ///
+    /// let path = Utf8NativePath::new("some_file.txt");
+    /// assert_eq!(path.as_std_path(), std::path::Path::new("some_file.txt"));
+    /// ```
+    #[inline]
+    pub fn as_std_path(&self) -> &std::path::Path {
+        std::path::Path::new(self.as_str())
+    }
+
+    /// Converts a [`Cow`] of a [`std::path::Path`] into a [`Cow`] of a
+    /// [`Utf8NativePath`](crate::Utf8NativePath), preserving whether it is borrowed or owned.
+    ///
+    /// Returns the original path as the error if it is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typed_path::Utf8NativePath;
+    ///
+    /// let std_path = Cow::Borrowed(std::path::Path::new("some_file.txt"));
+    /// let path = Utf8NativePath::try_from_std_cow(std_path).unwrap();
+    /// assert!(matches!(path, Cow::Borrowed(_)));
+    /// assert_eq!(path.as_str(), "some_file.txt");
+    ///
+    /// let std_path = Cow::Owned(std::path::PathBuf::from("some_file.txt"));
+    /// let path = Utf8NativePath::try_from_std_cow(std_path).unwrap();
+    /// assert!(matches!(path, Cow::Owned(_)));
+    /// ```
+    pub fn try_from_std_cow(
+        path: Cow<'_, std::path::Path>,
+    ) -> Result<Cow<'_, Self>, Cow<'_, std::path::Path>> {
+        match path {
+            Cow::Borrowed(path) => match path.to_str() {
+                Some(s) => Ok(Cow::Borrowed(Utf8Path::new(s))),
+                None => Err(Cow::Borrowed(path)),
+            },
+            Cow::Owned(path) => match path.into_os_string().into_string() {
+                Ok(s) => Ok(Cow::Owned(Utf8PathBuf::from(s))),
+                Err(s) => Err(Cow::Owned(std::path::PathBuf::from(s))),
+            },
         }
     }
 
-    impl<T> TryAsRef<Utf8Path<T>> for OsString
-    where
-        T: for<'enc> Utf8Encoding<'enc>,
-    {
-        #[inline]
-        fn try_as_ref(&self) -> Option<&Utf8Path<T>> {
-            std::str::from_utf8(self.as_bytes()).ok().map(Utf8Path::new)
+    /// Converts a [`Cow`] of a [`Utf8NativePath`](crate::Utf8NativePath) into a [`Cow`] of a
+    /// [`std::path::Path`], preserving whether it is borrowed or owned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typed_path::Utf8NativePath;
+    ///
+    /// let path = Cow::Borrowed(Utf8NativePath::new("some_file.txt"));
+    /// let std_path = Utf8NativePath::into_std_cow(path);
+    /// assert!(matches!(std_path, Cow::Borrowed(_)));
+    /// assert_eq!(std_path, std::path::Path::new("some_file.txt"));
+    /// ```
+    pub fn into_std_cow(path: Cow<'_, Self>) -> Cow<'_, std::path::Path> {
+        match path {
+            Cow::Borrowed(path) => Cow::Borrowed(path.as_std_path()),
+            Cow::Owned(path) => Cow::Owned(std::path::PathBuf::from(path.into_string())),
         }
     }
 
-    impl<T> AsRef<OsStr> for Utf8Path<T>
-    where
-        T: for<'enc> Utf8Encoding<'enc>,
-    {
-        #[inline]
-        fn as_ref(&self) -> &OsStr {
-            OsStrExt::from_bytes(self.as_str().as_bytes())
+    /// Walks the [`Utf8NativePath`](crate::Utf8NativePath) and its
+    /// [`ancestors`](Utf8Path::ancestors), returning the first directory containing an entry
+    /// named `marker` on the file system.
+    ///
+    /// Returns `None` without touching the file system if `marker` is not a single normal
+    /// component, such as `../Cargo.toml` or `/etc`, as joining it would look outside of each
+    /// directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8NativePath;
+    ///
+    /// let manifest_dir = Utf8NativePath::new(env!("CARGO_MANIFEST_DIR"));
+    /// let src_dir = manifest_dir.join("src");
+    /// assert_eq!(src_dir.find_up("Cargo.toml"), Some(manifest_dir));
+    /// assert_eq!(src_dir.find_up("../Cargo.toml"), None);
+    /// ```
+    pub fn find_up(&self, marker: impl AsRef<str>) -> Option<&Self> {
+        let marker = marker.as_ref();
+        if !helpers::is_single_normal_component::<crate::Utf8NativeEncoding>(marker) {
+            return None;
+        }
+
+        self.find_ancestor_with(|dir| dir.join(marker).as_std_path().exists())
+    }
+
+    /// Creates the directory at this [`Utf8NativePath`](crate::Utf8NativePath) along with any
+    /// missing [`ancestors`](Utf8Path::ancestors), like [`std::fs::create_dir_all`], returning
+    /// the directories that were created from the outermost inward.
+    ///
+    /// Directories that already exist, including those created concurrently by another
+    /// process, are not included. If an error occurs, the directories created before it
+    /// remain on the file system.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] under the same conditions as [`std::fs::create_dir_all`], including an
+    /// [`AlreadyExists`] error if the path already exists but is not a directory.
+    ///
+    /// [`AlreadyExists`]: std::io::ErrorKind::AlreadyExists
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8NativePathBuf;
+    ///
+    /// let root = Utf8NativePathBuf::from(std::env::temp_dir().to_str().unwrap())
+    ///     .join(format!("typed-path-utf8-doc-{}", std::process::id()));
+    /// let path = root.join("a/b");
+    ///
+    /// let created = path.create_dir_all_verbose().unwrap();
+    /// assert_eq!(created, [root.clone(), root.join("a"), root.join("a/b")]);
+    /// assert!(path.create_dir_all_verbose().unwrap().is_empty());
+    ///
+    /// for dir in created.iter().rev() {
+    ///     std::fs::remove_dir(dir).unwrap();
+    /// }
+    /// ```
+    pub fn create_dir_all_verbose(
+        &self,
+    ) -> std::io::Result<Vec<Utf8PathBuf<crate::Utf8NativeEncoding>>> {
+        let missing: Vec<&Self> = self
+            .ancestors()
+            .filter(|dir| !dir.as_str().is_empty())
+            .take_while(|dir| !dir.as_std_path().exists())
+            .collect();
+
+        if missing.is_empty() && !self.as_str().is_empty() && !self.as_std_path().is_dir() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                "path exists and is not a directory",
+            ));
+        }
+
+        let mut created = Vec::with_capacity(missing.len());
+        for dir in missing.into_iter().rev() {
+            match std::fs::create_dir(dir) {
+                Ok(()) => created.push(dir.to_path_buf()),
+                Err(x)
+                    if x.kind() == std::io::ErrorKind::AlreadyExists
+                        && dir.as_std_path().is_dir() => {}
+                Err(x) => return Err(x),
+            }
         }
+        Ok(created)
+    }
+}
+
+#[cfg(all(feature = "std", any(unix, windows, target_os = "wasi")))]
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
+impl AsRef<std::path::Path> for Utf8Path<crate::Utf8NativeEncoding> {
+    /// Views a [`Utf8NativePath`](crate::Utf8NativePath) as a [`std::path::Path`], allowing it
+    /// to be passed directly to functions like [`File::open`](std::fs::File::open).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8NativePath;
+    ///
+    /// let path = Utf8NativePath::new("some_file.txt");
+    /// let std_path: &std::path::Path = path.as_ref();
+    /// assert_eq!(std_path, std::path::Path::new("some_file.txt"));
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &std::path::Path {
+        self.as_std_path()
     }
 }
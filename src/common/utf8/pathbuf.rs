@@ -1,6 +1,5 @@
 use alloc::borrow::Cow;
 use alloc::collections::TryReserveError;
-use alloc::string::FromUtf8Error;
 use core::borrow::Borrow;
 use core::hash::{Hash, Hasher};
 use core::iter::{Extend, FromIterator};
@@ -9,8 +8,12 @@ use core::ops::Deref;
 use core::str::FromStr;
 use core::{cmp, fmt};
 
+use super::helpers;
 use crate::no_std_compat::*;
-use crate::{CheckedPathError, Encoding, PathBuf, Utf8Encoding, Utf8Iter, Utf8Path};
+use crate::{
+    CheckedPathError, Encoding, FromUtf8PathError, PathBuf, PathCheckpoint, Utf8Component,
+    Utf8Encoding, Utf8Iter, Utf8Path,
+};
 
 /// An owned, mutable path that mirrors [`std::path::PathBuf`], but operatings using a
 /// [`Utf8Encoding`] to determine how to parse the underlying str.
@@ -122,6 +125,75 @@ where
         }
     }
 
+    /// Parses, validates, and [`normalizes`] `input` in a single pass, allocating only the
+    /// resulting path.
+    ///
+    /// This produces the same path as `Utf8PathBuf::from(input).normalize()`, but fails instead
+    /// of silently repairing input that cannot be normalized safely:
+    ///
+    /// * [`CheckedPathError::InvalidFilename`] if a component contains characters that are not
+    ///   allowed by the encoding.
+    /// * [`CheckedPathError::PathTraversalAttack`] if a `..` component would escape a path that
+    ///   has no root. Leading `..` components of rooted paths are dropped as with [`normalize`].
+    ///
+    /// [`normalize`]: Utf8Path::normalize
+    /// [`normalizes`]: Utf8Path::normalize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// assert_eq!(
+    ///     Utf8PathBuf::<Utf8UnixEncoding>::normalized_from("/tmp//foo/./bar/.."),
+    ///     Ok(Utf8PathBuf::from("/tmp/foo")),
+    /// );
+    /// assert_eq!(
+    ///     Utf8PathBuf::<Utf8UnixEncoding>::normalized_from("/../etc"),
+    ///     Ok(Utf8PathBuf::from("/etc")),
+    /// );
+    /// assert_eq!(
+    ///     Utf8PathBuf::<Utf8UnixEncoding>::normalized_from("foo/../../etc"),
+    ///     Err(CheckedPathError::PathTraversalAttack),
+    /// );
+    /// assert_eq!(
+    ///     Utf8PathBuf::<Utf8WindowsEncoding>::normalized_from(r"C:\dir\file?.txt"),
+    ///     Err(CheckedPathError::InvalidFilename),
+    /// );
+    /// ```
+    pub fn normalized_from<P: AsRef<str>>(input: P) -> Result<Self, CheckedPathError> {
+        let input = input.as_ref();
+        let mut path = Self::with_capacity(input.len());
+
+        for component in T::components(input) {
+            if !component.is_valid() {
+                return Err(CheckedPathError::InvalidFilename);
+            }
+
+            if component.is_current() {
+                continue;
+            }
+
+            if component.is_parent() {
+                let after_normal = path
+                    .components()
+                    .next_back()
+                    .map_or(false, |c| c.is_normal());
+                if after_normal {
+                    path.pop();
+                } else if !path.has_root() {
+                    return Err(CheckedPathError::PathTraversalAttack);
+                }
+                continue;
+            }
+
+            T::push(&mut path.inner, component.as_str());
+        }
+
+        Ok(path)
+    }
+
     /// Coerces to a [`Utf8Path`] slice.
     ///
     /// # Examples
@@ -180,6 +252,24 @@ where
         T::push(&mut self.inner, path.as_ref().as_str());
     }
 
+    /// Like [`Utf8PathBuf::push`], extends `self` with `path`, but returns `self` so that further
+    /// changes can be chained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8UnixEncoding>::from("/tmp");
+    /// path.push_chained("a").push_chained("b").set_extension_chained("rs");
+    /// assert_eq!(path, Utf8PathBuf::from("/tmp/a/b.rs"));
+    /// ```
+    pub fn push_chained<P: AsRef<Utf8Path<T>>>(&mut self, path: P) -> &mut Self {
+        self.push(path);
+        self
+    }
+
     /// Like [`Utf8PathBuf::push`], extends `self` with `path`, but also checks to ensure that
     /// `path` abides by a set of rules.
     ///
@@ -267,6 +357,65 @@ where
         }
     }
 
+    /// Returns a [`PathCheckpoint`] recording the current length and contents of the buffer,
+    /// which [`truncate_to`] can later restore.
+    ///
+    /// [`truncate_to`]: Utf8PathBuf::truncate_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/home");
+    /// let checkpoint = p.checkpoint();
+    ///
+    /// p.push("ferris/projects");
+    /// assert!(p.truncate_to(checkpoint));
+    /// assert_eq!(p.as_str(), "/home");
+    /// ```
+    #[inline]
+    pub fn checkpoint(&self) -> PathCheckpoint {
+        PathCheckpoint::new(self.inner.as_bytes())
+    }
+
+    /// Truncates the buffer back to the length recorded by `checkpoint`, discarding everything
+    /// pushed since it was taken, and returns true.
+    ///
+    /// If the buffer no longer starts with the text it held when the checkpoint was taken, such
+    /// as after pushing an absolute path or calling [`clear`], the buffer is left unchanged and
+    /// false is returned.
+    ///
+    /// [`clear`]: Utf8PathBuf::clear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8WindowsEncoding>::from(r"C:");
+    /// for dir in ["Users", "Windows"] {
+    ///     let checkpoint = p.checkpoint();
+    ///     p.push(dir);
+    ///     p.push("Temp");
+    ///     assert!(p.ends_with("Temp"));
+    ///     assert!(p.truncate_to(checkpoint));
+    /// }
+    /// assert_eq!(p.as_str(), r"C:");
+    /// ```
+    #[inline]
+    pub fn truncate_to(&mut self, checkpoint: PathCheckpoint) -> bool {
+        match checkpoint.len_in(self.inner.as_bytes()) {
+            Some(len) if self.inner.is_char_boundary(len) => {
+                self.inner.truncate(len);
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Updates [`self.file_name`] to `file_name`.
     ///
     /// If [`self.file_name`] was [`None`], this is equivalent to pushing
@@ -305,6 +454,79 @@ where
         self.push(file_name);
     }
 
+    /// Like [`Utf8PathBuf::set_file_name`], updates [`self.file_name`] to `file_name`, but returns
+    /// `self` so that further changes can be chained.
+    ///
+    /// [`self.file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = Utf8PathBuf::<Utf8UnixEncoding>::from("/tmp/foo.txt");
+    /// buf.set_file_name_chained("bar").push_chained("baz.txt");
+    /// assert_eq!(buf, Utf8PathBuf::from("/tmp/bar/baz.txt"));
+    /// ```
+    pub fn set_file_name_chained<S: AsRef<str>>(&mut self, file_name: S) -> &mut Self {
+        self.set_file_name(file_name);
+        self
+    }
+
+    /// Like [`Utf8PathBuf::set_file_name`], updates [`self.file_name`] to `file_name`, but first
+    /// checks that `file_name` is a single, valid file name for the encoding.
+    ///
+    /// [`Utf8PathBuf::set_file_name`] pushes `file_name` as a path, so a value containing
+    /// separators silently introduces additional components. This should be used instead when
+    /// `file_name` comes from an untrusted source.
+    ///
+    /// [`self.file_name`]: Utf8Path::file_name
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] and leaves the path unchanged if
+    /// `file_name`:
+    ///
+    /// * is empty, `.`, or `..`
+    /// * contains a separator or any other character disallowed by the encoding, such as `\0`
+    ///   on Unix or `|` on Windows
+    /// * is a Windows prefix such as `C:`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8PathBuf, Utf8UnixEncoding, Utf8WindowsEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = Utf8PathBuf::<Utf8UnixEncoding>::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_file_name("bar.txt"), Ok(()));
+    /// assert_eq!(buf, Utf8PathBuf::from("/tmp/bar.txt"));
+    ///
+    /// assert_eq!(buf.try_set_file_name("../etc/passwd"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name(".."), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name("a\0b"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, Utf8PathBuf::from("/tmp/bar.txt"));
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = Utf8PathBuf::<Utf8WindowsEncoding>::from(r"C:\tmp\foo.txt");
+    /// assert_eq!(buf.try_set_file_name(r"bar\baz.txt"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name("bar|baz.txt"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf.try_set_file_name("D:"), Err(CheckedPathError::InvalidFilename));
+    /// ```
+    pub fn try_set_file_name<S: AsRef<str>>(
+        &mut self,
+        file_name: S,
+    ) -> Result<(), CheckedPathError> {
+        let file_name = file_name.as_ref();
+        if !helpers::is_single_normal_component::<T>(file_name) {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        self._set_file_name(file_name);
+        Ok(())
+    }
+
     /// Updates [`self.extension`] to `extension`.
     ///
     /// Returns `false` and does nothing if [`self.file_name`] is [`None`],
@@ -363,6 +585,107 @@ where
         true
     }
 
+    /// Like [`Utf8PathBuf::set_extension`], updates [`self.extension`] to `extension`, but returns
+    /// `self` so that further changes can be chained.
+    ///
+    /// As with [`Utf8PathBuf::set_extension`], nothing happens if [`self.file_name`] is [`None`].
+    ///
+    /// [`self.file_name`]: Utf8Path::file_name
+    /// [`self.extension`]: Utf8Path::extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = Utf8PathBuf::<Utf8UnixEncoding>::from("/tmp/foo.txt");
+    /// buf.set_extension_chained("rs").push_chained("..");
+    /// assert_eq!(buf, Utf8PathBuf::from("/tmp/foo.rs/.."));
+    /// ```
+    pub fn set_extension_chained<S: AsRef<str>>(&mut self, extension: S) -> &mut Self {
+        self.set_extension(extension);
+        self
+    }
+
+    /// Like [`Utf8PathBuf::set_extension`], updates [`self.extension`] to `extension`, but first
+    /// checks that the resulting file name would still be a single, valid file name.
+    ///
+    /// An empty `extension` is always accepted and removes the current extension.
+    ///
+    /// [`self.extension`]: Utf8Path::extension
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckedPathError::InvalidFilename`] and leaves the path unchanged if
+    /// `extension` contains a separator or any other character disallowed by the encoding, or
+    /// is `.` or `..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut buf = Utf8PathBuf::<Utf8UnixEncoding>::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_extension("tar.gz"), Ok(true));
+    /// assert_eq!(buf, Utf8PathBuf::from("/tmp/foo.tar.gz"));
+    ///
+    /// assert_eq!(buf.try_set_extension("gz/evil"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, Utf8PathBuf::from("/tmp/foo.tar.gz"));
+    ///
+    /// let mut buf = Utf8PathBuf::<Utf8UnixEncoding>::from("/");
+    /// assert_eq!(buf.try_set_extension("txt"), Ok(false));
+    /// ```
+    pub fn try_set_extension<S: AsRef<str>>(
+        &mut self,
+        extension: S,
+    ) -> Result<bool, CheckedPathError> {
+        let extension = extension.as_ref();
+        if !extension.is_empty() && !helpers::is_single_normal_component::<T>(extension) {
+            return Err(CheckedPathError::InvalidFilename);
+        }
+
+        Ok(self._set_extension(extension))
+    }
+
+    /// Converts [`self.extension`] to lowercase in place, so that extensions such as `JPG` and
+    /// `jpg` compare equal.
+    ///
+    /// Lowercasing follows [`str::to_lowercase`], so non-ASCII characters are converted as well.
+    ///
+    /// Returns `false` and does nothing if [`self.extension`] is [`None`], returns `true`
+    /// otherwise.
+    ///
+    /// [`self.extension`]: Utf8Path::extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8Path, Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/photos/IMG_0001.JPG");
+    /// assert!(p.lowercase_extension());
+    /// assert_eq!(Utf8Path::new("/photos/IMG_0001.jpg"), p.as_path());
+    ///
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/notes/Ελληνικά.ΚΕΊΜΕΝΟ");
+    /// assert!(p.lowercase_extension());
+    /// assert_eq!(Utf8Path::new("/notes/Ελληνικά.κείμενο"), p.as_path());
+    ///
+    /// let mut p = Utf8PathBuf::<Utf8UnixEncoding>::from("/photos/README");
+    /// assert!(!p.lowercase_extension());
+    /// ```
+    pub fn lowercase_extension(&mut self) -> bool {
+        match self.extension() {
+            Some(extension) => {
+                let extension = extension.to_lowercase();
+                self._set_extension(&extension)
+            }
+            None => false,
+        }
+    }
+
     /// Consumes the `PathBuf`, yielding its internal [`String`] storage.
     ///
     /// # Examples
@@ -450,12 +773,12 @@ where
     }
 
     /// Consumes [`PathBuf`] and returns a new [`Utf8PathBuf`] by checking that the path contains
-    /// valid UTF-8.
+    /// valid UTF-8, reusing its buffer.
     ///
     /// # Errors
     ///
-    /// Returns `Err` if the path is not UTF-8 with a description as to why the
-    /// provided component is not UTF-8.
+    /// Returns [`FromUtf8PathError`] if the path is not UTF-8, which reports the offset of the
+    /// first invalid byte and hands back the original [`PathBuf`] without copying it.
     ///
     /// # Examples
     ///
@@ -465,15 +788,114 @@ where
     /// let path_buf = PathBuf::<UnixEncoding>::from(&[0xf0, 0x9f, 0x92, 0x96]);
     /// let utf8_path_buf = Utf8PathBuf::<Utf8UnixEncoding>::from_bytes_path_buf(path_buf).unwrap();
     /// assert_eq!(utf8_path_buf.as_str(), "💖");
+    ///
+    /// let path_buf = PathBuf::<UnixEncoding>::from(b"/tmp/\xff");
+    /// let error = Utf8PathBuf::<Utf8UnixEncoding>::from_bytes_path_buf(path_buf).unwrap_err();
+    /// assert_eq!(error.utf8_error().valid_up_to(), 5);
+    /// assert_eq!(error.into_path().as_bytes(), b"/tmp/\xff");
+    /// ```
+    pub fn from_bytes_path_buf<U>(
+        path_buf: PathBuf<U>,
+    ) -> Result<Self, FromUtf8PathError<PathBuf<U>>>
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        match String::from_utf8(path_buf.inner) {
+            Ok(inner) => Ok(Self {
+                _encoding: PhantomData,
+                inner,
+            }),
+            Err(x) => Err(FromUtf8PathError {
+                error: x.utf8_error().into(),
+                path: PathBuf {
+                    _encoding: PhantomData,
+                    inner: x.into_bytes(),
+                },
+            }),
+        }
+    }
+
+    /// Consumes [`PathBuf`] and returns a new [`Utf8PathBuf`] by checking that the path contains
+    /// valid UTF-8, reusing its buffer.
+    ///
+    /// This is an alias of [`from_bytes_path_buf`] named after [`String::from_utf8`].
+    ///
+    /// [`from_bytes_path_buf`]: Utf8PathBuf::from_bytes_path_buf
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromUtf8PathError`] holding the original path if it is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
     /// ```
-    pub fn from_bytes_path_buf<U>(path_buf: PathBuf<U>) -> Result<Self, FromUtf8Error>
+    /// use typed_path::{UnixPathBuf, Utf8UnixPathBuf};
+    ///
+    /// let path = Utf8UnixPathBuf::from_utf8(UnixPathBuf::from("/tmp/💖")).unwrap();
+    /// assert_eq!(path.as_str(), "/tmp/💖");
+    ///
+    /// let error = Utf8UnixPathBuf::from_utf8(UnixPathBuf::from(b"/tmp/\xff")).unwrap_err();
+    /// assert_eq!(error.utf8_error().valid_up_to(), 5);
+    /// assert_eq!(error.into_path().as_bytes(), b"/tmp/\xff");
+    /// ```
+    #[inline]
+    pub fn from_utf8<U>(path_buf: PathBuf<U>) -> Result<Self, FromUtf8PathError<PathBuf<U>>>
     where
         U: for<'enc> Encoding<'enc>,
     {
-        Ok(Self {
+        Self::from_bytes_path_buf(path_buf)
+    }
+
+    /// Consumes [`PathBuf`] and returns a new [`Utf8PathBuf`], replacing any invalid UTF-8
+    /// sequences with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// The underlying buffer is reused when the path is already valid UTF-8.
+    ///
+    /// [U+FFFD]: std::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathBuf, Utf8PathBuf, UnixEncoding, Utf8UnixEncoding};
+    ///
+    /// let path_buf = PathBuf::<UnixEncoding>::from(b"/tmp/\xff.txt");
+    /// let utf8_path_buf = Utf8PathBuf::<Utf8UnixEncoding>::from_bytes_path_buf_lossy(path_buf);
+    /// assert_eq!(utf8_path_buf.as_str(), "/tmp/\u{FFFD}.txt");
+    /// ```
+    pub fn from_bytes_path_buf_lossy<U>(path_buf: PathBuf<U>) -> Self
+    where
+        U: for<'enc> Encoding<'enc>,
+    {
+        Self::from_vec_lossy(path_buf.inner)
+    }
+
+    /// Creates a new [`Utf8PathBuf`] from a vector of bytes, replacing any invalid UTF-8
+    /// sequences with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// The vector is reused when it is already valid UTF-8. Use [`String::from_utf8`] instead
+    /// to learn where the first invalid byte is through [`Utf8Error::valid_up_to`].
+    ///
+    /// [U+FFFD]: std::char::REPLACEMENT_CHARACTER
+    /// [`Utf8Error::valid_up_to`]: core::str::Utf8Error::valid_up_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// let path_buf = Utf8PathBuf::<Utf8UnixEncoding>::from_vec_lossy(b"/tmp/\xff.txt".to_vec());
+    /// assert_eq!(path_buf.as_str(), "/tmp/\u{FFFD}.txt");
+    /// ```
+    pub fn from_vec_lossy(bytes: Vec<u8>) -> Self {
+        let inner = match String::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(x) => String::from_utf8_lossy(x.as_bytes()).into_owned(),
+        };
+
+        Self {
             _encoding: PhantomData,
-            inner: String::from_utf8(path_buf.inner)?,
-        })
+            inner,
+        }
     }
 
     /// Consumes [`PathBuf`] and returns a new [`Utf8PathBuf`] by checking that the path contains
@@ -550,8 +972,8 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Utf8PathBuf")
-            .field("_encoding", &T::label())
-            .field("inner", &self.inner)
+            .field("encoding", &T::label())
+            .field("path", &self.as_str())
             .finish()
     }
 }
@@ -615,6 +1037,16 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T> defmt::Format for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::Format::format(self.as_path(), f)
+    }
+}
+
 impl<T> Deref for Utf8PathBuf<T>
 where
     T: for<'enc> Utf8Encoding<'enc>,
@@ -643,8 +1075,31 @@ where
     T: for<'enc> Utf8Encoding<'enc>,
     P: AsRef<Utf8Path<T>>,
 {
+    /// Extends `self` by [`pushing`] each path from `iter` in order.
+    ///
+    /// Before pushing, a byte is reserved for the separator of each path promised by the lower
+    /// bound of the iterator's [`size_hint`]. Paths are then pushed as they are produced, so the
+    /// buffer may still grow more than once. Use [`reserve`] beforehand when the total length is
+    /// known.
+    ///
+    /// [`pushing`]: Utf8PathBuf::push
+    /// [`reserve`]: Utf8PathBuf::reserve
+    /// [`size_hint`]: Iterator::size_hint
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+    ///
+    /// // NOTE: A pathbuf cannot be created on its own without a defined encoding
+    /// let mut path = Utf8PathBuf::<Utf8UnixEncoding>::from("/root");
+    /// path.extend(["a", "b", "c"]);
+    /// assert_eq!(path, Utf8PathBuf::from("/root/a/b/c"));
+    /// ```
     fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
-        iter.into_iter().for_each(move |p| self.push(p.as_ref()));
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(move |p| self.push(p.as_ref()));
     }
 }
 
@@ -779,46 +1234,72 @@ where
     }
 }
 
-#[cfg(any(
-    unix,
-    all(target_vendor = "fortanix", target_env = "sgx"),
-    target_os = "solid_asp3",
-    target_os = "hermit",
-    target_os = "wasi"
-))]
 #[cfg(feature = "std")]
-mod std_conversions {
-    use std::ffi::{OsStr, OsString};
-    #[cfg(all(target_vendor = "fortanix", target_env = "sgx"))]
-    use std::os::fortanix_sgx as os;
-    #[cfg(target_os = "solid_asp3")]
-    use std::os::solid as os;
-    #[cfg(any(target_os = "hermit", unix))]
-    use std::os::unix as os;
-    #[cfg(target_os = "wasi")]
-    use std::os::wasi as os;
-
-    use os::ffi::{OsStrExt, OsStringExt};
-
-    use super::*;
-
-    impl<T> From<Utf8PathBuf<T>> for OsString
-    where
-        T: for<'enc> Utf8Encoding<'enc>,
-    {
-        #[inline]
-        fn from(path_buf: Utf8PathBuf<T>) -> Self {
-            OsStringExt::from_vec(path_buf.into_string().into_bytes())
-        }
+impl<T> TryFrom<std::ffi::OsString> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    type Error = std::ffi::OsString;
+
+    /// Attempts to convert a [`std::ffi::OsString`] into a [`Utf8PathBuf`], returning a result
+    /// containing the new path when successful or the original string when it is not valid
+    /// UTF-8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsString;
+    /// use typed_path::Utf8NativePathBuf;
+    ///
+    /// let path = Utf8NativePathBuf::try_from(OsString::from("foo.txt")).unwrap();
+    /// assert_eq!(path, Utf8NativePathBuf::from("foo.txt"));
+    /// ```
+    #[inline]
+    fn try_from(s: std::ffi::OsString) -> Result<Self, Self::Error> {
+        s.into_string().map(Utf8PathBuf::from)
     }
+}
 
-    impl<T> AsRef<OsStr> for Utf8PathBuf<T>
-    where
-        T: for<'enc> Utf8Encoding<'enc>,
-    {
-        #[inline]
-        fn as_ref(&self) -> &OsStr {
-            OsStrExt::from_bytes(self.as_str().as_bytes())
-        }
+#[cfg(feature = "std")]
+impl<T> From<Utf8PathBuf<T>> for std::ffi::OsString
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn from(path_buf: Utf8PathBuf<T>) -> Self {
+        std::ffi::OsString::from(path_buf.into_string())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> AsRef<std::ffi::OsStr> for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &std::ffi::OsStr {
+        std::ffi::OsStr::new(self.as_str())
+    }
+}
+
+#[cfg(all(feature = "std", any(unix, windows, target_os = "wasi")))]
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
+impl AsRef<std::path::Path> for Utf8PathBuf<crate::Utf8NativeEncoding> {
+    /// Views a [`Utf8NativePathBuf`](crate::Utf8NativePathBuf) as a [`std::path::Path`],
+    /// allowing it to be passed directly to functions like [`File::open`](std::fs::File::open).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8NativePathBuf;
+    ///
+    /// let path = Utf8NativePathBuf::from("some_file.txt");
+    /// let std_path: &std::path::Path = path.as_ref();
+    /// assert_eq!(std_path, std::path::Path::new("some_file.txt"));
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &std::path::Path {
+        self.as_path().as_std_path()
     }
 }
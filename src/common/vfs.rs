@@ -0,0 +1,344 @@
+use crate::common::{Component, Encoding, Path, PathBuf, RealpathError};
+use crate::no_std_compat::*;
+
+/// Maximum number of symlinks that [`Path::realpath_with`] follows while resolving a single path
+/// before failing with [`RealpathError::TooManyLinks`], matching the limit used by Linux.
+pub const MAX_SYMLINKS: usize = 40;
+
+/// The kind of entry found at a path in a [`Vfs`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VfsFileType {
+    /// A regular file.
+    File,
+
+    /// A directory, which may contain other entries.
+    Dir,
+
+    /// A symbolic link, whose target is read with [`Vfs::read_link`].
+    Symlink,
+
+    /// Anything else, such as a device or a socket.
+    Other,
+}
+
+/// A minimal, read-only view of a filesystem, which is all that [`Path::realpath_with`] needs to
+/// resolve symlinks.
+///
+/// Implement this for archives, overlays, or remote filesystems to get the same canonicalization
+/// as [`std::fs::canonicalize`] without going through the operating system.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use typed_path::{UnixEncoding, UnixPath, UnixPathBuf, Vfs, VfsFileType};
+///
+/// /// Filesystem where every path is a directory, except for the symlinks in the map
+/// struct Links(BTreeMap<UnixPathBuf, UnixPathBuf>);
+///
+/// impl Vfs<UnixEncoding> for Links {
+///     type Error = ();
+///
+///     fn file_type(&self, path: &UnixPath) -> Result<VfsFileType, ()> {
+///         Ok(if self.0.contains_key(path) { VfsFileType::Symlink } else { VfsFileType::Dir })
+///     }
+///
+///     fn read_link(&self, path: &UnixPath) -> Result<UnixPathBuf, ()> {
+///         self.0.get(path).cloned().ok_or(())
+///     }
+/// }
+///
+/// let mut links = BTreeMap::new();
+/// links.insert(UnixPathBuf::from("/usr/lib64"), UnixPathBuf::from("lib"));
+/// let vfs = Links(links);
+///
+/// let path = UnixPath::new("/usr/lib64/../bin").realpath_with(&vfs).unwrap();
+/// assert_eq!(path, UnixPath::new("/usr/bin"));
+/// ```
+pub trait Vfs<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// The error returned when a path cannot be looked up, such as when it does not exist.
+    type Error;
+
+    /// Returns the kind of entry at `path`, which is always absolute.
+    ///
+    /// This must not follow a symlink at `path` itself, like [`std::fs::symlink_metadata`], but
+    /// may assume that none of its parents are symlinks.
+    fn file_type(&self, path: &Path<T>) -> Result<VfsFileType, Self::Error>;
+
+    /// Returns the target of the symlink at `path`, which is always absolute, as it was written.
+    fn read_link(&self, path: &Path<T>) -> Result<PathBuf<T>, Self::Error>;
+}
+
+impl<T> Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Returns the canonical form of the path as seen through `vfs`, with every symlink resolved
+    /// and every `.` and `..` removed, like [`std::fs::canonicalize`] or `realpath`.
+    ///
+    /// Components are resolved from left to right, so a `..` following a symlink goes to the
+    /// parent of the symlink's target rather than of the symlink itself. A `..` at the root stays
+    /// at the root.
+    ///
+    /// # Errors
+    ///
+    /// * [`RealpathError::NotAbsolute`] if the path, or the target of a symlink, is not absolute
+    ///   and yet has a root or prefix, such as `\dir` on Windows. A symlink target that is simply
+    ///   relative is resolved against the symlink's directory.
+    /// * [`RealpathError::NotADirectory`] if a path continues past a file.
+    /// * [`RealpathError::TooManyLinks`] if more than [`MAX_SYMLINKS`] symlinks are followed,
+    ///   which happens when symlinks form a loop.
+    /// * [`RealpathError::Vfs`] if `vfs` fails to look up any component, such as one that does not
+    ///   exist.
+    ///
+    /// # Examples
+    ///
+    /// See [`Vfs`] for a complete example.
+    ///
+    /// ```
+    /// use typed_path::{RealpathError, UnixEncoding, UnixPath, UnixPathBuf, Vfs, VfsFileType};
+    ///
+    /// /// Filesystem where `/loop` is a symlink to itself
+    /// struct Loop;
+    ///
+    /// impl Vfs<UnixEncoding> for Loop {
+    ///     type Error = ();
+    ///
+    ///     fn file_type(&self, _path: &UnixPath) -> Result<VfsFileType, ()> {
+    ///         Ok(VfsFileType::Symlink)
+    ///     }
+    ///
+    ///     fn read_link(&self, _path: &UnixPath) -> Result<UnixPathBuf, ()> {
+    ///         Ok(UnixPathBuf::from("/loop"))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(UnixPath::new("/loop").realpath_with(&Loop), Err(RealpathError::TooManyLinks));
+    /// assert_eq!(UnixPath::new("loop").realpath_with(&Loop), Err(RealpathError::NotAbsolute));
+    /// ```
+    pub fn realpath_with<V: Vfs<T>>(&self, vfs: &V) -> Result<PathBuf<T>, RealpathError<V::Error>> {
+        if !self.is_absolute() {
+            return Err(RealpathError::NotAbsolute);
+        }
+
+        let mut resolved = PathBuf::new();
+        let mut pending = Vec::new();
+        enqueue(self, &mut resolved, &mut pending);
+
+        let mut links = 0;
+        while let Some(next) = pending.pop() {
+            let name = match next {
+                Pending::Parent => {
+                    resolved.pop();
+                    continue;
+                }
+                Pending::Normal(name) => name,
+            };
+
+            resolved.push(name);
+            match vfs.file_type(&resolved).map_err(RealpathError::Vfs)? {
+                VfsFileType::Dir => {}
+                VfsFileType::Symlink => {
+                    links += 1;
+                    if links > MAX_SYMLINKS {
+                        return Err(RealpathError::TooManyLinks);
+                    }
+
+                    let target = vfs.read_link(&resolved).map_err(RealpathError::Vfs)?;
+                    let has_root = target
+                        .components()
+                        .next()
+                        .map_or(false, |c| is_root_part(&c));
+                    if has_root && !target.is_absolute() {
+                        return Err(RealpathError::NotAbsolute);
+                    }
+
+                    // A relative target is resolved against the directory holding the symlink
+                    resolved.pop();
+                    enqueue(&target, &mut resolved, &mut pending);
+                }
+                VfsFileType::File | VfsFileType::Other => {
+                    if !pending.is_empty() {
+                        return Err(RealpathError::NotADirectory);
+                    }
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+}
+
+/// Component of a path that is still waiting to be resolved.
+enum Pending {
+    Parent,
+    Normal(Vec<u8>),
+}
+
+/// Queues the components of `path` so that they are resolved next, in order, first replacing
+/// `resolved` with the root of `path` if it has one.
+fn enqueue<T>(path: &Path<T>, resolved: &mut PathBuf<T>, pending: &mut Vec<Pending>)
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let mut root = PathBuf::new();
+    let mut components = Vec::new();
+    for component in path.components() {
+        if is_root_part(&component) {
+            root.push(component.as_bytes());
+        } else if component.is_parent() {
+            components.push(Pending::Parent);
+        } else if component.is_normal() {
+            components.push(Pending::Normal(component.as_bytes().to_vec()));
+        }
+    }
+
+    if !root.is_empty() {
+        *resolved = root;
+    }
+
+    // Pending components are popped from the end, so the first must be pushed last
+    pending.extend(components.into_iter().rev());
+}
+
+/// Returns true if `component` is a root or any prefix, including a Windows disk prefix, which
+/// [`Component::is_root`] does not count on its own.
+fn is_root_part<'a, C: Component<'a>>(component: &C) -> bool {
+    !component.is_normal() && !component.is_parent() && !component.is_current()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    use super::*;
+    use crate::{
+        UnixEncoding, UnixPath, UnixPathBuf, WindowsEncoding, WindowsPath, WindowsPathBuf,
+    };
+
+    /// In-memory filesystem keyed by absolute path, where paths that are not listed do not exist
+    struct MemoryVfs<T>(BTreeMap<PathBuf<T>, Entry<T>>)
+    where
+        T: for<'enc> Encoding<'enc>;
+
+    enum Entry<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        File,
+        Dir,
+        Symlink(PathBuf<T>),
+    }
+
+    impl<T> Vfs<T> for MemoryVfs<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        type Error = PathBuf<T>;
+
+        fn file_type(&self, path: &Path<T>) -> Result<VfsFileType, Self::Error> {
+            match self.0.get(path) {
+                Some(Entry::File) => Ok(VfsFileType::File),
+                Some(Entry::Dir) => Ok(VfsFileType::Dir),
+                Some(Entry::Symlink(_)) => Ok(VfsFileType::Symlink),
+                None => Err(path.to_path_buf()),
+            }
+        }
+
+        fn read_link(&self, path: &Path<T>) -> Result<PathBuf<T>, Self::Error> {
+            match self.0.get(path) {
+                Some(Entry::Symlink(target)) => Ok(target.clone()),
+                _ => Err(path.to_path_buf()),
+            }
+        }
+    }
+
+    fn unix_vfs() -> MemoryVfs<UnixEncoding> {
+        let mut entries = BTreeMap::new();
+        for (path, entry) in [
+            ("/a", Entry::Dir),
+            ("/a/b", Entry::Dir),
+            ("/a/b/file", Entry::File),
+            ("/a/up", Entry::Symlink(UnixPathBuf::from(".."))),
+            ("/a/b/abs", Entry::Symlink(UnixPathBuf::from("/a/b/file"))),
+            ("/a/chain", Entry::Symlink(UnixPathBuf::from("up/a/b/abs"))),
+            ("/ping", Entry::Symlink(UnixPathBuf::from("pong"))),
+            ("/pong", Entry::Symlink(UnixPathBuf::from("/ping"))),
+        ] {
+            entries.insert(UnixPathBuf::from(path), entry);
+        }
+        MemoryVfs(entries)
+    }
+
+    #[test]
+    fn realpath_with_should_follow_symlinks_in_order() {
+        let vfs = unix_vfs();
+        let realpath = |path: &str| UnixPath::new(path).realpath_with(&vfs);
+
+        assert_eq!(realpath("/").unwrap(), UnixPath::new("/"));
+        assert_eq!(realpath("/../a/./b").unwrap(), UnixPath::new("/a/b"));
+        assert_eq!(realpath("/a/up").unwrap(), UnixPath::new("/"));
+        assert_eq!(
+            realpath("/a/up/a/b/abs").unwrap(),
+            UnixPath::new("/a/b/file")
+        );
+        assert_eq!(realpath("/a/chain").unwrap(), UnixPath::new("/a/b/file"));
+
+        // `..` applies to where the symlink led rather than to the symlink itself
+        assert_eq!(
+            realpath("/a/b/abs/..").unwrap_err(),
+            RealpathError::NotADirectory
+        );
+        assert_eq!(
+            realpath("/a/chain/../..").unwrap_err(),
+            RealpathError::NotADirectory
+        );
+        assert_eq!(realpath("/a/up/a/b/..").unwrap(), UnixPath::new("/a"));
+    }
+
+    #[test]
+    fn realpath_with_should_fail_on_loops_and_missing_entries() {
+        let vfs = unix_vfs();
+        let realpath = |path: &str| UnixPath::new(path).realpath_with(&vfs);
+
+        assert_eq!(realpath("/ping").unwrap_err(), RealpathError::TooManyLinks);
+        assert_eq!(
+            realpath("/a/b/file/x").unwrap_err(),
+            RealpathError::NotADirectory
+        );
+        assert_eq!(
+            realpath("/a/missing/..").unwrap_err(),
+            RealpathError::Vfs(UnixPathBuf::from("/a/missing"))
+        );
+    }
+
+    #[test]
+    fn realpath_with_should_keep_windows_prefixes() {
+        let mut entries = BTreeMap::new();
+        for (path, entry) in [
+            (r"C:\Users", Entry::Dir),
+            (
+                r"C:\Users\All Users",
+                Entry::Symlink(WindowsPathBuf::from(r"D:\Shared")),
+            ),
+            (r"D:\Shared", Entry::Dir),
+            (r"D:\Bad", Entry::Symlink(WindowsPathBuf::from(r"\Shared"))),
+        ] {
+            entries.insert(WindowsPathBuf::from(path), entry);
+        }
+        let vfs = MemoryVfs::<WindowsEncoding>(entries);
+        let realpath = |path: &str| WindowsPath::new(path).realpath_with(&vfs);
+
+        assert_eq!(
+            realpath(r"C:\Users\All Users\..\Shared")
+                .unwrap()
+                .as_bytes(),
+            br"D:\Shared"
+        );
+        assert_eq!(realpath(r"C:\..\..").unwrap().as_bytes(), br"C:\");
+        assert_eq!(realpath(r"D:\Bad").unwrap_err(), RealpathError::NotAbsolute);
+    }
+}
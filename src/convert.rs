@@ -0,0 +1,185 @@
+//! Utilities for moving paths from one base directory to another.
+
+use core::iter::{Enumerate, FusedIterator};
+
+use crate::common::{Encoding, Path, PathBuf, RebaseError, StripPrefixError};
+#[cfg(feature = "std")]
+use crate::TypedPathBuf;
+
+/// Moves `path` from under `from` to under `to`, as if by calling [`strip_prefix`] and then
+/// [`join`], allocating only the returned path.
+///
+/// Like [`strip_prefix`], this compares whole components, so `/srv/app` is not under `/srv/ap`.
+///
+/// [`strip_prefix`]: Path::strip_prefix
+/// [`join`]: Path::join
+///
+/// # Errors
+///
+/// Returns [`StripPrefixError`] if `path` does not start with `from`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::convert::rebase;
+/// use typed_path::UnixPath;
+///
+/// let path = rebase(UnixPath::new("/srv/old/data/a.bin"), "/srv/old", "/mnt/new").unwrap();
+/// assert_eq!(path.as_bytes(), b"/mnt/new/data/a.bin");
+///
+/// let path = rebase(UnixPath::new("/srv/old"), "/srv/old", "/mnt/new").unwrap();
+/// assert_eq!(path.as_bytes(), b"/mnt/new");
+///
+/// assert!(rebase(UnixPath::new("/srv/older/a.bin"), "/srv/old", "/mnt/new").is_err());
+/// ```
+pub fn rebase<T, P, F, B>(path: &P, from: F, to: B) -> Result<PathBuf<T>, StripPrefixError>
+where
+    T: for<'enc> Encoding<'enc>,
+    P: AsRef<Path<T>> + ?Sized,
+    F: AsRef<Path<T>>,
+    B: AsRef<Path<T>>,
+{
+    rebase_path(path.as_ref(), from.as_ref(), to.as_ref())
+}
+
+fn rebase_path<T>(
+    path: &Path<T>,
+    from: &Path<T>,
+    to: &Path<T>,
+) -> Result<PathBuf<T>, StripPrefixError>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let rest = path.strip_prefix(from)?;
+
+    // Reserve room for a separator between the two halves so that pushing does not reallocate
+    let mut rebased = PathBuf::with_capacity(to.as_bytes().len() + 1 + rest.as_bytes().len());
+    rebased.push(to);
+    rebased.push(rest);
+    Ok(rebased)
+}
+
+/// Reconstructs a [`TypedPathBuf`] from the [`std::path::Component`]s of a path, such as those
+/// yielded by [`std::path::Path::components`].
+///
+/// The components are joined the way [`std::path::PathBuf`] joins them, so Windows prefixes keep
+/// their kind, e.g. `\\?\C:` stays verbatim and `\\server\share` stays UNC, before the result is
+/// converted like [`TypedPathBuf::from_std_path`]: as a Windows path when compiling for Windows and
+/// as a Unix path everywhere else.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use typed_path::convert::from_std_components;
+///
+/// let std_path = Path::new("assets").join("images").join("logo.png");
+/// let path = from_std_components(std_path.components());
+/// assert_eq!(path.is_windows(), cfg!(windows));
+/// assert_eq!(path.as_bytes(), std_path.to_str().unwrap().as_bytes());
+///
+/// // Components that were already filtered or rearranged are joined as given
+/// let path = from_std_components(std_path.components().skip(1));
+/// assert_eq!(path.file_name(), Some(b"logo.png".as_slice()));
+/// assert_eq!(path.components().count(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn from_std_components<'a, I>(components: I) -> TypedPathBuf
+where
+    I: IntoIterator<Item = std::path::Component<'a>>,
+{
+    let path: std::path::PathBuf = components.into_iter().collect();
+    TypedPathBuf::from_std_path(path)
+}
+
+/// Returns an iterator that [`rebase`]s each of `paths` from under `from` to under `to`.
+///
+/// Each path is rebased independently, so a path that is not under `from` produces a
+/// [`RebaseError`] carrying its index without stopping the iteration. Collecting into a
+/// `Result` stops at the first failure.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::convert::rebase_all;
+/// use typed_path::{UnixPath, UnixPathBuf};
+///
+/// let manifest = ["/srv/old/a.bin", "/srv/old/nested/b.bin"];
+/// let from = UnixPath::new("/srv/old");
+/// let to = UnixPath::new("/mnt/new");
+///
+/// let rebased: Vec<UnixPathBuf> = rebase_all(manifest, from, to)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(rebased[0].as_bytes(), b"/mnt/new/a.bin");
+/// assert_eq!(rebased[1].as_bytes(), b"/mnt/new/nested/b.bin");
+///
+/// let manifest = ["/srv/old/a.bin", "/etc/passwd"];
+/// let error = rebase_all(manifest, from, to)
+///     .collect::<Result<Vec<UnixPathBuf>, _>>()
+///     .unwrap_err();
+/// assert_eq!(error.index(), 1);
+/// ```
+pub fn rebase_all<'a, T, I>(
+    paths: I,
+    from: &'a Path<T>,
+    to: &'a Path<T>,
+) -> RebaseAll<'a, T, I::IntoIter>
+where
+    T: for<'enc> Encoding<'enc>,
+    I: IntoIterator,
+    I::Item: AsRef<Path<T>>,
+{
+    RebaseAll {
+        paths: paths.into_iter().enumerate(),
+        from,
+        to,
+    }
+}
+
+/// An iterator that moves each path of a collection from one base path to another.
+///
+/// This `struct` is created by [`rebase_all`]. See its documentation for more.
+#[derive(Clone)]
+pub struct RebaseAll<'a, T, I>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    paths: Enumerate<I>,
+    from: &'a Path<T>,
+    to: &'a Path<T>,
+}
+
+impl<T, I> Iterator for RebaseAll<'_, T, I>
+where
+    T: for<'enc> Encoding<'enc>,
+    I: Iterator,
+    I::Item: AsRef<Path<T>>,
+{
+    type Item = Result<PathBuf<T>, RebaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, path) = self.paths.next()?;
+        Some(rebase_path(path.as_ref(), self.from, self.to).map_err(|_| RebaseError { index }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.paths.size_hint()
+    }
+}
+
+impl<T, I> ExactSizeIterator for RebaseAll<'_, T, I>
+where
+    T: for<'enc> Encoding<'enc>,
+    I: ExactSizeIterator,
+    I::Item: AsRef<Path<T>>,
+{
+}
+
+impl<T, I> FusedIterator for RebaseAll<'_, T, I>
+where
+    T: for<'enc> Encoding<'enc>,
+    I: FusedIterator,
+    I::Item: AsRef<Path<T>>,
+{
+}
@@ -0,0 +1,257 @@
+mod components;
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::Hasher;
+
+pub use components::*;
+
+use crate::common::{replace_tail, CheckedPathError, PushBuffer, RootKind};
+use crate::no_std_compat::*;
+use crate::{private, Component, Components, Encoding, Path, PathBuf};
+
+/// Represents a [`Path`] whose components are separated by `SEP`
+pub type DelimitedPath<const SEP: u8> = Path<DelimitedEncoding<SEP>>;
+
+/// Represents a [`PathBuf`] whose components are separated by `SEP`
+pub type DelimitedPathBuf<const SEP: u8> = PathBuf<DelimitedEncoding<SEP>>;
+
+/// Represents an [`Encoding`] for paths outside of any operating system, such as `:`-separated
+/// key paths or `.`-separated configuration keys, whose components are separated by `SEP`.
+///
+/// Paths are parsed much like Unix paths with a different separator:
+///
+/// * A leading `SEP` is a root, making the path absolute.
+/// * Empty components, as produced by repeated or trailing separators, are skipped.
+/// * `.` and `..` are the current and parent components wherever they appear, which can only
+///   happen when `SEP` is not itself `.`.
+///
+/// There are no prefixes, and a normal component is only invalid if it contains `SEP`.
+///
+/// `SEP` must be an ASCII byte, which is checked when the encoding is used.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::DelimitedPath;
+///
+/// let key = DelimitedPath::<b'.'>::new("server.http.port");
+/// assert_eq!(key.parent(), Some(DelimitedPath::new("server.http")));
+/// assert_eq!(key.file_name(), Some(b"port".as_slice()));
+/// assert!(key.starts_with("server"));
+/// assert!(!key.starts_with("serv"));
+///
+/// let path = DelimitedPath::<b':'>::new("app:cache").join("users:42");
+/// assert_eq!(path.as_bytes(), b"app:cache:users:42");
+/// assert_eq!(path.components().count(), 4);
+/// ```
+#[derive(Copy, Clone)]
+pub struct DelimitedEncoding<const SEP: u8>;
+
+impl<const SEP: u8> DelimitedEncoding<SEP> {
+    const ASSERT_ASCII: () = assert!(SEP.is_ascii(), "separator must be an ASCII byte");
+}
+
+impl<const SEP: u8> private::Sealed for DelimitedEncoding<SEP> {}
+
+impl<'a, const SEP: u8> Encoding<'a> for DelimitedEncoding<SEP> {
+    type Components = DelimitedComponents<'a, SEP>;
+
+    fn label() -> &'static str {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_ASCII;
+        core::str::from_utf8(separator_bytes::<SEP>()).expect("separator is ASCII")
+    }
+
+    fn components(path: &'a [u8]) -> Self::Components {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_ASCII;
+        DelimitedComponents::new(path)
+    }
+
+    fn hash<H: Hasher>(path: &[u8], h: &mut H) {
+        for component in Self::components(path) {
+            let bytes = component.as_bytes();
+            h.write_usize(bytes.len());
+            h.write(bytes);
+        }
+    }
+
+    fn cmp(path: &[u8], other: &[u8]) -> Ordering {
+        Self::components(path).cmp(Self::components(other))
+    }
+
+    fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        let pushed = Self::try_push(current_path, path);
+        debug_assert!(pushed, "a Vec can always grow");
+    }
+
+    fn try_push<B: PushBuffer>(current_path: &mut B, path: &[u8]) -> bool {
+        if path.is_empty() {
+            return true;
+        }
+
+        // Absolute path will replace entirely, otherwise add a separator if one is missing
+        let current = current_path.as_bytes();
+        let (len, needs_sep) = (
+            current.len(),
+            !current.is_empty() && current.last() != Some(&SEP),
+        );
+        if Self::components(path).is_absolute() {
+            replace_tail(current_path, 0, None, path)
+        } else if needs_sep {
+            replace_tail(current_path, len, Some(SEP), path)
+        } else {
+            replace_tail(current_path, len, None, path)
+        }
+    }
+
+    fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
+        // Count the normal components that have not been popped off by a parent component, as
+        // reaching a parent component with none remaining would escape the current path
+        let mut normal_cnt = 0;
+        for component in Self::components(path) {
+            match component {
+                DelimitedComponent::RootDir => return Err(CheckedPathError::UnexpectedRoot),
+                DelimitedComponent::ParentDir if normal_cnt == 0 => {
+                    return Err(CheckedPathError::PathTraversalAttack)
+                }
+                DelimitedComponent::ParentDir => normal_cnt -= 1,
+                DelimitedComponent::Normal(_) => normal_cnt += 1,
+                DelimitedComponent::CurDir => continue,
+            }
+        }
+
+        Self::push(current_path, path);
+        Ok(())
+    }
+
+    fn starts_with_fast(_path: &[u8], _base: &[u8]) -> Option<bool> {
+        None
+    }
+
+    fn root_kind(path: &[u8]) -> RootKind {
+        if path.first() == Some(&SEP) {
+            RootKind::Absolute
+        } else {
+            RootKind::Relative
+        }
+    }
+}
+
+impl<const SEP: u8> fmt::Debug for DelimitedEncoding<SEP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelimitedEncoding")
+            .field("separator", &char::from(SEP))
+            .finish()
+    }
+}
+
+impl<const SEP: u8> fmt::Display for DelimitedEncoding<SEP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DelimitedEncoding({:?})", char::from(SEP))
+    }
+}
+
+/// Every possible byte, so that any separator can be borrowed for `'static`
+static BYTES: [u8; 256] = {
+    let mut bytes = [0; 256];
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = i as u8;
+        i += 1;
+    }
+    bytes
+};
+
+/// Returns `SEP` as a single byte slice.
+fn separator_bytes<const SEP: u8>() -> &'static [u8] {
+    let i = SEP as usize;
+    &BYTES[i..i + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type KeyPath = DelimitedPath<b'.'>;
+    type KeyPathBuf = DelimitedPathBuf<b'.'>;
+
+    #[test]
+    fn components_should_skip_empty_segments() {
+        let path = KeyPath::new(".a..b.");
+        let components: Vec<_> = path.components().collect();
+        assert_eq!(
+            components,
+            [
+                DelimitedComponent::RootDir,
+                DelimitedComponent::Normal(b"a"),
+                DelimitedComponent::Normal(b"b"),
+            ]
+        );
+
+        let components: Vec<_> = path.components().rev().collect();
+        assert_eq!(
+            components,
+            [
+                DelimitedComponent::Normal(b"b"),
+                DelimitedComponent::Normal(b"a"),
+                DelimitedComponent::RootDir,
+            ]
+        );
+
+        assert_eq!(path, KeyPath::new(".a.b"));
+        assert_eq!(path.parent(), Some(KeyPath::new(".a")));
+        assert_eq!(KeyPath::new(".a").parent(), Some(KeyPath::new(".")));
+        assert_eq!(KeyPath::new(".").parent(), None);
+    }
+
+    #[test]
+    fn push_should_use_separator() {
+        let mut path = KeyPathBuf::from("a");
+        path.push("b");
+        path.push("c.d");
+        assert_eq!(path.as_bytes(), b"a.b.c.d");
+
+        path.push(".root");
+        assert_eq!(path.as_bytes(), b".root");
+
+        let mut path = DelimitedPathBuf::<b':'>::from("a");
+        assert_eq!(
+            path.push_checked(".."),
+            Err(CheckedPathError::PathTraversalAttack)
+        );
+        assert_eq!(
+            path.push_checked(":b"),
+            Err(CheckedPathError::UnexpectedRoot)
+        );
+        assert_eq!(path.push_checked("b:.."), Ok(()));
+        assert_eq!(path.as_bytes(), b"a:b:..");
+    }
+
+    #[test]
+    fn with_encoding_should_translate_separators() {
+        let path = crate::UnixPath::new("/etc/app/../config");
+        assert_eq!(
+            path.with_encoding::<DelimitedEncoding<b':'>>().as_bytes(),
+            b":etc:app:..:config"
+        );
+    }
+
+    #[test]
+    fn transcode_should_rewrite_every_separator_of_the_source() {
+        use crate::{UnixPath, WindowsPath};
+
+        let path = WindowsPath::new(r"a/b\c").transcode::<DelimitedEncoding<b':'>>();
+        assert_eq!(path.unwrap().as_bytes(), b"a:b:c");
+
+        let path = DelimitedPath::<b':'>::new("a:b").transcode::<crate::UnixEncoding>();
+        assert_eq!(path.unwrap().as_bytes(), b"a/b");
+
+        // A byte that is only a separator in the new encoding would split the component
+        assert_eq!(
+            UnixPath::new("a:b/c").transcode::<DelimitedEncoding<b':'>>(),
+            Err(CheckedPathError::InvalidFilename)
+        );
+    }
+}
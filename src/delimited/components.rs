@@ -0,0 +1,177 @@
+mod component;
+
+use core::{cmp, fmt, iter};
+
+pub use component::*;
+
+use crate::no_std_compat::*;
+use crate::{private, Components, Encoding, Path};
+
+/// Iterator over the [`DelimitedComponent`]s of a path separated by `SEP`
+#[derive(Clone)]
+pub struct DelimitedComponents<'a, const SEP: u8> {
+    /// Portion of the path remaining for iteration
+    path: &'a [u8],
+
+    /// Whether the remaining path begins with a root that has not been yielded yet
+    has_root: bool,
+}
+
+impl<'a, const SEP: u8> DelimitedComponents<'a, SEP> {
+    pub(crate) fn new(path: &'a [u8]) -> Self {
+        Self {
+            path,
+            has_root: path.first() == Some(&SEP),
+        }
+    }
+
+    /// Extracts a slice corresponding to the portion of the path remaining for iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::DelimitedPath;
+    ///
+    /// let mut components = DelimitedPath::<b'.'>::new("a.b.c").components();
+    /// components.next();
+    ///
+    /// assert_eq!(DelimitedPath::<b'.'>::new("b.c"), components.as_path());
+    /// ```
+    pub fn as_path<T>(&self) -> &'a Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.path)
+    }
+
+    /// Returns the remaining path after the root, if any.
+    fn body(&self) -> &'a [u8] {
+        if self.has_root {
+            &self.path[1..]
+        } else {
+            self.path
+        }
+    }
+}
+
+impl<const SEP: u8> private::Sealed for DelimitedComponents<'_, SEP> {}
+
+impl<'a, const SEP: u8> Components<'a> for DelimitedComponents<'a, SEP> {
+    type Component = DelimitedComponent<'a, SEP>;
+
+    fn as_bytes(&self) -> &'a [u8] {
+        self.path
+    }
+
+    fn is_absolute(&self) -> bool {
+        self.has_root
+    }
+
+    fn has_root(&self) -> bool {
+        self.has_root
+    }
+}
+
+impl<const SEP: u8> AsRef<[u8]> for DelimitedComponents<'_, SEP> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T, const SEP: u8> AsRef<Path<T>> for DelimitedComponents<'_, SEP>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<const SEP: u8> fmt::Debug for DelimitedComponents<'_, SEP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DelimitedComponents")
+            .field(&self.clone().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<'a, const SEP: u8> Iterator for DelimitedComponents<'a, SEP> {
+    type Item = DelimitedComponent<'a, SEP>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let trim = |bytes: &'a [u8]| {
+            let start = bytes.iter().position(|b| *b != SEP).unwrap_or(bytes.len());
+            &bytes[start..]
+        };
+
+        if self.has_root {
+            self.has_root = false;
+            self.path = trim(&self.path[1..]);
+            return Some(DelimitedComponent::RootDir);
+        }
+
+        if self.path.is_empty() {
+            return None;
+        }
+
+        let end = self
+            .path
+            .iter()
+            .position(|b| *b == SEP)
+            .unwrap_or(self.path.len());
+        let component = DelimitedComponent::from_segment(&self.path[..end]);
+        self.path = trim(&self.path[end..]);
+        Some(component)
+    }
+}
+
+impl<const SEP: u8> DoubleEndedIterator for DelimitedComponents<'_, SEP> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let root_len = usize::from(self.has_root);
+        let trimmed_len = |bytes: &[u8]| bytes.iter().rposition(|b| *b != SEP).map_or(0, |i| i + 1);
+
+        let body = self.body();
+        let body = &body[..trimmed_len(body)];
+        if body.is_empty() {
+            self.path = &self.path[..0];
+            return if self.has_root {
+                self.has_root = false;
+                Some(DelimitedComponent::RootDir)
+            } else {
+                None
+            };
+        }
+
+        let start = body.iter().rposition(|b| *b == SEP).map_or(0, |i| i + 1);
+        let component = DelimitedComponent::from_segment(&body[start..]);
+        self.path = &self.path[..root_len + trimmed_len(&body[..start])];
+        Some(component)
+    }
+}
+
+impl<const SEP: u8> iter::FusedIterator for DelimitedComponents<'_, SEP> {}
+
+impl<const SEP: u8> cmp::PartialEq for DelimitedComponents<'_, SEP> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.clone().eq(other.clone())
+    }
+}
+
+impl<const SEP: u8> cmp::Eq for DelimitedComponents<'_, SEP> {}
+
+impl<const SEP: u8> cmp::PartialOrd for DelimitedComponents<'_, SEP> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const SEP: u8> cmp::Ord for DelimitedComponents<'_, SEP> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.clone().cmp(other.clone())
+    }
+}
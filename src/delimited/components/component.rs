@@ -0,0 +1,160 @@
+use core::convert::TryFrom;
+
+use crate::delimited::separator_bytes;
+use crate::{private, Component, DelimitedComponents, Encoding, ParseError, Path};
+
+/// Byte slice version of [`std::path::Component`] for a path separated by `SEP`
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DelimitedComponent<'a, const SEP: u8> {
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a [u8]),
+}
+
+impl<'a, const SEP: u8> DelimitedComponent<'a, SEP> {
+    /// Classifies a non-empty segment found between separators
+    pub(crate) fn from_segment(segment: &'a [u8]) -> Self {
+        match segment {
+            b"." => Self::CurDir,
+            b".." => Self::ParentDir,
+            _ => Self::Normal(segment),
+        }
+    }
+
+    /// Returns path representing this specific component
+    pub fn as_path<T>(&self) -> &Path<T>
+    where
+        T: for<'enc> Encoding<'enc>,
+    {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<const SEP: u8> private::Sealed for DelimitedComponent<'_, SEP> {}
+
+impl<'a, const SEP: u8> Component<'a> for DelimitedComponent<'a, SEP> {
+    /// Extracts the underlying [`[u8]`] slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, DelimitedPath};
+    ///
+    /// let path = DelimitedPath::<b':'>::new(":a:..:b");
+    /// let components: Vec<_> = path.components().map(|comp| comp.as_bytes()).collect();
+    /// assert_eq!(&components, &[
+    ///     b":".as_slice(),
+    ///     b"a".as_slice(),
+    ///     b"..".as_slice(),
+    ///     b"b".as_slice(),
+    /// ]);
+    /// ```
+    fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::RootDir => separator_bytes::<SEP>(),
+            Self::CurDir => b".",
+            Self::ParentDir => b"..",
+            Self::Normal(bytes) => bytes,
+        }
+    }
+
+    fn is_root(&self) -> bool {
+        matches!(self, Self::RootDir)
+    }
+
+    fn is_normal(&self) -> bool {
+        matches!(self, Self::Normal(_))
+    }
+
+    fn is_parent(&self) -> bool {
+        matches!(self, Self::ParentDir)
+    }
+
+    fn is_current(&self) -> bool {
+        matches!(self, Self::CurDir)
+    }
+
+    /// Returns true if this component is valid, meaning that it is not a normal component
+    /// containing `SEP`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Component, DelimitedComponent};
+    ///
+    /// assert!(DelimitedComponent::<b'.'>::Normal(b"port").is_valid());
+    /// assert!(!DelimitedComponent::<b'.'>::Normal(b"a.b").is_valid());
+    /// ```
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::RootDir | Self::ParentDir | Self::CurDir => true,
+            Self::Normal(bytes) => !bytes.contains(&SEP),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    fn root() -> Self {
+        Self::RootDir
+    }
+
+    fn parent() -> Self {
+        Self::ParentDir
+    }
+
+    fn current() -> Self {
+        Self::CurDir
+    }
+}
+
+impl<const SEP: u8> AsRef<[u8]> for DelimitedComponent<'_, SEP> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T, const SEP: u8> AsRef<Path<T>> for DelimitedComponent<'_, SEP>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    #[inline]
+    fn as_ref(&self) -> &Path<T> {
+        Path::new(self.as_bytes())
+    }
+}
+
+impl<'a, const SEP: u8> TryFrom<&'a [u8]> for DelimitedComponent<'a, SEP> {
+    type Error = ParseError;
+
+    /// Parses the byte slice into a [`DelimitedComponent`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::DelimitedComponent;
+    /// use std::convert::TryFrom;
+    ///
+    /// type KeyComponent<'a> = DelimitedComponent<'a, b':'>;
+    ///
+    /// assert_eq!(KeyComponent::try_from(b":".as_slice()), Ok(KeyComponent::RootDir));
+    /// assert_eq!(
+    ///     KeyComponent::try_from(b"users:".as_slice()),
+    ///     Ok(KeyComponent::Normal(b"users"))
+    /// );
+    /// assert!(KeyComponent::try_from(b"users:42".as_slice()).is_err());
+    /// ```
+    fn try_from(path: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut components = DelimitedComponents::<SEP>::new(path);
+
+        let component = components.next().ok_or("no component found")?;
+        if components.next().is_some() {
+            return Err("found more than one component");
+        }
+
+        Ok(component)
+    }
+}
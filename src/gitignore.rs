@@ -0,0 +1,1070 @@
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::no_std_compat::*;
+use crate::{Component, Encoding, Path, WindowsEncoding};
+
+/// Result of matching a path against a [`GitignoreMatcher`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GitignoreMatch {
+    /// No pattern matched the path.
+    None,
+
+    /// The last matching pattern ignores the path.
+    Ignore,
+
+    /// The last matching pattern was negated with `!`, re-including the path.
+    Whitelist,
+}
+
+impl GitignoreMatch {
+    /// Returns true if this is [`GitignoreMatch::Ignore`].
+    pub fn is_ignore(self) -> bool {
+        matches!(self, Self::Ignore)
+    }
+}
+
+/// Single pattern written in [`.gitignore`] syntax, parsed once so that it can be reused.
+///
+/// [`.gitignore`]: https://git-scm.com/docs/gitignore
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{Path, Pattern, UnixEncoding};
+///
+/// let patterns = [Pattern::new("*.log").unwrap(), Pattern::new("!keep.log").unwrap()];
+/// assert!(patterns[1].is_negated());
+/// assert!(Pattern::new("# comment").is_none());
+///
+/// let path = Path::<UnixEncoding>::new("logs/today.log");
+/// assert!(path.matches_any(&patterns, false));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    rule: Rule,
+}
+
+impl Pattern {
+    /// Parses a single pattern line, returning `None` for blank lines and comments.
+    pub fn new<S: AsRef<[u8]> + ?Sized>(line: &S) -> Option<Self> {
+        Rule::parse(line.as_ref()).map(|rule| Self { rule })
+    }
+
+    /// Returns true if the pattern was negated with `!`, re-including the paths it matches.
+    pub fn is_negated(&self) -> bool {
+        self.rule.negated
+    }
+}
+
+/// Matches paths against a set of patterns written in [`.gitignore`] syntax.
+///
+/// Paths are split into components using the encoding `T`, so a matcher over
+/// [`WindowsEncoding`] understands both `\` and `/` in the paths it is given, while the patterns
+/// themselves always use `/` as in any `.gitignore` file. Matching is ASCII case-insensitive by
+/// default for [`WindowsEncoding`] and case-sensitive otherwise, which can be changed with
+/// [`GitignoreMatcher::case_insensitive`].
+///
+/// Paths are interpreted relative to the directory containing the patterns. Root and prefix
+/// components are skipped, and a path containing `..` never matches.
+///
+/// [`.gitignore`]: https://git-scm.com/docs/gitignore
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{GitignoreMatcher, UnixEncoding, WindowsEncoding};
+///
+/// let patterns = "
+/// # build output
+/// /target/
+/// *.log
+/// !keep.log
+/// ";
+///
+/// let matcher = GitignoreMatcher::<UnixEncoding>::new(patterns);
+/// assert!(matcher.is_ignored("target/debug/app", false));
+/// assert!(matcher.is_ignored("logs/today.log", false));
+/// assert!(!matcher.is_ignored("logs/keep.log", false));
+/// assert!(!matcher.is_ignored("src/target", true));
+///
+/// // Windows paths are split on either separator and compared case-insensitively
+/// let matcher = GitignoreMatcher::<WindowsEncoding>::new(patterns);
+/// assert!(matcher.is_ignored(r"Target\Debug\app.exe", false));
+/// assert!(matcher.is_ignored(r"logs\TODAY.LOG", false));
+/// ```
+pub struct GitignoreMatcher<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Encoding associated with the matcher
+    _encoding: PhantomData<T>,
+
+    /// Rules in the order they were added, where later rules take precedence
+    rules: Vec<Rule>,
+
+    /// Whether names are compared ignoring ASCII case
+    case_insensitive: bool,
+}
+
+impl<T> GitignoreMatcher<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a matcher from the contents of a `.gitignore` file, one pattern per line.
+    ///
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn new<S: AsRef<[u8]> + ?Sized>(patterns: &S) -> Self {
+        let mut matcher = Self {
+            _encoding: PhantomData,
+            rules: Vec::new(),
+            case_insensitive: default_case_insensitive::<T>(),
+        };
+
+        for line in patterns.as_ref().split(|b| *b == b'\n') {
+            matcher.add_line(line);
+        }
+
+        matcher
+    }
+
+    /// Adds a single pattern line, which takes precedence over all previously added lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{GitignoreMatcher, UnixEncoding};
+    ///
+    /// let mut matcher = GitignoreMatcher::<UnixEncoding>::new("");
+    /// assert!(!matcher.is_ignored("a.tmp", false));
+    ///
+    /// matcher.add_line("*.tmp");
+    /// assert!(matcher.is_ignored("a.tmp", false));
+    /// ```
+    pub fn add_line<S: AsRef<[u8]> + ?Sized>(&mut self, line: &S) {
+        if let Some(pattern) = Pattern::new(line) {
+            self.add_pattern(pattern);
+        }
+    }
+
+    /// Adds an already parsed pattern, which takes precedence over all previously added lines.
+    pub fn add_pattern(&mut self, pattern: Pattern) {
+        self.rules.push(pattern.rule);
+    }
+
+    /// Sets whether names are compared ignoring ASCII case, overriding the default for the
+    /// encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{GitignoreMatcher, UnixEncoding};
+    ///
+    /// let matcher = GitignoreMatcher::<UnixEncoding>::new("*.log");
+    /// assert!(!matcher.is_ignored("APP.LOG", false));
+    ///
+    /// let matcher = matcher.case_insensitive(true);
+    /// assert!(matcher.is_ignored("APP.LOG", false));
+    /// ```
+    pub fn case_insensitive(mut self, yes: bool) -> Self {
+        self.case_insensitive = yes;
+        self
+    }
+
+    /// Returns the number of patterns in the matcher, not counting blank lines or comments.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if the matcher has no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Matches `path`, which refers to a directory if `is_dir` is true, against the patterns.
+    ///
+    /// As with git, a path inside an ignored directory is ignored even if a later pattern would
+    /// re-include it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{GitignoreMatch, GitignoreMatcher, UnixEncoding};
+    ///
+    /// let matcher = GitignoreMatcher::<UnixEncoding>::new("build/\n*.o\n!main.o");
+    /// assert_eq!(matcher.matched("src/lib.rs", false), GitignoreMatch::None);
+    /// assert_eq!(matcher.matched("src/lib.o", false), GitignoreMatch::Ignore);
+    /// assert_eq!(matcher.matched("src/main.o", false), GitignoreMatch::Whitelist);
+    ///
+    /// // `build/` only matches directories, but everything inside one is ignored
+    /// assert_eq!(matcher.matched("build", false), GitignoreMatch::None);
+    /// assert_eq!(matcher.matched("build/main.o", false), GitignoreMatch::Ignore);
+    /// ```
+    pub fn matched<P: AsRef<Path<T>>>(&self, path: P, is_dir: bool) -> GitignoreMatch {
+        matched_path(path.as_ref(), is_dir, |names, is_dir| {
+            self.matched_names(names, is_dir)
+        })
+    }
+
+    /// Returns true if `path`, which refers to a directory if `is_dir` is true, is ignored.
+    ///
+    /// See [`GitignoreMatcher::matched`] for more details.
+    pub fn is_ignored<P: AsRef<Path<T>>>(&self, path: P, is_dir: bool) -> bool {
+        self.matched(path, is_dir).is_ignore()
+    }
+
+    fn matched_names(&self, names: &[&[u8]], is_dir: bool) -> GitignoreMatch {
+        matched_rules(self.rules.iter(), names, is_dir, self.case_insensitive)
+    }
+}
+
+impl<T> Clone for GitignoreMatcher<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _encoding: PhantomData,
+            rules: self.rules.clone(),
+            case_insensitive: self.case_insensitive,
+        }
+    }
+}
+
+impl<T> fmt::Debug for GitignoreMatcher<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GitignoreMatcher")
+            .field("encoding", &T::label())
+            .field("rules", &self.rules.len())
+            .field("case_insensitive", &self.case_insensitive)
+            .finish()
+    }
+}
+
+/// Pre-compiled set of [`.gitignore`] patterns for matching many paths.
+///
+/// Where [`GitignoreMatcher`] tries every pattern against each path, a `PatternSet` indexes
+/// patterns by the literal file name or extension they require, such as `Cargo.lock` or
+/// `*.log`, and only tries those whose literal fits the path along with any patterns that could
+/// not be indexed. Paths match exactly as they would with the [`GitignoreMatcher`] the set was
+/// built from, including the precedence of later patterns and ASCII case-insensitivity.
+///
+/// [`.gitignore`]: https://git-scm.com/docs/gitignore
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{GitignoreMatch, GitignoreMatcher, PatternSet, UnixEncoding};
+///
+/// let matcher = GitignoreMatcher::<UnixEncoding>::new("target/\n*.log\n!keep.log\nCargo.lock");
+/// let set = PatternSet::from(matcher);
+/// assert_eq!(set.len(), 4);
+///
+/// assert!(set.is_match("target/debug/app", false));
+/// assert!(set.is_match("logs/today.log", false));
+/// assert!(set.is_match("Cargo.lock", false));
+/// assert_eq!(set.matched("logs/keep.log", false), GitignoreMatch::Whitelist);
+/// assert_eq!(set.matched("src/lib.rs", false), GitignoreMatch::None);
+/// ```
+pub struct PatternSet<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Encoding associated with the set
+    _encoding: PhantomData<T>,
+
+    /// Rules in the order they were added, where later rules take precedence
+    rules: Vec<Rule>,
+
+    /// Whether names are compared ignoring ASCII case
+    case_insensitive: bool,
+
+    /// Indices of rules that only match a file name equal to the key
+    names: BTreeMap<Vec<u8>, Vec<usize>>,
+
+    /// Indices of rules that only match a file name ending with `.` followed by the key
+    extensions: BTreeMap<Vec<u8>, Vec<usize>>,
+
+    /// Indices of rules that have to be tried against every path
+    others: Vec<usize>,
+}
+
+impl<T> PatternSet<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates a set from `patterns`, where later patterns take precedence over earlier ones.
+    ///
+    /// Matching is ASCII case-insensitive for [`WindowsEncoding`] and case-sensitive otherwise,
+    /// as with [`GitignoreMatcher::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Pattern, PatternSet, WindowsEncoding};
+    ///
+    /// let patterns: Vec<Pattern> = ["*.PSD", "Thumbs.db"]
+    ///     .iter()
+    ///     .filter_map(Pattern::new)
+    ///     .collect();
+    ///
+    /// let set = PatternSet::<WindowsEncoding>::new(&patterns);
+    /// assert!(set.is_match(r"art\hero.psd", false));
+    /// assert!(set.is_match(r"art\THUMBS.DB", false));
+    /// assert!(!set.is_match(r"art\hero.png", false));
+    /// ```
+    pub fn new(patterns: &[Pattern]) -> Self {
+        let rules = patterns
+            .iter()
+            .map(|pattern| pattern.rule.clone())
+            .collect();
+        Self::from_rules(rules, default_case_insensitive::<T>())
+    }
+
+    fn from_rules(rules: Vec<Rule>, case_insensitive: bool) -> Self {
+        let mut names = BTreeMap::<_, Vec<usize>>::new();
+        let mut extensions = BTreeMap::<_, Vec<usize>>::new();
+        let mut others = Vec::new();
+
+        for (i, rule) in rules.iter().enumerate() {
+            match rule.literal() {
+                Some(Literal::Name(name)) => names
+                    .entry(fold_case(&name, case_insensitive).into_owned())
+                    .or_default()
+                    .push(i),
+                Some(Literal::Extension(ext)) => extensions
+                    .entry(fold_case(&ext, case_insensitive).into_owned())
+                    .or_default()
+                    .push(i),
+                None => others.push(i),
+            }
+        }
+
+        Self {
+            _encoding: PhantomData,
+            rules,
+            case_insensitive,
+            names,
+            extensions,
+            others,
+        }
+    }
+
+    /// Returns the number of patterns in the set.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if the set has no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Matches `path`, which refers to a directory if `is_dir` is true, against the patterns.
+    ///
+    /// See [`GitignoreMatcher::matched`] for more details.
+    pub fn matched<P: AsRef<Path<T>>>(&self, path: P, is_dir: bool) -> GitignoreMatch {
+        matched_path(path.as_ref(), is_dir, |names, is_dir| {
+            self.matched_names(names, is_dir)
+        })
+    }
+
+    /// Returns true if `path`, which refers to a directory if `is_dir` is true, is ignored by the
+    /// patterns.
+    ///
+    /// See [`GitignoreMatcher::matched`] for more details.
+    pub fn is_match<P: AsRef<Path<T>>>(&self, path: P, is_dir: bool) -> bool {
+        self.matched(path, is_dir).is_ignore()
+    }
+
+    fn matched_names(&self, names: &[&[u8]], is_dir: bool) -> GitignoreMatch {
+        let name = match names.last() {
+            Some(name) => fold_case(name, self.case_insensitive),
+            None => return GitignoreMatch::None,
+        };
+        let ext = name
+            .iter()
+            .rposition(|b| *b == b'.')
+            .map(|i| &name[i + 1..])
+            .filter(|ext| !ext.is_empty());
+
+        // Only the highest index that matches matters, so each list of candidates is tried from
+        // its end and abandoned once it falls below the best match found so far
+        let mut best: Option<usize> = None;
+        let candidates = [
+            self.names.get(&*name),
+            ext.and_then(|ext| self.extensions.get(ext)),
+            Some(&self.others),
+        ];
+        for indices in candidates.iter().flatten() {
+            for &i in indices.iter().rev() {
+                if best.map_or(false, |best| i < best) {
+                    break;
+                }
+
+                if self.rules[i].matches(names, is_dir, self.case_insensitive) {
+                    best = Some(i);
+                    break;
+                }
+            }
+        }
+
+        best.map_or(GitignoreMatch::None, |i| self.rules[i].result())
+    }
+}
+
+impl<T> From<GitignoreMatcher<T>> for PatternSet<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Pre-compiles the patterns of `matcher`, keeping its case sensitivity.
+    fn from(matcher: GitignoreMatcher<T>) -> Self {
+        Self::from_rules(matcher.rules, matcher.case_insensitive)
+    }
+}
+
+impl<T> Clone for PatternSet<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            _encoding: PhantomData,
+            rules: self.rules.clone(),
+            case_insensitive: self.case_insensitive,
+            names: self.names.clone(),
+            extensions: self.extensions.clone(),
+            others: self.others.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for PatternSet<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PatternSet")
+            .field("encoding", &T::label())
+            .field("rules", &self.rules.len())
+            .field("case_insensitive", &self.case_insensitive)
+            .finish()
+    }
+}
+
+/// Returns how `patterns`, read in order, match `path`, which refers to a directory if `is_dir`
+/// is true. Backs [`Path::matches_any`].
+pub(crate) fn matches_any<T>(path: &Path<T>, patterns: &[Pattern], is_dir: bool) -> bool
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    let case_insensitive = default_case_insensitive::<T>();
+    matched_path(path, is_dir, |names, is_dir| {
+        matched_rules(
+            patterns.iter().map(|pattern| &pattern.rule),
+            names,
+            is_dir,
+            case_insensitive,
+        )
+    })
+    .is_ignore()
+}
+
+fn default_case_insensitive<T>() -> bool
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    T::label() == WindowsEncoding::label()
+}
+
+fn fold_case(bytes: &[u8], case_insensitive: bool) -> Cow<'_, [u8]> {
+    if case_insensitive {
+        Cow::Owned(bytes.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(bytes)
+    }
+}
+
+/// Splits `path` into names and matches them with `matched_names`, checking the parent
+/// directories first.
+fn matched_path<T, F>(path: &Path<T>, is_dir: bool, matched_names: F) -> GitignoreMatch
+where
+    T: for<'enc> Encoding<'enc>,
+    F: Fn(&[&[u8]], bool) -> GitignoreMatch,
+{
+    let mut names = Vec::new();
+    for component in path.components() {
+        if component.is_parent() {
+            return GitignoreMatch::None;
+        } else if component.is_normal() {
+            names.push(component.as_bytes());
+        }
+    }
+
+    // A path cannot be re-included once any of its parent directories is ignored
+    for i in 1..names.len() {
+        if matched_names(&names[..i], true).is_ignore() {
+            return GitignoreMatch::Ignore;
+        }
+    }
+
+    matched_names(&names, is_dir)
+}
+
+fn matched_rules<'a, I>(
+    rules: I,
+    names: &[&[u8]],
+    is_dir: bool,
+    case_insensitive: bool,
+) -> GitignoreMatch
+where
+    I: DoubleEndedIterator<Item = &'a Rule>,
+{
+    if names.is_empty() {
+        return GitignoreMatch::None;
+    }
+
+    rules
+        .rev()
+        .find(|rule| rule.matches(names, is_dir, case_insensitive))
+        .map_or(GitignoreMatch::None, Rule::result)
+}
+
+/// Single parsed line of a `.gitignore` file.
+#[derive(Clone, Debug)]
+struct Rule {
+    segments: Vec<Segment>,
+    negated: bool,
+    dir_only: bool,
+}
+
+impl Rule {
+    fn parse(mut line: &[u8]) -> Option<Self> {
+        if let Some(rest) = line.strip_suffix(b"\r") {
+            line = rest;
+        }
+
+        if line.is_empty() || line[0] == b'#' {
+            return None;
+        }
+
+        let negated = line[0] == b'!';
+        if negated {
+            line = &line[1..];
+        }
+
+        // Trailing spaces are dropped unless escaped with a backslash
+        while line.ends_with(b" ") && !line[..line.len() - 1].ends_with(b"\\") {
+            line = &line[..line.len() - 1];
+        }
+
+        let dir_only = line.ends_with(b"/");
+        if dir_only {
+            line = &line[..line.len() - 1];
+        }
+
+        // A separator anywhere but the end anchors the pattern to the top directory
+        let anchored = line.contains(&b'/');
+        if let Some(rest) = line.strip_prefix(b"/") {
+            line = rest;
+        }
+
+        if line.is_empty() {
+            return None;
+        }
+
+        let mut segments = Vec::new();
+        if !anchored {
+            segments.push(Segment::AnyDirs);
+        }
+
+        let count = line.split(|b| *b == b'/').count();
+        for (i, part) in line.split(|b| *b == b'/').enumerate() {
+            if part == b"**" {
+                // A trailing `/**` matches everything inside, but not the directory itself
+                if i + 1 == count {
+                    segments.push(Segment::Glob(vec![Token::Star]));
+                }
+                segments.push(Segment::AnyDirs);
+            } else {
+                segments.push(Segment::Glob(Token::parse(part)));
+            }
+        }
+
+        Some(Self {
+            segments,
+            negated,
+            dir_only,
+        })
+    }
+
+    fn matches(&self, names: &[&[u8]], is_dir: bool, case_insensitive: bool) -> bool {
+        (is_dir || !self.dir_only) && match_segments(&self.segments, names, case_insensitive)
+    }
+
+    fn result(&self) -> GitignoreMatch {
+        if self.negated {
+            GitignoreMatch::Whitelist
+        } else {
+            GitignoreMatch::Ignore
+        }
+    }
+
+    /// Returns the literal that the last name of any matching path must have, if there is one.
+    fn literal(&self) -> Option<Literal> {
+        let tokens = match self.segments.last()? {
+            Segment::Glob(tokens) => tokens,
+            Segment::AnyDirs => return None,
+        };
+
+        let literal = |tokens: &[Token]| {
+            tokens
+                .iter()
+                .map(|token| match token {
+                    Token::Byte(b) => Some(*b),
+                    _ => None,
+                })
+                .collect::<Option<Vec<u8>>>()
+        };
+
+        match tokens.split_first()? {
+            (Token::Star, rest) => {
+                let ext = literal(rest)?;
+                match ext.split_first() {
+                    Some((b'.', ext)) if !ext.is_empty() && !ext.contains(&b'.') => {
+                        Some(Literal::Extension(ext.to_vec()))
+                    }
+                    _ => None,
+                }
+            }
+            _ => literal(tokens).map(Literal::Name),
+        }
+    }
+}
+
+/// Literal required of the last name of a path matched by a [`Rule`].
+enum Literal {
+    /// The name must equal these bytes
+    Name(Vec<u8>),
+
+    /// The name must end with `.` followed by these bytes, which contain no `.`
+    Extension(Vec<u8>),
+}
+
+/// Portion of a pattern between separators.
+#[derive(Clone, Debug)]
+enum Segment {
+    /// `**`, matching zero or more whole components
+    AnyDirs,
+
+    /// Pattern matching exactly one component
+    Glob(Vec<Token>),
+}
+
+#[derive(Clone, Debug)]
+enum Token {
+    Byte(u8),
+    AnyByte,
+    Star,
+    Class {
+        negated: bool,
+        ranges: Vec<(u8, u8)>,
+    },
+}
+
+impl Token {
+    fn parse(pattern: &[u8]) -> Vec<Self> {
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < pattern.len() {
+            match pattern[i] {
+                b'\\' if i + 1 < pattern.len() => {
+                    tokens.push(Self::Byte(pattern[i + 1]));
+                    i += 2;
+                }
+                b'?' => {
+                    tokens.push(Self::AnyByte);
+                    i += 1;
+                }
+                b'*' => {
+                    if !matches!(tokens.last(), Some(Self::Star)) {
+                        tokens.push(Self::Star);
+                    }
+                    i += 1;
+                }
+                b'[' => match Self::parse_class(&pattern[i + 1..]) {
+                    Some((class, len)) => {
+                        tokens.push(class);
+                        i += len + 1;
+                    }
+                    None => {
+                        tokens.push(Self::Byte(b'['));
+                        i += 1;
+                    }
+                },
+                b => {
+                    tokens.push(Self::Byte(b));
+                    i += 1;
+                }
+            }
+        }
+        tokens
+    }
+
+    /// Parses the remainder of a `[...]` class following the opening bracket, returning the
+    /// class and the number of bytes consumed including the closing bracket.
+    fn parse_class(pattern: &[u8]) -> Option<(Self, usize)> {
+        let mut i = 0;
+        let negated = matches!(pattern.first(), Some(b'!' | b'^'));
+        if negated {
+            i += 1;
+        }
+
+        let mut ranges = Vec::new();
+        let start = i;
+        loop {
+            let mut lo = *pattern.get(i)?;
+            if lo == b']' && i > start {
+                return Some((Self::Class { negated, ranges }, i + 1));
+            } else if lo == b'\\' {
+                i += 1;
+                lo = *pattern.get(i)?;
+            }
+            i += 1;
+
+            let mut hi = lo;
+            if pattern.get(i) == Some(&b'-') && !matches!(pattern.get(i + 1), None | Some(b']')) {
+                i += 1;
+                hi = pattern[i];
+                if hi == b'\\' {
+                    i += 1;
+                    hi = *pattern.get(i)?;
+                }
+                i += 1;
+            }
+            ranges.push((lo, hi));
+        }
+    }
+
+    fn matches(&self, b: u8, case_insensitive: bool) -> bool {
+        let eq = |x: u8, y: u8| {
+            if case_insensitive {
+                x.eq_ignore_ascii_case(&y)
+            } else {
+                x == y
+            }
+        };
+
+        match self {
+            Self::Byte(x) => eq(*x, b),
+            Self::AnyByte => true,
+            Self::Star => false,
+            Self::Class { negated, ranges } => {
+                let found = ranges.iter().any(|(lo, hi)| {
+                    (*lo..=*hi).contains(&b)
+                        || (case_insensitive
+                            && ((*lo..=*hi).contains(&b.to_ascii_lowercase())
+                                || (*lo..=*hi).contains(&b.to_ascii_uppercase())))
+                });
+                found != *negated
+            }
+        }
+    }
+}
+
+fn match_segments(segments: &[Segment], names: &[&[u8]], case_insensitive: bool) -> bool {
+    match segments.split_first() {
+        None => names.is_empty(),
+        Some((Segment::AnyDirs, rest)) => {
+            (0..=names.len()).any(|i| match_segments(rest, &names[i..], case_insensitive))
+        }
+        Some((Segment::Glob(tokens), rest)) => match names.split_first() {
+            Some((name, names)) => {
+                match_tokens(tokens, name, case_insensitive)
+                    && match_segments(rest, names, case_insensitive)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_tokens(tokens: &[Token], name: &[u8], case_insensitive: bool) -> bool {
+    let (mut t, mut n) = (0, 0);
+
+    // Position of the most recent `*` and the byte it is currently extended to, used to
+    // backtrack when the tokens after it fail to match
+    let mut star = None;
+
+    while n < name.len() {
+        match tokens.get(t) {
+            Some(Token::Star) => {
+                star = Some((t, n));
+                t += 1;
+                continue;
+            }
+            Some(token) if token.matches(name[n], case_insensitive) => {
+                t += 1;
+                n += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        match star {
+            Some((star_t, star_n)) => {
+                t = star_t + 1;
+                n = star_n + 1;
+                star = Some((star_t, star_n + 1));
+            }
+            None => return false,
+        }
+    }
+
+    tokens[t..].iter().all(|token| matches!(token, Token::Star))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnixEncoding;
+
+    fn ignored(patterns: &str, path: &str, is_dir: bool) -> bool {
+        GitignoreMatcher::<UnixEncoding>::new(patterns).is_ignored(path, is_dir)
+    }
+
+    #[test]
+    fn should_skip_blank_lines_and_comments() {
+        let matcher = GitignoreMatcher::<UnixEncoding>::new("\n# comment\n\r\n   \n\\#hash\n");
+        assert_eq!(matcher.len(), 1);
+        assert!(matcher.is_ignored("#hash", false));
+        assert!(!matcher.is_ignored("# comment", false));
+    }
+
+    #[test]
+    fn should_match_unanchored_patterns_at_any_depth() {
+        assert!(ignored("foo", "foo", false));
+        assert!(ignored("foo", "a/b/foo", false));
+        assert!(ignored("foo", "a/foo/b", false));
+        assert!(!ignored("foo", "foobar", false));
+        assert!(ignored("*.o", "a/b/c.o", false));
+    }
+
+    #[test]
+    fn should_anchor_patterns_containing_a_separator() {
+        assert!(ignored("/foo", "foo", false));
+        assert!(!ignored("/foo", "a/foo", false));
+        assert!(ignored("a/b", "a/b", false));
+        assert!(!ignored("a/b", "x/a/b", false));
+        assert!(ignored("a/*.c", "a/x.c", false));
+        assert!(!ignored("a/*.c", "a/b/x.c", false));
+    }
+
+    #[test]
+    fn should_only_match_directories_with_trailing_separator() {
+        assert!(ignored("out/", "out", true));
+        assert!(!ignored("out/", "out", false));
+        assert!(ignored("out/", "a/out/file", false));
+    }
+
+    #[test]
+    fn should_support_double_star() {
+        assert!(ignored("**/foo", "foo", false));
+        assert!(ignored("**/foo", "a/b/foo", false));
+        assert!(ignored("**/foo/bar", "a/foo/bar", false));
+        assert!(ignored("abc/**", "abc/x", false));
+        assert!(ignored("abc/**", "abc/x/y", false));
+        assert!(!ignored("abc/**", "abc", true));
+        assert!(ignored("a/**/b", "a/b", false));
+        assert!(ignored("a/**/b", "a/x/y/b", false));
+        assert!(!ignored("a/**/b", "x/a/b", false));
+    }
+
+    #[test]
+    fn should_support_wildcards_and_classes() {
+        assert!(ignored("?.txt", "a.txt", false));
+        assert!(!ignored("?.txt", "ab.txt", false));
+        assert!(ignored("*", "anything", false));
+        assert!(ignored("a*b*c", "axxbyyc", false));
+        assert!(!ignored("a*b*c", "axxbyy", false));
+        assert!(ignored("[abc].txt", "b.txt", false));
+        assert!(!ignored("[abc].txt", "d.txt", false));
+        assert!(ignored("[a-c].txt", "c.txt", false));
+        assert!(ignored("[!a-c].txt", "d.txt", false));
+        assert!(!ignored("[^a-c].txt", "a.txt", false));
+        assert!(ignored("[]].txt", "].txt", false));
+        assert!(ignored("[.txt", "[.txt", false));
+        assert!(ignored("\\*.txt", "*.txt", false));
+        assert!(!ignored("\\*.txt", "a.txt", false));
+    }
+
+    #[test]
+    fn should_handle_trailing_spaces() {
+        assert!(ignored("foo   ", "foo", false));
+        assert!(ignored("foo\\ ", "foo ", false));
+        assert!(!ignored("foo\\ ", "foo", false));
+    }
+
+    #[test]
+    fn should_let_later_rules_take_precedence() {
+        assert!(!ignored("*.log\n!keep.log", "keep.log", false));
+        assert!(ignored("!keep.log\n*.log", "keep.log", false));
+        assert!(ignored("\\!important", "!important", false));
+    }
+
+    #[test]
+    fn should_not_reinclude_files_in_ignored_directories() {
+        assert!(ignored("build/\n!build/keep", "build/keep", false));
+        assert!(!ignored("build/*\n!build/keep", "build/keep", false));
+    }
+
+    #[test]
+    fn should_skip_root_and_never_match_parent_dirs() {
+        assert!(ignored("foo", "/foo", false));
+        assert!(ignored("foo", "./foo", false));
+        assert!(!ignored("foo", "../foo", false));
+    }
+
+    #[test]
+    fn should_respect_windows_separators_and_case() {
+        let matcher = GitignoreMatcher::<WindowsEncoding>::new("/Assets/**/*.PSD\n");
+        assert!(matcher.is_ignored(r"assets\textures\hero.psd", false));
+        assert!(matcher.is_ignored("assets/textures/hero.psd", false));
+        assert!(matcher.is_ignored(r"C:\assets\hero.psd", false));
+
+        let matcher = matcher.case_insensitive(false);
+        assert!(!matcher.is_ignored(r"assets\textures\hero.psd", false));
+        assert!(matcher.is_ignored(r"Assets\textures\hero.PSD", false));
+    }
+
+    #[test]
+    fn pattern_set_should_agree_with_matcher() {
+        let patterns = "
+            target/
+            *.log
+            !keep.log
+            Cargo.lock
+            /docs/*.md
+            !README.md
+            **/cache/**
+            [Tt]emp
+            *.tar.gz
+            *
+            !*.rs
+            !src/
+            src/*.rs
+            !src/lib.rs
+        ";
+        let paths = [
+            "target/debug/app",
+            "src/target",
+            "logs/today.log",
+            "logs/keep.log",
+            "Cargo.lock",
+            "sub/Cargo.lock",
+            "docs/guide.md",
+            "docs/README.md",
+            "a/cache/b/c",
+            "temp",
+            "Temp/file",
+            "dist/app.tar.gz",
+            "main.rs",
+            "src/main.rs",
+            "src/lib.rs",
+            "../foo.log",
+            "",
+        ];
+
+        let lines: Vec<&str> = patterns.lines().map(str::trim).collect();
+        for n in 0..=lines.len() {
+            let matcher = GitignoreMatcher::<UnixEncoding>::new(&lines[..n].join("\n"));
+            let set = PatternSet::from(matcher.clone());
+            assert_eq!(set.len(), matcher.len());
+
+            for path in paths {
+                for is_dir in [false, true] {
+                    assert_eq!(
+                        set.matched(path, is_dir),
+                        matcher.matched(path, is_dir),
+                        "{:?} with {:?} (is_dir: {})",
+                        path,
+                        &lines[..n],
+                        is_dir
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pattern_set_should_keep_case_sensitivity() {
+        let patterns: Vec<Pattern> = ["*.PSD", "Thumbs.db"]
+            .iter()
+            .filter_map(Pattern::new)
+            .collect();
+
+        let set = PatternSet::<WindowsEncoding>::new(&patterns);
+        assert!(set.is_match(r"art\HERO.psd", false));
+        assert!(set.is_match(r"art\thumbs.DB", false));
+
+        let set = PatternSet::<UnixEncoding>::new(&patterns);
+        assert!(!set.is_match("art/hero.psd", false));
+        assert!(set.is_match("art/hero.PSD", false));
+
+        let matcher = GitignoreMatcher::<WindowsEncoding>::new("*.PSD").case_insensitive(false);
+        let set = PatternSet::from(matcher);
+        assert!(!set.is_match(r"art\hero.psd", false));
+    }
+
+    #[test]
+    fn matches_any_should_use_last_matching_pattern() {
+        let patterns: Vec<Pattern> = ["*.log", "!keep.log", "build/"]
+            .iter()
+            .filter_map(Pattern::new)
+            .collect();
+
+        let matches = |path: &str, is_dir: bool| {
+            crate::Path::<UnixEncoding>::new(path).matches_any(&patterns, is_dir)
+        };
+        assert!(matches("a.log", false));
+        assert!(!matches("keep.log", false));
+        assert!(!matches("build", false));
+        assert!(matches("build", true));
+        assert!(matches("build/keep.log", false));
+        assert!(!matches("../a.log", false));
+        assert!(!crate::Path::<UnixEncoding>::new("a.log").matches_any(&[], false));
+
+        let matches =
+            |path: &str| crate::Path::<WindowsEncoding>::new(path).matches_any(&patterns, false);
+        assert!(matches(r"logs\A.LOG"));
+        assert!(!matches(r"logs\KEEP.LOG"));
+    }
+
+    #[test]
+    fn pattern_should_skip_blank_lines_and_comments() {
+        assert!(Pattern::new("").is_none());
+        assert!(Pattern::new("# comment").is_none());
+        assert!(Pattern::new("/").is_none());
+        assert!(!Pattern::new("\\!important").unwrap().is_negated());
+        assert!(Pattern::new("!important").unwrap().is_negated());
+    }
+
+    #[test]
+    fn debug_should_include_encoding_label() {
+        let matcher = GitignoreMatcher::<UnixEncoding>::new("target/\n*.log");
+        assert_eq!(
+            format!("{matcher:?}"),
+            "GitignoreMatcher { encoding: \"unix\", rules: 2, case_insensitive: false }"
+        );
+
+        let set = PatternSet::from(matcher);
+        assert_eq!(
+            format!("{set:?}"),
+            "PatternSet { encoding: \"unix\", rules: 2, case_insensitive: false }"
+        );
+    }
+}
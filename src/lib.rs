@@ -19,13 +19,23 @@ mod no_std_compat {
 
 #[macro_use]
 mod common;
-#[cfg(not(target_family = "wasm"))]
+pub mod convert;
+mod delimited;
+#[cfg(feature = "gitignore")]
+mod gitignore;
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
 mod native;
-#[cfg(not(target_family = "wasm"))]
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
 mod platform;
+#[cfg(feature = "remote")]
+mod remote;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod typed;
 mod unix;
-#[cfg(all(feature = "std", not(target_family = "wasm")))]
+#[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
 pub mod utils;
 mod windows;
 
@@ -35,10 +45,15 @@ mod private {
 }
 
 pub use common::*;
-#[cfg(not(target_family = "wasm"))]
+pub use delimited::*;
+#[cfg(feature = "gitignore")]
+pub use gitignore::*;
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
 pub use native::*;
-#[cfg(not(target_family = "wasm"))]
+#[cfg(any(not(target_family = "wasm"), target_os = "wasi"))]
 pub use platform::*;
+#[cfg(feature = "remote")]
+pub use remote::*;
 pub use typed::*;
 pub use unix::*;
 pub use windows::*;
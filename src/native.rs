@@ -3,19 +3,19 @@ pub use self::utf8::*;
 
 mod non_utf8 {
     /// [`Encoding`](crate::Encoding) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type NativeEncoding = crate::unix::UnixEncoding;
 
     /// [`Path`](crate::Path) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type NativePath = crate::unix::UnixPath;
 
     /// [`PathBuf`](crate::PathBuf) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type NativePathBuf = crate::unix::UnixPathBuf;
 
     /// [`Component`](crate::Component) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type NativeComponent<'a> = crate::unix::UnixComponent<'a>;
 
     /// [`Encoding`](crate::Encoding) that is native to the platform during compilation
@@ -34,6 +34,47 @@ mod non_utf8 {
     #[cfg(windows)]
     pub type NativeComponent<'a> = crate::windows::WindowsComponent<'a>;
 
+    #[cfg(all(feature = "std", any(unix, target_os = "wasi", windows)))]
+    impl NativePathBuf {
+        /// Creates a [`NativePathBuf`] by copying an [`OsStr`] without loss, such as one returned
+        /// by [`std::env::args_os`] or [`std::path::Path::as_os_str`].
+        ///
+        /// This is the borrowed counterpart of `From<OsString>`. `From<&OsStr>` cannot be
+        /// implemented, as it would overlap with the conversion from any `&V` where
+        /// `V: AsRef<[u8]>`. On Windows, the string is stored as WTF-8, as done by
+        /// [`TypedPathBuf::from_std_path`](crate::TypedPathBuf::from_std_path).
+        ///
+        /// [`OsStr`]: std::ffi::OsStr
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::ffi::OsStr;
+        /// use typed_path::{NativePath, NativePathBuf};
+        ///
+        /// let path = NativePathBuf::from_os_str(OsStr::new("foo.txt"));
+        /// assert_eq!(path, NativePath::new("foo.txt"));
+        /// ```
+        pub fn from_os_str(s: &std::ffi::OsStr) -> Self {
+            #[cfg(unix)]
+            {
+                Self::from(std::os::unix::ffi::OsStrExt::as_bytes(s))
+            }
+
+            #[cfg(target_os = "wasi")]
+            {
+                Self::from(std::os::wasi::ffi::OsStrExt::as_bytes(s))
+            }
+
+            #[cfg(windows)]
+            {
+                use std::os::windows::ffi::OsStrExt;
+
+                Self::from(crate::windows::wtf8::from_wide(s.encode_wide()))
+            }
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -43,24 +84,81 @@ mod non_utf8 {
             let path = NativePathBuf::from("hello.txt");
             assert_eq!(path, path.clone());
         }
+
+        #[cfg(all(feature = "std", unix))]
+        #[test]
+        fn create_dir_all_verbose_should_fail_if_path_is_a_file() {
+            let dir = std::env::temp_dir().join(format!(
+                "typed-path-{}-{}",
+                stringify!(NativePathBuf),
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let file = dir.join("file.txt");
+            std::fs::write(&file, b"").unwrap();
+
+            let path = NativePathBuf::from(file.to_str().unwrap());
+            let err = path.create_dir_all_verbose().unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+            assert!(std::fs::create_dir_all(&file).is_err());
+
+            // Creating under the file fails as well, without creating anything
+            assert!(path.join("child").create_dir_all_verbose().is_err());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(all(feature = "std", unix))]
+        #[test]
+        fn from_os_str_should_keep_bytes_that_are_not_utf8() {
+            use std::os::unix::ffi::OsStrExt;
+
+            let s = std::ffi::OsStr::from_bytes(b"/tmp/\xffile");
+            let path = NativePathBuf::from_os_str(s);
+            assert_eq!(path.as_bytes(), b"/tmp/\xffile");
+            assert_eq!(path, NativePathBuf::from(s.to_os_string()));
+        }
+
+        #[cfg(all(feature = "std", windows))]
+        #[test]
+        fn from_os_str_should_keep_unpaired_surrogates() {
+            use std::ffi::OsString;
+            use std::os::windows::ffi::OsStringExt;
+
+            let s = OsString::from_wide(&[u16::from(b'a'), 0xd800]);
+            let path = NativePathBuf::from_os_str(&s);
+            assert_eq!(path.as_bytes(), b"a\xed\xa0\x80");
+        }
+
+        #[cfg(all(feature = "std", unix))]
+        #[test]
+        fn find_up_should_ignore_markers_that_are_not_a_single_normal_component() {
+            let manifest_dir = NativePath::new(env!("CARGO_MANIFEST_DIR"));
+            let src_dir = manifest_dir.join("src");
+            assert_eq!(src_dir.find_up("Cargo.toml"), Some(manifest_dir));
+
+            for marker in ["", ".", "..", "../Cargo.toml", "src/lib.rs", "/", "/etc"] {
+                assert_eq!(src_dir.find_up(marker), None, "{marker:?}");
+            }
+        }
     }
 }
 
 mod utf8 {
     /// [`Utf8Path`](crate::Utf8Encoding) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type Utf8NativeEncoding = crate::unix::Utf8UnixEncoding;
 
     /// [`Utf8Path`](crate::Utf8Path) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type Utf8NativePath = crate::unix::Utf8UnixPath;
 
     /// [`Utf8PathBuf`](crate::Utf8PathBuf) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type Utf8NativePathBuf = crate::unix::Utf8UnixPathBuf;
 
     /// [`Utf8Component`](crate::Utf8Component) that is native to the platform during compilation
-    #[cfg(unix)]
+    #[cfg(any(unix, target_os = "wasi"))]
     pub type Utf8NativeComponent<'a> = crate::unix::Utf8UnixComponent<'a>;
 
     /// [`Utf8Path`](crate::Utf8Encoding) that is native to the platform during compilation
@@ -88,5 +186,40 @@ mod utf8 {
             let path = Utf8NativePathBuf::from("hello.txt");
             assert_eq!(path, path.clone());
         }
+
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        #[test]
+        fn create_dir_all_verbose_should_fail_if_path_is_a_file() {
+            let dir = std::env::temp_dir().join(format!(
+                "typed-path-{}-{}",
+                stringify!(Utf8NativePathBuf),
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let file = dir.join("file.txt");
+            std::fs::write(&file, b"").unwrap();
+
+            let path = Utf8NativePathBuf::from(file.to_str().unwrap());
+            let err = path.create_dir_all_verbose().unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+            assert!(std::fs::create_dir_all(&file).is_err());
+
+            // Creating under the file fails as well, without creating anything
+            assert!(path.join("child").create_dir_all_verbose().is_err());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        #[test]
+        fn find_up_should_ignore_markers_that_are_not_a_single_normal_component() {
+            let manifest_dir = Utf8NativePath::new(env!("CARGO_MANIFEST_DIR"));
+            let src_dir = manifest_dir.join("src");
+            assert_eq!(src_dir.find_up("Cargo.toml"), Some(manifest_dir));
+
+            for marker in ["", ".", "..", "../Cargo.toml", "src/lib.rs", "/", "/etc"] {
+                assert_eq!(src_dir.find_up(marker), None, "{marker:?}");
+            }
+        }
     }
 }
@@ -2,11 +2,12 @@ pub use self::non_utf8::*;
 pub use self::utf8::*;
 
 mod non_utf8 {
-    use crate::common::{CheckedPathError, Encoding, Path, PathBuf};
+    use crate::common::{CheckedPathError, Encoding, Path, PathBuf, PushBuffer, RootKind};
     use crate::native::NativeEncoding;
     use crate::no_std_compat::*;
     use crate::private;
     use core::any::TypeId;
+    use core::cmp::Ordering;
     use core::fmt;
     use core::hash::Hasher;
 
@@ -86,13 +87,29 @@ mod non_utf8 {
             <NativeEncoding as Encoding<'a>>::hash(path, h)
         }
 
+        fn cmp(path: &[u8], other: &[u8]) -> Ordering {
+            <NativeEncoding as Encoding<'a>>::cmp(path, other)
+        }
+
         fn push(current_path: &mut Vec<u8>, path: &[u8]) {
             <NativeEncoding as Encoding<'a>>::push(current_path, path);
         }
 
+        fn try_push<B: PushBuffer>(current_path: &mut B, path: &[u8]) -> bool {
+            <NativeEncoding as Encoding<'a>>::try_push(current_path, path)
+        }
+
         fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
             <NativeEncoding as Encoding<'a>>::push_checked(current_path, path)
         }
+
+        fn starts_with_fast(path: &[u8], base: &[u8]) -> Option<bool> {
+            <NativeEncoding as Encoding<'a>>::starts_with_fast(path, base)
+        }
+
+        fn root_kind(path: &[u8]) -> RootKind {
+            <NativeEncoding as Encoding<'a>>::root_kind(path)
+        }
     }
 
     impl fmt::Debug for PlatformEncoding {
@@ -147,11 +164,12 @@ mod non_utf8 {
 }
 
 mod utf8 {
-    use crate::common::{CheckedPathError, Utf8Encoding, Utf8Path, Utf8PathBuf};
+    use crate::common::{CheckedPathError, RootKind, Utf8Encoding, Utf8Path, Utf8PathBuf};
     use crate::native::Utf8NativeEncoding;
     use crate::no_std_compat::*;
     use crate::private;
     use core::any::TypeId;
+    use core::cmp::Ordering;
     use core::fmt;
     use core::hash::Hasher;
 
@@ -235,6 +253,10 @@ mod utf8 {
             <Utf8NativeEncoding as Utf8Encoding<'a>>::hash(path, h)
         }
 
+        fn cmp(path: &str, other: &str) -> Ordering {
+            <Utf8NativeEncoding as Utf8Encoding<'a>>::cmp(path, other)
+        }
+
         fn push(current_path: &mut String, path: &str) {
             <Utf8NativeEncoding as Utf8Encoding<'a>>::push(current_path, path);
         }
@@ -242,6 +264,14 @@ mod utf8 {
         fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
             <Utf8NativeEncoding as Utf8Encoding<'a>>::push_checked(current_path, path)
         }
+
+        fn starts_with_fast(path: &str, base: &str) -> Option<bool> {
+            <Utf8NativeEncoding as Utf8Encoding<'a>>::starts_with_fast(path, base)
+        }
+
+        fn root_kind(path: &str) -> RootKind {
+            <Utf8NativeEncoding as Utf8Encoding<'a>>::root_kind(path)
+        }
     }
 
     impl fmt::Debug for Utf8PlatformEncoding {
@@ -294,7 +324,7 @@ mod utf8 {
         }
     }
 
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     impl AsRef<StdPath> for Utf8PlatformPath {
         /// Converts a platform utf8 path (based on compilation family) into [`std::path::Path`].
         ///
@@ -312,7 +342,7 @@ mod utf8 {
         }
     }
 
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     impl AsRef<StdPath> for Utf8PlatformPathBuf {
         /// Converts a platform utf8 pathbuf (based on compilation family) into [`std::path::Path`].
         ///
@@ -330,7 +360,7 @@ mod utf8 {
         }
     }
 
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     impl From<Utf8PlatformPathBuf> for StdPathBuf {
         /// Converts a platform utf8 pathbuf (based on compilation family) into [`std::path::PathBuf`].
         ///
@@ -0,0 +1,170 @@
+use crate::no_std_compat::*;
+use crate::{PathType, RemotePathError, TypedPath, TypedPathBuf};
+
+/// A path on a remote host written in the `[user@]host:path` form used by `scp`, `rsync`, and
+/// similar tools.
+///
+/// The separator between the host and the path is the first `:` that is not inside an IPv6
+/// address written in brackets, such as `[::1]`, so any colons within the path itself, like the
+/// one in a Windows drive letter, are left alone. The flavor of the path cannot be known from the
+/// spec, so it is given explicitly when parsing.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PathType, RemotePath, TypedPath};
+///
+/// let remote =
+///     RemotePath::parse(r"alice@build01:C:\Users\alice\out.zip", PathType::Windows).unwrap();
+/// assert_eq!(remote.user(), Some("alice"));
+/// assert_eq!(remote.host(), "build01");
+/// assert_eq!(remote.path(), TypedPath::windows(r"C:\Users\alice\out.zip"));
+///
+/// let remote = RemotePath::parse("[fe80::1]:/var/log", PathType::Unix).unwrap();
+/// assert_eq!(remote.user(), None);
+/// assert_eq!(remote.host(), "fe80::1");
+/// assert_eq!(remote.path(), TypedPath::unix("/var/log"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RemotePath {
+    user: Option<String>,
+    host: String,
+    path: TypedPathBuf,
+}
+
+impl RemotePath {
+    /// Parses a `[user@]host:path` spec, interpreting the path as the given `flavor`.
+    ///
+    /// An empty path, as in `host:`, is allowed and conventionally refers to the home directory
+    /// of the user on the remote host.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RemotePathError::NotRemote`] if the spec refers to a local path, which is the
+    /// case when:
+    ///
+    /// * there is no `:` separating a host from a path
+    /// * a `/` or `\` appears before the first `:`, as in `./a:b`
+    /// * the part before the first `:` is a single letter with no user, as in `C:\Users`, which
+    ///   is a Windows drive letter rather than a host
+    ///
+    /// Returns [`RemotePathError::EmptyHost`] if the host is empty, as in `user@:path`, and
+    /// [`RemotePathError::UnclosedBracket`] if a `[` around an IPv6 address is never closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathType, RemotePath, RemotePathError};
+    ///
+    /// assert_eq!(
+    ///     RemotePath::parse(r"C:\Users", PathType::Windows),
+    ///     Err(RemotePathError::NotRemote)
+    /// );
+    /// assert_eq!(RemotePath::parse("./a:b", PathType::Unix), Err(RemotePathError::NotRemote));
+    /// assert_eq!(RemotePath::parse("notes.txt", PathType::Unix), Err(RemotePathError::NotRemote));
+    /// assert_eq!(RemotePath::parse("bob@:/tmp", PathType::Unix), Err(RemotePathError::EmptyHost));
+    /// assert_eq!(
+    ///     RemotePath::parse("[::1:/tmp", PathType::Unix),
+    ///     Err(RemotePathError::UnclosedBracket)
+    /// );
+    /// ```
+    pub fn parse(spec: &str, flavor: PathType) -> Result<Self, RemotePathError> {
+        let mut in_brackets = false;
+        let mut separator = None;
+        for (i, c) in spec.char_indices() {
+            match c {
+                '[' => in_brackets = true,
+                ']' => in_brackets = false,
+                ':' if !in_brackets => {
+                    separator = Some(i);
+                    break;
+                }
+                '/' | '\\' if !in_brackets => return Err(RemotePathError::NotRemote),
+                _ => {}
+            }
+        }
+
+        let separator = match separator {
+            Some(i) => i,
+            None if in_brackets => return Err(RemotePathError::UnclosedBracket),
+            None => return Err(RemotePathError::NotRemote),
+        };
+        let (authority, path) = (&spec[..separator], &spec[separator + 1..]);
+
+        let (user, host) = match authority.rfind('@') {
+            Some(i) => (Some(&authority[..i]), &authority[i + 1..]),
+            None => (None, authority),
+        };
+
+        let is_drive_letter = |s: &str| s.len() == 1 && s.as_bytes()[0].is_ascii_alphabetic();
+        if user.is_none() && is_drive_letter(host) {
+            return Err(RemotePathError::NotRemote);
+        }
+
+        let host = match host.strip_prefix('[') {
+            Some(host) => host.strip_suffix(']').unwrap_or(host),
+            None => host,
+        };
+        if host.is_empty() {
+            return Err(RemotePathError::EmptyHost);
+        }
+
+        Ok(Self {
+            user: user.map(String::from),
+            host: String::from(host),
+            path: TypedPath::new(path, flavor).to_path_buf(),
+        })
+    }
+
+    /// Returns the user to log in as, if one was given.
+    #[inline]
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// Returns the host, without the brackets around an IPv6 address.
+    #[inline]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the path on the remote host.
+    #[inline]
+    pub fn path(&self) -> TypedPath<'_> {
+        self.path.to_path()
+    }
+
+    /// Consumes the `RemotePath`, returning the path on the remote host.
+    #[inline]
+    pub fn into_path(self) -> TypedPathBuf {
+        self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_should_split_user_on_last_at_sign() {
+        let remote = RemotePath::parse("a@b.example@host:file", PathType::Unix).unwrap();
+        assert_eq!(remote.user(), Some("a@b.example"));
+        assert_eq!(remote.host(), "host");
+        assert_eq!(remote.path(), TypedPath::unix("file"));
+    }
+
+    #[test]
+    fn parse_should_allow_single_letter_host_with_user_and_empty_path() {
+        let remote = RemotePath::parse("root@c:", PathType::Unix).unwrap();
+        assert_eq!(remote.host(), "c");
+        assert!(remote.path().as_bytes().is_empty());
+    }
+
+    #[test]
+    fn parse_should_ignore_colons_within_brackets() {
+        let remote = RemotePath::parse("me@[2001:db8::2]:D:\\data", PathType::Windows).unwrap();
+        assert_eq!(remote.user(), Some("me"));
+        assert_eq!(remote.host(), "2001:db8::2");
+        assert_eq!(remote.path(), TypedPath::windows(r"D:\data"));
+    }
+}
@@ -0,0 +1,75 @@
+//! Helpers for (de)serializing typed paths with [`serde`].
+//!
+//! A [`TypedPathBuf`] can be represented on the wire in one of two ways, each provided as a
+//! module to be used with `#[serde(with = "...")]`:
+//!
+//! * [`tagged`] serializes the path alongside its encoding, e.g. `{"unix": "/a"}`.
+//! * [`untagged`] serializes the path on its own, e.g. `"/a"`, and detects the encoding at
+//!   runtime when deserializing via [`TypedPath::derive`].
+//!
+//! Paths that are valid UTF-8 are serialized as strings, and all other paths are serialized as
+//! bytes. Deserializing accepts strings, bytes, or sequences of bytes.
+//!
+//! [`TypedPath::derive`]: crate::TypedPath::derive
+//! [`TypedPathBuf`]: crate::TypedPathBuf
+
+pub mod tagged;
+pub mod untagged;
+
+use core::fmt;
+
+use ::serde::de::{self, SeqAccess, Visitor};
+use ::serde::{Deserializer, Serializer};
+
+use crate::no_std_compat::*;
+
+/// Serializes `bytes` as a string if they are valid UTF-8, or as bytes otherwise.
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => serializer.serialize_str(s),
+        Err(_) => serializer.serialize_bytes(bytes),
+    }
+}
+
+/// Path bytes deserialized from a string, bytes, or a sequence of bytes.
+struct PathBytes(Vec<u8>);
+
+impl<'de> ::serde::Deserialize<'de> for PathBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(PathBytesVisitor)
+    }
+}
+
+struct PathBytesVisitor;
+
+impl<'de> Visitor<'de> for PathBytesVisitor {
+    type Value = PathBytes;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a path as a string or bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(PathBytes(v.as_bytes().to_vec()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(PathBytes(v.into_bytes()))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(PathBytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(PathBytes(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(PathBytes(bytes))
+    }
+}
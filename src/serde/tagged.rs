@@ -0,0 +1,134 @@
+//! (De)serializes a [`TypedPathBuf`] alongside its encoding.
+//!
+//! The path is written as an externally-tagged enum whose variant is either `unix` or
+//! `windows`, which in JSON looks like `{"unix": "/a"}`.
+//!
+//! # Examples
+//!
+//! Typically used on a field, e.g. `#[serde(with = "typed_path::serde::tagged")]`, but the
+//! functions can also be called directly:
+//!
+//! ```
+//! use serde::de::value::{Error, MapAccessDeserializer, MapDeserializer};
+//! use typed_path::serde::tagged;
+//! use typed_path::TypedPathBuf;
+//!
+//! let map = MapDeserializer::<_, Error>::new(std::iter::once(("windows", r"dir\file.txt")));
+//! let path = tagged::deserialize(MapAccessDeserializer::new(map)).unwrap();
+//! assert_eq!(path, TypedPathBuf::from_windows(r"dir\file.txt"));
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, EnumAccess, VariantAccess, Visitor};
+use ::serde::ser::Serialize;
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+use super::PathBytes;
+use crate::TypedPathBuf;
+
+const NAME: &str = "TypedPathBuf";
+const VARIANTS: &[&str] = &["unix", "windows"];
+
+/// Serializes `path` as a newtype variant named after its encoding.
+pub fn serialize<S: Serializer>(path: &TypedPathBuf, serializer: S) -> Result<S::Ok, S::Error> {
+    match path {
+        TypedPathBuf::Unix(p) => {
+            serializer.serialize_newtype_variant(NAME, 0, VARIANTS[0], &Bytes(p.as_bytes()))
+        }
+        TypedPathBuf::Windows(p) => {
+            serializer.serialize_newtype_variant(NAME, 1, VARIANTS[1], &Bytes(p.as_bytes()))
+        }
+    }
+}
+
+/// Deserializes a path written by [`serialize`], using the variant to determine its encoding.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypedPathBuf, D::Error> {
+    deserializer.deserialize_enum(NAME, VARIANTS, TaggedVisitor)
+}
+
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serialize_bytes(self.0, serializer)
+    }
+}
+
+enum Tag {
+    Unix,
+    Windows,
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(TagVisitor)
+    }
+}
+
+struct TagVisitor;
+
+impl<'de> Visitor<'de> for TagVisitor {
+    type Value = Tag;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`unix` or `windows`")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        match v {
+            0 => Ok(Tag::Unix),
+            1 => Ok(Tag::Windows),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v {
+            "unix" => Ok(Tag::Unix),
+            "windows" => Ok(Tag::Windows),
+            _ => Err(E::unknown_variant(v, VARIANTS)),
+        }
+    }
+}
+
+struct TaggedVisitor;
+
+impl<'de> Visitor<'de> for TaggedVisitor {
+    type Value = TypedPathBuf;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a path tagged with `unix` or `windows`")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (tag, variant) = data.variant::<Tag>()?;
+        let PathBytes(bytes) = variant.newtype_variant()?;
+        Ok(match tag {
+            Tag::Unix => TypedPathBuf::from_unix(bytes),
+            Tag::Windows => TypedPathBuf::from_windows(bytes),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::de::value::{Error, MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+
+    use super::*;
+    use crate::no_std_compat::*;
+
+    #[test]
+    fn deserialize_should_accept_a_sequence_of_bytes() {
+        let bytes = SeqDeserializer::<_, Error>::new(vec![b'/', 0xFF].into_iter());
+        let map = MapDeserializer::new(core::iter::once(("unix", bytes)));
+        let path = deserialize(MapAccessDeserializer::new(map)).unwrap();
+        assert_eq!(path, TypedPathBuf::from_unix([b'/', 0xFF]));
+    }
+
+    #[test]
+    fn deserialize_should_fail_for_unknown_encoding() {
+        let map = MapDeserializer::<_, Error>::new(core::iter::once(("mac", "/tmp")));
+        assert!(deserialize(MapAccessDeserializer::new(map)).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+//! (De)serializes a [`TypedPathBuf`] as a bare path, detecting its encoding when deserializing.
+//!
+//! Because the encoding is not recorded, it is guessed with [`TypedPath::derive`] when
+//! deserializing. This means that a relative Windows path such as `dir\file.txt` will come back
+//! as a Unix path. Use [`tagged`](super::tagged) when the encoding must survive a round trip.
+//!
+//! # Examples
+//!
+//! Typically used on a field, e.g. `#[serde(with = "typed_path::serde::untagged")]`, but the
+//! functions can also be called directly:
+//!
+//! ```
+//! use serde::de::value::{Error, StrDeserializer};
+//! use typed_path::serde::untagged;
+//!
+//! let path = untagged::deserialize(StrDeserializer::<Error>::new(r"C:\data")).unwrap();
+//! assert!(path.is_windows());
+//!
+//! let path = untagged::deserialize(StrDeserializer::<Error>::new("/etc")).unwrap();
+//! assert!(path.is_unix());
+//! ```
+//!
+//! [`TypedPath::derive`]: crate::TypedPath::derive
+
+use ::serde::{Deserialize, Deserializer, Serializer};
+
+use super::PathBytes;
+use crate::{TypedPath, TypedPathBuf};
+
+/// Serializes `path` without its encoding.
+pub fn serialize<S: Serializer>(path: &TypedPathBuf, serializer: S) -> Result<S::Ok, S::Error> {
+    super::serialize_bytes(path.as_bytes(), serializer)
+}
+
+/// Deserializes a path, using [`TypedPath::derive`] to determine its encoding.
+///
+/// [`TypedPath::derive`]: crate::TypedPath::derive
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypedPathBuf, D::Error> {
+    let PathBytes(bytes) = PathBytes::deserialize(deserializer)?;
+    Ok(TypedPath::derive(&bytes).to_path_buf())
+}
@@ -0,0 +1,373 @@
+//! Utilities for writing tests against typed paths.
+//!
+//! * [`assert_paths_eq!`] compares two paths and, when they differ, reports them component by
+//!   component rather than as arrays of bytes.
+//! * [`upath!`] and [`wpath!`] build a [`UnixPathBuf`] or [`WindowsPathBuf`] from a list of
+//!   segments.
+//!
+//! [`UnixPathBuf`]: crate::UnixPathBuf
+//! [`WindowsPathBuf`]: crate::WindowsPathBuf
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_path::testing::{assert_paths_eq, upath, wpath};
+//! use typed_path::{UnixPath, WindowsPath};
+//!
+//! assert_paths_eq!(upath!["/", "usr", "lib"], UnixPath::new("/usr/lib"));
+//! assert_paths_eq!(wpath![r"C:\", "Users"], WindowsPath::new(r"C:\Users"));
+//! ```
+
+use core::fmt;
+
+use crate::no_std_compat::*;
+use crate::{
+    Component, Encoding, Path, PathBuf, TypedPath, TypedPathBuf, Utf8Component, Utf8Encoding,
+    Utf8Path, Utf8PathBuf, Utf8TypedPath, Utf8TypedPathBuf,
+};
+
+pub use crate::{assert_paths_eq, upath, wpath};
+
+/// Asserts that two paths are equal, reporting the components of each path on failure.
+///
+/// Both sides are compared with `==` and must implement [`TestPath`], which covers every path
+/// type in this crate. Like [`assert_eq!`], an optional message can follow the paths.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::testing::assert_paths_eq;
+/// use typed_path::{Utf8UnixPath, Utf8UnixPathBuf};
+///
+/// let path = Utf8UnixPathBuf::from("a/b/c");
+/// assert_paths_eq!(path, Utf8UnixPath::new("a/b/c"));
+/// assert_paths_eq!(path, Utf8UnixPath::new("a/b/c"), "while checking {}", "c");
+/// ```
+///
+/// A failure lists the components side by side, marking those that differ:
+///
+/// ```should_panic
+/// use typed_path::testing::assert_paths_eq;
+/// use typed_path::UnixPath;
+///
+/// // paths are not equal
+/// //   left: "a/b/c"
+/// //  right: "a/x/c"
+/// //  components:
+/// //      "a"  "a"
+/// //   !  "b"  "x"
+/// //      "c"  "c"
+/// assert_paths_eq!(UnixPath::new("a/b/c"), UnixPath::new("a/x/c"));
+/// ```
+#[macro_export]
+macro_rules! assert_paths_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::testing::assert_paths_failed(left, right, ::core::option::Option::None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::testing::assert_paths_failed(
+                        left,
+                        right,
+                        ::core::option::Option::Some(::core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Builds a [`UnixPathBuf`] by pushing each segment in turn.
+///
+/// [`UnixPathBuf`]: crate::UnixPathBuf
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::testing::upath;
+/// use typed_path::UnixPath;
+///
+/// assert_eq!(upath!["a", "b", "c"], UnixPath::new("a/b/c"));
+/// assert_eq!(upath!["/etc", "hosts"], UnixPath::new("/etc/hosts"));
+/// assert!(upath![].as_bytes().is_empty());
+/// ```
+#[macro_export]
+macro_rules! upath {
+    ($($segment:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut path = $crate::UnixPathBuf::new();
+        $(path.push($segment);)*
+        path
+    }};
+}
+
+/// Builds a [`WindowsPathBuf`] by pushing each segment in turn.
+///
+/// [`WindowsPathBuf`]: crate::WindowsPathBuf
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::testing::wpath;
+/// use typed_path::WindowsPath;
+///
+/// assert_eq!(wpath!["a", "b", "c"], WindowsPath::new(r"a\b\c"));
+/// assert_eq!(wpath![r"C:\", "Windows"], WindowsPath::new(r"C:\Windows"));
+/// ```
+#[macro_export]
+macro_rules! wpath {
+    ($($segment:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut path = $crate::WindowsPathBuf::new();
+        $(path.push($segment);)*
+        path
+    }};
+}
+
+/// A path that [`assert_paths_eq!`] can describe when an assertion fails.
+pub trait TestPath {
+    /// Returns the whole path as it should appear in a failure message.
+    fn render(&self) -> String;
+
+    /// Returns each component of the path as it should appear in a failure message.
+    fn render_components(&self) -> Vec<String>;
+}
+
+impl<P: TestPath + ?Sized> TestPath for &P {
+    fn render(&self) -> String {
+        (**self).render()
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        (**self).render_components()
+    }
+}
+
+impl<T> TestPath for Path<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn render(&self) -> String {
+        render_bytes(self.as_bytes())
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        self.components()
+            .map(|c| render_bytes(c.as_bytes()))
+            .collect()
+    }
+}
+
+impl<T> TestPath for PathBuf<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn render(&self) -> String {
+        self.as_path().render()
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        self.as_path().render_components()
+    }
+}
+
+impl<T> TestPath for Utf8Path<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn render(&self) -> String {
+        render_bytes(self.as_str().as_bytes())
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        self.components()
+            .map(|c| render_bytes(c.as_str().as_bytes()))
+            .collect()
+    }
+}
+
+impl<T> TestPath for Utf8PathBuf<T>
+where
+    T: for<'enc> Utf8Encoding<'enc>,
+{
+    fn render(&self) -> String {
+        self.as_path().render()
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        self.as_path().render_components()
+    }
+}
+
+impl TestPath for TypedPath<'_> {
+    fn render(&self) -> String {
+        match self {
+            Self::Unix(path) => path.render(),
+            Self::Windows(path) => path.render(),
+        }
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        match self {
+            Self::Unix(path) => path.render_components(),
+            Self::Windows(path) => path.render_components(),
+        }
+    }
+}
+
+impl TestPath for TypedPathBuf {
+    fn render(&self) -> String {
+        self.to_path().render()
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        self.to_path().render_components()
+    }
+}
+
+impl TestPath for Utf8TypedPath<'_> {
+    fn render(&self) -> String {
+        match self {
+            Self::Unix(path) => path.render(),
+            Self::Windows(path) => path.render(),
+        }
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        match self {
+            Self::Unix(path) => path.render_components(),
+            Self::Windows(path) => path.render_components(),
+        }
+    }
+}
+
+impl TestPath for Utf8TypedPathBuf {
+    fn render(&self) -> String {
+        self.to_path().render()
+    }
+
+    fn render_components(&self) -> Vec<String> {
+        self.to_path().render_components()
+    }
+}
+
+/// Renders `bytes` as a quoted string, escaping any bytes that are not valid UTF-8.
+fn render_bytes(bytes: &[u8]) -> String {
+    struct Escaped<'a>(&'a [u8]);
+
+    impl fmt::Display for Escaped<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match core::str::from_utf8(self.0) {
+                Ok(s) => write!(f, "{s:?}"),
+                Err(_) => {
+                    f.write_str("b\"")?;
+                    for byte in self.0 {
+                        write!(f, "{}", core::ascii::escape_default(*byte))?;
+                    }
+                    f.write_str("\"")
+                }
+            }
+        }
+    }
+
+    Escaped(bytes).to_string()
+}
+
+/// Panics with a description of how `left` and `right` differ.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_paths_failed<L, R>(left: &L, right: &R, args: Option<fmt::Arguments<'_>>) -> !
+where
+    L: TestPath + ?Sized,
+    R: TestPath + ?Sized,
+{
+    match args {
+        Some(args) => panic!(
+            "paths are not equal: {}\n{}",
+            args,
+            describe_difference(left, right)
+        ),
+        None => panic!("paths are not equal\n{}", describe_difference(left, right)),
+    }
+}
+
+/// Describes both paths and lists their components side by side, marking those that differ.
+fn describe_difference<L, R>(left: &L, right: &R) -> String
+where
+    L: TestPath + ?Sized,
+    R: TestPath + ?Sized,
+{
+    let left_components = left.render_components();
+    let right_components = right.render_components();
+    let width = left_components.iter().map(String::len).max().unwrap_or(0);
+
+    let mut report = String::from("  left: ");
+    report.push_str(&left.render());
+    report.push_str("\n right: ");
+    report.push_str(&right.render());
+    report.push_str("\n components:");
+
+    let count = left_components.len().max(right_components.len());
+    for i in 0..count {
+        let l = left_components.get(i).map_or("", String::as_str);
+        let r = right_components.get(i).map_or("", String::as_str);
+        report.push_str(if l == r { "\n      " } else { "\n   !  " });
+        report.push_str(l);
+        report.extend(core::iter::repeat(' ').take(width - l.len() + 2));
+        report.push_str(r);
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixPath, WindowsPath};
+
+    #[test]
+    fn render_should_escape_non_utf8_bytes() {
+        assert_eq!(UnixPath::new(b"a/\xffb").render(), r#"b"a/\xffb""#);
+        assert_eq!(
+            UnixPath::new(b"a/\xffb").render_components(),
+            [r#""a""#, r#"b"\xffb""#]
+        );
+    }
+
+    #[test]
+    fn describe_difference_should_list_components_side_by_side() {
+        let report = describe_difference(UnixPath::new("/a/bc"), UnixPath::new("/a/x/y"));
+        assert_eq!(
+            report,
+            [
+                r#"  left: "/a/bc""#,
+                r#" right: "/a/x/y""#,
+                r#" components:"#,
+                r#"      "/"   "/""#,
+                r#"      "a"   "a""#,
+                r#"   !  "bc"  "x""#,
+                r#"   !        "y""#,
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "paths are not equal: case 1\n  left: \"C:\\\\a\"")]
+    fn assert_paths_eq_should_include_message() {
+        assert_paths_eq!(
+            WindowsPath::new(r"C:\a"),
+            WindowsPath::new(r"C:\b"),
+            "case {}",
+            1
+        );
+    }
+}
@@ -17,6 +17,7 @@ pub use non_utf8::*;
 pub use utf8::*;
 
 /// Represents the type of the path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PathType {
     /// Path is for a Unix platform.
     Unix,
@@ -24,3 +25,72 @@ pub enum PathType {
     /// Path is for a Windows platform.
     Windows,
 }
+
+/// Represents the operating system of a host, typically a remote peer whose paths are being
+/// handled, used to pick the [`PathType`] of its paths explicitly.
+///
+/// More operating systems may be added in future releases, so matching on a `HostOs` requires a
+/// wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{HostOs, PathType};
+///
+/// let host = HostOs::from_os_name("macos").unwrap();
+/// assert_eq!(host, HostOs::MacOs);
+/// assert_eq!(host.path_type(), PathType::Unix);
+///
+/// assert_eq!(HostOs::Windows.path_type(), PathType::Windows);
+/// assert_eq!(HostOs::from_os_name("plan9"), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HostOs {
+    /// Linux, including distributions such as Android.
+    Linux,
+
+    /// macOS and other Apple platforms such as iOS.
+    MacOs,
+
+    /// Any of the BSD family, e.g. FreeBSD, NetBSD, OpenBSD, or DragonFly.
+    Bsd,
+
+    /// Solaris or illumos.
+    Solaris,
+
+    /// Microsoft Windows.
+    Windows,
+}
+
+impl HostOs {
+    /// Parses the name of an operating system as reported by [`std::env::consts::OS`] on that
+    /// host, ignoring ASCII case. Returns [`None`] for names that are not recognized.
+    ///
+    /// [`std::env::consts::OS`]: https://doc.rust-lang.org/std/env/consts/constant.OS.html
+    pub fn from_os_name(name: &str) -> Option<Self> {
+        let is = |s: &str| name.eq_ignore_ascii_case(s);
+
+        if is("linux") || is("android") {
+            Some(Self::Linux)
+        } else if is("macos") || is("ios") || is("tvos") || is("watchos") || is("visionos") {
+            Some(Self::MacOs)
+        } else if is("freebsd") || is("netbsd") || is("openbsd") || is("dragonfly") {
+            Some(Self::Bsd)
+        } else if is("solaris") || is("illumos") {
+            Some(Self::Solaris)
+        } else if is("windows") {
+            Some(Self::Windows)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`PathType`] used by paths on this host.
+    pub fn path_type(self) -> PathType {
+        match self {
+            Self::Linux | Self::MacOs | Self::Bsd | Self::Solaris => PathType::Unix,
+            Self::Windows => PathType::Windows,
+        }
+    }
+}
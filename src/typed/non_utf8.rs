@@ -2,8 +2,10 @@ mod components;
 mod iter;
 mod path;
 mod pathbuf;
+mod prefix_map;
 
 pub use components::*;
 pub use iter::*;
 pub use path::*;
 pub use pathbuf::*;
+pub use prefix_map::*;
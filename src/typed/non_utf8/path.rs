@@ -1,17 +1,29 @@
 use alloc::borrow::Cow;
 use core::fmt;
-#[cfg(all(feature = "std", not(target_family = "wasm")))]
+#[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
 use std::io;
 
-use crate::common::{CheckedPathError, StripPrefixError, TryAsRef};
-use crate::typed::{PathType, TypedAncestors, TypedComponents, TypedIter, TypedPathBuf};
-use crate::unix::UnixPath;
-use crate::windows::WindowsPath;
+use crate::common::{
+    CheckedPathError, PathId, RootKind, StripPrefixError, TryAsRef, Utf8PathError,
+};
+use crate::typed::{
+    PathType, TypedAncestors, TypedComponents, TypedIter, TypedPathBuf, Utf8TypedPath,
+};
+use crate::unix::{UnixPath, Utf8UnixPath};
+use crate::windows::{Utf8WindowsPath, WindowsPath};
 
 /// Represents a path with a known type that can be one of:
 ///
 /// * [`UnixPath`]
 /// * [`WindowsPath`]
+///
+/// Methods that build a new path from this one, such as [`with_file_name`] and
+/// [`with_extension`], always return the same flavor as `self`, even when the result would be
+/// [derived] as the other flavor on its own.
+///
+/// [`with_file_name`]: TypedPath::with_file_name
+/// [`with_extension`]: TypedPath::with_extension
+/// [derived]: TypedPath::derive
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TypedPath<'a> {
     Unix(&'a UnixPath),
@@ -82,6 +94,51 @@ impl<'a> TypedPath<'a> {
         impl_typed_fn!(self, as_bytes)
     }
 
+    /// Returns `true` if the path contains no bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert!(TypedPath::derive("").is_empty());
+    /// assert!(!TypedPath::derive(r"C:\foo.txt").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        impl_typed_fn!(self, is_empty)
+    }
+
+    /// Returns the length of the path in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(TypedPath::derive(r"C:\foo.txt").len(), 10);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        impl_typed_fn!(self, len)
+    }
+
+    /// Returns the number of [`components`](TypedPath::components) in the path.
+    ///
+    /// This parses the path each time that it is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(TypedPath::derive(r"C:\foo.txt").components_len(), 3);
+    /// assert_eq!(TypedPath::derive("").components_len(), 0);
+    /// ```
+    pub fn components_len(&self) -> usize {
+        impl_typed_fn!(self, components_len)
+    }
+
     /// Yields a [`&str`] slice if the [`TypedPath`] is valid unicode.
     ///
     /// This conversion may entail doing a check for UTF-8 validity.
@@ -212,6 +269,38 @@ impl<'a> TypedPath<'a> {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Classifies how the [`TypedPath`] is anchored. See [`RootKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RootKind, TypedPath};
+    ///
+    /// assert_eq!(TypedPath::derive("/etc/passwd").root_kind(), RootKind::Absolute);
+    /// assert_eq!(TypedPath::derive(r"C:foo").root_kind(), RootKind::DriveRelative);
+    /// ```
+    #[inline]
+    pub fn root_kind(&self) -> RootKind {
+        impl_typed_fn!(self, root_kind)
+    }
+
+    /// Returns a compact identifier for the [`TypedPath`] that is shared by every path of the same
+    /// flavor with the same normalized components. See [`PathId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let unix = TypedPath::unix("/a/b");
+    /// assert_eq!(unix.stable_id(), TypedPath::unix("/a/./b/").stable_id());
+    /// assert_ne!(unix.stable_id(), TypedPath::windows("/a/b").stable_id());
+    /// ```
+    #[inline]
+    pub fn stable_id(&self) -> PathId {
+        impl_typed_fn!(self, stable_id)
+    }
+
     /// Returns the [`TypedPath`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -272,6 +361,29 @@ impl<'a> TypedPath<'a> {
         }
     }
 
+    /// Returns the ancestor `n` levels above the [`TypedPath`], or [`None`] if that would go past
+    /// the root or the start of a relative path.
+    ///
+    /// See [`Path::ancestor`] for more details.
+    ///
+    /// [`Path::ancestor`]: crate::Path::ancestor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive(r"C:\foo\bar");
+    /// assert_eq!(path.ancestor(1), Some(TypedPath::derive(r"C:\foo")));
+    /// assert_eq!(path.ancestor(3), None);
+    /// ```
+    pub fn ancestor(&self, n: usize) -> Option<Self> {
+        match self {
+            Self::Unix(path) => path.ancestor(n).map(Self::Unix),
+            Self::Windows(path) => path.ancestor(n).map(Self::Windows),
+        }
+    }
+
     /// Returns the final component of the [`TypedPath`], if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -295,6 +407,23 @@ impl<'a> TypedPath<'a> {
         impl_typed_fn!(self, file_name)
     }
 
+    /// Returns true if the [`file_name`] begins with a `.`, which is how Unix-style tools mark
+    /// a file or directory as hidden.
+    ///
+    /// [`file_name`]: TypedPath::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert!(TypedPath::derive("/home/user/.bashrc").file_name_is_dotfile());
+    /// assert!(!TypedPath::derive("/home/user").file_name_is_dotfile());
+    /// ```
+    pub fn file_name_is_dotfile(&self) -> bool {
+        impl_typed_fn!(self, file_name_is_dotfile)
+    }
+
     /// Returns a path that, when joined onto `base`, yields `self`.
     ///
     /// # Difference from Path
@@ -330,7 +459,7 @@ impl<'a> TypedPath<'a> {
     /// let prefix = TypedPathBuf::from("/test/");
     /// assert_eq!(path.strip_prefix(prefix), Ok(TypedPath::derive("haha/foo.txt")));
     /// ```
-    pub fn strip_prefix(&self, base: impl AsRef<[u8]>) -> Result<TypedPath, StripPrefixError> {
+    pub fn strip_prefix(&self, base: impl AsRef<[u8]>) -> Result<TypedPath<'_>, StripPrefixError> {
         match self {
             Self::Unix(p) => p.strip_prefix(UnixPath::new(&base)).map(TypedPath::Unix),
             Self::Windows(p) => p
@@ -407,6 +536,55 @@ impl<'a> TypedPath<'a> {
         }
     }
 
+    /// Like [`TypedPath::starts_with`], but compares against an iterator of individual components
+    /// rather than a path.
+    ///
+    /// See [`Path::starts_with_components`] for more details.
+    ///
+    /// [`Path::starts_with_components`]: crate::Path::starts_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc/ssh/sshd_config");
+    /// assert!(path.starts_with_components(["/", "etc", "ssh"]));
+    /// assert!(!path.starts_with_components(["/", "etc/ssh"]));
+    /// ```
+    pub fn starts_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        impl_typed_fn!(self, starts_with_components, components)
+    }
+
+    /// Like [`TypedPath::ends_with`], but compares against an iterator of individual components
+    /// rather than a path.
+    ///
+    /// See [`Path::ends_with_components`] for more details.
+    ///
+    /// [`Path::ends_with_components`]: crate::Path::ends_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive("/etc/ssh/sshd_config");
+    /// assert!(path.ends_with_components(["ssh", "sshd_config"]));
+    /// assert!(!path.ends_with_components(["ssh/sshd_config"]));
+    /// ```
+    pub fn ends_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        impl_typed_fn!(self, ends_with_components, components)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: TypedPath::file_name
@@ -532,7 +710,7 @@ impl<'a> TypedPath<'a> {
     /// let path = cwd.join("a/b/../c/./d");
     /// assert_eq!(path.absolutize().unwrap(), cwd.join("a/c/d"));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     pub fn absolutize(&self) -> io::Result<TypedPathBuf> {
         Ok(match self {
             Self::Unix(path) => TypedPathBuf::Unix(path.absolutize()?),
@@ -618,6 +796,13 @@ impl<'a> TypedPath<'a> {
     ///
     /// let path = TypedPath::derive("/tmp");
     /// assert_eq!(path.with_file_name("var"), TypedPathBuf::from("/var"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = TypedPath::unix(r"C:\tmp\foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), TypedPathBuf::from_unix("bar.txt"));
+    ///
+    /// let path = TypedPath::windows("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), TypedPathBuf::from_windows(r"\tmp\bar.txt"));
     /// ```
     pub fn with_file_name<S: AsRef<[u8]>>(&self, file_name: S) -> TypedPathBuf {
         match self {
@@ -642,6 +827,13 @@ impl<'a> TypedPath<'a> {
     /// assert_eq!(path.with_extension(""), TypedPathBuf::from("foo.tar"));
     /// assert_eq!(path.with_extension("xz"), TypedPathBuf::from("foo.tar.xz"));
     /// assert_eq!(path.with_extension("").with_extension("txt"), TypedPathBuf::from("foo.txt"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = TypedPath::unix(r"C:\tmp\foo.rs");
+    /// assert_eq!(path.with_extension("txt"), TypedPathBuf::from_unix(r"C:\tmp\foo.txt"));
+    ///
+    /// let path = TypedPath::windows("/tmp/foo.rs");
+    /// assert_eq!(path.with_extension("txt"), TypedPathBuf::from_windows(r"\tmp\foo.txt"));
     /// ```
     pub fn with_extension<S: AsRef<[u8]>>(&self, extension: S) -> TypedPathBuf {
         match self {
@@ -650,6 +842,25 @@ impl<'a> TypedPath<'a> {
         }
     }
 
+    /// Creates an owned [`TypedPathBuf`] like `self` but with its extension converted to lowercase.
+    ///
+    /// See [`TypedPathBuf::lowercase_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedPath, TypedPathBuf};
+    ///
+    /// let path = TypedPath::derive("IMG_0001.JPG");
+    /// assert_eq!(path.with_lowercase_extension(), TypedPathBuf::from("IMG_0001.jpg"));
+    /// ```
+    pub fn with_lowercase_extension(&self) -> TypedPathBuf {
+        match self {
+            Self::Unix(path) => TypedPathBuf::Unix(path.with_lowercase_extension()),
+            Self::Windows(path) => TypedPathBuf::Windows(path.with_lowercase_extension()),
+        }
+    }
+
     /// Produces an iterator over the [`TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -797,6 +1008,43 @@ impl<'a> TypedPath<'a> {
             Self::Windows(p) => TypedPathBuf::Windows(p.with_windows_encoding_checked()?),
         })
     }
+
+    /// Converts this [`TypedPath`] into a [`Utf8TypedPath`] of the same type without copying,
+    /// checking that the path contains valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the path is not UTF-8 with a description as to why the
+    /// provided component is not UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// let path = TypedPath::derive(r"C:\tmp\💖");
+    /// let utf8_path = path.as_utf8().unwrap();
+    /// assert!(utf8_path.is_windows());
+    /// assert_eq!(utf8_path.as_str(), r"C:\tmp\💖");
+    ///
+    /// assert!(TypedPath::unix(b"/tmp/\xff").as_utf8().is_err());
+    /// ```
+    pub fn as_utf8(&self) -> Result<Utf8TypedPath<'a>, Utf8PathError> {
+        Ok(match *self {
+            Self::Unix(p) => Utf8TypedPath::Unix(Utf8UnixPath::from_bytes_path(p)?),
+            Self::Windows(p) => Utf8TypedPath::Windows(Utf8WindowsPath::from_bytes_path(p)?),
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TypedPath<'_> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Unix(path) => defmt::Format::format(path, f),
+            Self::Windows(path) => defmt::Format::format(path, f),
+        }
+    }
 }
 
 impl<'a> From<&'a [u8]> for TypedPath<'a> {
@@ -838,6 +1086,39 @@ impl TryAsRef<WindowsPath> for TypedPath<'_> {
     }
 }
 
+impl TryAsRef<str> for TypedPath<'_> {
+    /// Views the path as a [`str`], failing if the path is not valid UTF-8.
+    #[inline]
+    fn try_as_ref(&self) -> Option<&str> {
+        self.to_str()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryAsRef<std::path::Path> for TypedPath<'_> {
+    /// Views the path as a [`std::path::Path`], failing if the path is not valid UTF-8 or if
+    /// the path's type does not match the platform being compiled for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TryAsRef, TypedPath};
+    ///
+    /// let path = TypedPath::unix("/tmp/foo.txt");
+    /// let std_path: Option<&std::path::Path> = path.try_as_ref();
+    /// assert_eq!(std_path.is_some(), cfg!(unix));
+    /// ```
+    fn try_as_ref(&self) -> Option<&std::path::Path> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(path) => path.try_as_ref(),
+            #[cfg(windows)]
+            Self::Windows(path) => path.try_as_ref(),
+            _ => None,
+        }
+    }
+}
+
 impl PartialEq<TypedPathBuf> for TypedPath<'_> {
     fn eq(&self, path: &TypedPathBuf) -> bool {
         self.eq(&path.to_path())
@@ -4,16 +4,24 @@ use core::convert::TryFrom;
 #[cfg(feature = "std")]
 use std::{io, path::PathBuf};
 
-use crate::common::{CheckedPathError, StripPrefixError};
+use crate::common::{CheckedPathError, FlavorMismatchError, RootKind, StripPrefixError, TryAsRef};
 use crate::no_std_compat::*;
-use crate::typed::{PathType, TypedAncestors, TypedComponents, TypedIter, TypedPath};
-use crate::unix::{UnixPath, UnixPathBuf};
-use crate::windows::{WindowsPath, WindowsPathBuf};
+use crate::typed::{HostOs, PathType, TypedAncestors, TypedComponents, TypedIter, TypedPath};
+use crate::unix::{UnixEncoding, UnixPath, UnixPathBuf};
+use crate::windows::{WindowsEncoding, WindowsPath, WindowsPathBuf};
 
 /// Represents a pathbuf with a known type that can be one of:
 ///
 /// * [`UnixPathBuf`]
 /// * [`WindowsPathBuf`]
+///
+/// Methods that build a new path from this one, such as [`with_file_name`] and
+/// [`with_extension`], always return the same flavor as `self`, even when the result would be
+/// [derived] as the other flavor on its own.
+///
+/// [`with_file_name`]: TypedPathBuf::with_file_name
+/// [`with_extension`]: TypedPathBuf::with_extension
+/// [derived]: TypedPath::derive
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TypedPathBuf {
     Unix(UnixPathBuf),
@@ -66,6 +74,112 @@ impl TypedPathBuf {
         })
     }
 
+    /// Converts into the underlying [`UnixPathBuf`], or returns an error carrying `self` if this is
+    /// a Windows path.
+    ///
+    /// Unlike the [`TryFrom`] conversion, which returns the original path itself as the error,
+    /// the error here implements [`std::error::Error`] so that it can be propagated with `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathType, TypedPathBuf, UnixPathBuf};
+    ///
+    /// let path = TypedPathBuf::from_unix("/tmp");
+    /// assert_eq!(path.into_unix(), Ok(UnixPathBuf::from("/tmp")));
+    ///
+    /// let err = TypedPathBuf::from_windows(r"C:\tmp").into_unix().unwrap_err();
+    /// assert_eq!(err.expected(), PathType::Unix);
+    /// assert_eq!(err.actual(), PathType::Windows);
+    /// assert_eq!(err.to_string(), "expected unix path, found windows path");
+    /// assert_eq!(err.into_path(), TypedPathBuf::from_windows(r"C:\tmp"));
+    /// ```
+    pub fn into_unix(self) -> Result<UnixPathBuf, FlavorMismatchError<Self>> {
+        match self {
+            Self::Unix(p) => Ok(p),
+            path => Err(FlavorMismatchError {
+                expected: PathType::Unix,
+                actual: PathType::Windows,
+                path,
+            }),
+        }
+    }
+
+    /// Converts into the underlying [`WindowsPathBuf`], or returns an error carrying `self` if this
+    /// is a Unix path.
+    ///
+    /// Unlike the [`TryFrom`] conversion, which returns the original path itself as the error,
+    /// the error here implements [`std::error::Error`] so that it can be propagated with `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathType, TypedPathBuf, WindowsPathBuf};
+    ///
+    /// let path = TypedPathBuf::from_windows(r"C:\tmp");
+    /// assert_eq!(path.into_windows(), Ok(WindowsPathBuf::from(r"C:\tmp")));
+    ///
+    /// let err = TypedPathBuf::from_unix("/tmp").into_windows().unwrap_err();
+    /// assert_eq!(err.expected(), PathType::Windows);
+    /// assert_eq!(err.actual(), PathType::Unix);
+    /// assert_eq!(err.into_path(), TypedPathBuf::from_unix("/tmp"));
+    /// ```
+    pub fn into_windows(self) -> Result<WindowsPathBuf, FlavorMismatchError<Self>> {
+        match self {
+            Self::Windows(p) => Ok(p),
+            path => Err(FlavorMismatchError {
+                expected: PathType::Windows,
+                actual: PathType::Unix,
+                path,
+            }),
+        }
+    }
+
+    /// Applies `f` to the path if it is a Unix path, leaving a Windows path untouched.
+    ///
+    /// This allows flavor-specific changes to be expressed without matching on every variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_unix("/home/user/bashrc").map_unix(|p| p.with_hidden());
+    /// assert_eq!(path, TypedPathBuf::from_unix("/home/user/.bashrc"));
+    ///
+    /// let path =
+    ///     TypedPathBuf::from_windows(r"C:\Users\user\bashrc").map_unix(|p| p.with_hidden());
+    /// assert_eq!(path, TypedPathBuf::from_windows(r"C:\Users\user\bashrc"));
+    /// ```
+    pub fn map_unix(self, f: impl FnOnce(UnixPathBuf) -> UnixPathBuf) -> Self {
+        match self {
+            Self::Unix(p) => Self::Unix(f(p)),
+            Self::Windows(p) => Self::Windows(p),
+        }
+    }
+
+    /// Applies `f` to the path if it is a Windows path, leaving a Unix path untouched.
+    ///
+    /// This allows flavor-specific changes to be expressed without matching on every variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_windows(r"C:\tmp").map_windows(|path| path.join("foo"));
+    /// assert_eq!(path, TypedPathBuf::from_windows(r"C:\tmp\foo"));
+    ///
+    /// let path = TypedPathBuf::from_unix("/tmp").map_windows(|path| path.join("foo"));
+    /// assert_eq!(path, TypedPathBuf::from_unix("/tmp"));
+    /// ```
+    pub fn map_windows(self, f: impl FnOnce(WindowsPathBuf) -> WindowsPathBuf) -> Self {
+        match self {
+            Self::Unix(p) => Self::Unix(p),
+            Self::Windows(p) => Self::Windows(f(p)),
+        }
+    }
+
     /// Allocates an empty [`TypedPathBuf`] for the specified path type.
     ///
     /// # Examples
@@ -119,8 +233,172 @@ impl TypedPathBuf {
         Self::Windows(WindowsPathBuf::from(s.as_ref()))
     }
 
+    /// Creates a new [`TypedPathBuf`] with the flavor used by `host`, rather than guessing it from
+    /// the contents of the path as [`TypedPath::derive`] does.
+    ///
+    /// [`TypedPath::derive`]: crate::TypedPath::derive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{HostOs, TypedPathBuf};
+    ///
+    /// // A relative path with backslashes would be assumed to be a Unix path
+    /// let path = TypedPathBuf::for_host(HostOs::Windows, r"logs\today.txt");
+    /// assert!(path.is_windows());
+    /// assert_eq!(path.file_name(), Some("today.txt".as_ref()));
+    ///
+    /// let path = TypedPathBuf::for_host(HostOs::Linux, r"logs\today.txt");
+    /// assert!(path.is_unix());
+    /// ```
+    pub fn for_host(host: HostOs, s: impl AsRef<[u8]>) -> Self {
+        match host.path_type() {
+            PathType::Unix => Self::from_unix(s),
+            PathType::Windows => Self::from_windows(s),
+        }
+    }
+
+    /// Creates a new [`TypedPathBuf`] as a Unix path by pushing each segment from `iter` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::collect_unix(["/tmp", "foo", "bar.txt"]);
+    /// assert!(path.is_unix());
+    /// assert_eq!(path.as_bytes(), b"/tmp/foo/bar.txt");
+    /// ```
+    pub fn collect_unix<I, P>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let mut buf = Self::unix();
+        for path in iter {
+            buf.push(path);
+        }
+        buf
+    }
+
+    /// Creates a new [`TypedPathBuf`] as a Windows path by pushing each segment from `iter` in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::collect_windows(["tmp", "foo", "bar.txt"]);
+    /// assert!(path.is_windows());
+    /// assert_eq!(path.as_bytes(), br"tmp\foo\bar.txt");
+    /// ```
+    pub fn collect_windows<I, P>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let mut buf = Self::windows();
+        for path in iter {
+            buf.push(path);
+        }
+        buf
+    }
+
+    /// Creates a new [`TypedPathBuf`] from a [`std::path::Path`], using the Windows type when
+    /// compiling for Windows and the Unix type everywhere else.
+    ///
+    /// On Unix-like platforms, the bytes of the path are copied as-is. On Windows, the path is
+    /// stored in its [WTF-8] encoding, so unpaired surrogates are kept and
+    /// [`to_std_path`](TypedPathBuf::to_std_path) restores them.
+    ///
+    /// [WTF-8]: https://simonsapin.github.io/wtf-8/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_std_path(std::path::Path::new("foo.txt"));
+    /// assert_eq!(path.is_windows(), cfg!(windows));
+    /// assert_eq!(path.as_bytes(), b"foo.txt");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_std_path(path: impl AsRef<std::path::Path>) -> Self {
+        let path = path.as_ref();
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+
+            let bytes = crate::windows::wtf8::from_wide(path.as_os_str().encode_wide());
+            Self::Windows(WindowsPathBuf::from(bytes))
+        }
+
+        #[cfg(all(
+            not(windows),
+            any(
+                unix,
+                all(target_vendor = "fortanix", target_env = "sgx"),
+                target_os = "solid_asp3",
+                target_os = "hermit",
+                target_os = "wasi"
+            )
+        ))]
+        {
+            Self::Unix(AsRef::<UnixPath>::as_ref(path.as_os_str()).to_path_buf())
+        }
+
+        #[cfg(not(any(
+            windows,
+            unix,
+            all(target_vendor = "fortanix", target_env = "sgx"),
+            target_os = "solid_asp3",
+            target_os = "hermit",
+            target_os = "wasi"
+        )))]
+        {
+            Self::Unix(UnixPathBuf::from(path.to_string_lossy().as_ref()))
+        }
+    }
+
+    /// Converts into a [`std::path::PathBuf`] if the type of this path matches the one chosen by
+    /// [`TypedPathBuf::from_std_path`] for the platform being compiled for, returning `None`
+    /// otherwise.
+    ///
+    /// On Windows, the path is decoded as WTF-8, so unpaired surrogates kept by
+    /// [`TypedPathBuf::from_std_path`] are restored, and `None` is also returned if the path is
+    /// not valid WTF-8. On Unix-like platforms, the bytes of the path are passed through as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_unix("/tmp/foo.txt");
+    /// assert_eq!(path.to_std_path().is_some(), !cfg!(windows));
+    ///
+    /// let path = TypedPathBuf::from_windows(r"C:\tmp\foo.txt");
+    /// assert_eq!(path.to_std_path().is_some(), cfg!(windows));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_std_path(&self) -> Option<PathBuf> {
+        match self {
+            #[cfg(not(windows))]
+            Self::Unix(path) => Some(path.to_std_path_lossy()),
+            #[cfg(windows)]
+            Self::Windows(path) => {
+                use std::os::windows::ffi::OsStringExt;
+
+                let wide = crate::windows::wtf8::to_wide(path.as_bytes())?;
+                Some(PathBuf::from(std::ffi::OsString::from_wide(&wide)))
+            }
+            _ => None,
+        }
+    }
+
     /// Converts into a [`TypedPath`].
-    pub fn to_path(&self) -> TypedPath {
+    pub fn to_path(&self) -> TypedPath<'_> {
         match self {
             Self::Unix(path) => TypedPath::Unix(path.as_path()),
             Self::Windows(path) => TypedPath::Windows(path.as_path()),
@@ -149,6 +427,13 @@ impl TypedPathBuf {
     ///
     /// [`PathBuf::push`]: crate::PathBuf::push
     ///
+    /// # Flavor of `path`
+    ///
+    /// `path` is always parsed using the flavor of `self` and is never converted. Passing a
+    /// [`TypedPath`] of the other flavor, which implements `AsRef<[u8]>`, reinterprets its raw
+    /// value: a Windows path like `C:\temp` becomes a single normal component when pushed onto a
+    /// Unix path. Use [`TypedPathBuf::push_typed_checked`] to push a path of either flavor safely.
+    ///
     /// # Examples
     ///
     /// Pushing a relative path extends the existing path:
@@ -194,6 +479,13 @@ impl TypedPathBuf {
     ///
     /// [`PathBuf::push_checked`]: crate::PathBuf::push_checked
     ///
+    /// # Flavor of `path`
+    ///
+    /// `path` is always parsed using the flavor of `self` and is never converted. Passing a
+    /// [`TypedPath`] of the other flavor, which implements `AsRef<[u8]>`, reinterprets its raw
+    /// value: a Windows path like `C:\temp` becomes a single normal component when pushed onto a
+    /// Unix path. Use [`TypedPathBuf::push_typed_checked`] to push a path of either flavor safely.
+    ///
     /// # Examples
     ///
     /// Pushing a relative path extends the existing path:
@@ -239,6 +531,72 @@ impl TypedPathBuf {
         }
     }
 
+    /// Like [`TypedPathBuf::push_checked`], extends `self` with `path` while enforcing the same
+    /// rules, but accepts a [`TypedPath`] of either flavor.
+    ///
+    /// # Conversion
+    ///
+    /// When `path` has the same flavor as `self`, this is identical to
+    /// [`TypedPathBuf::push_checked`]. Otherwise, `path` is first converted with
+    /// [`with_encoding_checked`], which maps separators, `.`, and `..` to the flavor of `self`
+    /// and fails if a component is not a valid filename in that flavor. A path of the other
+    /// flavor is never allowed to introduce a root or prefix:
+    ///
+    /// * A Windows path with a prefix (e.g. `C:temp`) fails with
+    ///   [`CheckedPathError::UnexpectedPrefix`] instead of having its prefix dropped.
+    /// * A path with a root (e.g. `\temp` or `/temp`) fails with
+    ///   [`CheckedPathError::UnexpectedRoot`] instead of replacing `self`.
+    /// * A Unix path whose first component looks like a Windows prefix (e.g. `c:/temp`) fails
+    ///   with [`CheckedPathError::UnexpectedPrefix`].
+    ///
+    /// On failure, `self` is left unchanged.
+    ///
+    /// [`with_encoding_checked`]: crate::Path::with_encoding_checked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPath, TypedPathBuf};
+    ///
+    /// let mut path = TypedPathBuf::from_unix("/tmp");
+    ///
+    /// // Windows separators are converted to Unix ones
+    /// assert!(path.push_typed_checked(TypedPath::windows(r"dir\file.txt")).is_ok());
+    /// assert_eq!(path, TypedPathBuf::from_unix("/tmp/dir/file.txt"));
+    ///
+    /// // Prefixes and roots from the other flavor are rejected
+    /// assert_eq!(
+    ///     path.push_typed_checked(TypedPath::windows("C:temp")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// assert_eq!(
+    ///     path.push_typed_checked(TypedPath::windows(r"\temp")),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// assert_eq!(path, TypedPathBuf::from_unix("/tmp/dir/file.txt"));
+    ///
+    /// let mut path = TypedPathBuf::from_windows(r"C:\tmp");
+    /// assert_eq!(
+    ///     path.push_typed_checked(TypedPath::unix("c:/temp")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn push_typed_checked(&mut self, path: TypedPath<'_>) -> Result<(), CheckedPathError> {
+        match (self, path) {
+            (Self::Unix(a), TypedPath::Unix(b)) => a.push_checked(b),
+            (Self::Windows(a), TypedPath::Windows(b)) => a.push_checked(b),
+            (Self::Unix(a), TypedPath::Windows(b)) => {
+                if b.components().has_prefix() {
+                    return Err(CheckedPathError::UnexpectedPrefix);
+                }
+                a.push_checked(b.with_encoding_checked::<UnixEncoding>()?)
+            }
+            (Self::Windows(a), TypedPath::Unix(b)) => {
+                a.push_checked(b.with_encoding_checked::<WindowsEncoding>()?)
+            }
+        }
+    }
+
     /// Truncates `self` to [`self.parent`].
     ///
     /// Returns `false` and does nothing if [`self.parent`] is [`None`].
@@ -291,6 +649,30 @@ impl TypedPathBuf {
         impl_typed_fn!(self, set_file_name, file_name)
     }
 
+    /// Like [`TypedPathBuf::set_file_name`], but returns an error instead of introducing additional
+    /// components when `file_name` is not a single, valid file name.
+    ///
+    /// See [`PathBuf::try_set_file_name`] for more details.
+    ///
+    /// [`PathBuf::try_set_file_name`]: crate::PathBuf::try_set_file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPathBuf};
+    ///
+    /// let mut buf = TypedPathBuf::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_file_name("bar.txt"), Ok(()));
+    /// assert_eq!(buf.try_set_file_name("../bar.txt"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, TypedPathBuf::from("/tmp/bar.txt"));
+    /// ```
+    pub fn try_set_file_name<S: AsRef<[u8]>>(
+        &mut self,
+        file_name: S,
+    ) -> Result<(), CheckedPathError> {
+        impl_typed_fn!(self, try_set_file_name, file_name)
+    }
+
     /// Updates [`self.extension`] to `extension`.
     ///
     /// Returns `false` and does nothing if [`self.file_name`] is [`None`],
@@ -319,6 +701,68 @@ impl TypedPathBuf {
         impl_typed_fn!(self, set_extension, extension)
     }
 
+    /// Like [`TypedPathBuf::set_extension`], but returns an error if `extension` would not leave
+    /// the file name as a single, valid file name.
+    ///
+    /// See [`PathBuf::try_set_extension`] for more details.
+    ///
+    /// [`PathBuf::try_set_extension`]: crate::PathBuf::try_set_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, TypedPathBuf};
+    ///
+    /// let mut buf = TypedPathBuf::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_extension("rs"), Ok(true));
+    /// assert_eq!(buf.try_set_extension("rs/evil"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, TypedPathBuf::from("/tmp/foo.rs"));
+    /// ```
+    pub fn try_set_extension<S: AsRef<[u8]>>(
+        &mut self,
+        extension: S,
+    ) -> Result<bool, CheckedPathError> {
+        impl_typed_fn!(self, try_set_extension, extension)
+    }
+
+    /// Converts the extension to lowercase in place.
+    ///
+    /// See [`PathBuf::lowercase_extension`] for more details.
+    ///
+    /// [`PathBuf::lowercase_extension`]: crate::PathBuf::lowercase_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let mut p = TypedPathBuf::from("/photos/IMG_0001.JPG");
+    /// assert!(p.lowercase_extension());
+    /// assert_eq!(p, TypedPathBuf::from("/photos/IMG_0001.jpg"));
+    /// ```
+    pub fn lowercase_extension(&mut self) -> bool {
+        impl_typed_fn!(self, lowercase_extension)
+    }
+
+    /// Converts into a [`std::path::PathBuf`], returning the original path if it is not valid
+    /// UTF-8 or if its type does not match the platform being compiled for.
+    ///
+    /// This is equivalent to `TryFrom<TypedPathBuf>` for [`std::path::PathBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from_unix("/tmp/foo.txt");
+    /// assert_eq!(path.try_into_std_pathbuf().is_ok(), cfg!(unix));
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn try_into_std_pathbuf(self) -> Result<PathBuf, Self> {
+        PathBuf::try_from(self)
+    }
+
     /// Consumes the [`TypedPathBuf`], yielding its internal [`Vec<u8>`] storage.
     ///
     /// # Examples
@@ -416,6 +860,51 @@ impl TypedPathBuf {
         impl_typed_fn!(self, as_bytes)
     }
 
+    /// Returns `true` if the path contains no bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert!(TypedPathBuf::from("").is_empty());
+    /// assert!(!TypedPathBuf::from(r"C:\foo.txt").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        impl_typed_fn!(self, is_empty)
+    }
+
+    /// Returns the length of the path in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert_eq!(TypedPathBuf::from(r"C:\foo.txt").len(), 10);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        impl_typed_fn!(self, len)
+    }
+
+    /// Returns the number of [`components`](TypedPathBuf::components) in the path.
+    ///
+    /// This parses the path each time that it is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert_eq!(TypedPathBuf::from(r"C:\foo.txt").components_len(), 3);
+    /// assert_eq!(TypedPathBuf::from("").components_len(), 0);
+    /// ```
+    pub fn components_len(&self) -> usize {
+        impl_typed_fn!(self, components_len)
+    }
+
     /// Yields a [`&str`] slice if the [`TypedPathBuf`] is valid unicode.
     ///
     /// This conversion may entail doing a check for UTF-8 validity.
@@ -529,6 +1018,21 @@ impl TypedPathBuf {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Classifies how the [`TypedPathBuf`] is anchored. See [`RootKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RootKind, TypedPathBuf};
+    ///
+    /// assert_eq!(TypedPathBuf::from("/etc/passwd").root_kind(), RootKind::Absolute);
+    /// assert_eq!(TypedPathBuf::from(r"C:foo").root_kind(), RootKind::DriveRelative);
+    /// ```
+    #[inline]
+    pub fn root_kind(&self) -> RootKind {
+        impl_typed_fn!(self, root_kind)
+    }
+
     /// Returns the [`TypedPathBuf`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -546,7 +1050,7 @@ impl TypedPathBuf {
     /// assert_eq!(grand_parent, TypedPathBuf::from("/"));
     /// assert_eq!(grand_parent.parent(), None);
     /// ```
-    pub fn parent(&self) -> Option<TypedPath> {
+    pub fn parent(&self) -> Option<TypedPath<'_>> {
         self.to_path().parent()
     }
 
@@ -581,7 +1085,7 @@ impl TypedPathBuf {
     ///
     /// [`parent`]: TypedPathBuf::parent
     #[inline]
-    pub fn ancestors(&self) -> TypedAncestors {
+    pub fn ancestors(&self) -> TypedAncestors<'_> {
         self.to_path().ancestors()
     }
 
@@ -608,6 +1112,23 @@ impl TypedPathBuf {
         impl_typed_fn!(self, file_name)
     }
 
+    /// Returns true if the [`file_name`] begins with a `.`, which is how Unix-style tools mark
+    /// a file or directory as hidden.
+    ///
+    /// [`file_name`]: TypedPathBuf::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// assert!(TypedPathBuf::from("/home/user/.bashrc").file_name_is_dotfile());
+    /// assert!(!TypedPathBuf::from("/home/user").file_name_is_dotfile());
+    /// ```
+    pub fn file_name_is_dotfile(&self) -> bool {
+        impl_typed_fn!(self, file_name_is_dotfile)
+    }
+
     /// Returns a path that, when joined onto `base`, yields `self`.
     ///
     /// # Errors
@@ -636,7 +1157,7 @@ impl TypedPathBuf {
     /// let prefix = TypedPathBuf::from("/test/");
     /// assert_eq!(path.strip_prefix(prefix), Ok(TypedPath::derive("haha/foo.txt")));
     /// ```
-    pub fn strip_prefix(&self, base: impl AsRef<[u8]>) -> Result<TypedPath, StripPrefixError> {
+    pub fn strip_prefix(&self, base: impl AsRef<[u8]>) -> Result<TypedPath<'_>, StripPrefixError> {
         match self {
             Self::Unix(p) => p.strip_prefix(UnixPath::new(&base)).map(TypedPath::Unix),
             Self::Windows(p) => p
@@ -707,6 +1228,55 @@ impl TypedPathBuf {
         self.to_path().ends_with(child)
     }
 
+    /// Like [`TypedPathBuf::starts_with`], but compares against an iterator of individual
+    /// components rather than a path.
+    ///
+    /// See [`Path::starts_with_components`] for more details.
+    ///
+    /// [`Path::starts_with_components`]: crate::Path::starts_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/etc/ssh/sshd_config");
+    /// assert!(path.starts_with_components(["/", "etc", "ssh"]));
+    /// assert!(!path.starts_with_components(["/", "etc/ssh"]));
+    /// ```
+    pub fn starts_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        self.to_path().starts_with_components(components)
+    }
+
+    /// Like [`TypedPathBuf::ends_with`], but compares against an iterator of individual components
+    /// rather than a path.
+    ///
+    /// See [`Path::ends_with_components`] for more details.
+    ///
+    /// [`Path::ends_with_components`]: crate::Path::ends_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("/etc/ssh/sshd_config");
+    /// assert!(path.ends_with_components(["ssh", "sshd_config"]));
+    /// assert!(!path.ends_with_components(["ssh/sshd_config"]));
+    /// ```
+    pub fn ends_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        self.to_path().ends_with_components(components)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: TypedPathBuf::file_name
@@ -828,7 +1398,7 @@ impl TypedPathBuf {
     /// let path = cwd.join("a/b/../c/./d");
     /// assert_eq!(path.absolutize().unwrap(), cwd.join("a/c/d"));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     pub fn absolutize(&self) -> io::Result<TypedPathBuf> {
         self.to_path().absolutize()
     }
@@ -907,6 +1477,13 @@ impl TypedPathBuf {
     ///
     /// let path = TypedPathBuf::from("/tmp");
     /// assert_eq!(path.with_file_name("var"), TypedPathBuf::from("/var"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = TypedPathBuf::from_unix(r"C:\tmp\foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), TypedPathBuf::from_unix("bar.txt"));
+    ///
+    /// let path = TypedPathBuf::from_windows("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), TypedPathBuf::from_windows(r"\tmp\bar.txt"));
     /// ```
     pub fn with_file_name<S: AsRef<[u8]>>(&self, file_name: S) -> TypedPathBuf {
         self.to_path().with_file_name(file_name)
@@ -928,11 +1505,34 @@ impl TypedPathBuf {
     /// assert_eq!(path.with_extension(""), TypedPathBuf::from("foo.tar"));
     /// assert_eq!(path.with_extension("xz"), TypedPathBuf::from("foo.tar.xz"));
     /// assert_eq!(path.with_extension("").with_extension("txt"), TypedPathBuf::from("foo.txt"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = TypedPathBuf::from_unix(r"C:\tmp\foo.rs");
+    /// assert_eq!(path.with_extension("txt"), TypedPathBuf::from_unix(r"C:\tmp\foo.txt"));
+    ///
+    /// let path = TypedPathBuf::from_windows("/tmp/foo.rs");
+    /// assert_eq!(path.with_extension("txt"), TypedPathBuf::from_windows(r"\tmp\foo.txt"));
     /// ```
     pub fn with_extension<S: AsRef<[u8]>>(&self, extension: S) -> TypedPathBuf {
         self.to_path().with_extension(extension)
     }
 
+    /// Creates an owned [`TypedPathBuf`] like `self` but with its extension converted to lowercase.
+    ///
+    /// See [`TypedPathBuf::lowercase_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path = TypedPathBuf::from("IMG_0001.JPG");
+    /// assert_eq!(path.with_lowercase_extension(), TypedPathBuf::from("IMG_0001.jpg"));
+    /// ```
+    pub fn with_lowercase_extension(&self) -> TypedPathBuf {
+        self.to_path().with_lowercase_extension()
+    }
+
     /// Produces an iterator over the [`TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -966,7 +1566,7 @@ impl TypedPathBuf {
     /// ```
     ///
     /// [`TypedComponent`]: crate::TypedComponent
-    pub fn components(&self) -> TypedComponents {
+    pub fn components(&self) -> TypedComponents<'_> {
         self.to_path().components()
     }
 
@@ -991,11 +1591,21 @@ impl TypedPathBuf {
     /// assert_eq!(it.next(), None)
     /// ```
     #[inline]
-    pub fn iter(&self) -> TypedIter {
+    pub fn iter(&self) -> TypedIter<'_> {
         self.to_path().iter()
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for TypedPathBuf {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Unix(path) => defmt::Format::format(path, f),
+            Self::Windows(path) => defmt::Format::format(path, f),
+        }
+    }
+}
+
 impl AsRef<[u8]> for TypedPathBuf {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -1049,6 +1659,43 @@ impl From<Vec<u8>> for TypedPathBuf {
     }
 }
 
+impl<P> FromIterator<P> for TypedPathBuf
+where
+    P: AsRef<[u8]>,
+{
+    /// Creates a new typed pathbuf by pushing each segment from `iter` in order. The type of the
+    /// path is derived from the first segment the same way as [`TypedPathBuf::from`], and an empty
+    /// iterator produces an empty Unix path.
+    ///
+    /// Use [`TypedPathBuf::collect_unix`] or [`TypedPathBuf::collect_windows`] to pick the type
+    /// explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPathBuf;
+    ///
+    /// let path: TypedPathBuf = [r"C:\", "foo", "bar.txt"].into_iter().collect();
+    /// assert!(path.is_windows());
+    /// assert_eq!(path.as_bytes(), br"C:\foo\bar.txt");
+    ///
+    /// let path: TypedPathBuf = "/tmp/foo/bar.txt".split_inclusive('/').collect();
+    /// assert!(path.is_unix());
+    /// assert_eq!(path.as_bytes(), b"/tmp/foo/bar.txt");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut buf = match iter.next() {
+            Some(first) => TypedPathBuf::from(first.as_ref()),
+            None => return TypedPathBuf::unix(),
+        };
+        for path in iter {
+            buf.push(path);
+        }
+        buf
+    }
+}
+
 impl<'a> From<&'a str> for TypedPathBuf {
     #[inline]
     fn from(s: &'a str) -> Self {
@@ -1094,6 +1741,12 @@ impl TryFrom<TypedPathBuf> for WindowsPathBuf {
 impl TryFrom<TypedPathBuf> for PathBuf {
     type Error = TypedPathBuf;
 
+    /// Attempts to convert a [`TypedPathBuf`] into a [`std::path::PathBuf`], returning the
+    /// original path when it is not valid UTF-8 or when its type is not native to the platform
+    /// being compiled for.
+    ///
+    /// See [`TypedPathBuf::to_std_path`] for a conversion that passes bytes through as-is on
+    /// Unix-like platforms.
     fn try_from(path: TypedPathBuf) -> Result<Self, Self::Error> {
         match path {
             #[cfg(unix)]
@@ -1105,8 +1758,65 @@ impl TryFrom<TypedPathBuf> for PathBuf {
     }
 }
 
+impl TryAsRef<str> for TypedPathBuf {
+    /// Views the path as a [`str`], failing if the path is not valid UTF-8.
+    #[inline]
+    fn try_as_ref(&self) -> Option<&str> {
+        self.to_str()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryAsRef<std::path::Path> for TypedPathBuf {
+    /// Views the path as a [`std::path::Path`], failing if the path is not valid UTF-8 or if
+    /// the path's type does not match the platform being compiled for.
+    fn try_as_ref(&self) -> Option<&std::path::Path> {
+        match self {
+            #[cfg(unix)]
+            Self::Unix(path) => path.try_as_ref(),
+            #[cfg(windows)]
+            Self::Windows(path) => path.try_as_ref(),
+            _ => None,
+        }
+    }
+}
+
 impl PartialEq<TypedPath<'_>> for TypedPathBuf {
     fn eq(&self, path: &TypedPath<'_>) -> bool {
         path.eq(&self.to_path())
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_std_path_should_round_trip_through_from_std_path() {
+        let std_path = std::path::Path::new("dir").join("file.txt");
+        let path = TypedPathBuf::from_std_path(&std_path);
+        assert_eq!(path.to_std_path(), Some(std_path));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn to_std_path_should_round_trip_unpaired_surrogates() {
+        use std::ffi::OsString;
+        use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+        let wide = [
+            u16::from(b'C'),
+            u16::from(b':'),
+            u16::from(b'\\'),
+            0xd800,
+            u16::from(b'x'),
+        ];
+        let std_path = PathBuf::from(OsString::from_wide(&wide));
+
+        let path = TypedPathBuf::from_std_path(&std_path);
+        assert_eq!(path.as_bytes(), b"C:\\\xed\xa0\x80x");
+
+        let std_path = path.to_std_path().unwrap();
+        assert_eq!(std_path.as_os_str().encode_wide().collect::<Vec<_>>(), wide);
+    }
+}
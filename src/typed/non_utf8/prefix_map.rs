@@ -0,0 +1,230 @@
+use core::fmt;
+
+use crate::no_std_compat::*;
+use crate::typed::{TypedPath, TypedPathBuf};
+use crate::{Component, Encoding, PathBuf};
+
+/// An ordered table of prefix rewrites, mapping paths under a source prefix of either flavor to
+/// the same location under a target prefix of encoding `T`.
+///
+/// This models mount points and deployment layouts, where a tree such as `/var/www` on one host
+/// lives at `C:\sites` on another. When several source prefixes match a path, the one with the
+/// most components wins, and ties go to the rule that was inserted first. The portion of the
+/// path after the matched prefix is converted to `T` as with [`Path::with_encoding`].
+///
+/// A source prefix only matches paths of the same flavor, and prefixes are matched by
+/// component as with [`Path::starts_with`], so `/var/www` does not match `/var/www2`.
+///
+/// [`Path::with_encoding`]: crate::Path::with_encoding
+/// [`Path::starts_with`]: crate::Path::starts_with
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{PrefixMap, TypedPath, WindowsEncoding, WindowsPathBuf};
+///
+/// let mut map = PrefixMap::<WindowsEncoding>::new();
+/// map.insert(TypedPath::unix("/var/www"), r"C:\sites");
+/// map.insert(TypedPath::unix("/var/www/static"), r"D:\cdn");
+/// map.insert(TypedPath::windows(r"\\fileserver\share"), r"S:\");
+///
+/// assert_eq!(
+///     map.rewrite(TypedPath::unix("/var/www/blog/index.html")),
+///     Some(WindowsPathBuf::from(r"C:\sites\blog\index.html")),
+/// );
+/// assert_eq!(
+///     map.rewrite(TypedPath::unix("/var/www/static/app.js")),
+///     Some(WindowsPathBuf::from(r"D:\cdn\app.js")),
+/// );
+/// assert_eq!(
+///     map.rewrite(TypedPath::windows(r"\\fileserver\share\report.docx")),
+///     Some(WindowsPathBuf::from(r"S:\report.docx")),
+/// );
+///
+/// // Prefixes must match whole components and have the same flavor
+/// assert_eq!(map.rewrite(TypedPath::unix("/var/www2/index.html")), None);
+/// assert_eq!(map.rewrite(TypedPath::windows(r"\var\www\index.html")), None);
+/// ```
+pub struct PrefixMap<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    rules: Vec<(TypedPathBuf, PathBuf<T>)>,
+}
+
+impl<T> PrefixMap<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    /// Creates an empty `PrefixMap`.
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Returns the number of rules in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if the map has no rules.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Adds a rule rewriting paths under `from` to be under `to` instead.
+    pub fn insert(&mut self, from: TypedPath<'_>, to: impl Into<PathBuf<T>>) {
+        self.rules.push((from.to_path_buf(), to.into()));
+    }
+
+    /// Rewrites `path` using the rule with the longest source prefix that it starts with,
+    /// returning [`None`] if no rule matches.
+    ///
+    /// [`None`] is also returned if the rest of `path` could land outside of the target prefix,
+    /// meaning it contains a `..` component, or if it cannot be represented in `T` as with
+    /// [`Path::transcode`], such as a Unix name containing `\` or starting with `C:`.
+    ///
+    /// [`Path::transcode`]: crate::Path::transcode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PrefixMap, TypedPath, WindowsEncoding};
+    ///
+    /// let mut map = PrefixMap::<WindowsEncoding>::new();
+    /// map.insert(TypedPath::unix("/var/www"), r"C:\sites");
+    ///
+    /// assert_eq!(map.rewrite(TypedPath::unix("/var/www/../../etc/passwd")), None);
+    /// assert_eq!(map.rewrite(TypedPath::unix("/var/www/D:/secret")), None);
+    /// ```
+    pub fn rewrite(&self, path: TypedPath<'_>) -> Option<PathBuf<T>> {
+        let mut best: Option<(usize, &PathBuf<T>, TypedPath<'_>)> = None;
+
+        for (from, to) in self.rules.iter() {
+            let rest = match (from.to_path(), path) {
+                (TypedPath::Unix(from), TypedPath::Unix(path)) => {
+                    path.strip_prefix(from).ok().map(TypedPath::Unix)
+                }
+                (TypedPath::Windows(from), TypedPath::Windows(path)) => {
+                    path.strip_prefix(from).ok().map(TypedPath::Windows)
+                }
+                _ => None,
+            };
+
+            if let Some(rest) = rest {
+                let depth = from.components().count();
+                if best.as_ref().map_or(true, |(best, _, _)| depth > *best) {
+                    best = Some((depth, to, rest));
+                }
+            }
+        }
+
+        let (_, to, rest) = best?;
+        let rest = match rest {
+            TypedPath::Unix(rest) => rest.transcode::<T>().ok()?,
+            TypedPath::Windows(rest) => rest.transcode::<T>().ok()?,
+        };
+
+        // Push each component rather than joining so that the rest can only descend into `to`
+        let mut rewritten = to.clone();
+        for component in rest.components() {
+            if component.is_root() || component.is_current() {
+                // A root is left behind when the source prefix is just a prefix, such as
+                // `\\server\share`, and does not make the rest absolute
+                continue;
+            }
+
+            if !component.is_normal() {
+                return None;
+            }
+
+            rewritten.push(component.as_bytes());
+        }
+
+        Some(rewritten)
+    }
+}
+
+impl<T> Clone for PrefixMap<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+        }
+    }
+}
+
+impl<T> Default for PrefixMap<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for PrefixMap<T>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.rules.iter().map(|(from, to)| (from, to)))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{UnixEncoding, UnixPathBuf, WindowsEncoding, WindowsPathBuf};
+
+    #[test]
+    fn rewrite_should_prefer_longest_then_first_inserted_prefix() {
+        let mut map = PrefixMap::<UnixEncoding>::new();
+        map.insert(TypedPath::windows(r"C:\Users"), "/home");
+        map.insert(TypedPath::windows(r"C:/Users"), "/ignored");
+        map.insert(TypedPath::windows(r"C:\"), "/mnt/c");
+
+        assert_eq!(
+            map.rewrite(TypedPath::windows(r"C:\Users\alice\notes.txt")),
+            Some(UnixPathBuf::from("/home/alice/notes.txt"))
+        );
+        assert_eq!(
+            map.rewrite(TypedPath::windows(r"C:\Users")),
+            Some(UnixPathBuf::from("/home"))
+        );
+        assert_eq!(
+            map.rewrite(TypedPath::windows(r"C:\Windows\System32")),
+            Some(UnixPathBuf::from("/mnt/c/Windows/System32"))
+        );
+        assert_eq!(map.rewrite(TypedPath::windows(r"D:\data")), None);
+    }
+
+    #[test]
+    fn rewrite_should_reject_rest_that_escapes_the_target_prefix() {
+        let mut map = PrefixMap::<WindowsEncoding>::new();
+        map.insert(TypedPath::unix("/var/www"), r"C:\sites");
+
+        // Read by Windows as a drive-relative path on another drive
+        assert_eq!(map.rewrite(TypedPath::unix("/var/www/D:/secret")), None);
+
+        // Climbs out of the target prefix
+        assert_eq!(
+            map.rewrite(TypedPath::unix("/var/www/../../etc/passwd")),
+            None
+        );
+        assert_eq!(map.rewrite(TypedPath::unix("/var/www/a/../b")), None);
+
+        // A single Unix name that Windows would split in two
+        assert_eq!(map.rewrite(TypedPath::unix(r"/var/www/a\b")), None);
+
+        assert_eq!(
+            map.rewrite(TypedPath::unix("/var/www/./a/b.txt")),
+            Some(WindowsPathBuf::from(r"C:\sites\a\b.txt"))
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use core::fmt;
 
-use crate::common::{CheckedPathError, StripPrefixError, TryAsRef};
+use crate::common::{CheckedPathError, PathId, RootKind, StripPrefixError, TryAsRef};
 use crate::typed::{
-    PathType, Utf8TypedAncestors, Utf8TypedComponents, Utf8TypedIter, Utf8TypedPathBuf,
+    PathType, TypedPath, Utf8TypedAncestors, Utf8TypedComponents, Utf8TypedIter, Utf8TypedPathBuf,
 };
 use crate::unix::Utf8UnixPath;
 use crate::windows::Utf8WindowsPath;
@@ -11,6 +11,14 @@ use crate::windows::Utf8WindowsPath;
 ///
 /// * [`Utf8UnixPath`]
 /// * [`Utf8WindowsPath`]
+///
+/// Methods that build a new path from this one, such as [`with_file_name`] and
+/// [`with_extension`], always return the same flavor as `self`, even when the result would be
+/// [derived] as the other flavor on its own.
+///
+/// [`with_file_name`]: Utf8TypedPath::with_file_name
+/// [`with_extension`]: Utf8TypedPath::with_extension
+/// [derived]: Utf8TypedPath::derive
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Utf8TypedPath<'a> {
     Unix(&'a Utf8UnixPath),
@@ -81,6 +89,51 @@ impl<'a> Utf8TypedPath<'a> {
         impl_typed_fn!(self, as_str)
     }
 
+    /// Returns `true` if the path contains no bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert!(Utf8TypedPath::derive("").is_empty());
+    /// assert!(!Utf8TypedPath::derive(r"C:\foo.txt").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        impl_typed_fn!(self, is_empty)
+    }
+
+    /// Returns the length of the path in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert_eq!(Utf8TypedPath::derive(r"C:\foo.txt").len(), 10);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        impl_typed_fn!(self, len)
+    }
+
+    /// Returns the number of [`components`](Utf8TypedPath::components) in the path.
+    ///
+    /// This parses the path each time that it is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert_eq!(Utf8TypedPath::derive(r"C:\foo.txt").components_len(), 3);
+    /// assert_eq!(Utf8TypedPath::derive("").components_len(), 0);
+    /// ```
+    pub fn components_len(&self) -> usize {
+        impl_typed_fn!(self, components_len)
+    }
+
     /// Converts a [`Utf8TypedPath`] into a [`Utf8TypedPathBuf`].
     ///
     /// # Examples
@@ -165,6 +218,38 @@ impl<'a> Utf8TypedPath<'a> {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Classifies how the [`Utf8TypedPath`] is anchored. See [`RootKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RootKind, Utf8TypedPath};
+    ///
+    /// assert_eq!(Utf8TypedPath::derive("/etc/passwd").root_kind(), RootKind::Absolute);
+    /// assert_eq!(Utf8TypedPath::derive(r"C:foo").root_kind(), RootKind::DriveRelative);
+    /// ```
+    #[inline]
+    pub fn root_kind(&self) -> RootKind {
+        impl_typed_fn!(self, root_kind)
+    }
+
+    /// Returns a compact identifier for the [`Utf8TypedPath`] that is shared by every path of the
+    /// same flavor with the same normalized components. See [`PathId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let unix = Utf8TypedPath::unix("/a/b");
+    /// assert_eq!(unix.stable_id(), Utf8TypedPath::unix("/a/./b/").stable_id());
+    /// assert_ne!(unix.stable_id(), Utf8TypedPath::windows("/a/b").stable_id());
+    /// ```
+    #[inline]
+    pub fn stable_id(&self) -> PathId {
+        impl_typed_fn!(self, stable_id)
+    }
+
     /// Returns the [`Utf8TypedPath`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -225,6 +310,29 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Returns the ancestor `n` levels above the [`Utf8TypedPath`], or [`None`] if that would go
+    /// past the root or the start of a relative path.
+    ///
+    /// See [`Path::ancestor`] for more details.
+    ///
+    /// [`Path::ancestor`]: crate::Path::ancestor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\foo\bar");
+    /// assert_eq!(path.ancestor(1), Some(Utf8TypedPath::derive(r"C:\foo")));
+    /// assert_eq!(path.ancestor(3), None);
+    /// ```
+    pub fn ancestor(&self, n: usize) -> Option<Self> {
+        match self {
+            Self::Unix(path) => path.ancestor(n).map(Self::Unix),
+            Self::Windows(path) => path.ancestor(n).map(Self::Windows),
+        }
+    }
+
     /// Returns the final component of the [`Utf8TypedPath`], if there is one.
     ///
     /// If the path is a normal file, this is the file name. If it's the path of a directory, this
@@ -248,6 +356,23 @@ impl<'a> Utf8TypedPath<'a> {
         impl_typed_fn!(self, file_name)
     }
 
+    /// Returns true if the [`file_name`] begins with a `.`, which is how Unix-style tools mark
+    /// a file or directory as hidden.
+    ///
+    /// [`file_name`]: Utf8TypedPath::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// assert!(Utf8TypedPath::derive("/home/user/.bashrc").file_name_is_dotfile());
+    /// assert!(!Utf8TypedPath::derive("/home/user").file_name_is_dotfile());
+    /// ```
+    pub fn file_name_is_dotfile(&self) -> bool {
+        impl_typed_fn!(self, file_name_is_dotfile)
+    }
+
     /// Returns a path that, when joined onto `base`, yields `self`.
     ///
     /// # Difference from Path
@@ -283,7 +408,10 @@ impl<'a> Utf8TypedPath<'a> {
     /// let prefix = Utf8TypedPathBuf::from("/test/");
     /// assert_eq!(path.strip_prefix(prefix), Ok(Utf8TypedPath::derive("haha/foo.txt")));
     /// ```
-    pub fn strip_prefix(&self, base: impl AsRef<str>) -> Result<Utf8TypedPath, StripPrefixError> {
+    pub fn strip_prefix(
+        &self,
+        base: impl AsRef<str>,
+    ) -> Result<Utf8TypedPath<'_>, StripPrefixError> {
         match self {
             Self::Unix(p) => p
                 .strip_prefix(Utf8UnixPath::new(&base))
@@ -362,6 +490,55 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Like [`Utf8TypedPath::starts_with`], but compares against an iterator of individual
+    /// components rather than a path.
+    ///
+    /// See [`Utf8Path::starts_with_components`] for more details.
+    ///
+    /// [`Utf8Path::starts_with_components`]: crate::Utf8Path::starts_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/etc/ssh/sshd_config");
+    /// assert!(path.starts_with_components(["/", "etc", "ssh"]));
+    /// assert!(!path.starts_with_components(["/", "etc/ssh"]));
+    /// ```
+    pub fn starts_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        impl_typed_fn!(self, starts_with_components, components)
+    }
+
+    /// Like [`Utf8TypedPath::ends_with`], but compares against an iterator of individual components
+    /// rather than a path.
+    ///
+    /// See [`Utf8Path::ends_with_components`] for more details.
+    ///
+    /// [`Utf8Path::ends_with_components`]: crate::Utf8Path::ends_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive("/etc/ssh/sshd_config");
+    /// assert!(path.ends_with_components(["ssh", "sshd_config"]));
+    /// assert!(!path.ends_with_components(["ssh/sshd_config"]));
+    /// ```
+    pub fn ends_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: AsRef<str>,
+    {
+        impl_typed_fn!(self, ends_with_components, components)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: Utf8TypedPath::file_name
@@ -486,7 +663,7 @@ impl<'a> Utf8TypedPath<'a> {
     /// let path = cwd.join("a/b/../c/./d");
     /// assert_eq!(path.absolutize().unwrap(), cwd.join("a/c/d"));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     pub fn absolutize(&self) -> std::io::Result<Utf8TypedPathBuf> {
         Ok(match self {
             Self::Unix(path) => Utf8TypedPathBuf::Unix(path.absolutize()?),
@@ -577,6 +754,13 @@ impl<'a> Utf8TypedPath<'a> {
     ///
     /// let path = Utf8TypedPath::derive("/tmp");
     /// assert_eq!(path.with_file_name("var"), Utf8TypedPathBuf::from("/var"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = Utf8TypedPath::unix(r"C:\tmp\foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Utf8TypedPathBuf::from_unix("bar.txt"));
+    ///
+    /// let path = Utf8TypedPath::windows("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Utf8TypedPathBuf::from_windows(r"\tmp\bar.txt"));
     /// ```
     pub fn with_file_name<S: AsRef<str>>(&self, file_name: S) -> Utf8TypedPathBuf {
         match self {
@@ -601,6 +785,13 @@ impl<'a> Utf8TypedPath<'a> {
     /// assert_eq!(path.with_extension(""), Utf8TypedPathBuf::from("foo.tar"));
     /// assert_eq!(path.with_extension("xz"), Utf8TypedPathBuf::from("foo.tar.xz"));
     /// assert_eq!(path.with_extension("").with_extension("txt"), Utf8TypedPathBuf::from("foo.txt"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = Utf8TypedPath::unix(r"C:\tmp\foo.rs");
+    /// assert_eq!(path.with_extension("txt"), Utf8TypedPathBuf::from_unix(r"C:\tmp\foo.txt"));
+    ///
+    /// let path = Utf8TypedPath::windows("/tmp/foo.rs");
+    /// assert_eq!(path.with_extension("txt"), Utf8TypedPathBuf::from_windows(r"\tmp\foo.txt"));
     /// ```
     pub fn with_extension<S: AsRef<str>>(&self, extension: S) -> Utf8TypedPathBuf {
         match self {
@@ -609,6 +800,26 @@ impl<'a> Utf8TypedPath<'a> {
         }
     }
 
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with its extension converted to
+    /// lowercase.
+    ///
+    /// See [`Utf8TypedPathBuf::lowercase_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let path = Utf8TypedPath::derive("IMG_0001.JPG");
+    /// assert_eq!(path.with_lowercase_extension(), Utf8TypedPathBuf::from("IMG_0001.jpg"));
+    /// ```
+    pub fn with_lowercase_extension(&self) -> Utf8TypedPathBuf {
+        match self {
+            Self::Unix(path) => Utf8TypedPathBuf::Unix(path.with_lowercase_extension()),
+            Self::Windows(path) => Utf8TypedPathBuf::Windows(path.with_lowercase_extension()),
+        }
+    }
+
     /// Produces an iterator over the [`Utf8TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -720,6 +931,25 @@ impl<'a> Utf8TypedPath<'a> {
             Self::Windows(p) => Utf8TypedPathBuf::Windows(p.with_windows_encoding_checked()?),
         })
     }
+
+    /// Converts this [`Utf8TypedPath`] into a [`TypedPath`] of the same type without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPath;
+    ///
+    /// let path = Utf8TypedPath::derive(r"C:\tmp\💖");
+    /// let bytes_path = path.as_bytes_typed();
+    /// assert!(bytes_path.is_windows());
+    /// assert_eq!(bytes_path.as_bytes(), r"C:\tmp\💖".as_bytes());
+    /// ```
+    pub fn as_bytes_typed(&self) -> TypedPath<'a> {
+        match *self {
+            Self::Unix(p) => TypedPath::Unix(p.as_bytes_path()),
+            Self::Windows(p) => TypedPath::Windows(p.as_bytes_path()),
+        }
+    }
 }
 
 impl fmt::Display for Utf8TypedPath<'_> {
@@ -731,6 +961,16 @@ impl fmt::Display for Utf8TypedPath<'_> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Utf8TypedPath<'_> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Unix(path) => defmt::Format::format(path, f),
+            Self::Windows(path) => defmt::Format::format(path, f),
+        }
+    }
+}
+
 impl<'a> From<&'a str> for Utf8TypedPath<'a> {
     #[inline]
     fn from(s: &'a str) -> Self {
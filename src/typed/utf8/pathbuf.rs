@@ -2,18 +2,26 @@ use alloc::collections::TryReserveError;
 use core::convert::TryFrom;
 use core::fmt;
 
-use crate::common::{CheckedPathError, StripPrefixError};
+use crate::common::{CheckedPathError, FlavorMismatchError, RootKind, StripPrefixError};
 use crate::no_std_compat::*;
 use crate::typed::{
-    PathType, Utf8TypedAncestors, Utf8TypedComponents, Utf8TypedIter, Utf8TypedPath,
+    HostOs, PathType, Utf8TypedAncestors, Utf8TypedComponents, Utf8TypedIter, Utf8TypedPath,
 };
-use crate::unix::{Utf8UnixPath, Utf8UnixPathBuf};
-use crate::windows::{Utf8WindowsPath, Utf8WindowsPathBuf};
+use crate::unix::{Utf8UnixEncoding, Utf8UnixPath, Utf8UnixPathBuf};
+use crate::windows::{Utf8WindowsEncoding, Utf8WindowsPath, Utf8WindowsPathBuf};
 
 /// Represents a pathbuf with a known type that can be one of:
 ///
 /// * [`Utf8UnixPathBuf`]
 /// * [`Utf8WindowsPathBuf`]
+///
+/// Methods that build a new path from this one, such as [`with_file_name`] and
+/// [`with_extension`], always return the same flavor as `self`, even when the result would be
+/// [derived] as the other flavor on its own.
+///
+/// [`with_file_name`]: Utf8TypedPathBuf::with_file_name
+/// [`with_extension`]: Utf8TypedPathBuf::with_extension
+/// [derived]: Utf8TypedPath::derive
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Utf8TypedPathBuf {
     Unix(Utf8UnixPathBuf),
@@ -67,6 +75,112 @@ impl Utf8TypedPathBuf {
         })
     }
 
+    /// Converts into the underlying [`Utf8UnixPathBuf`], or returns an error carrying `self` if
+    /// this is a Windows path.
+    ///
+    /// Unlike the [`TryFrom`] conversion, which returns the original path itself as the error,
+    /// the error here implements [`std::error::Error`] so that it can be propagated with `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathType, Utf8TypedPathBuf, Utf8UnixPathBuf};
+    ///
+    /// let path = Utf8TypedPathBuf::from_unix("/tmp");
+    /// assert_eq!(path.into_unix(), Ok(Utf8UnixPathBuf::from("/tmp")));
+    ///
+    /// let err = Utf8TypedPathBuf::from_windows(r"C:\tmp").into_unix().unwrap_err();
+    /// assert_eq!(err.expected(), PathType::Unix);
+    /// assert_eq!(err.actual(), PathType::Windows);
+    /// assert_eq!(err.to_string(), "expected unix path, found windows path");
+    /// assert_eq!(err.into_path(), Utf8TypedPathBuf::from_windows(r"C:\tmp"));
+    /// ```
+    pub fn into_unix(self) -> Result<Utf8UnixPathBuf, FlavorMismatchError<Self>> {
+        match self {
+            Self::Unix(p) => Ok(p),
+            path => Err(FlavorMismatchError {
+                expected: PathType::Unix,
+                actual: PathType::Windows,
+                path,
+            }),
+        }
+    }
+
+    /// Converts into the underlying [`Utf8WindowsPathBuf`], or returns an error carrying `self` if
+    /// this is a Unix path.
+    ///
+    /// Unlike the [`TryFrom`] conversion, which returns the original path itself as the error,
+    /// the error here implements [`std::error::Error`] so that it can be propagated with `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{PathType, Utf8TypedPathBuf, Utf8WindowsPathBuf};
+    ///
+    /// let path = Utf8TypedPathBuf::from_windows(r"C:\tmp");
+    /// assert_eq!(path.into_windows(), Ok(Utf8WindowsPathBuf::from(r"C:\tmp")));
+    ///
+    /// let err = Utf8TypedPathBuf::from_unix("/tmp").into_windows().unwrap_err();
+    /// assert_eq!(err.expected(), PathType::Windows);
+    /// assert_eq!(err.actual(), PathType::Unix);
+    /// assert_eq!(err.into_path(), Utf8TypedPathBuf::from_unix("/tmp"));
+    /// ```
+    pub fn into_windows(self) -> Result<Utf8WindowsPathBuf, FlavorMismatchError<Self>> {
+        match self {
+            Self::Windows(p) => Ok(p),
+            path => Err(FlavorMismatchError {
+                expected: PathType::Windows,
+                actual: PathType::Unix,
+                path,
+            }),
+        }
+    }
+
+    /// Applies `f` to the path if it is a Unix path, leaving a Windows path untouched.
+    ///
+    /// This allows flavor-specific changes to be expressed without matching on every variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from_unix("/home/user/bashrc").map_unix(|p| p.with_hidden());
+    /// assert_eq!(path, Utf8TypedPathBuf::from_unix("/home/user/.bashrc"));
+    ///
+    /// let path =
+    ///     Utf8TypedPathBuf::from_windows(r"C:\Users\user\bashrc").map_unix(|p| p.with_hidden());
+    /// assert_eq!(path, Utf8TypedPathBuf::from_windows(r"C:\Users\user\bashrc"));
+    /// ```
+    pub fn map_unix(self, f: impl FnOnce(Utf8UnixPathBuf) -> Utf8UnixPathBuf) -> Self {
+        match self {
+            Self::Unix(p) => Self::Unix(f(p)),
+            Self::Windows(p) => Self::Windows(p),
+        }
+    }
+
+    /// Applies `f` to the path if it is a Windows path, leaving a Unix path untouched.
+    ///
+    /// This allows flavor-specific changes to be expressed without matching on every variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from_windows(r"C:\tmp").map_windows(|path| path.join("foo"));
+    /// assert_eq!(path, Utf8TypedPathBuf::from_windows(r"C:\tmp\foo"));
+    ///
+    /// let path = Utf8TypedPathBuf::from_unix("/tmp").map_windows(|path| path.join("foo"));
+    /// assert_eq!(path, Utf8TypedPathBuf::from_unix("/tmp"));
+    /// ```
+    pub fn map_windows(self, f: impl FnOnce(Utf8WindowsPathBuf) -> Utf8WindowsPathBuf) -> Self {
+        match self {
+            Self::Unix(p) => Self::Unix(p),
+            Self::Windows(p) => Self::Windows(f(p)),
+        }
+    }
+
     /// Allocates an empty [`Utf8TypedPathBuf`] for the specified path type.
     ///
     /// # Examples
@@ -120,8 +234,81 @@ impl Utf8TypedPathBuf {
         Self::Windows(Utf8WindowsPathBuf::from(s.as_ref()))
     }
 
+    /// Creates a new [`Utf8TypedPathBuf`] with the flavor used by `host`, rather than guessing it
+    /// from the contents of the path as [`TypedPath::derive`] does.
+    ///
+    /// [`TypedPath::derive`]: crate::TypedPath::derive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{HostOs, Utf8TypedPathBuf};
+    ///
+    /// // A relative path with backslashes would be assumed to be a Unix path
+    /// let path = Utf8TypedPathBuf::for_host(HostOs::Windows, r"logs\today.txt");
+    /// assert!(path.is_windows());
+    /// assert_eq!(path.file_name(), Some("today.txt".as_ref()));
+    ///
+    /// let path = Utf8TypedPathBuf::for_host(HostOs::Linux, r"logs\today.txt");
+    /// assert!(path.is_unix());
+    /// ```
+    pub fn for_host(host: HostOs, s: impl AsRef<str>) -> Self {
+        match host.path_type() {
+            PathType::Unix => Self::from_unix(s),
+            PathType::Windows => Self::from_windows(s),
+        }
+    }
+
+    /// Creates a new [`Utf8TypedPathBuf`] as a Unix path by pushing each segment from `iter` in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::collect_unix(["/tmp", "foo", "bar.txt"]);
+    /// assert!(path.is_unix());
+    /// assert_eq!(path, "/tmp/foo/bar.txt");
+    /// ```
+    pub fn collect_unix<I, P>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let mut buf = Self::unix();
+        for path in iter {
+            buf.push(path);
+        }
+        buf
+    }
+
+    /// Creates a new [`Utf8TypedPathBuf`] as a Windows path by pushing each segment from `iter` in
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::collect_windows(["tmp", "foo", "bar.txt"]);
+    /// assert!(path.is_windows());
+    /// assert_eq!(path, r"tmp\foo\bar.txt");
+    /// ```
+    pub fn collect_windows<I, P>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let mut buf = Self::windows();
+        for path in iter {
+            buf.push(path);
+        }
+        buf
+    }
+
     /// Converts into a [`Utf8TypedPath`].
-    pub fn to_path(&self) -> Utf8TypedPath {
+    pub fn to_path(&self) -> Utf8TypedPath<'_> {
         match self {
             Self::Unix(path) => Utf8TypedPath::Unix(path.as_path()),
             Self::Windows(path) => Utf8TypedPath::Windows(path.as_path()),
@@ -150,6 +337,14 @@ impl Utf8TypedPathBuf {
     ///
     /// [`Utf8PathBuf::push`]: crate::Utf8PathBuf::push
     ///
+    /// # Flavor of `path`
+    ///
+    /// `path` is always parsed using the flavor of `self` and is never converted. Passing a
+    /// [`Utf8TypedPath`] of the other flavor, which implements `AsRef<str>`, reinterprets its raw
+    /// value: a Windows path like `C:\temp` becomes a single normal component when pushed onto a
+    /// Unix path. Use [`Utf8TypedPathBuf::push_typed_checked`] to push a path of either flavor
+    /// safely.
+    ///
     /// # Examples
     ///
     /// Pushing a relative path extends the existing path:
@@ -195,6 +390,14 @@ impl Utf8TypedPathBuf {
     ///
     /// [`PathBuf::push_checked`]: crate::PathBuf::push_checked
     ///
+    /// # Flavor of `path`
+    ///
+    /// `path` is always parsed using the flavor of `self` and is never converted. Passing a
+    /// [`Utf8TypedPath`] of the other flavor, which implements `AsRef<str>`, reinterprets its raw
+    /// value: a Windows path like `C:\temp` becomes a single normal component when pushed onto a
+    /// Unix path. Use [`Utf8TypedPathBuf::push_typed_checked`] to push a path of either flavor
+    /// safely.
+    ///
     /// # Examples
     ///
     /// Pushing a relative path extends the existing path:
@@ -240,6 +443,72 @@ impl Utf8TypedPathBuf {
         }
     }
 
+    /// Like [`Utf8TypedPathBuf::push_checked`], extends `self` with `path` while enforcing the same
+    /// rules, but accepts a [`Utf8TypedPath`] of either flavor.
+    ///
+    /// # Conversion
+    ///
+    /// When `path` has the same flavor as `self`, this is identical to
+    /// [`Utf8TypedPathBuf::push_checked`]. Otherwise, `path` is first converted with
+    /// [`with_encoding_checked`], which maps separators, `.`, and `..` to the flavor of `self`
+    /// and fails if a component is not a valid filename in that flavor. A path of the other
+    /// flavor is never allowed to introduce a root or prefix:
+    ///
+    /// * A Windows path with a prefix (e.g. `C:temp`) fails with
+    ///   [`CheckedPathError::UnexpectedPrefix`] instead of having its prefix dropped.
+    /// * A path with a root (e.g. `\temp` or `/temp`) fails with
+    ///   [`CheckedPathError::UnexpectedRoot`] instead of replacing `self`.
+    /// * A Unix path whose first component looks like a Windows prefix (e.g. `c:/temp`) fails
+    ///   with [`CheckedPathError::UnexpectedPrefix`].
+    ///
+    /// On failure, `self` is left unchanged.
+    ///
+    /// [`with_encoding_checked`]: crate::Utf8Path::with_encoding_checked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPath, Utf8TypedPathBuf};
+    ///
+    /// let mut path = Utf8TypedPathBuf::from_unix("/tmp");
+    ///
+    /// // Windows separators are converted to Unix ones
+    /// assert!(path.push_typed_checked(Utf8TypedPath::windows(r"dir\file.txt")).is_ok());
+    /// assert_eq!(path, Utf8TypedPathBuf::from_unix("/tmp/dir/file.txt"));
+    ///
+    /// // Prefixes and roots from the other flavor are rejected
+    /// assert_eq!(
+    ///     path.push_typed_checked(Utf8TypedPath::windows("C:temp")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// assert_eq!(
+    ///     path.push_typed_checked(Utf8TypedPath::windows(r"\temp")),
+    ///     Err(CheckedPathError::UnexpectedRoot),
+    /// );
+    /// assert_eq!(path, Utf8TypedPathBuf::from_unix("/tmp/dir/file.txt"));
+    ///
+    /// let mut path = Utf8TypedPathBuf::from_windows(r"C:\tmp");
+    /// assert_eq!(
+    ///     path.push_typed_checked(Utf8TypedPath::unix("c:/temp")),
+    ///     Err(CheckedPathError::UnexpectedPrefix),
+    /// );
+    /// ```
+    pub fn push_typed_checked(&mut self, path: Utf8TypedPath<'_>) -> Result<(), CheckedPathError> {
+        match (self, path) {
+            (Self::Unix(a), Utf8TypedPath::Unix(b)) => a.push_checked(b),
+            (Self::Windows(a), Utf8TypedPath::Windows(b)) => a.push_checked(b),
+            (Self::Unix(a), Utf8TypedPath::Windows(b)) => {
+                if b.components().has_prefix() {
+                    return Err(CheckedPathError::UnexpectedPrefix);
+                }
+                a.push_checked(b.with_encoding_checked::<Utf8UnixEncoding>()?)
+            }
+            (Self::Windows(a), Utf8TypedPath::Unix(b)) => {
+                a.push_checked(b.with_encoding_checked::<Utf8WindowsEncoding>()?)
+            }
+        }
+    }
+
     /// Truncates `self` to [`self.parent`].
     ///
     /// Returns `false` and does nothing if [`self.parent`] is [`None`].
@@ -292,6 +561,30 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, set_file_name, file_name)
     }
 
+    /// Like [`Utf8TypedPathBuf::set_file_name`], but returns an error instead of introducing
+    /// additional components when `file_name` is not a single, valid file name.
+    ///
+    /// See [`Utf8PathBuf::try_set_file_name`] for more details.
+    ///
+    /// [`Utf8PathBuf::try_set_file_name`]: crate::Utf8PathBuf::try_set_file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPathBuf};
+    ///
+    /// let mut buf = Utf8TypedPathBuf::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_file_name("bar.txt"), Ok(()));
+    /// assert_eq!(buf.try_set_file_name("../bar.txt"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, Utf8TypedPathBuf::from("/tmp/bar.txt"));
+    /// ```
+    pub fn try_set_file_name<S: AsRef<str>>(
+        &mut self,
+        file_name: S,
+    ) -> Result<(), CheckedPathError> {
+        impl_typed_fn!(self, try_set_file_name, file_name)
+    }
+
     /// Updates [`self.extension`] to `extension`.
     ///
     /// Returns `false` and does nothing if [`self.file_name`] is [`None`],
@@ -320,6 +613,49 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, set_extension, extension)
     }
 
+    /// Like [`Utf8TypedPathBuf::set_extension`], but returns an error if `extension` would not
+    /// leave the file name as a single, valid file name.
+    ///
+    /// See [`Utf8PathBuf::try_set_extension`] for more details.
+    ///
+    /// [`Utf8PathBuf::try_set_extension`]: crate::Utf8PathBuf::try_set_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, Utf8TypedPathBuf};
+    ///
+    /// let mut buf = Utf8TypedPathBuf::from("/tmp/foo.txt");
+    /// assert_eq!(buf.try_set_extension("rs"), Ok(true));
+    /// assert_eq!(buf.try_set_extension("rs/evil"), Err(CheckedPathError::InvalidFilename));
+    /// assert_eq!(buf, Utf8TypedPathBuf::from("/tmp/foo.rs"));
+    /// ```
+    pub fn try_set_extension<S: AsRef<str>>(
+        &mut self,
+        extension: S,
+    ) -> Result<bool, CheckedPathError> {
+        impl_typed_fn!(self, try_set_extension, extension)
+    }
+
+    /// Converts the extension to lowercase in place.
+    ///
+    /// See [`Utf8PathBuf::lowercase_extension`] for more details.
+    ///
+    /// [`Utf8PathBuf::lowercase_extension`]: crate::Utf8PathBuf::lowercase_extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let mut p = Utf8TypedPathBuf::from("/photos/IMG_0001.JPG");
+    /// assert!(p.lowercase_extension());
+    /// assert_eq!(p, Utf8TypedPathBuf::from("/photos/IMG_0001.jpg"));
+    /// ```
+    pub fn lowercase_extension(&mut self) -> bool {
+        impl_typed_fn!(self, lowercase_extension)
+    }
+
     /// Consumes the [`Utf8TypedPathBuf`], yielding its internal [`String`] storage.
     ///
     /// # Examples
@@ -418,6 +754,51 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, as_str)
     }
 
+    /// Returns `true` if the path contains no bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert!(Utf8TypedPathBuf::from("").is_empty());
+    /// assert!(!Utf8TypedPathBuf::from(r"C:\foo.txt").is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        impl_typed_fn!(self, is_empty)
+    }
+
+    /// Returns the length of the path in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert_eq!(Utf8TypedPathBuf::from(r"C:\foo.txt").len(), 10);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        impl_typed_fn!(self, len)
+    }
+
+    /// Returns the number of [`components`](Utf8TypedPathBuf::components) in the path.
+    ///
+    /// This parses the path each time that it is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert_eq!(Utf8TypedPathBuf::from(r"C:\foo.txt").components_len(), 3);
+    /// assert_eq!(Utf8TypedPathBuf::from("").components_len(), 0);
+    /// ```
+    pub fn components_len(&self) -> usize {
+        impl_typed_fn!(self, components_len)
+    }
+
     /// Returns `true` if the [`Utf8TypedPathBuf`] is absolute, i.e., if it is independent of
     /// the current directory.
     ///
@@ -485,6 +866,21 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, has_root)
     }
 
+    /// Classifies how the [`Utf8TypedPathBuf`] is anchored. See [`RootKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{RootKind, Utf8TypedPathBuf};
+    ///
+    /// assert_eq!(Utf8TypedPathBuf::from("/etc/passwd").root_kind(), RootKind::Absolute);
+    /// assert_eq!(Utf8TypedPathBuf::from(r"C:foo").root_kind(), RootKind::DriveRelative);
+    /// ```
+    #[inline]
+    pub fn root_kind(&self) -> RootKind {
+        impl_typed_fn!(self, root_kind)
+    }
+
     /// Returns the [`Utf8TypedPathBuf`] without its final component, if there is one.
     ///
     /// Returns [`None`] if the path terminates in a root or prefix.
@@ -502,7 +898,7 @@ impl Utf8TypedPathBuf {
     /// assert_eq!(grand_parent, Utf8TypedPathBuf::from("/"));
     /// assert_eq!(grand_parent.parent(), None);
     /// ```
-    pub fn parent(&self) -> Option<Utf8TypedPath> {
+    pub fn parent(&self) -> Option<Utf8TypedPath<'_>> {
         self.to_path().parent()
     }
 
@@ -537,7 +933,7 @@ impl Utf8TypedPathBuf {
     ///
     /// [`parent`]: Utf8TypedPathBuf::parent
     #[inline]
-    pub fn ancestors(&self) -> Utf8TypedAncestors {
+    pub fn ancestors(&self) -> Utf8TypedAncestors<'_> {
         self.to_path().ancestors()
     }
 
@@ -564,6 +960,23 @@ impl Utf8TypedPathBuf {
         impl_typed_fn!(self, file_name)
     }
 
+    /// Returns true if the [`file_name`] begins with a `.`, which is how Unix-style tools mark
+    /// a file or directory as hidden.
+    ///
+    /// [`file_name`]: Utf8TypedPathBuf::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// assert!(Utf8TypedPathBuf::from("/home/user/.bashrc").file_name_is_dotfile());
+    /// assert!(!Utf8TypedPathBuf::from("/home/user").file_name_is_dotfile());
+    /// ```
+    pub fn file_name_is_dotfile(&self) -> bool {
+        impl_typed_fn!(self, file_name_is_dotfile)
+    }
+
     /// Returns a path that, when joined onto `base`, yields `self`.
     ///
     /// # Errors
@@ -592,7 +1005,10 @@ impl Utf8TypedPathBuf {
     /// let prefix = Utf8TypedPathBuf::from("/test/");
     /// assert_eq!(path.strip_prefix(prefix), Ok(Utf8TypedPath::derive("haha/foo.txt")));
     /// ```
-    pub fn strip_prefix(&self, base: impl AsRef<str>) -> Result<Utf8TypedPath, StripPrefixError> {
+    pub fn strip_prefix(
+        &self,
+        base: impl AsRef<str>,
+    ) -> Result<Utf8TypedPath<'_>, StripPrefixError> {
         match self {
             Self::Unix(p) => p
                 .strip_prefix(Utf8UnixPath::new(&base))
@@ -665,6 +1081,55 @@ impl Utf8TypedPathBuf {
         self.to_path().ends_with(child)
     }
 
+    /// Like [`Utf8TypedPathBuf::starts_with`], but compares against an iterator of individual
+    /// components rather than a path.
+    ///
+    /// See [`Utf8Path::starts_with_components`] for more details.
+    ///
+    /// [`Utf8Path::starts_with_components`]: crate::Utf8Path::starts_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/etc/ssh/sshd_config");
+    /// assert!(path.starts_with_components(["/", "etc", "ssh"]));
+    /// assert!(!path.starts_with_components(["/", "etc/ssh"]));
+    /// ```
+    pub fn starts_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.to_path().starts_with_components(components)
+    }
+
+    /// Like [`Utf8TypedPathBuf::ends_with`], but compares against an iterator of individual
+    /// components rather than a path.
+    ///
+    /// See [`Utf8Path::ends_with_components`] for more details.
+    ///
+    /// [`Utf8Path::ends_with_components`]: crate::Utf8Path::ends_with_components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("/etc/ssh/sshd_config");
+    /// assert!(path.ends_with_components(["ssh", "sshd_config"]));
+    /// assert!(!path.ends_with_components(["ssh/sshd_config"]));
+    /// ```
+    pub fn ends_with_components<I>(&self, components: I) -> bool
+    where
+        I: IntoIterator,
+        I::IntoIter: DoubleEndedIterator,
+        I::Item: AsRef<str>,
+    {
+        self.to_path().ends_with_components(components)
+    }
+
     /// Extracts the stem (non-extension) portion of [`self.file_name`].
     ///
     /// [`self.file_name`]: Utf8TypedPathBuf::file_name
@@ -787,7 +1252,7 @@ impl Utf8TypedPathBuf {
     /// let path = cwd.join("a/b/../c/./d");
     /// assert_eq!(path.absolutize().unwrap(), cwd.join("a/c/d"));
     /// ```
-    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    #[cfg(all(feature = "std", any(not(target_family = "wasm"), target_os = "wasi")))]
     pub fn absolutize(&self) -> std::io::Result<Utf8TypedPathBuf> {
         self.to_path().absolutize()
     }
@@ -869,6 +1334,13 @@ impl Utf8TypedPathBuf {
     ///
     /// let path = Utf8TypedPathBuf::from("/tmp");
     /// assert_eq!(path.with_file_name("var"), Utf8TypedPathBuf::from("/var"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = Utf8TypedPathBuf::from_unix(r"C:\tmp\foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Utf8TypedPathBuf::from_unix("bar.txt"));
+    ///
+    /// let path = Utf8TypedPathBuf::from_windows("/tmp/foo.txt");
+    /// assert_eq!(path.with_file_name("bar.txt"), Utf8TypedPathBuf::from_windows(r"\tmp\bar.txt"));
     /// ```
     pub fn with_file_name<S: AsRef<str>>(&self, file_name: S) -> Utf8TypedPathBuf {
         self.to_path().with_file_name(file_name)
@@ -890,11 +1362,35 @@ impl Utf8TypedPathBuf {
     /// assert_eq!(path.with_extension(""), Utf8TypedPathBuf::from("foo.tar"));
     /// assert_eq!(path.with_extension("xz"), Utf8TypedPathBuf::from("foo.tar.xz"));
     /// assert_eq!(path.with_extension("").with_extension("txt"), Utf8TypedPathBuf::from("foo.txt"));
+    ///
+    /// // A Unix path keeps its flavor even though it contains `\`
+    /// let path = Utf8TypedPathBuf::from_unix(r"C:\tmp\foo.rs");
+    /// assert_eq!(path.with_extension("txt"), Utf8TypedPathBuf::from_unix(r"C:\tmp\foo.txt"));
+    ///
+    /// let path = Utf8TypedPathBuf::from_windows("/tmp/foo.rs");
+    /// assert_eq!(path.with_extension("txt"), Utf8TypedPathBuf::from_windows(r"\tmp\foo.txt"));
     /// ```
     pub fn with_extension<S: AsRef<str>>(&self, extension: S) -> Utf8TypedPathBuf {
         self.to_path().with_extension(extension)
     }
 
+    /// Creates an owned [`Utf8TypedPathBuf`] like `self` but with its extension converted to
+    /// lowercase.
+    ///
+    /// See [`Utf8TypedPathBuf::lowercase_extension`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path = Utf8TypedPathBuf::from("IMG_0001.JPG");
+    /// assert_eq!(path.with_lowercase_extension(), Utf8TypedPathBuf::from("IMG_0001.jpg"));
+    /// ```
+    pub fn with_lowercase_extension(&self) -> Utf8TypedPathBuf {
+        self.to_path().with_lowercase_extension()
+    }
+
     /// Produces an iterator over the [`Utf8TypedComponent`]s of the path.
     ///
     /// When parsing the path, there is a small amount of normalization:
@@ -928,7 +1424,7 @@ impl Utf8TypedPathBuf {
     /// ```
     ///
     /// [`Utf8TypedComponent`]: crate::Utf8TypedComponent
-    pub fn components(&self) -> Utf8TypedComponents {
+    pub fn components(&self) -> Utf8TypedComponents<'_> {
         self.to_path().components()
     }
 
@@ -953,7 +1449,7 @@ impl Utf8TypedPathBuf {
     /// assert_eq!(it.next(), None)
     /// ```
     #[inline]
-    pub fn iter(&self) -> Utf8TypedIter {
+    pub fn iter(&self) -> Utf8TypedIter<'_> {
         self.to_path().iter()
     }
 }
@@ -967,6 +1463,16 @@ impl fmt::Display for Utf8TypedPathBuf {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Utf8TypedPathBuf {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Unix(path) => defmt::Format::format(path, f),
+            Self::Windows(path) => defmt::Format::format(path, f),
+        }
+    }
+}
+
 impl AsRef<[u8]> for Utf8TypedPathBuf {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -981,6 +1487,43 @@ impl AsRef<str> for Utf8TypedPathBuf {
     }
 }
 
+impl<P> FromIterator<P> for Utf8TypedPathBuf
+where
+    P: AsRef<str>,
+{
+    /// Creates a new typed pathbuf by pushing each segment from `iter` in order. The type of the
+    /// path is derived from the first segment the same way as [`Utf8TypedPathBuf::from`], and an
+    /// empty iterator produces an empty Unix path.
+    ///
+    /// Use [`Utf8TypedPathBuf::collect_unix`] or [`Utf8TypedPathBuf::collect_windows`] to pick the
+    /// type explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8TypedPathBuf;
+    ///
+    /// let path: Utf8TypedPathBuf = [r"C:\", "foo", "bar.txt"].into_iter().collect();
+    /// assert!(path.is_windows());
+    /// assert_eq!(path, r"C:\foo\bar.txt");
+    ///
+    /// let path: Utf8TypedPathBuf = "/tmp/foo/bar.txt".split_inclusive('/').collect();
+    /// assert!(path.is_unix());
+    /// assert_eq!(path, "/tmp/foo/bar.txt");
+    /// ```
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut buf = match iter.next() {
+            Some(first) => Utf8TypedPathBuf::from(first.as_ref()),
+            None => return Utf8TypedPathBuf::unix(),
+        };
+        for path in iter {
+            buf.push(path);
+        }
+        buf
+    }
+}
+
 impl<'a> From<&'a str> for Utf8TypedPathBuf {
     /// Creates a new typed pathbuf from a byte slice by determining if the path represents a
     /// Windows or Unix path. This is accomplished by first trying to parse as a Windows path. If
@@ -1,6 +1,8 @@
 pub(crate) mod constants;
+mod filename;
 mod non_utf8;
 mod utf8;
 
+pub use filename::*;
 pub use non_utf8::*;
 pub use utf8::*;
@@ -21,3 +21,9 @@ pub const DISALLOWED_FILENAME_BYTES: [u8; 2] = [b'/', b'\0'];
 
 /// Bytes that are not allowed in file or directory names
 pub const DISALLOWED_FILENAME_CHARS: [char; 2] = ['/', '\0'];
+
+/// Maximum length, in bytes, of a single file or directory name on most Unix filesystems
+pub const MAX_COMPONENT_LEN: usize = 255;
+
+/// Maximum length, in bytes, of a path including its null terminator on Linux (`PATH_MAX`)
+pub const MAX_PATH_LEN: usize = 4096;
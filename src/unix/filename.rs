@@ -0,0 +1,34 @@
+use crate::unix::constants::{
+    CURRENT_DIR, DISALLOWED_FILENAME_BYTES, MAX_COMPONENT_LEN, PARENT_DIR,
+};
+
+/// Returns `true` if `name` can be used as the name of a file or directory on Unix.
+///
+/// A valid name is not empty, `.`, or `..`, is no longer than [`MAX_COMPONENT_LEN`] bytes, and
+/// does not contain any of the [`DISALLOWED_FILENAME_BYTES`], which are `/` and the null byte.
+///
+/// [`DISALLOWED_FILENAME_BYTES`]: crate::constants::unix::DISALLOWED_FILENAME_BYTES
+/// [`MAX_COMPONENT_LEN`]: crate::constants::unix::MAX_COMPONENT_LEN
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::is_valid_unix_filename;
+///
+/// assert!(is_valid_unix_filename(b"report.txt"));
+/// assert!(is_valid_unix_filename(b"a:b"));
+/// assert!(is_valid_unix_filename(b"\xff"));
+///
+/// assert!(!is_valid_unix_filename(b""));
+/// assert!(!is_valid_unix_filename(b".."));
+/// assert!(!is_valid_unix_filename(b"dir/file"));
+/// assert!(!is_valid_unix_filename(b"nul\0"));
+/// assert!(!is_valid_unix_filename(&[b'a'; 256]));
+/// ```
+pub fn is_valid_unix_filename(name: &[u8]) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_COMPONENT_LEN
+        && name != CURRENT_DIR
+        && name != PARENT_DIR
+        && !name.iter().any(|b| DISALLOWED_FILENAME_BYTES.contains(b))
+}
@@ -1,12 +1,13 @@
 mod components;
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::Hasher;
 
 pub use components::*;
 
 use super::constants::*;
-use crate::common::CheckedPathError;
+use crate::common::{replace_tail, CheckedPathError, PushBuffer, RootKind};
 use crate::no_std_compat::*;
 use crate::typed::{TypedPath, TypedPathBuf};
 use crate::{private, Components, Encoding, Path, PathBuf};
@@ -70,9 +71,41 @@ impl<'a> Encoding<'a> for UnixEncoding {
         h.write_usize(bytes_hashed);
     }
 
+    fn cmp(path: &[u8], other: &[u8]) -> Ordering {
+        let first_difference = match path.iter().zip(other).position(|(a, b)| a != b) {
+            None if path.len() == other.len() => return Ordering::Equal,
+            None => path.len().min(other.len()),
+            Some(i) => i,
+        };
+
+        // Back up to the start of the component containing the first difference, as everything
+        // before it parses into the same components for both paths
+        let start = path[..first_difference]
+            .iter()
+            .rposition(|b| *b == SEPARATOR as u8)
+            .map_or(0, |i| i + 1);
+        let (rest, other_rest) = (&path[start..], &other[start..]);
+
+        // When parsed on its own, a remainder starting with a separator or `.` would yield a root
+        // or current directory component that does not exist within the full path
+        let is_ambiguous =
+            |bytes: &[u8]| matches!(bytes.first(), Some(b) if *b == SEPARATOR as u8 || *b == b'.');
+
+        if is_ambiguous(rest) || is_ambiguous(other_rest) {
+            Self::components(path).cmp(Self::components(other))
+        } else {
+            Self::components(rest).cmp(Self::components(other_rest))
+        }
+    }
+
     fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        let pushed = Self::try_push(current_path, path);
+        debug_assert!(pushed, "a Vec can always grow");
+    }
+
+    fn try_push<B: PushBuffer>(current_path: &mut B, path: &[u8]) -> bool {
         if path.is_empty() {
-            return;
+            return true;
         }
 
         // Absolute path will replace entirely, otherwise check if we need to add our separator,
@@ -80,13 +113,18 @@ impl<'a> Encoding<'a> for UnixEncoding {
         //
         // Otherwise, if our current path is not empty, we will append the provided path
         // to the end with a separator inbetween
+        let current = current_path.as_bytes();
+        let (len, needs_sep) = (
+            current.len(),
+            !current.is_empty() && !current.ends_with(&[SEPARATOR as u8]),
+        );
         if Self::components(path).is_absolute() {
-            current_path.clear();
-        } else if !current_path.is_empty() && !current_path.ends_with(&[SEPARATOR as u8]) {
-            current_path.push(SEPARATOR as u8);
+            replace_tail(current_path, 0, None, path)
+        } else if needs_sep {
+            replace_tail(current_path, len, Some(SEPARATOR as u8), path)
+        } else {
+            replace_tail(current_path, len, None, path)
         }
-
-        current_path.extend_from_slice(path);
     }
 
     fn push_checked(current_path: &mut Vec<u8>, path: &[u8]) -> Result<(), CheckedPathError> {
@@ -117,6 +155,34 @@ impl<'a> Encoding<'a> for UnixEncoding {
         Self::push(current_path, path);
         Ok(())
     }
+
+    fn starts_with_fast(path: &[u8], base: &[u8]) -> Option<bool> {
+        if !path.starts_with(base) {
+            return None;
+        }
+
+        // A byte prefix only lines up with the components of `path` if it ends on a component
+        // boundary, meaning nothing remains, or a separator sits on either side of the split
+        let rest = &path[base.len()..];
+        let on_boundary = base.is_empty()
+            || rest.is_empty()
+            || base.ends_with(&[SEPARATOR as u8])
+            || rest.starts_with(&[SEPARATOR as u8]);
+
+        if on_boundary {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    fn root_kind(path: &[u8]) -> RootKind {
+        if path.first() == Some(&(SEPARATOR as u8)) {
+            RootKind::Absolute
+        } else {
+            RootKind::Relative
+        }
+    }
 }
 
 impl fmt::Debug for UnixEncoding {
@@ -166,13 +232,118 @@ where
 }
 
 impl UnixPath {
-    pub fn to_typed_path(&self) -> TypedPath {
+    /// The root directory, `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    ///
+    /// assert_eq!(UnixPath::ROOT, UnixPath::new("/"));
+    /// assert!(UnixPath::ROOT.is_absolute());
+    /// ```
+    pub const ROOT: &'static UnixPath = UnixPath::new_const(SEPARATOR_STR.as_bytes());
+
+    pub fn to_typed_path(&self) -> TypedPath<'_> {
         TypedPath::unix(self)
     }
 
     pub fn to_typed_path_buf(&self) -> TypedPathBuf {
         TypedPathBuf::from_unix(self)
     }
+
+    /// Creates an owned [`UnixPathBuf`] like `self` but with a `.` prepended to the
+    /// [`file_name`], hiding it from directory listings that skip dotfiles.
+    ///
+    /// If the [`file_name`] is already a dotfile, or there is no [`file_name`], the path is
+    /// returned unchanged.
+    ///
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    ///
+    /// assert_eq!(
+    ///     UnixPath::new("/home/user/bashrc").with_hidden(),
+    ///     UnixPath::new("/home/user/.bashrc")
+    /// );
+    /// assert_eq!(
+    ///     UnixPath::new("/home/user/.bashrc").with_hidden(),
+    ///     UnixPath::new("/home/user/.bashrc")
+    /// );
+    /// assert_eq!(UnixPath::new("/").with_hidden(), UnixPath::new("/"));
+    /// ```
+    pub fn with_hidden(&self) -> UnixPathBuf {
+        match self.file_name() {
+            Some(name) if !self.file_name_is_dotfile() => {
+                let mut hidden = Vec::with_capacity(name.len() + 1);
+                hidden.push(b'.');
+                hidden.extend_from_slice(name);
+                self.with_file_name(hidden)
+            }
+            _ => self.to_path_buf(),
+        }
+    }
+
+    /// Creates an owned [`UnixPathBuf`] like `self` but with the leading `.` removed from the
+    /// [`file_name`], making it visible in directory listings that skip dotfiles.
+    ///
+    /// Only a single `.` is removed. If the [`file_name`] is not a dotfile, or there is no
+    /// [`file_name`], the path is returned unchanged.
+    ///
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    ///
+    /// assert_eq!(
+    ///     UnixPath::new("/home/user/.bashrc").without_hidden(),
+    ///     UnixPath::new("/home/user/bashrc")
+    /// );
+    /// assert_eq!(
+    ///     UnixPath::new("/home/user/bashrc").without_hidden(),
+    ///     UnixPath::new("/home/user/bashrc")
+    /// );
+    /// assert_eq!(UnixPath::new("..bashrc").without_hidden(), UnixPath::new(".bashrc"));
+    /// ```
+    pub fn without_hidden(&self) -> UnixPathBuf {
+        match self.file_name() {
+            Some(name) if self.file_name_is_dotfile() => self.with_file_name(&name[1..]),
+            _ => self.to_path_buf(),
+        }
+    }
+
+    /// Returns true if the path begins with exactly two slashes, as in `//network/share`.
+    ///
+    /// POSIX leaves the meaning of such a root up to the implementation, and some systems,
+    /// such as Cygwin, use it to refer to network locations. Parsing into [`components`] treats
+    /// it like any other run of slashes and yields a single root directory, so this is the way
+    /// to detect the form before it is lost. Three or more leading slashes are always
+    /// equivalent to a single one and are not a double root.
+    ///
+    /// [`components`]: Path::components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::UnixPath;
+    ///
+    /// assert!(UnixPath::new("//network/share").has_double_root());
+    /// assert!(UnixPath::new("//").has_double_root());
+    /// assert!(!UnixPath::new("/usr/lib").has_double_root());
+    /// assert!(!UnixPath::new("///usr/lib").has_double_root());
+    ///
+    /// // The double root is collapsed when iterating over components
+    /// assert_eq!(UnixPath::new("//network").components().count(), 2);
+    /// ```
+    pub fn has_double_root(&self) -> bool {
+        let bytes = self.as_bytes();
+        bytes.starts_with(b"//") && bytes.get(2) != Some(&b'/')
+    }
 }
 
 #[cfg(test)]
@@ -324,4 +495,206 @@ mod tests {
         );
         assert_eq!(current_path, b"/some/path/abc/../def/.");
     }
+
+    #[test]
+    fn starts_with_fast_should_succeed_if_base_ends_on_a_component_boundary() {
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b""),
+            Some(true)
+        );
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b"/"),
+            Some(true)
+        );
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b"/etc"),
+            Some(true)
+        );
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b"/etc/"),
+            Some(true)
+        );
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b"/etc/passwd"),
+            Some(true)
+        );
+        assert_eq!(UnixEncoding::starts_with_fast(b"./a/b", b"."), Some(true));
+    }
+
+    #[test]
+    fn starts_with_fast_should_be_inconclusive_if_bytes_do_not_line_up_with_components() {
+        // Base is not a byte prefix at all
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b"/usr"),
+            None
+        );
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc/passwd", b"/etc/passwd/"),
+            None
+        );
+        assert_eq!(
+            UnixEncoding::starts_with_fast(b"/etc//passwd", b"/etc/passwd"),
+            None
+        );
+
+        // Base ends in the middle of a component
+        assert_eq!(UnixEncoding::starts_with_fast(b"/etc/passwd", b"/e"), None);
+        assert_eq!(UnixEncoding::starts_with_fast(b"a/..", b"a/."), None);
+    }
+
+    #[test]
+    fn cmp_should_match_comparing_components() {
+        let paths: &[&[u8]] = &[
+            b"",
+            b".",
+            b"..",
+            b"/",
+            b"//",
+            b"-",
+            b"a",
+            b"a/",
+            b"a//",
+            b"a/.",
+            b"a/./b",
+            b"a/.c",
+            b"a/b",
+            b"a//b",
+            b"a/b/",
+            b"a/b/c",
+            b"a/../b",
+            b"a/..",
+            b"./a",
+            b"/a",
+            b"/a/b",
+            b"/a/-",
+            b"/a/.b",
+            b"/a/b.txt",
+            b"/ab",
+        ];
+
+        for path in paths {
+            for other in paths {
+                assert_eq!(
+                    UnixEncoding::cmp(path, other),
+                    UnixEncoding::components(path).cmp(UnixEncoding::components(other)),
+                    "{:?} <=> {:?}",
+                    String::from_utf8_lossy(path),
+                    String::from_utf8_lossy(other),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_from_should_match_normalize_for_valid_paths() {
+        let paths: &[&[u8]] = &[
+            br"",
+            br"file.txt",
+            br"./dir/file.txt",
+            br"dir/../file.txt",
+            br"/dir//file.txt",
+            br"/../dir",
+            br"/dir/./file.txt/.",
+        ];
+
+        for path in paths {
+            assert_eq!(
+                UnixPathBuf::normalized_from(path),
+                Ok(UnixPath::new(path).normalize()),
+                "{:?}",
+                UnixPath::new(path),
+            );
+        }
+    }
+
+    #[test]
+    fn clean_should_match_go_path_clean() {
+        // Test cases from Go's path.Clean
+        let cases: &[(&[u8], &[u8])] = &[
+            (b"", b"."),
+            (b"abc", b"abc"),
+            (b"abc/def", b"abc/def"),
+            (b".", b"."),
+            (b"..", b".."),
+            (b"../..", b"../.."),
+            (b"../../abc", b"../../abc"),
+            (b"/abc", b"/abc"),
+            (b"/", b"/"),
+            (b"abc/", b"abc"),
+            (b"abc//def//ghi", b"abc/def/ghi"),
+            (b"//abc", b"/abc"),
+            (b"///abc", b"/abc"),
+            (b"//abc//", b"/abc"),
+            (b"abc/./def", b"abc/def"),
+            (b"/./abc/def", b"/abc/def"),
+            (b"abc/.", b"abc"),
+            (b"abc/def/ghi/../jkl", b"abc/def/jkl"),
+            (b"abc/def/../ghi/../jkl", b"abc/jkl"),
+            (b"abc/def/..", b"abc"),
+            (b"abc/def/../..", b"."),
+            (b"/abc/def/../..", b"/"),
+            (b"abc/def/../../..", b".."),
+            (b"/abc/def/../../..", b"/"),
+            (b"abc/def/../../../ghi/jkl/../../../mno", b"../../mno"),
+            (b"abc/./../def", b"def"),
+            (b"abc//./../def", b"def"),
+            (b"abc/../../././../def", b"../../def"),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(
+                UnixPath::new(path).clean().as_bytes(),
+                *expected,
+                "{:?}",
+                UnixPath::new(path),
+            );
+        }
+    }
+
+    #[test]
+    fn debug_should_include_encoding_label_and_lossy_path() {
+        let path = crate::UnixPath::new(b"/tmp/\xFFfile");
+        assert_eq!(
+            alloc::format!("{path:?}"),
+            "Path { encoding: \"unix\", path: \"/tmp/\u{FFFD}file\" }"
+        );
+        assert_eq!(
+            alloc::format!("{:?}", path.to_path_buf()),
+            "PathBuf { encoding: \"unix\", path: \"/tmp/\u{FFFD}file\" }"
+        );
+    }
+
+    #[test]
+    fn truncate_to_should_fail_if_buffer_was_replaced_since_checkpoint() {
+        let mut path = crate::UnixPathBuf::from("/srv/app");
+        let checkpoint = path.checkpoint();
+
+        path.push("logs");
+        assert!(path.truncate_to(checkpoint));
+        assert_eq!(path.as_bytes(), b"/srv/app");
+
+        // Same length, different bytes
+        path.push("/tmp/abc");
+        assert!(!path.truncate_to(checkpoint));
+        assert_eq!(path.as_bytes(), b"/tmp/abc");
+
+        path.set_file_name("abcdef");
+        assert!(!path.truncate_to(checkpoint));
+
+        path.clear();
+        assert!(!path.truncate_to(checkpoint));
+        assert_eq!(path.as_bytes(), b"");
+    }
+
+    #[test]
+    fn extend_should_reserve_capacity_from_size_hint() {
+        let mut path = crate::UnixPathBuf::new();
+        path.extend((0..64).map(|_| ""));
+        assert_eq!(path.as_bytes(), b"");
+        assert!(path.capacity() >= 64, "{}", path.capacity());
+
+        let mut path = crate::UnixPathBuf::from("/root");
+        path.extend(["a", "b", "c"]);
+        assert_eq!(path.as_bytes(), b"/root/a/b/c");
+    }
 }
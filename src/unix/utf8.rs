@@ -1,11 +1,13 @@
 mod components;
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::Hasher;
 
 pub use components::*;
 
-use crate::common::CheckedPathError;
+use super::constants::SEPARATOR_STR;
+use crate::common::{CheckedPathError, RootKind};
 use crate::no_std_compat::*;
 use crate::typed::{Utf8TypedPath, Utf8TypedPathBuf};
 use crate::{private, Encoding, UnixEncoding, Utf8Encoding, Utf8Path, Utf8PathBuf};
@@ -37,6 +39,10 @@ impl<'a> Utf8Encoding<'a> for Utf8UnixEncoding {
         UnixEncoding::hash(path.as_bytes(), h);
     }
 
+    fn cmp(path: &str, other: &str) -> Ordering {
+        UnixEncoding::cmp(path.as_bytes(), other.as_bytes())
+    }
+
     fn push(current_path: &mut String, path: &str) {
         unsafe {
             UnixEncoding::push(current_path.as_mut_vec(), path.as_bytes());
@@ -46,6 +52,14 @@ impl<'a> Utf8Encoding<'a> for Utf8UnixEncoding {
     fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
         unsafe { UnixEncoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
     }
+
+    fn starts_with_fast(path: &str, base: &str) -> Option<bool> {
+        UnixEncoding::starts_with_fast(path.as_bytes(), base.as_bytes())
+    }
+
+    fn root_kind(path: &str) -> RootKind {
+        UnixEncoding::root_kind(path.as_bytes())
+    }
 }
 
 impl fmt::Debug for Utf8UnixEncoding {
@@ -97,13 +111,118 @@ where
 }
 
 impl Utf8UnixPath {
-    pub fn to_typed_path(&self) -> Utf8TypedPath {
+    /// The root directory, `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert_eq!(Utf8UnixPath::ROOT, Utf8UnixPath::new("/"));
+    /// assert!(Utf8UnixPath::ROOT.is_absolute());
+    /// ```
+    pub const ROOT: &'static Utf8UnixPath = Utf8UnixPath::new_const(SEPARATOR_STR);
+
+    pub fn to_typed_path(&self) -> Utf8TypedPath<'_> {
         Utf8TypedPath::unix(self)
     }
 
     pub fn to_typed_path_buf(&self) -> Utf8TypedPathBuf {
         Utf8TypedPathBuf::from_unix(self)
     }
+
+    /// Creates an owned [`Utf8UnixPathBuf`] like `self` but with a `.` prepended to the
+    /// [`file_name`], hiding it from directory listings that skip dotfiles.
+    ///
+    /// If the [`file_name`] is already a dotfile, or there is no [`file_name`], the path is
+    /// returned unchanged.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert_eq!(
+    ///     Utf8UnixPath::new("/home/user/bashrc").with_hidden(),
+    ///     Utf8UnixPath::new("/home/user/.bashrc")
+    /// );
+    /// assert_eq!(
+    ///     Utf8UnixPath::new("/home/user/.bashrc").with_hidden(),
+    ///     Utf8UnixPath::new("/home/user/.bashrc")
+    /// );
+    /// assert_eq!(Utf8UnixPath::new("/").with_hidden(), Utf8UnixPath::new("/"));
+    /// ```
+    pub fn with_hidden(&self) -> Utf8UnixPathBuf {
+        match self.file_name() {
+            Some(name) if !self.file_name_is_dotfile() => {
+                let mut hidden = String::with_capacity(name.len() + 1);
+                hidden.push('.');
+                hidden.push_str(name);
+                self.with_file_name(hidden)
+            }
+            _ => self.to_path_buf(),
+        }
+    }
+
+    /// Creates an owned [`Utf8UnixPathBuf`] like `self` but with the leading `.` removed from
+    /// the [`file_name`], making it visible in directory listings that skip dotfiles.
+    ///
+    /// Only a single `.` is removed. If the [`file_name`] is not a dotfile, or there is no
+    /// [`file_name`], the path is returned unchanged.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert_eq!(
+    ///     Utf8UnixPath::new("/home/user/.bashrc").without_hidden(),
+    ///     Utf8UnixPath::new("/home/user/bashrc")
+    /// );
+    /// assert_eq!(
+    ///     Utf8UnixPath::new("/home/user/bashrc").without_hidden(),
+    ///     Utf8UnixPath::new("/home/user/bashrc")
+    /// );
+    /// assert_eq!(Utf8UnixPath::new("..bashrc").without_hidden(), Utf8UnixPath::new(".bashrc"));
+    /// ```
+    pub fn without_hidden(&self) -> Utf8UnixPathBuf {
+        match self.file_name() {
+            Some(name) if self.file_name_is_dotfile() => self.with_file_name(&name[1..]),
+            _ => self.to_path_buf(),
+        }
+    }
+
+    /// Returns true if the path begins with exactly two slashes, as in `//network/share`.
+    ///
+    /// POSIX leaves the meaning of such a root up to the implementation, and some systems,
+    /// such as Cygwin, use it to refer to network locations. Parsing into [`components`] treats
+    /// it like any other run of slashes and yields a single root directory, so this is the way
+    /// to detect the form before it is lost. Three or more leading slashes are always
+    /// equivalent to a single one and are not a double root.
+    ///
+    /// [`components`]: Utf8Path::components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8UnixPath;
+    ///
+    /// assert!(Utf8UnixPath::new("//network/share").has_double_root());
+    /// assert!(Utf8UnixPath::new("//").has_double_root());
+    /// assert!(!Utf8UnixPath::new("/usr/lib").has_double_root());
+    /// assert!(!Utf8UnixPath::new("///usr/lib").has_double_root());
+    ///
+    /// // The double root is collapsed when iterating over components
+    /// assert_eq!(Utf8UnixPath::new("//network").components().count(), 2);
+    /// ```
+    pub fn has_double_root(&self) -> bool {
+        let bytes = self.as_str().as_bytes();
+        bytes.starts_with(b"//") && bytes.get(2) != Some(&b'/')
+    }
 }
 
 #[cfg(test)]
@@ -255,4 +374,87 @@ mod tests {
         );
         assert_eq!(current_path, "/some/path/abc/../def/.");
     }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn try_from_std_cow_should_return_original_path_if_not_utf8() {
+        use std::borrow::Cow;
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let std_path = std::path::Path::new(OsStr::from_bytes(b"/tmp/\xff"));
+        assert_eq!(
+            Utf8UnixPath::try_from_std_cow(Cow::Borrowed(std_path)),
+            Err(Cow::Borrowed(std_path))
+        );
+        assert_eq!(
+            Utf8UnixPath::try_from_std_cow(Cow::Owned(std_path.to_path_buf())),
+            Err(Cow::Owned(std_path.to_path_buf()))
+        );
+    }
+
+    #[test]
+    fn debug_should_include_encoding_label_and_path() {
+        let path = crate::Utf8UnixPath::new("/tmp/file");
+        assert_eq!(
+            alloc::format!("{path:?}"),
+            "Utf8Path { encoding: \"unix\", path: \"/tmp/file\" }"
+        );
+        assert_eq!(
+            alloc::format!("{:?}", path.to_path_buf()),
+            "Utf8PathBuf { encoding: \"unix\", path: \"/tmp/file\" }"
+        );
+    }
+
+    #[test]
+    fn normalized_from_should_match_normalize_for_valid_paths() {
+        let paths: &[&str] = &[
+            r"",
+            r"file.txt",
+            r"./dir/file.txt",
+            r"dir/../file.txt",
+            r"/dir//file.txt",
+            r"/../dir",
+            r"/dir/./file.txt/.",
+        ];
+
+        for path in paths {
+            assert_eq!(
+                crate::Utf8UnixPathBuf::normalized_from(path),
+                Ok(crate::Utf8UnixPath::new(path).normalize()),
+                "{:?}",
+                path,
+            );
+        }
+    }
+
+    #[test]
+    fn truncate_to_should_fail_rather_than_split_a_char_if_buffer_was_replaced() {
+        let mut path = crate::Utf8UnixPathBuf::from("/ab");
+        let checkpoint = path.checkpoint();
+
+        path.push("c");
+        assert!(path.truncate_to(checkpoint));
+        assert_eq!(path.as_str(), "/ab");
+
+        // The checkpoint would fall inside `日`
+        path.push("/日/c");
+        assert!(!path.truncate_to(checkpoint));
+        assert_eq!(path.as_str(), "/日/c");
+
+        path.clear();
+        assert!(!path.truncate_to(checkpoint));
+    }
+
+    #[test]
+    fn extend_should_reserve_capacity_from_size_hint() {
+        let mut path = crate::Utf8UnixPathBuf::new();
+        path.extend((0..64).map(|_| ""));
+        assert_eq!(path.as_str(), "");
+        assert!(path.capacity() >= 64, "{}", path.capacity());
+
+        let mut path = crate::Utf8UnixPathBuf::from("/root");
+        path.extend(["a", "b", "c"]);
+        assert_eq!(path.as_str(), "/root/a/b/c");
+    }
 }
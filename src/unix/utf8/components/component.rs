@@ -343,3 +343,127 @@ impl<'a> TryFrom<&'a str> for Utf8UnixComponent<'a> {
         Ok(component)
     }
 }
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<Utf8UnixComponent<'a>> for std::path::Component<'a> {
+    type Error = Utf8UnixComponent<'a>;
+
+    /// Attempts to convert a [`Utf8UnixComponent`] into a [`std::path::Component`], returning a
+    /// result containing the new path when successful or the original path when failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Component;
+    /// use typed_path::Utf8UnixComponent;
+    ///
+    /// let component = Component::try_from(Utf8UnixComponent::RootDir).unwrap();
+    /// assert_eq!(component, Component::RootDir);
+    ///
+    /// let component = Component::try_from(Utf8UnixComponent::CurDir).unwrap();
+    /// assert_eq!(component, Component::CurDir);
+    ///
+    /// let component = Component::try_from(Utf8UnixComponent::ParentDir).unwrap();
+    /// assert_eq!(component, Component::ParentDir);
+    ///
+    /// let component = Component::try_from(Utf8UnixComponent::Normal("file.txt")).unwrap();
+    /// assert_eq!(component, Component::Normal(OsStr::new("file.txt")));
+    /// ```
+    fn try_from(component: Utf8UnixComponent<'a>) -> Result<Self, Self::Error> {
+        match component {
+            Utf8UnixComponent::RootDir => Ok(Self::RootDir),
+            Utf8UnixComponent::CurDir => Ok(Self::CurDir),
+            Utf8UnixComponent::ParentDir => Ok(Self::ParentDir),
+            Utf8UnixComponent::Normal(x) => Ok(Self::Normal(std::ffi::OsStr::new(x))),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<std::path::Component<'a>> for Utf8UnixComponent<'a> {
+    type Error = std::path::Component<'a>;
+
+    /// Attempts to convert a [`std::path::Component`] into a [`Utf8UnixComponent`], returning a
+    /// result containing the new component when successful or the original component when failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Component;
+    /// use typed_path::Utf8UnixComponent;
+    ///
+    /// let component = Utf8UnixComponent::try_from(Component::RootDir).unwrap();
+    /// assert_eq!(component, Utf8UnixComponent::RootDir);
+    ///
+    /// let component = Utf8UnixComponent::try_from(Component::CurDir).unwrap();
+    /// assert_eq!(component, Utf8UnixComponent::CurDir);
+    ///
+    /// let component = Utf8UnixComponent::try_from(Component::ParentDir).unwrap();
+    /// assert_eq!(component, Utf8UnixComponent::ParentDir);
+    ///
+    /// let component =
+    ///     Utf8UnixComponent::try_from(Component::Normal(OsStr::new("file.txt"))).unwrap();
+    /// assert_eq!(component, Utf8UnixComponent::Normal("file.txt"));
+    /// ```
+    fn try_from(component: std::path::Component<'a>) -> Result<Self, Self::Error> {
+        match &component {
+            std::path::Component::Prefix(_) => Err(component),
+            std::path::Component::RootDir => Ok(Self::RootDir),
+            std::path::Component::CurDir => Ok(Self::CurDir),
+            std::path::Component::ParentDir => Ok(Self::ParentDir),
+            std::path::Component::Normal(x) => Ok(Self::Normal(x.to_str().ok_or(component)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use std::convert::TryFrom;
+    use std::path::{Component, Path};
+
+    use super::*;
+
+    #[test]
+    fn try_from_std_component_should_yield_nothing_for_empty_path() {
+        let components = Path::new("")
+            .components()
+            .map(Utf8UnixComponent::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert!(components.is_empty());
+    }
+
+    #[test]
+    fn try_from_std_component_should_round_trip_every_component() {
+        let path = Path::new("/a/./b/../c.txt");
+        for component in path.components() {
+            let utf8 = Utf8UnixComponent::try_from(component).unwrap();
+            assert_eq!(Component::try_from(utf8).unwrap(), component);
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn try_from_std_component_should_fail_for_normal_that_is_not_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let component = Component::Normal(OsStr::from_bytes(b"fo\xffo"));
+        assert_eq!(Utf8UnixComponent::try_from(component), Err(component));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn try_from_std_component_should_fail_for_prefix() {
+        for s in ["C:", r"\\server\share", r"\\?\C:"] {
+            let component = Path::new(s).components().next().unwrap();
+            assert!(matches!(component, Component::Prefix(_)));
+            assert_eq!(Utf8UnixComponent::try_from(component), Err(component));
+        }
+    }
+}
@@ -1,7 +1,15 @@
+use std::collections::hash_map::RandomState;
 use std::convert::TryFrom;
-use std::{env, io};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
 
-use crate::{NativePathBuf, Utf8NativePathBuf};
+use crate::common::non_utf8::helpers;
+use crate::{
+    CheckedPathError, Encoding, NativePath, NativePathBuf, Path, PathBuf, Utf8NativePath,
+    Utf8NativePathBuf,
+};
 
 /// Returns the current working directory as [`NativePathBuf`].
 ///
@@ -63,6 +71,67 @@ pub fn utf8_current_dir() -> io::Result<Utf8NativePathBuf> {
     }
 }
 
+/// Returns `path` relative to the current working directory when it is under it, or `path`
+/// unchanged otherwise, such as when it is already relative or lies outside the current directory.
+///
+/// This is meant for displaying paths, e.g. showing `src/lib.rs` rather than
+/// `/home/user/project/src/lib.rs`, so the current directory itself becomes `.` and any failure
+/// to read the current directory leaves `path` unchanged rather than returning an error.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::utils::{current_dir, relative_to_cwd};
+/// use typed_path::NativePath;
+///
+/// let cwd = current_dir().unwrap();
+/// let path = cwd.join("src").join("lib.rs");
+/// assert_eq!(relative_to_cwd(&path), NativePath::new("src").join("lib.rs"));
+/// assert_eq!(relative_to_cwd(&cwd), NativePath::new("."));
+///
+/// let path = NativePath::new("already").join("relative");
+/// assert_eq!(relative_to_cwd(&path), path);
+/// ```
+pub fn relative_to_cwd<P: AsRef<NativePath> + ?Sized>(path: &P) -> &NativePath {
+    let path = path.as_ref();
+    match current_dir() {
+        Ok(cwd) => match path.strip_prefix(&cwd) {
+            Ok(rest) if rest.is_empty() => NativePath::new("."),
+            Ok(rest) => rest,
+            Err(_) => path,
+        },
+        Err(_) => path,
+    }
+}
+
+/// Returns `path` relative to the current working directory when it is under it, or `path`
+/// unchanged otherwise.
+///
+/// See [`relative_to_cwd`] for details. A current directory that is not valid UTF-8 also leaves
+/// `path` unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::utils::{utf8_current_dir, utf8_relative_to_cwd};
+/// use typed_path::Utf8NativePath;
+///
+/// let cwd = utf8_current_dir().unwrap();
+/// let path = cwd.join("Cargo.toml");
+/// assert_eq!(utf8_relative_to_cwd(&path).as_str(), "Cargo.toml");
+/// ```
+pub fn utf8_relative_to_cwd<P: AsRef<Utf8NativePath> + ?Sized>(path: &P) -> &Utf8NativePath {
+    let path = path.as_ref();
+    match utf8_current_dir() {
+        Ok(cwd) => match path.strip_prefix(&cwd) {
+            Ok(rest) if rest.is_empty() => Utf8NativePath::new("."),
+            Ok(rest) => rest,
+            Err(_) => path,
+        },
+        Err(_) => path,
+    }
+}
+
 /// Returns the full filesystem path of the current running executable as [`NativePathBuf`].
 ///
 /// # Errors
@@ -170,3 +239,349 @@ pub fn utf8_temp_dir() -> io::Result<Utf8NativePathBuf> {
         Err(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
     }
 }
+
+/// Returns a path within `base` whose file name is `prefix`, followed by sixteen random
+/// hexadecimal digits, followed by `suffix`, without touching the file system.
+///
+/// The random portion mixes the current time, the process id, a per-process counter, and the
+/// randomly seeded hasher from the standard library, so names generated concurrently by threads
+/// or processes are very unlikely to collide. Callers that need a guarantee should still create
+/// the entry exclusively and retry on [`AlreadyExists`](io::ErrorKind::AlreadyExists), as
+/// [`create_temp_dir_in`] does.
+///
+/// # Errors
+///
+/// Returns [`CheckedPathError::InvalidFilename`] if the generated file name is not a single
+/// valid component for the encoding of `base`, such as when `prefix` or `suffix` contains a
+/// separator, or a character that Windows does not allow in file names.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::utils::unique_child;
+/// use typed_path::{CheckedPathError, UnixPath, WindowsPath};
+///
+/// let path = unique_child(UnixPath::new("/tmp"), "build-", ".log").unwrap();
+/// let name = path.file_name().unwrap();
+/// assert!(path.starts_with("/tmp"));
+/// assert!(name.starts_with(b"build-") && name.ends_with(b".log"));
+/// assert_eq!(name.len(), "build-".len() + 16 + ".log".len());
+///
+/// assert_ne!(path, unique_child(UnixPath::new("/tmp"), "build-", ".log").unwrap());
+///
+/// assert_eq!(
+///     unique_child(WindowsPath::new(r"C:\Temp"), "job:", ""),
+///     Err(CheckedPathError::InvalidFilename),
+/// );
+/// ```
+pub fn unique_child<T, P>(
+    base: P,
+    prefix: &str,
+    suffix: &str,
+) -> Result<PathBuf<T>, CheckedPathError>
+where
+    T: for<'enc> Encoding<'enc>,
+    P: AsRef<Path<T>>,
+{
+    let name = format!("{prefix}{:016x}{suffix}", random_u64());
+
+    if helpers::is_single_normal_component::<T>(name.as_bytes()) {
+        Ok(base.as_ref().join(&name))
+    } else {
+        Err(CheckedPathError::InvalidFilename)
+    }
+}
+
+/// Creates a new, empty directory within `base` with a name generated by [`unique_child`],
+/// returning its path.
+///
+/// Unlike [`std::env::temp_dir`], the directory is created so that no other caller can be
+/// handed the same path. It is not removed automatically.
+///
+/// # Errors
+///
+/// Returns an [`Err`] if `base` cannot be used as a [`std::path::Path`], if the directory cannot
+/// be created, or if every generated name already exists.
+///
+/// # Examples
+///
+/// ```
+/// fn main() -> std::io::Result<()> {
+///     let base = typed_path::utils::temp_dir()?;
+///     let dir = typed_path::utils::create_temp_dir_in(&base)?;
+///     assert_eq!(dir.parent(), Some(base.as_path()));
+///     std::fs::remove_dir(dir.to_str().unwrap())?;
+///     Ok(())
+/// }
+/// ```
+pub fn create_temp_dir_in(base: impl AsRef<NativePath>) -> io::Result<NativePathBuf> {
+    const ATTEMPTS: usize = 16;
+
+    let base = base.as_ref();
+    for _ in 0..ATTEMPTS {
+        let path = unique_child(base, ".tmp", "")
+            .map_err(|x| io::Error::new(io::ErrorKind::InvalidInput, x))?;
+        match fs::create_dir(to_std_path(&path)?) {
+            Ok(()) => return Ok(path),
+            Err(x) if x.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(x) => return Err(x),
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "too many temporary directories already exist",
+    ))
+}
+
+/// Returns a relative path that spreads content-addressed entries across directories, made of
+/// `depth` directories named after successive `width`-digit slices of the lowercase hexadecimal
+/// form of `hash`, followed by the full hexadecimal form as the file name.
+///
+/// This is the layout used by caches that would otherwise place too many entries in a single
+/// directory. Only hexadecimal digits and separators are written, so the result is valid for
+/// every encoding.
+///
+/// Returns [`None`] if `width` is zero while `depth` is not, or if the directories would need
+/// more digits than `hash` provides.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::utils::shard_path;
+/// use typed_path::{UnixEncoding, WindowsEncoding};
+///
+/// let hash = [0xab, 0xcd, 0xef, 0x01];
+///
+/// let path = shard_path::<UnixEncoding>(hash, 2, 2).unwrap();
+/// assert_eq!(path.as_bytes(), b"ab/cd/abcdef01");
+///
+/// let path = shard_path::<WindowsEncoding>(hash, 1, 3).unwrap();
+/// assert_eq!(path.as_bytes(), br"abc\abcdef01");
+///
+/// let path = shard_path::<UnixEncoding>(hash, 0, 2).unwrap();
+/// assert_eq!(path.as_bytes(), b"abcdef01");
+///
+/// assert_eq!(shard_path::<UnixEncoding>(hash, 3, 3), None);
+/// ```
+pub fn shard_path<T>(hash: impl AsRef<[u8]>, depth: usize, width: usize) -> Option<PathBuf<T>>
+where
+    T: for<'enc> Encoding<'enc>,
+{
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let hex: Vec<u8> = hash
+        .as_ref()
+        .iter()
+        .flat_map(|b| {
+            [
+                HEX_DIGITS[usize::from(b >> 4)],
+                HEX_DIGITS[usize::from(b & 0xf)],
+            ]
+        })
+        .collect();
+
+    if depth > 0 && (width == 0 || depth.checked_mul(width)? > hex.len()) {
+        return None;
+    }
+
+    let mut path = PathBuf::new();
+    for shard in hex.chunks(width.max(1)).take(depth) {
+        path.push(shard);
+    }
+    path.push(&hex);
+    Some(path)
+}
+
+/// Returns `rel` adjoined to the first of `roots` for which `exists` returns true, searching them
+/// in order like an include path or a stack of overlay directories.
+///
+/// `rel` is adjoined with [`Path::join_checked`], so a `rel` that has a root, or whose `..`
+/// components would leave a root, is never found. This keeps every result within one of `roots`.
+///
+/// Use [`resolve_in_roots_fs`] to check for existence on the file system.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::utils::resolve_in_roots;
+/// use typed_path::{UnixPath, WindowsPath};
+///
+/// let roots = [UnixPath::new("/project/assets"), UnixPath::new("/usr/share/assets")];
+/// let exists = |path: &UnixPath| path.starts_with("/usr/share");
+///
+/// let path = resolve_in_roots("icons/app.png", roots, exists).unwrap();
+/// assert_eq!(path, UnixPath::new("/usr/share/assets/icons/app.png"));
+///
+/// assert_eq!(resolve_in_roots("/etc/passwd", roots, exists), None);
+/// assert_eq!(resolve_in_roots("../../etc/passwd", roots, exists), None);
+///
+/// let roots = [WindowsPath::new(r"C:\include"), WindowsPath::new(r"D:\sdk\include")];
+/// let path = resolve_in_roots(r"sys\types.h", roots, |_| true).unwrap();
+/// assert_eq!(path.as_bytes(), br"C:\include\sys\types.h");
+/// ```
+pub fn resolve_in_roots<T, P, I, R, F>(rel: P, roots: I, mut exists: F) -> Option<PathBuf<T>>
+where
+    T: for<'enc> Encoding<'enc>,
+    P: AsRef<Path<T>>,
+    I: IntoIterator<Item = R>,
+    R: AsRef<Path<T>>,
+    F: FnMut(&Path<T>) -> bool,
+{
+    let rel = rel.as_ref();
+    roots
+        .into_iter()
+        .filter_map(|root| root.as_ref().join_checked(rel).ok())
+        .find(|path| exists(path))
+}
+
+/// Returns `rel` adjoined to the first of `roots` where it exists on the file system, following
+/// symlinks, as [`resolve_in_roots`] does with [`std::fs::metadata`] as the check.
+///
+/// Roots that cannot be used as a [`std::path::Path`] are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::utils::{create_temp_dir_in, resolve_in_roots_fs, temp_dir};
+///
+/// fn main() -> std::io::Result<()> {
+///     let base = temp_dir()?;
+///     let (overlay, lower) = (create_temp_dir_in(&base)?, create_temp_dir_in(&base)?);
+///     std::fs::write(lower.join("config.toml").to_str().unwrap(), "")?;
+///
+///     let path = resolve_in_roots_fs("config.toml", [&overlay, &lower]).unwrap();
+///     assert_eq!(path, lower.join("config.toml"));
+///     assert_eq!(resolve_in_roots_fs("missing.toml", [&overlay, &lower]), None);
+///
+///     std::fs::remove_file(path.to_str().unwrap())?;
+///     std::fs::remove_dir(overlay.to_str().unwrap())?;
+///     std::fs::remove_dir(lower.to_str().unwrap())?;
+///     Ok(())
+/// }
+/// ```
+pub fn resolve_in_roots_fs<P, I, R>(rel: P, roots: I) -> Option<NativePathBuf>
+where
+    P: AsRef<NativePath>,
+    I: IntoIterator<Item = R>,
+    R: AsRef<NativePath>,
+{
+    resolve_in_roots(rel, roots, |path| {
+        to_std_path(path).map_or(false, |path| fs::metadata(path).is_ok())
+    })
+}
+
+/// Returns 64 bits that differ between calls, threads, and processes.
+fn random_u64() -> u64 {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(nanos);
+    hasher.write_u32(std::process::id());
+    hasher.write_usize(COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
+/// Views `path` as a [`std::path::Path`], which requires valid UTF-8 outside of Unix.
+fn to_std_path(path: &NativePath) -> io::Result<&std::path::Path> {
+    #[cfg(unix)]
+    {
+        Ok(path.as_std_path())
+    }
+
+    #[cfg(not(unix))]
+    match path.to_str() {
+        Some(s) => Ok(std::path::Path::new(s)),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "path is not valid UTF-8",
+        )),
+    }
+}
+
+/// Returns a stream over the entries within a directory, yielding each entry's path as a
+/// [`NativePathBuf`].
+///
+/// This wraps [`tokio::fs::read_dir`], and is only available with the `tokio` feature.
+///
+/// # Errors
+///
+/// Returns an [`Err`] under the same conditions as [`tokio::fs::read_dir`].
+///
+/// # Examples
+///
+/// ```
+/// async fn print_entries() -> std::io::Result<()> {
+///     let mut entries = typed_path::utils::read_dir_typed(".").await?;
+///     while let Some(path) = entries.next_entry().await? {
+///         println!("{}", path.display());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+pub async fn read_dir_typed(path: impl AsRef<std::path::Path>) -> io::Result<ReadDir> {
+    Ok(ReadDir(tokio::fs::read_dir(path).await?))
+}
+
+/// Entries within a directory, returned by [`read_dir_typed`].
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+#[derive(Debug)]
+pub struct ReadDir(tokio::fs::ReadDir);
+
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+impl ReadDir {
+    /// Returns the path of the next entry in the directory as a [`NativePathBuf`], or [`None`]
+    /// once there are no more entries.
+    ///
+    /// The bytes of each entry's path are kept as-is, so names that are not valid UTF-8 are
+    /// returned rather than rejected. On Windows, this relies on `OsStr::as_encoded_bytes` from
+    /// Rust 1.74; older compilers replace any non-Unicode sequences with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if reading the next entry fails.
+    ///
+    /// [U+FFFD]: std::char::REPLACEMENT_CHARACTER
+    pub async fn next_entry(&mut self) -> io::Result<Option<NativePathBuf>> {
+        Ok(self
+            .0
+            .next_entry()
+            .await?
+            .map(|entry| native_path_buf_from_std(entry.path())))
+    }
+}
+
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+#[cfg(any(
+    unix,
+    all(target_vendor = "fortanix", target_env = "sgx"),
+    target_os = "solid_asp3",
+    target_os = "hermit",
+    target_os = "wasi",
+    typed_path_os_str_encoded_bytes
+))]
+#[inline]
+fn native_path_buf_from_std(path: std::path::PathBuf) -> NativePathBuf {
+    NativePathBuf::from(path.into_os_string())
+}
+
+#[cfg(all(feature = "tokio", not(target_family = "wasm")))]
+#[cfg(not(any(
+    unix,
+    all(target_vendor = "fortanix", target_env = "sgx"),
+    target_os = "solid_asp3",
+    target_os = "hermit",
+    target_os = "wasi",
+    typed_path_os_str_encoded_bytes
+)))]
+#[inline]
+fn native_path_buf_from_std(path: std::path::PathBuf) -> NativePathBuf {
+    NativePathBuf::from(path.to_string_lossy().as_ref())
+}
@@ -1,6 +1,22 @@
+mod absolutize;
+mod case;
 pub(crate) mod constants;
+mod filename;
+mod join;
+mod key;
 mod non_utf8;
+mod separator;
 mod utf8;
+#[cfg(feature = "windows-interop")]
+mod wide;
+#[cfg(any(feature = "windows-interop", all(feature = "std", windows)))]
+pub(crate) mod wtf8;
 
+pub use absolutize::*;
+pub use case::*;
+pub use filename::*;
+pub use join::*;
+pub use key::*;
 pub use non_utf8::*;
+pub use separator::*;
 pub use utf8::*;
@@ -0,0 +1,387 @@
+use alloc::collections::BTreeMap;
+
+use crate::no_std_compat::*;
+use crate::{WindowsComponent, WindowsPath, WindowsPathBuf, WindowsPrefix, WindowsPrefixComponent};
+
+/// Options describing the current directories that [`WindowsPath::absolutize_with`] resolves
+/// relative paths against, in place of those of the running process.
+///
+/// Windows keeps a current directory for the process and, separately, one for each drive. This
+/// lets an emulation layer supply all of them so that every relative form resolves the way
+/// `GetFullPathNameW` would:
+///
+/// * `foo` is resolved against the [current directory](AbsolutizeOptions::new).
+/// * `C:foo` is resolved against the current directory of drive `C`, which is the current
+///   directory itself when that is on drive `C`, then any directory set with
+///   [`drive_dir`](AbsolutizeOptions::drive_dir), and otherwise the root of the drive, `C:\`.
+/// * `\foo` is resolved against the [`rooted_base`](AbsolutizeOptions::rooted_base) if one is
+///   set, and otherwise against the drive or share of the current directory.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{AbsolutizeOptions, WindowsPath};
+///
+/// let options = AbsolutizeOptions::new(r"C:\Users\Alice").drive_dir('d', r"D:\Games");
+///
+/// let path = WindowsPath::new(r"D:saves\..\config").absolutize_with(&options);
+/// assert_eq!(path.as_bytes(), br"D:\Games\config");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbsolutizeOptions {
+    current_dir: WindowsPathBuf,
+    drive_dirs: BTreeMap<u8, WindowsPathBuf>,
+    rooted_base: Option<WindowsPathBuf>,
+}
+
+impl AbsolutizeOptions {
+    /// Creates a new set of options that resolves relative paths against `current_dir`, which
+    /// should be absolute.
+    pub fn new(current_dir: impl Into<WindowsPathBuf>) -> Self {
+        Self {
+            current_dir: current_dir.into(),
+            drive_dirs: BTreeMap::new(),
+            rooted_base: None,
+        }
+    }
+
+    /// Sets the current directory of drive `letter`, which drive-relative paths such as `D:foo`
+    /// are resolved against. The letter is matched without regard to ASCII case.
+    ///
+    /// This is ignored for the drive of the [current directory](AbsolutizeOptions::new), which
+    /// always takes precedence, as it does on Windows.
+    pub fn drive_dir(mut self, letter: char, dir: impl Into<WindowsPathBuf>) -> Self {
+        if letter.is_ascii_alphabetic() {
+            self.drive_dirs
+                .insert(letter.to_ascii_uppercase() as u8, dir.into());
+        }
+        self
+    }
+
+    /// Sets the drive or share that rooted paths such as `\foo` are resolved against, e.g. `E:` or
+    /// `\\server\share`, rather than the one of the [current directory](AbsolutizeOptions::new).
+    ///
+    /// Only the prefix of `base` is used.
+    pub fn rooted_base(mut self, base: impl Into<WindowsPathBuf>) -> Self {
+        self.rooted_base = Some(base.into());
+        self
+    }
+
+    /// Returns the directory that drive-relative paths on drive `letter` are resolved against.
+    fn dir_for_drive(&self, letter: u8) -> WindowsPathBuf {
+        let letter = letter.to_ascii_uppercase();
+        if let Some(WindowsPrefix::Disk(cwd_letter)) = prefix_kind(&self.current_dir) {
+            if cwd_letter.to_ascii_uppercase() == letter {
+                return self.current_dir.clone();
+            }
+        }
+
+        match self.drive_dirs.get(&letter) {
+            Some(dir) => dir.clone(),
+            None => WindowsPathBuf::from(vec![letter, b':', b'\\']),
+        }
+    }
+}
+
+impl WindowsPath {
+    /// Converts a path to an absolute form by resolving it against the directories described by
+    /// `options` and then [normalizing](crate::Path::normalize) it.
+    ///
+    /// Unlike [`absolutize`](crate::Path::absolutize), this never consults the running process,
+    /// and it resolves drive-relative (`C:foo`) and rooted (`\foo`) paths the way Windows does.
+    /// Paths that are already absolute are only normalized, except for those beginning with
+    /// exactly `\\?\`, which are returned unchanged because Windows never normalizes them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{AbsolutizeOptions, WindowsPath};
+    ///
+    /// let options = AbsolutizeOptions::new(r"C:\work\project");
+    ///
+    /// let resolve = |path| WindowsPath::new(path).absolutize_with(&options);
+    /// assert_eq!(resolve(r"src\..\Cargo.toml").as_bytes(), br"C:\work\project\Cargo.toml");
+    /// assert_eq!(resolve(r"c:target").as_bytes(), br"C:\work\project\target");
+    /// assert_eq!(resolve(r"E:data").as_bytes(), br"E:\data");
+    /// assert_eq!(resolve(r"\Windows").as_bytes(), br"C:\Windows");
+    /// assert_eq!(resolve(r"\\server\share\.\x").as_bytes(), br"\\server\share\x");
+    ///
+    /// let options = options.rooted_base(r"\\server\share");
+    /// let path = WindowsPath::new(r"\tools").absolutize_with(&options);
+    /// assert_eq!(path.as_bytes(), br"\\server\share\tools");
+    ///
+    /// let path = WindowsPath::new(r"\\?\C:\a\..\b").absolutize_with(&options);
+    /// assert_eq!(path.as_bytes(), br"\\?\C:\a\..\b");
+    /// ```
+    pub fn absolutize_with(&self, options: &AbsolutizeOptions) -> WindowsPathBuf {
+        if self.as_bytes().starts_with(br"\\?\") {
+            return self.to_path_buf();
+        }
+
+        if self.is_absolute() {
+            return self.normalize();
+        }
+
+        resolve(self, options).normalize()
+    }
+
+    /// Computes the full path that Win32 functions such as `CreateFileW` would open for this
+    /// path, following the same normalization as `GetFullPathNameW` without calling into the
+    /// operating system.
+    ///
+    /// Relative paths are first resolved against `options` like
+    /// [`absolutize_with`](WindowsPath::absolutize_with). Then:
+    ///
+    /// * Paths beginning with exactly `\\?\` are returned unchanged, as Windows passes them
+    ///   through without any normalization. The same prefix written with `/`, such as `//?/C:`,
+    ///   is normalized like any other device path.
+    /// * Every `/` becomes `\`, and repeated separators are collapsed.
+    /// * `.` segments are removed, and `..` segments remove the segment before them, but never
+    ///   the drive, share, or device that the path is rooted in.
+    /// * Trailing periods and spaces are removed from every segment, so `C:\dir.\file.txt. .`
+    ///   opens `C:\dir\file.txt`.
+    ///
+    /// Unlike [`normalize`](crate::Path::normalize), a trailing separator is kept. Legacy device
+    /// names such as `CON` or `NUL` within a path are not translated to device paths, as
+    /// different versions of Windows disagree on when that happens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{AbsolutizeOptions, WindowsPath};
+    ///
+    /// let options = AbsolutizeOptions::new(r"C:\work");
+    /// let full_path = |path| WindowsPath::new(path).win32_full_path(&options);
+    ///
+    /// assert_eq!(full_path(r"src//lib.rs. ").as_bytes(), br"C:\work\src\lib.rs");
+    /// assert_eq!(full_path(r"..\..\..\temp\").as_bytes(), br"C:\temp\");
+    /// assert_eq!(full_path(r"D:/a/./b/..").as_bytes(), br"D:\a");
+    /// assert_eq!(full_path(r"//server/share/../x").as_bytes(), br"\\server\share\x");
+    /// assert_eq!(full_path(r"\\.\C:\a\..\..").as_bytes(), br"\\.\C:\");
+    /// assert_eq!(full_path(r"\\?\C:\a\..\b.").as_bytes(), br"\\?\C:\a\..\b.");
+    /// assert_eq!(full_path(r"//?/C:/a/../b.").as_bytes(), br"\\?\C:\b");
+    /// ```
+    pub fn win32_full_path(&self, options: &AbsolutizeOptions) -> WindowsPathBuf {
+        if self.as_bytes().starts_with(br"\\?\") {
+            return self.to_path_buf();
+        }
+
+        let resolved = if self.is_absolute() {
+            self.to_path_buf()
+        } else {
+            resolve(self, options)
+        };
+        let bytes = resolved.as_bytes();
+
+        let (mut full_path, rest) = match WindowsPrefixComponent::parse(bytes) {
+            Some((prefix, rest)) => {
+                let mut root = Vec::new();
+                match prefix.kind() {
+                    WindowsPrefix::Disk(letter) => root.extend_from_slice(&[letter, b':']),
+                    WindowsPrefix::UNC(server, share) => {
+                        root.extend_from_slice(br"\\");
+                        root.extend_from_slice(server);
+                        root.push(b'\\');
+                        root.extend_from_slice(share);
+                    }
+                    WindowsPrefix::DeviceNS(device) => {
+                        root.extend_from_slice(br"\\.\");
+                        root.extend_from_slice(device);
+                    }
+
+                    // Not exactly `\\?\`, so normalized like any other device path
+                    WindowsPrefix::Verbatim(name) => {
+                        root.extend_from_slice(br"\\?\");
+                        root.extend_from_slice(name);
+                    }
+                    WindowsPrefix::VerbatimDisk(letter) => {
+                        root.extend_from_slice(br"\\?\");
+                        root.extend_from_slice(&[letter, b':']);
+                    }
+                    WindowsPrefix::VerbatimUNC(server, share) => {
+                        root.extend_from_slice(br"\\?\UNC\");
+                        root.extend_from_slice(server);
+                        if !share.is_empty() {
+                            root.push(b'\\');
+                            root.extend_from_slice(share);
+                        }
+                    }
+                }
+                (root, rest)
+            }
+            None => (Vec::new(), bytes),
+        };
+
+        let is_separator = |b: &u8| *b == b'\\' || *b == b'/';
+        let has_root = rest.first().map_or(false, is_separator);
+        let mut ends_with_separator = rest.last().map_or(false, is_separator);
+
+        let mut segments: Vec<&[u8]> = Vec::new();
+        let last = rest.split(is_separator).count() - 1;
+        for (i, segment) in rest.split(is_separator).enumerate() {
+            match segment {
+                b"" | b"." => {}
+                b".." => {
+                    segments.pop();
+                }
+                segment => {
+                    let len = segment
+                        .iter()
+                        .rposition(|b| *b != b'.' && *b != b' ')
+                        .map_or(0, |i| i + 1);
+
+                    // A final segment made only of periods and spaces, such as `...`, leaves
+                    // the path ending with a separator
+                    if len > 0 {
+                        segments.push(&segment[..len]);
+                    } else if i == last {
+                        ends_with_separator = true;
+                    }
+                }
+            }
+        }
+
+        if has_root || (!full_path.is_empty() && !segments.is_empty()) {
+            full_path.push(b'\\');
+        }
+        for (i, segment) in segments.iter().enumerate() {
+            if i > 0 {
+                full_path.push(b'\\');
+            }
+            full_path.extend_from_slice(segment);
+        }
+        if ends_with_separator && !segments.is_empty() {
+            full_path.push(b'\\');
+        }
+
+        WindowsPathBuf::from(full_path)
+    }
+}
+
+/// Resolves a relative `path` against the directories described by `options`, without
+/// normalizing the result.
+fn resolve(path: &WindowsPath, options: &AbsolutizeOptions) -> WindowsPathBuf {
+    let mut components = path.components();
+    match components.next() {
+        // Drive-relative, e.g. `C:foo`, as a disk prefix without a root is never absolute
+        Some(WindowsComponent::Prefix(prefix)) => match prefix.kind() {
+            WindowsPrefix::Disk(letter) => options
+                .dir_for_drive(letter)
+                .join(components.as_path::<crate::WindowsEncoding>()),
+            _ => path.to_path_buf(),
+        },
+
+        // Rooted, e.g. `\foo`, which keeps only the drive or share of its base
+        Some(WindowsComponent::RootDir) => {
+            let base = options
+                .rooted_base
+                .as_deref()
+                .unwrap_or(&options.current_dir);
+            match base.components().next() {
+                Some(WindowsComponent::Prefix(prefix)) => {
+                    let mut bytes = prefix.as_bytes().to_vec();
+                    bytes.extend_from_slice(path.as_bytes());
+                    WindowsPathBuf::from(bytes)
+                }
+                _ => path.to_path_buf(),
+            }
+        }
+
+        _ => options.current_dir.join(path),
+    }
+}
+
+fn prefix_kind(path: &WindowsPath) -> Option<WindowsPrefix<'_>> {
+    match path.components().next() {
+        Some(WindowsComponent::Prefix(prefix)) => Some(prefix.kind()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absolutize_with_should_resolve_drive_relative_paths_per_drive() {
+        let options = AbsolutizeOptions::new(r"c:\Users\Alice")
+            .drive_dir('C', r"C:\ignored")
+            .drive_dir('d', r"D:\Games\")
+            .drive_dir('e', r"\\server\share\e");
+
+        let resolve = |path: &str| WindowsPath::new(path).absolutize_with(&options);
+
+        // The drive of the current directory always uses the current directory
+        assert_eq!(resolve(r"C:..\Bob").as_bytes(), br"c:\Users\Bob");
+        assert_eq!(resolve(r"C:").as_bytes(), br"c:\Users\Alice");
+        assert_eq!(resolve(r"d:saves").as_bytes(), br"D:\Games\saves");
+        assert_eq!(resolve(r"D:..\..\..").as_bytes(), br"D:\");
+        assert_eq!(resolve(r"E:x").as_bytes(), br"\\server\share\e\x");
+        assert_eq!(resolve(r"Z:x\.\y").as_bytes(), br"Z:\x\y");
+    }
+
+    #[test]
+    fn absolutize_with_should_resolve_rooted_paths_against_the_current_share() {
+        let options = AbsolutizeOptions::new(r"\\server\share\dir");
+        let resolve = |path: &str| WindowsPath::new(path).absolutize_with(&options);
+
+        assert_eq!(resolve(r"\x\..\y").as_bytes(), br"\\server\share\y");
+        assert_eq!(resolve(r"x").as_bytes(), br"\\server\share\dir\x");
+        assert_eq!(resolve(r"Q:x").as_bytes(), br"Q:\x");
+
+        let options = options.rooted_base(r"E:\anything");
+        let path = WindowsPath::new(r"\x").absolutize_with(&options);
+        assert_eq!(path.as_bytes(), br"E:\x");
+    }
+
+    #[test]
+    fn absolutize_with_should_return_verbatim_paths_unchanged() {
+        let options = AbsolutizeOptions::new(r"C:\work");
+        let resolve = |path: &str| WindowsPath::new(path).absolutize_with(&options);
+
+        assert_eq!(resolve(r"\\?\C:\a\..\b").as_bytes(), br"\\?\C:\a\..\b");
+        assert_eq!(resolve(r"\\?\C:\a\.\b").as_bytes(), br"\\?\C:\a\.\b");
+        assert_eq!(
+            resolve(r"\\?\UNC\server\share\..\x").as_bytes(),
+            br"\\?\UNC\server\share\..\x"
+        );
+        assert_eq!(resolve(r"\\.\C:\a\..\b").as_bytes(), br"\\.\C:\b");
+    }
+
+    #[test]
+    fn win32_full_path_should_trim_every_segment() {
+        let options = AbsolutizeOptions::new(r"C:\work");
+        let full_path = |path: &str| WindowsPath::new(path).win32_full_path(&options);
+
+        assert_eq!(full_path(r"C:\a.\b").as_bytes(), br"C:\a\b");
+        assert_eq!(full_path(r"C:\a\b.\").as_bytes(), br"C:\a\b\");
+        assert_eq!(full_path(r"a. \b. ").as_bytes(), br"C:\work\a\b");
+        assert_eq!(full_path(r"a\b. \").as_bytes(), br"C:\work\a\b\");
+        assert_eq!(full_path(r"a\ . \b").as_bytes(), br"C:\work\a\b");
+        assert_eq!(full_path(r"a\...\b...").as_bytes(), br"C:\work\a\b");
+        assert_eq!(full_path(r"a\...").as_bytes(), br"C:\work\a\");
+        assert_eq!(full_path(r"C:").as_bytes(), br"C:\work");
+        assert_eq!(full_path(r"C:\").as_bytes(), br"C:\");
+        assert_eq!(full_path(r"\\server\share").as_bytes(), br"\\server\share");
+        assert_eq!(
+            full_path(r"\\server\share\").as_bytes(),
+            br"\\server\share\"
+        );
+        assert_eq!(full_path(r"\a\\\b\\").as_bytes(), br"C:\a\b\");
+    }
+
+    #[test]
+    fn win32_full_path_should_only_pass_through_the_exact_verbatim_prefix() {
+        let options = AbsolutizeOptions::new(r"C:\work");
+        let full_path = |path: &str| WindowsPath::new(path).win32_full_path(&options);
+
+        assert_eq!(full_path(r"\\?\C:\a\..\b").as_bytes(), br"\\?\C:\a\..\b");
+        assert_eq!(full_path(r"//?/C:/a/../b").as_bytes(), br"\\?\C:\b");
+        assert_eq!(full_path(r"//?/C:/a/../..").as_bytes(), br"\\?\C:\");
+        assert_eq!(
+            full_path(r"//?/UNC/server/share/a./../b").as_bytes(),
+            br"\\?\UNC\server\share\b"
+        );
+        assert_eq!(full_path(r"//?/pipe/x/./y").as_bytes(), br"\\?\pipe\x\y");
+    }
+}
@@ -0,0 +1,756 @@
+use core::cmp::Ordering;
+use core::hash::Hasher;
+
+use crate::{
+    Utf8WindowsComponent, Utf8WindowsPath, Utf8WindowsPrefix, WindowsComponent, WindowsPath,
+    WindowsPrefix,
+};
+
+/// How letters are folded when comparing Windows paths without regard to case.
+///
+/// Windows filesystems compare names by mapping each character through a per-volume uppercase
+/// table, so which characters fold together differs slightly between filesystems. This selects
+/// which approximation of that table to use with methods such as
+/// [`WindowsPath::eq_ignore_case_with`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{CaseFolding, WindowsPath};
+///
+/// let a = WindowsPath::new(r"C:\Renée");
+/// let b = WindowsPath::new(r"C:\RENÉE");
+/// assert!(!a.eq_ignore_case_with(b, CaseFolding::Ascii));
+/// assert!(a.eq_ignore_case_with(b, CaseFolding::Ntfs));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CaseFolding {
+    /// Only ASCII letters are folded, which is how [`WindowsPath::eq_ignore_case`] compares.
+    Ascii,
+
+    /// Every character with a single-character uppercase form is folded, which is how
+    /// [`Utf8WindowsPath::eq_ignore_case`] compares.
+    Unicode,
+
+    /// Characters are folded the way the NTFS `$UpCase` table does, so that names are equal
+    /// exactly when NTFS would consider them the same file.
+    ///
+    /// ASCII and Latin-1 letters follow the table exactly, including `ÿ` folding to `Ÿ` and `µ` to
+    /// `Μ`. Beyond Latin-1 this approximates the table with the simple uppercase mappings of the
+    /// Basic Multilingual Plane, leaving alone characters outside it as NTFS compares UTF-16 code
+    /// units one at a time. Non-ASCII characters never fold onto ASCII letters, so `ı` and `ſ`
+    /// stay distinct from `I` and `S`, and characters that only uppercase to several, such as
+    /// `ß`, are never folded. The table written by a given version of Windows can still differ
+    /// for characters added in later versions of Unicode.
+    ///
+    /// Paths of bytes that are not valid UTF-8 only have their ASCII letters folded.
+    Ntfs,
+}
+
+impl CaseFolding {
+    fn fold(self, c: char) -> char {
+        match self {
+            Self::Ascii => c.to_ascii_uppercase(),
+            Self::Unicode => fold_char(c),
+            Self::Ntfs => ntfs_upcase(c),
+        }
+    }
+}
+
+impl WindowsPath {
+    /// Returns true if `self` and `other` have the same components when ASCII letters are
+    /// compared without regard to case, the way Windows compares paths on most filesystems.
+    ///
+    /// Like `==`, this compares components, so separators and other normalization handled by
+    /// [`components`](crate::Path::components) do not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPath;
+    ///
+    /// let path = WindowsPath::new(r"C:\Program Files\App");
+    /// assert!(path.eq_ignore_case(WindowsPath::new("c:/PROGRAM FILES/app")));
+    /// assert!(path.eq_ignore_case(WindowsPath::new(r"C:\Program Files\.\App\")));
+    /// assert!(!path.eq_ignore_case(WindowsPath::new(r"C:\Program Files\App2")));
+    ///
+    /// let path = WindowsPath::new(r"\\Server\Share\file.txt");
+    /// assert!(path.eq_ignore_case(WindowsPath::new(r"\\SERVER\share\FILE.TXT")));
+    /// ```
+    pub fn eq_ignore_case(&self, other: &WindowsPath) -> bool {
+        self.cmp_ignore_case(other) == Ordering::Equal
+    }
+
+    /// Compares `self` and `other` component by component like [`Ord`], but with ASCII letters
+    /// compared without regard to case.
+    ///
+    /// This returns [`Ordering::Equal`] exactly when
+    /// [`eq_ignore_case`](WindowsPath::eq_ignore_case) returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use typed_path::WindowsPath;
+    ///
+    /// let path = WindowsPath::new(r"C:\Users\bob");
+    /// assert_eq!(path.cmp_ignore_case(WindowsPath::new(r"c:\users\BOB")), Ordering::Equal);
+    /// assert_eq!(path.cmp_ignore_case(WindowsPath::new(r"C:\Users\Carol")), Ordering::Less);
+    /// assert_eq!(path.cmp_ignore_case(WindowsPath::new(r"C:\Users")), Ordering::Greater);
+    /// ```
+    pub fn cmp_ignore_case(&self, other: &WindowsPath) -> Ordering {
+        self.cmp_ignore_case_with(other, CaseFolding::Ascii)
+    }
+
+    /// Like [`eq_ignore_case`](WindowsPath::eq_ignore_case), but folding letters as described by
+    /// `folding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CaseFolding, WindowsPath};
+    ///
+    /// let path = WindowsPath::new(r"C:\Users\Zoë");
+    /// assert!(path.eq_ignore_case_with(WindowsPath::new(r"c:\users\ZOË"), CaseFolding::Ntfs));
+    ///
+    /// // NTFS does not fold the dotless i onto an ASCII letter
+    /// let path = WindowsPath::new(r"C:\ı");
+    /// assert!(!path.eq_ignore_case_with(WindowsPath::new(r"C:\I"), CaseFolding::Ntfs));
+    /// assert!(path.eq_ignore_case_with(WindowsPath::new(r"C:\I"), CaseFolding::Unicode));
+    /// ```
+    pub fn eq_ignore_case_with(&self, other: &WindowsPath, folding: CaseFolding) -> bool {
+        self.cmp_ignore_case_with(other, folding) == Ordering::Equal
+    }
+
+    /// Like [`cmp_ignore_case`](WindowsPath::cmp_ignore_case), but folding letters as described
+    /// by `folding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use typed_path::{CaseFolding, WindowsPath};
+    ///
+    /// let path = WindowsPath::new(r"C:\Émile");
+    /// assert_eq!(
+    ///     path.cmp_ignore_case_with(WindowsPath::new(r"C:\émile"), CaseFolding::Ntfs),
+    ///     Ordering::Equal,
+    /// );
+    /// ```
+    pub fn cmp_ignore_case_with(&self, other: &WindowsPath, folding: CaseFolding) -> Ordering {
+        let mut components = self.components();
+        let mut other_components = other.components();
+        loop {
+            let ordering = match (components.next(), other_components.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(a), Some(b)) => component_cmp_ignore_case(a, b, folding),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+    }
+
+    /// Feeds the components of this path into `state`, ignoring the case of ASCII letters.
+    ///
+    /// Paths that are equal according to [`eq_ignore_case`](WindowsPath::eq_ignore_case) hash
+    /// to the same value, so the two can be paired to key a case-insensitive index without
+    /// allocating lowercase copies of each path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use std::hash::{Hash, Hasher};
+    /// use typed_path::WindowsPath;
+    ///
+    /// struct IgnoreCase<'a>(&'a WindowsPath);
+    ///
+    /// impl PartialEq for IgnoreCase<'_> {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0.eq_ignore_case(other.0)
+    ///     }
+    /// }
+    ///
+    /// impl Eq for IgnoreCase<'_> {}
+    ///
+    /// impl Hash for IgnoreCase<'_> {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.0.hash_ignore_case(state)
+    ///     }
+    /// }
+    ///
+    /// let mut seen = HashSet::new();
+    /// assert!(seen.insert(IgnoreCase(WindowsPath::new(r"C:\Windows\System32"))));
+    /// assert!(!seen.insert(IgnoreCase(WindowsPath::new(r"c:\WINDOWS\system32"))));
+    /// ```
+    pub fn hash_ignore_case<H: Hasher>(&self, state: &mut H) {
+        self.hash_ignore_case_with(CaseFolding::Ascii, state)
+    }
+
+    /// Like [`hash_ignore_case`](WindowsPath::hash_ignore_case), but folding letters as
+    /// described by `folding`.
+    ///
+    /// Paths that are equal according to
+    /// [`eq_ignore_case_with`](WindowsPath::eq_ignore_case_with) with the same `folding` hash to
+    /// the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use typed_path::{CaseFolding, WindowsPath};
+    ///
+    /// fn hash(path: &str) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     WindowsPath::new(path).hash_ignore_case_with(CaseFolding::Ntfs, &mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash(r"D:\Çà\ÿ"), hash(r"d:\çÀ\Ÿ"));
+    /// ```
+    pub fn hash_ignore_case_with<H: Hasher>(&self, folding: CaseFolding, state: &mut H) {
+        let mut count = 0;
+        for component in self.components() {
+            match component {
+                WindowsComponent::Prefix(prefix) => {
+                    state.write_u8(0);
+                    hash_prefix_ignore_case(prefix.kind(), folding, state);
+                }
+                WindowsComponent::RootDir => state.write_u8(1),
+                WindowsComponent::CurDir => state.write_u8(2),
+                WindowsComponent::ParentDir => state.write_u8(3),
+                WindowsComponent::Normal(bytes) => {
+                    state.write_u8(4);
+                    bytes.hash_folded(folding, state);
+                }
+            }
+            count += 1;
+        }
+        state.write_usize(count);
+    }
+}
+
+impl Utf8WindowsPath {
+    /// Returns true if `self` and `other` have the same components when characters are compared
+    /// without regard to case.
+    ///
+    /// Unlike [`WindowsPath::eq_ignore_case`], this folds all Unicode letters that have a
+    /// single-character uppercase form, such as `é` and `É`, rather than only ASCII letters.
+    ///
+    /// [`WindowsPath::eq_ignore_case`]: crate::WindowsPath::eq_ignore_case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Users\Renée\Documents");
+    /// assert!(path.eq_ignore_case(Utf8WindowsPath::new(r"c:\users\RENÉE\documents")));
+    /// assert!(!path.eq_ignore_case(Utf8WindowsPath::new(r"C:\Users\Renee\Documents")));
+    /// ```
+    pub fn eq_ignore_case(&self, other: &Utf8WindowsPath) -> bool {
+        self.cmp_ignore_case(other) == Ordering::Equal
+    }
+
+    /// Compares `self` and `other` component by component like [`Ord`], but with characters
+    /// compared without regard to case.
+    ///
+    /// This returns [`Ordering::Equal`] exactly when
+    /// [`eq_ignore_case`](Utf8WindowsPath::eq_ignore_case) returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Users\Émile");
+    /// assert_eq!(path.cmp_ignore_case(Utf8WindowsPath::new(r"c:\users\émile")), Ordering::Equal);
+    /// assert_eq!(path.cmp_ignore_case(Utf8WindowsPath::new(r"C:\Users\Zoë")), Ordering::Greater);
+    /// ```
+    pub fn cmp_ignore_case(&self, other: &Utf8WindowsPath) -> Ordering {
+        self.cmp_ignore_case_with(other, CaseFolding::Unicode)
+    }
+
+    /// Like [`eq_ignore_case`](Utf8WindowsPath::eq_ignore_case), but folding letters as
+    /// described by `folding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CaseFolding, Utf8WindowsPath};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Straße");
+    /// assert!(path.eq_ignore_case_with(Utf8WindowsPath::new(r"c:\STRAßE"), CaseFolding::Ntfs));
+    /// assert!(!path.eq_ignore_case_with(Utf8WindowsPath::new(r"C:\STRASSE"), CaseFolding::Ntfs));
+    /// ```
+    pub fn eq_ignore_case_with(&self, other: &Utf8WindowsPath, folding: CaseFolding) -> bool {
+        self.cmp_ignore_case_with(other, folding) == Ordering::Equal
+    }
+
+    /// Like [`cmp_ignore_case`](Utf8WindowsPath::cmp_ignore_case), but folding letters as
+    /// described by `folding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use typed_path::{CaseFolding, Utf8WindowsPath};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\ſ");
+    /// assert_eq!(
+    ///     path.cmp_ignore_case_with(Utf8WindowsPath::new(r"C:\S"), CaseFolding::Unicode),
+    ///     Ordering::Equal,
+    /// );
+    /// assert_eq!(
+    ///     path.cmp_ignore_case_with(Utf8WindowsPath::new(r"C:\S"), CaseFolding::Ntfs),
+    ///     Ordering::Greater,
+    /// );
+    /// ```
+    pub fn cmp_ignore_case_with(&self, other: &Utf8WindowsPath, folding: CaseFolding) -> Ordering {
+        let mut components = self.components();
+        let mut other_components = other.components();
+        loop {
+            let ordering = match (components.next(), other_components.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(a), Some(b)) => utf8_component_cmp_ignore_case(a, b, folding),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+    }
+
+    /// Feeds the components of this path into `state`, ignoring the case of each character.
+    ///
+    /// Paths that are equal according to
+    /// [`eq_ignore_case`](Utf8WindowsPath::eq_ignore_case) hash to the same value. See
+    /// [`WindowsPath::hash_ignore_case`] for an example of pairing the two.
+    ///
+    /// [`WindowsPath::hash_ignore_case`]: crate::WindowsPath::hash_ignore_case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// fn hash(path: &str) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     Utf8WindowsPath::new(path).hash_ignore_case(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash(r"C:\Users\Renée"), hash("c:/USERS/RENÉE"));
+    /// ```
+    pub fn hash_ignore_case<H: Hasher>(&self, state: &mut H) {
+        self.hash_ignore_case_with(CaseFolding::Unicode, state)
+    }
+
+    /// Like [`hash_ignore_case`](Utf8WindowsPath::hash_ignore_case), but folding letters as
+    /// described by `folding`.
+    ///
+    /// Paths that are equal according to
+    /// [`eq_ignore_case_with`](Utf8WindowsPath::eq_ignore_case_with) with the same `folding`
+    /// hash to the same value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use typed_path::{CaseFolding, Utf8WindowsPath};
+    ///
+    /// fn hash(path: &str) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     Utf8WindowsPath::new(path).hash_ignore_case_with(CaseFolding::Ntfs, &mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash(r"C:\µ"), hash(r"c:\Μ"));
+    /// ```
+    pub fn hash_ignore_case_with<H: Hasher>(&self, folding: CaseFolding, state: &mut H) {
+        let mut count = 0;
+        for component in self.components() {
+            match component {
+                Utf8WindowsComponent::Prefix(prefix) => {
+                    state.write_u8(0);
+                    hash_utf8_prefix_ignore_case(prefix.kind(), folding, state);
+                }
+                Utf8WindowsComponent::RootDir => state.write_u8(1),
+                Utf8WindowsComponent::CurDir => state.write_u8(2),
+                Utf8WindowsComponent::ParentDir => state.write_u8(3),
+                Utf8WindowsComponent::Normal(s) => {
+                    state.write_u8(4);
+                    s.hash_folded(folding, state);
+                }
+            }
+            count += 1;
+        }
+        state.write_usize(count);
+    }
+}
+
+/// Case-insensitive ordering and hashing that agree with each other for the same folding
+trait FoldCase {
+    fn cmp_folded(&self, other: &Self, folding: CaseFolding) -> Ordering;
+    fn hash_folded<H: Hasher>(&self, folding: CaseFolding, state: &mut H);
+}
+
+impl FoldCase for [u8] {
+    fn cmp_folded(&self, other: &Self, folding: CaseFolding) -> Ordering {
+        if folding != CaseFolding::Ascii {
+            // Valid UTF-8 is folded by character and ordered before anything else, which only
+            // has its ASCII letters folded
+            match (core::str::from_utf8(self), core::str::from_utf8(other)) {
+                (Ok(a), Ok(b)) => return a.cmp_folded(b, folding),
+                (Ok(_), Err(_)) => return Ordering::Less,
+                (Err(_), Ok(_)) => return Ordering::Greater,
+                (Err(_), Err(_)) => {}
+            }
+        }
+
+        let fold = |b: &u8| b.to_ascii_uppercase();
+        self.iter().map(fold).cmp(other.iter().map(fold))
+    }
+
+    fn hash_folded<H: Hasher>(&self, folding: CaseFolding, state: &mut H) {
+        if folding != CaseFolding::Ascii {
+            match core::str::from_utf8(self) {
+                Ok(s) => {
+                    state.write_u8(0);
+                    return s.hash_folded(folding, state);
+                }
+                Err(_) => state.write_u8(1),
+            }
+        }
+
+        state.write_usize(self.len());
+
+        // Fold into a small buffer so that the hasher still sees larger writes
+        let mut buf = [0; 64];
+        for chunk in self.chunks(buf.len()) {
+            let buf = &mut buf[..chunk.len()];
+            buf.copy_from_slice(chunk);
+            buf.make_ascii_uppercase();
+            state.write(buf);
+        }
+    }
+}
+
+impl FoldCase for str {
+    fn cmp_folded(&self, other: &Self, folding: CaseFolding) -> Ordering {
+        let fold = |c| folding.fold(c);
+        self.chars().map(fold).cmp(other.chars().map(fold))
+    }
+
+    fn hash_folded<H: Hasher>(&self, folding: CaseFolding, state: &mut H) {
+        for c in self.chars() {
+            state.write_u32(folding.fold(c) as u32);
+        }
+        state.write_u8(0xff);
+    }
+}
+
+/// Maps `c` to its uppercase form when that form is a single character, mirroring how Windows
+/// compares names with a simple per-character uppercase table.
+fn fold_char(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_uppercase();
+    }
+
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+/// Maps `c` through an approximation of the NTFS `$UpCase` table, as described by
+/// [`CaseFolding::Ntfs`].
+fn ntfs_upcase(c: char) -> char {
+    match c {
+        'a'..='z' => c.to_ascii_uppercase(),
+        '\u{b5}' => '\u{39c}',
+        '\u{ff}' => '\u{178}',
+        '\u{e0}'..='\u{fe}' if c != '\u{f7}' => {
+            char::from_u32(c as u32 - 0x20).expect("Latin-1 uppercase letter")
+        }
+        '\u{0}'..='\u{ff}' | '\u{10000}'..=char::MAX => c,
+        _ => match fold_char(c) {
+            u if ('\u{100}'..='\u{ffff}').contains(&u) => u,
+            _ => c,
+        },
+    }
+}
+
+/// Orders components like [`WindowsComponent`] does, with prefixes first and normal components
+/// last, folding case within prefixes and normal components.
+fn component_cmp_ignore_case(
+    a: WindowsComponent<'_>,
+    b: WindowsComponent<'_>,
+    folding: CaseFolding,
+) -> Ordering {
+    match (a, b) {
+        (WindowsComponent::Prefix(a), WindowsComponent::Prefix(b)) => {
+            prefix_cmp_ignore_case(a.kind(), b.kind(), folding)
+        }
+        (WindowsComponent::Normal(a), WindowsComponent::Normal(b)) => a.cmp_folded(b, folding),
+        (a, b) => a.cmp(&b),
+    }
+}
+
+fn prefix_cmp_ignore_case(
+    a: WindowsPrefix<'_>,
+    b: WindowsPrefix<'_>,
+    folding: CaseFolding,
+) -> Ordering {
+    match (a, b) {
+        (WindowsPrefix::Verbatim(a), WindowsPrefix::Verbatim(b))
+        | (WindowsPrefix::DeviceNS(a), WindowsPrefix::DeviceNS(b)) => a.cmp_folded(b, folding),
+        (WindowsPrefix::VerbatimUNC(a1, a2), WindowsPrefix::VerbatimUNC(b1, b2))
+        | (WindowsPrefix::UNC(a1, a2), WindowsPrefix::UNC(b1, b2)) => a1
+            .cmp_folded(b1, folding)
+            .then_with(|| a2.cmp_folded(b2, folding)),
+        (WindowsPrefix::VerbatimDisk(a), WindowsPrefix::VerbatimDisk(b))
+        | (WindowsPrefix::Disk(a), WindowsPrefix::Disk(b)) => {
+            a.to_ascii_uppercase().cmp(&b.to_ascii_uppercase())
+        }
+        (a, b) => a.cmp(&b),
+    }
+}
+
+fn hash_prefix_ignore_case<H: Hasher>(
+    prefix: WindowsPrefix<'_>,
+    folding: CaseFolding,
+    state: &mut H,
+) {
+    match prefix {
+        WindowsPrefix::Verbatim(x) => {
+            state.write_u8(0);
+            x.hash_folded(folding, state);
+        }
+        WindowsPrefix::VerbatimUNC(server, share) => {
+            state.write_u8(1);
+            server.hash_folded(folding, state);
+            share.hash_folded(folding, state);
+        }
+        WindowsPrefix::VerbatimDisk(drive) => {
+            state.write_u8(2);
+            state.write_u8(drive.to_ascii_uppercase());
+        }
+        WindowsPrefix::DeviceNS(x) => {
+            state.write_u8(3);
+            x.hash_folded(folding, state);
+        }
+        WindowsPrefix::UNC(server, share) => {
+            state.write_u8(4);
+            server.hash_folded(folding, state);
+            share.hash_folded(folding, state);
+        }
+        WindowsPrefix::Disk(drive) => {
+            state.write_u8(5);
+            state.write_u8(drive.to_ascii_uppercase());
+        }
+    }
+}
+
+fn utf8_component_cmp_ignore_case(
+    a: Utf8WindowsComponent<'_>,
+    b: Utf8WindowsComponent<'_>,
+    folding: CaseFolding,
+) -> Ordering {
+    match (a, b) {
+        (Utf8WindowsComponent::Prefix(a), Utf8WindowsComponent::Prefix(b)) => {
+            utf8_prefix_cmp_ignore_case(a.kind(), b.kind(), folding)
+        }
+        (Utf8WindowsComponent::Normal(a), Utf8WindowsComponent::Normal(b)) => {
+            a.cmp_folded(b, folding)
+        }
+        (a, b) => a.cmp(&b),
+    }
+}
+
+fn utf8_prefix_cmp_ignore_case(
+    a: Utf8WindowsPrefix<'_>,
+    b: Utf8WindowsPrefix<'_>,
+    folding: CaseFolding,
+) -> Ordering {
+    match (a, b) {
+        (Utf8WindowsPrefix::Verbatim(a), Utf8WindowsPrefix::Verbatim(b))
+        | (Utf8WindowsPrefix::DeviceNS(a), Utf8WindowsPrefix::DeviceNS(b)) => {
+            a.cmp_folded(b, folding)
+        }
+        (Utf8WindowsPrefix::VerbatimUNC(a1, a2), Utf8WindowsPrefix::VerbatimUNC(b1, b2))
+        | (Utf8WindowsPrefix::UNC(a1, a2), Utf8WindowsPrefix::UNC(b1, b2)) => a1
+            .cmp_folded(b1, folding)
+            .then_with(|| a2.cmp_folded(b2, folding)),
+        (Utf8WindowsPrefix::VerbatimDisk(a), Utf8WindowsPrefix::VerbatimDisk(b))
+        | (Utf8WindowsPrefix::Disk(a), Utf8WindowsPrefix::Disk(b)) => {
+            folding.fold(a).cmp(&folding.fold(b))
+        }
+        (a, b) => a.cmp(&b),
+    }
+}
+
+fn hash_utf8_prefix_ignore_case<H: Hasher>(
+    prefix: Utf8WindowsPrefix<'_>,
+    folding: CaseFolding,
+    state: &mut H,
+) {
+    match prefix {
+        Utf8WindowsPrefix::Verbatim(x) => {
+            state.write_u8(0);
+            x.hash_folded(folding, state);
+        }
+        Utf8WindowsPrefix::VerbatimUNC(server, share) => {
+            state.write_u8(1);
+            server.hash_folded(folding, state);
+            share.hash_folded(folding, state);
+        }
+        Utf8WindowsPrefix::VerbatimDisk(drive) => {
+            state.write_u8(2);
+            state.write_u32(folding.fold(drive) as u32);
+        }
+        Utf8WindowsPrefix::DeviceNS(x) => {
+            state.write_u8(3);
+            x.hash_folded(folding, state);
+        }
+        Utf8WindowsPrefix::UNC(server, share) => {
+            state.write_u8(4);
+            server.hash_folded(folding, state);
+            share.hash_folded(folding, state);
+        }
+        Utf8WindowsPrefix::Disk(drive) => {
+            state.write_u8(5);
+            state.write_u32(folding.fold(drive) as u32);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+
+    fn hash(path: &WindowsPath) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        path.hash_ignore_case(&mut hasher);
+        hasher.finish()
+    }
+
+    fn utf8_hash(path: &Utf8WindowsPath) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        path.hash_ignore_case(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hash_ignore_case_should_agree_with_eq_ignore_case() {
+        let pairs = [
+            (r"C:\Windows", "c:/WINDOWS/"),
+            (r"\\?\UNC\Server\Share\a", r"\\?\UNC\SERVER\share\A"),
+            (r"\\.\COM1\x", r"\\.\com1\X"),
+            (r"\\?\c:\Long", r"\\?\C:\LONG"),
+            (r"a\..\B", r"A\..\b"),
+        ];
+        for (a, b) in pairs {
+            let (a, b) = (WindowsPath::new(a), WindowsPath::new(b));
+            assert!(a.eq_ignore_case(b), "{a:?} != {b:?}");
+            assert_eq!(hash(a), hash(b), "{a:?} and {b:?} hash differently");
+
+            let (a, b) = (a.to_str().unwrap(), b.to_str().unwrap());
+            let (a, b) = (Utf8WindowsPath::new(a), Utf8WindowsPath::new(b));
+            assert!(a.eq_ignore_case(b), "{a:?} != {b:?}");
+            assert_eq!(
+                utf8_hash(a),
+                utf8_hash(b),
+                "{a:?} and {b:?} hash differently"
+            );
+        }
+    }
+
+    #[test]
+    fn eq_ignore_case_should_distinguish_component_boundaries() {
+        let a = WindowsPath::new(r"C:\ab\c");
+        let b = WindowsPath::new(r"C:\a\bc");
+        assert!(!a.eq_ignore_case(b));
+        assert_ne!(hash(a), hash(b));
+
+        assert!(!WindowsPath::new(r"C:\a").eq_ignore_case(WindowsPath::new(r"D:\a")));
+        assert!(!WindowsPath::new(r"\\s\a").eq_ignore_case(WindowsPath::new(r"\\?\UNC\s\a")));
+        assert!(!WindowsPath::new(r"a\b").eq_ignore_case(WindowsPath::new(r"a\b\c")));
+    }
+
+    #[test]
+    fn eq_ignore_case_should_only_fold_ascii_for_bytes() {
+        let a = WindowsPath::new("C:\\Ren\u{e9}e");
+        let b = WindowsPath::new("C:\\REN\u{c9}E");
+        assert!(!a.eq_ignore_case(b));
+
+        let a = Utf8WindowsPath::new("C:\\Ren\u{e9}e");
+        let b = Utf8WindowsPath::new("C:\\REN\u{c9}E");
+        assert!(a.eq_ignore_case(b));
+        assert_eq!(utf8_hash(a), utf8_hash(b));
+    }
+
+    #[test]
+    fn ntfs_folding_should_agree_with_its_hash() {
+        fn hash_with(path: &WindowsPath, folding: CaseFolding) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            path.hash_ignore_case_with(folding, &mut hasher);
+            hasher.finish()
+        }
+
+        let pairs = [
+            ("C:\\caf\u{e9}", "C:\\CAF\u{c9}"),
+            ("C:\\\u{ff}\u{b5}", "C:\\\u{178}\u{39c}"),
+            ("C:\\\u{3c3}\u{3b1}", "C:\\\u{3a3}\u{391}"),
+            ("\\\\Serv\u{e9}r\\share", "\\\\SERV\u{c9}R\\SHARE"),
+        ];
+        for (a, b) in pairs {
+            let (a, b) = (WindowsPath::new(a), WindowsPath::new(b));
+            assert!(
+                a.eq_ignore_case_with(b, CaseFolding::Ntfs),
+                "{a:?} != {b:?}"
+            );
+            assert_eq!(
+                hash_with(a, CaseFolding::Ntfs),
+                hash_with(b, CaseFolding::Ntfs)
+            );
+        }
+
+        // Characters that NTFS leaves alone
+        let distinct = [
+            ("C:\\\u{df}", "C:\\SS"),
+            ("C:\\\u{131}", "C:\\I"),
+            ("C:\\\u{17f}", "C:\\S"),
+            ("C:\\\u{f7}", "C:\\\u{d7}"),
+            ("C:\\\u{10428}", "C:\\\u{10400}"),
+        ];
+        for (a, b) in distinct {
+            let (a, b) = (WindowsPath::new(a), WindowsPath::new(b));
+            assert!(
+                !a.eq_ignore_case_with(b, CaseFolding::Ntfs),
+                "{a:?} == {b:?}"
+            );
+        }
+
+        // Bytes that are not UTF-8 only fold ASCII, and never match valid UTF-8
+        let a = WindowsPath::new(b"C:\\a\xc9");
+        assert!(a.eq_ignore_case_with(WindowsPath::new(b"C:\\A\xc9"), CaseFolding::Ntfs));
+        assert!(!a.eq_ignore_case_with(WindowsPath::new("C:\\a\u{c9}"), CaseFolding::Ntfs));
+        assert_eq!(
+            hash_with(a, CaseFolding::Ntfs),
+            hash_with(WindowsPath::new(b"C:\\A\xc9"), CaseFolding::Ntfs)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{Utf8WindowsPath, WindowsPath};
+
 /// The primary separator of path components for windows platforms
 pub const SEPARATOR: char = '\\';
 
@@ -22,6 +24,66 @@ pub const CURRENT_DIR: &[u8] = b".";
 /// Path component value that represents the current directory
 pub const CURRENT_DIR_STR: &str = ".";
 
+/// Root directories of every possible drive, from `A:\` to `Z:\`
+pub const DRIVE_ROOTS: [&WindowsPath; 26] = [
+    WindowsPath::new_const(br"A:\"),
+    WindowsPath::new_const(br"B:\"),
+    WindowsPath::new_const(br"C:\"),
+    WindowsPath::new_const(br"D:\"),
+    WindowsPath::new_const(br"E:\"),
+    WindowsPath::new_const(br"F:\"),
+    WindowsPath::new_const(br"G:\"),
+    WindowsPath::new_const(br"H:\"),
+    WindowsPath::new_const(br"I:\"),
+    WindowsPath::new_const(br"J:\"),
+    WindowsPath::new_const(br"K:\"),
+    WindowsPath::new_const(br"L:\"),
+    WindowsPath::new_const(br"M:\"),
+    WindowsPath::new_const(br"N:\"),
+    WindowsPath::new_const(br"O:\"),
+    WindowsPath::new_const(br"P:\"),
+    WindowsPath::new_const(br"Q:\"),
+    WindowsPath::new_const(br"R:\"),
+    WindowsPath::new_const(br"S:\"),
+    WindowsPath::new_const(br"T:\"),
+    WindowsPath::new_const(br"U:\"),
+    WindowsPath::new_const(br"V:\"),
+    WindowsPath::new_const(br"W:\"),
+    WindowsPath::new_const(br"X:\"),
+    WindowsPath::new_const(br"Y:\"),
+    WindowsPath::new_const(br"Z:\"),
+];
+
+/// Root directories of every possible drive, from `A:\` to `Z:\`
+pub const UTF8_DRIVE_ROOTS: [&Utf8WindowsPath; 26] = [
+    Utf8WindowsPath::new_const(r"A:\"),
+    Utf8WindowsPath::new_const(r"B:\"),
+    Utf8WindowsPath::new_const(r"C:\"),
+    Utf8WindowsPath::new_const(r"D:\"),
+    Utf8WindowsPath::new_const(r"E:\"),
+    Utf8WindowsPath::new_const(r"F:\"),
+    Utf8WindowsPath::new_const(r"G:\"),
+    Utf8WindowsPath::new_const(r"H:\"),
+    Utf8WindowsPath::new_const(r"I:\"),
+    Utf8WindowsPath::new_const(r"J:\"),
+    Utf8WindowsPath::new_const(r"K:\"),
+    Utf8WindowsPath::new_const(r"L:\"),
+    Utf8WindowsPath::new_const(r"M:\"),
+    Utf8WindowsPath::new_const(r"N:\"),
+    Utf8WindowsPath::new_const(r"O:\"),
+    Utf8WindowsPath::new_const(r"P:\"),
+    Utf8WindowsPath::new_const(r"Q:\"),
+    Utf8WindowsPath::new_const(r"R:\"),
+    Utf8WindowsPath::new_const(r"S:\"),
+    Utf8WindowsPath::new_const(r"T:\"),
+    Utf8WindowsPath::new_const(r"U:\"),
+    Utf8WindowsPath::new_const(r"V:\"),
+    Utf8WindowsPath::new_const(r"W:\"),
+    Utf8WindowsPath::new_const(r"X:\"),
+    Utf8WindowsPath::new_const(r"Y:\"),
+    Utf8WindowsPath::new_const(r"Z:\"),
+];
+
 /// Reserved names (case insensitive) that cannot be used with files or directories
 /// for personal use (system only)
 pub const RESERVED_DEVICE_NAMES: &[&[u8]] = &[
@@ -44,3 +106,13 @@ pub const DISALLOWED_FILENAME_BYTES: &[u8] =
 
 pub const DISALLOWED_FILENAME_CHARS: &[char] =
     &['\\', '/', ':', '?', '*', '"', '>', '<', '|', '\0'];
+
+/// Maximum length, in UTF-16 code units, of a single file or directory name
+pub const MAX_COMPONENT_LEN: usize = 255;
+
+/// Maximum length, in UTF-16 code units, of a path including its null terminator when not using
+/// a verbatim (`\\?\`) prefix or opting into long paths (`MAX_PATH`)
+pub const MAX_PATH_LEGACY: usize = 260;
+
+/// Maximum length, in UTF-16 code units, of a path that uses a verbatim (`\\?\`) prefix
+pub const MAX_PATH_EXTENDED: usize = 32767;
@@ -0,0 +1,63 @@
+use crate::windows::constants::{
+    CURRENT_DIR_STR, DISALLOWED_FILENAME_CHARS, MAX_COMPONENT_LEN, PARENT_DIR_STR,
+    RESERVED_DEVICE_NAMES_STR,
+};
+
+/// Returns `true` if `name` can be used as the name of a file or directory on Windows.
+///
+/// A valid name:
+///
+/// * is not empty, `.`, or `..`.
+/// * is no longer than [`MAX_COMPONENT_LEN`] UTF-16 code units.
+/// * does not contain any of the [`DISALLOWED_FILENAME_CHARS`], which includes separators.
+/// * is not a reserved device name such as `CON` or `LPT1`, in any case and with or without an
+///   extension (e.g. `nul.txt`).
+/// * does not end with a space or `.`, which Windows silently strips.
+///
+/// This is stricter than [`Utf8Component::is_valid`], which only rejects the characters that
+/// would change how a path is parsed.
+///
+/// [`DISALLOWED_FILENAME_CHARS`]: crate::constants::windows::DISALLOWED_FILENAME_CHARS
+/// [`MAX_COMPONENT_LEN`]: crate::constants::windows::MAX_COMPONENT_LEN
+/// [`Utf8Component::is_valid`]: crate::Utf8Component::is_valid
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::is_valid_windows_filename;
+///
+/// assert!(is_valid_windows_filename("report.txt"));
+/// assert!(is_valid_windows_filename(".gitignore"));
+///
+/// assert!(!is_valid_windows_filename(""));
+/// assert!(!is_valid_windows_filename(".."));
+/// assert!(!is_valid_windows_filename("a:b"));
+/// assert!(!is_valid_windows_filename(r"dir\file"));
+/// assert!(!is_valid_windows_filename("Con"));
+/// assert!(!is_valid_windows_filename("lpt1.log"));
+/// assert!(!is_valid_windows_filename("trailing."));
+/// assert!(!is_valid_windows_filename(&"a".repeat(256)));
+/// ```
+pub fn is_valid_windows_filename(name: &str) -> bool {
+    if name.is_empty() || name == CURRENT_DIR_STR || name == PARENT_DIR_STR {
+        return false;
+    }
+
+    if name.encode_utf16().count() > MAX_COMPONENT_LEN {
+        return false;
+    }
+
+    if name.chars().any(|c| DISALLOWED_FILENAME_CHARS.contains(&c)) {
+        return false;
+    }
+
+    if name.ends_with(' ') || name.ends_with('.') {
+        return false;
+    }
+
+    // Device names are reserved regardless of extension and of trailing spaces before it
+    let stem = name.split('.').next().unwrap_or(name).trim_end_matches(' ');
+    !RESERVED_DEVICE_NAMES_STR
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+}
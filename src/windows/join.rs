@@ -0,0 +1,241 @@
+use crate::no_std_compat::*;
+use crate::windows::constants::MAX_PATH_LEGACY;
+use crate::{
+    Utf8WindowsPath, Utf8WindowsPathBuf, WindowsComponent, WindowsPath, WindowsPathBuf,
+    WindowsPrefix,
+};
+
+/// Options controlling how [`WindowsPath::join_with`] and [`Utf8WindowsPath::join_with`]
+/// build the joined path.
+///
+/// [`Utf8WindowsPath::join_with`]: crate::Utf8WindowsPath::join_with
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::JoinOptions;
+///
+/// let options = JoinOptions::new().auto_verbatim(true);
+/// assert!(options.is_auto_verbatim());
+/// assert!(!JoinOptions::default().is_auto_verbatim());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JoinOptions {
+    auto_verbatim: bool,
+}
+
+impl JoinOptions {
+    /// Creates a new set of options, with everything disabled.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether an absolute path too long for legacy Windows APIs is converted to its
+    /// verbatim (`\\?\`) form.
+    ///
+    /// A path is too long when it needs [`MAX_PATH_LEGACY`] or more UTF-16 code units once its
+    /// null terminator is counted. As Windows does not interpret `.` and `..` within verbatim
+    /// paths, the path is normalized during the conversion and every separator becomes `\`.
+    /// Relative paths, and paths that already have a verbatim or device prefix, are never
+    /// converted.
+    ///
+    /// [`MAX_PATH_LEGACY`]: crate::constants::windows::MAX_PATH_LEGACY
+    #[inline]
+    pub fn auto_verbatim(mut self, yes: bool) -> Self {
+        self.auto_verbatim = yes;
+        self
+    }
+
+    /// Returns true if long paths will be converted to their verbatim form.
+    #[inline]
+    pub fn is_auto_verbatim(&self) -> bool {
+        self.auto_verbatim
+    }
+}
+
+impl WindowsPath {
+    /// Like [`join`], but with additional behavior controlled by `options`.
+    ///
+    /// [`join`]: crate::Path::join
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{JoinOptions, WindowsPath};
+    ///
+    /// let options = JoinOptions::new().auto_verbatim(true);
+    ///
+    /// // Short paths are joined as usual
+    /// let path = WindowsPath::new(r"C:\build").join_with("out", options);
+    /// assert_eq!(path, WindowsPath::new(r"C:\build\out"));
+    ///
+    /// // Long paths switch to the verbatim form
+    /// let name = "x".repeat(250);
+    /// let path = WindowsPath::new(r"C:\build\.\target").join_with(&name, options);
+    /// assert!(path.starts_with(r"\\?\C:\build\target"));
+    ///
+    /// // UNC paths use the verbatim UNC form
+    /// let path = WindowsPath::new(r"\\server\share").join_with(&name, options);
+    /// assert!(path.starts_with(r"\\?\UNC\server\share"));
+    /// ```
+    pub fn join_with<P: AsRef<WindowsPath>>(
+        &self,
+        path: P,
+        options: JoinOptions,
+    ) -> WindowsPathBuf {
+        let joined = self.join(path);
+        if options.auto_verbatim && exceeds_legacy_limit(joined.as_bytes()) {
+            if let Some(verbatim) = to_verbatim(&joined) {
+                return verbatim;
+            }
+        }
+        joined
+    }
+}
+
+impl Utf8WindowsPath {
+    /// Like [`join`], but with additional behavior controlled by `options`.
+    ///
+    /// [`join`]: crate::Utf8Path::join
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{JoinOptions, Utf8WindowsPath};
+    ///
+    /// let options = JoinOptions::new().auto_verbatim(true);
+    /// let name = "x".repeat(250);
+    ///
+    /// let path = Utf8WindowsPath::new(r"D:/work/../build").join_with(&name, options);
+    /// assert_eq!(path.as_str(), format!(r"\\?\D:\build\{name}"));
+    ///
+    /// // Relative paths cannot be made verbatim
+    /// let path = Utf8WindowsPath::new("build").join_with(&name, options);
+    /// assert_eq!(path.as_str(), format!(r"build\{name}"));
+    /// ```
+    pub fn join_with<P: AsRef<Utf8WindowsPath>>(
+        &self,
+        path: P,
+        options: JoinOptions,
+    ) -> Utf8WindowsPathBuf {
+        let joined = self.join(path);
+        if options.auto_verbatim && exceeds_legacy_limit(joined.as_str().as_bytes()) {
+            let bytes = WindowsPath::new(joined.as_str());
+            if let Some(verbatim) = to_verbatim(bytes) {
+                // Only ASCII was added to the UTF-8 components of the joined path
+                let verbatim = String::from_utf8(verbatim.into_vec()).expect("valid UTF-8");
+                return Utf8WindowsPathBuf::from(verbatim);
+            }
+        }
+        joined
+    }
+}
+
+/// Returns true if `bytes`, plus a null terminator, do not fit within [`MAX_PATH_LEGACY`]
+/// UTF-16 code units.
+pub(super) fn exceeds_legacy_limit(bytes: &[u8]) -> bool {
+    // Every UTF-16 code unit needs at least one byte, so short paths skip the conversion
+    if bytes.len() < MAX_PATH_LEGACY {
+        return false;
+    }
+    String::from_utf8_lossy(bytes).encode_utf16().count() >= MAX_PATH_LEGACY
+}
+
+/// Converts an absolute disk or UNC path into its normalized verbatim form, returning [`None`]
+/// for any other kind of path.
+pub(super) fn to_verbatim(path: &WindowsPath) -> Option<WindowsPathBuf> {
+    if !path.is_absolute() {
+        return None;
+    }
+
+    let mut bytes = br"\\?\".to_vec();
+    let mut components = normalized_components(path).into_iter();
+    match components.next() {
+        Some(WindowsComponent::Prefix(prefix)) => match prefix.kind() {
+            WindowsPrefix::Disk(letter) => bytes.extend_from_slice(&[letter, b':']),
+            WindowsPrefix::UNC(server, share) => {
+                bytes.extend_from_slice(br"UNC\");
+                bytes.extend_from_slice(server);
+                bytes.push(b'\\');
+                bytes.extend_from_slice(share);
+            }
+            _ => return None,
+        },
+        _ => return None,
+    }
+
+    for component in components {
+        match component {
+            WindowsComponent::RootDir => bytes.push(b'\\'),
+            WindowsComponent::Normal(name) => {
+                if bytes.last() != Some(&b'\\') {
+                    bytes.push(b'\\');
+                }
+                bytes.extend_from_slice(name);
+            }
+            _ => {}
+        }
+    }
+
+    Some(WindowsPathBuf::from(bytes))
+}
+
+/// Returns the components of `path` with `.` and `..` resolved as by [`Path::normalize`].
+///
+/// [`Path::normalize`]: crate::Path::normalize
+fn normalized_components(path: &WindowsPath) -> Vec<WindowsComponent<'_>> {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            WindowsComponent::CurDir => {}
+            WindowsComponent::ParentDir => {
+                if let Some(WindowsComponent::Normal(_)) = components.last() {
+                    components.pop();
+                }
+            }
+            component => components.push(component),
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_with_should_only_convert_long_disk_and_unc_paths() {
+        let options = JoinOptions::new().auto_verbatim(true);
+        let name = "x".repeat(260);
+
+        let path = WindowsPath::new("//server/share/a/./b").join_with(&name, options);
+        assert_eq!(
+            path.as_bytes(),
+            [br"\\?\UNC\server\share\a\b\".as_slice(), name.as_bytes()].concat()
+        );
+
+        // Already verbatim and device paths are left alone
+        let path = WindowsPath::new(r"\\?\C:\a\.").join_with(&name, options);
+        assert!(path.starts_with(r"\\?\C:\a\."));
+        let path = WindowsPath::new(r"\\.\COM1").join_with(&name, options);
+        assert!(path.starts_with(r"\\.\COM1"));
+
+        // Drive-relative paths are not absolute
+        let path = WindowsPath::new(r"C:a").join_with(&name, options);
+        assert!(path.starts_with(r"C:a"));
+
+        // The option is disabled by default
+        let path = WindowsPath::new(r"C:\a").join_with(&name, JoinOptions::default());
+        assert!(path.starts_with(r"C:\a"));
+    }
+
+    #[test]
+    fn exceeds_legacy_limit_should_count_utf16_code_units_and_terminator() {
+        assert!(!exceeds_legacy_limit("x".repeat(259).as_bytes()));
+        assert!(exceeds_legacy_limit("x".repeat(260).as_bytes()));
+
+        // Each of these takes three bytes but a single UTF-16 code unit
+        assert!(!exceeds_legacy_limit("€".repeat(259).as_bytes()));
+    }
+}
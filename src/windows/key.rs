@@ -0,0 +1,293 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use crate::{WindowsPath, WindowsPathBuf};
+
+/// Borrowed form of a [`WindowsPathKey`], used to look up keys in maps and sets.
+///
+/// This wraps a [`WindowsPath`] and compares, orders, and hashes it without regard to ASCII case
+/// using [`WindowsPath::cmp_ignore_case`] and [`WindowsPath::hash_ignore_case`].
+///
+/// [`WindowsPathKey`] cannot implement `Borrow<WindowsPath>` because [`WindowsPath`] orders and
+/// hashes case-sensitively, which would break the contract that [`Borrow`] requires and leave
+/// lookups silently missing entries. Wrap the borrowed path with [`WindowsPathKeyRef::new`]
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use typed_path::{WindowsPath, WindowsPathKey, WindowsPathKeyRef};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(WindowsPathKey::new(r"C:\Program Files"), 1);
+///
+/// let path = WindowsPath::new(r"c:\PROGRAM FILES");
+/// assert_eq!(map.get(WindowsPathKeyRef::new(path)), Some(&1));
+/// ```
+#[repr(transparent)]
+pub struct WindowsPathKeyRef(WindowsPath);
+
+impl WindowsPathKeyRef {
+    /// Wraps a path as a case-insensitive key without copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPathKeyRef;
+    ///
+    /// assert_eq!(WindowsPathKeyRef::new(r"C:\Temp"), WindowsPathKeyRef::new(r"c:\TEMP"));
+    /// ```
+    pub fn new<P: AsRef<WindowsPath> + ?Sized>(path: &P) -> &Self {
+        unsafe { &*(path.as_ref() as *const WindowsPath as *const Self) }
+    }
+
+    /// Returns the wrapped path with its original spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPath, WindowsPathKeyRef};
+    ///
+    /// let key = WindowsPathKeyRef::new(r"C:\Temp");
+    /// assert_eq!(key.as_path().as_bytes(), br"C:\Temp");
+    /// ```
+    pub fn as_path(&self) -> &WindowsPath {
+        &self.0
+    }
+}
+
+impl PartialEq for WindowsPathKeyRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_case(&other.0)
+    }
+}
+
+impl Eq for WindowsPathKeyRef {}
+
+impl PartialOrd for WindowsPathKeyRef {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WindowsPathKeyRef {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_ignore_case(&other.0)
+    }
+}
+
+impl Hash for WindowsPathKeyRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_ignore_case(state)
+    }
+}
+
+impl AsRef<WindowsPath> for WindowsPathKeyRef {
+    #[inline]
+    fn as_ref(&self) -> &WindowsPath {
+        &self.0
+    }
+}
+
+impl ToOwned for WindowsPathKeyRef {
+    type Owned = WindowsPathKey;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        WindowsPathKey(self.0.to_path_buf())
+    }
+}
+
+impl fmt::Debug for WindowsPathKeyRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for WindowsPathKeyRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0.display(), f)
+    }
+}
+
+/// An owned [`WindowsPathBuf`] that compares, orders, and hashes without regard to ASCII case
+/// while keeping its original spelling, suitable as a key in a `BTreeMap` or `HashMap`.
+///
+/// Two keys are equal when their paths are equal under [`WindowsPath::eq_ignore_case`], so the
+/// first spelling inserted into a map is the one that is kept and displayed. Look up entries with
+/// a borrowed path by wrapping it in a [`WindowsPathKeyRef`].
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use typed_path::{WindowsPath, WindowsPathKey, WindowsPathKeyRef};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(WindowsPathKey::new(r"C:\Users\Alice"), "alice");
+/// map.insert(WindowsPathKey::new(r"c:\users\ALICE"), "still alice");
+/// assert_eq!(map.len(), 1);
+///
+/// let (key, value) = map.iter().next().unwrap();
+/// assert_eq!(key.to_string(), r"C:\Users\Alice");
+/// assert_eq!(*value, "still alice");
+///
+/// let lookup = WindowsPath::new(r"C:\USERS\alice");
+/// assert!(map.contains_key(WindowsPathKeyRef::new(lookup)));
+/// ```
+#[derive(Clone)]
+pub struct WindowsPathKey(WindowsPathBuf);
+
+impl WindowsPathKey {
+    /// Creates a new key from a path, preserving its spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPathKey;
+    ///
+    /// assert_eq!(WindowsPathKey::new(r"C:\Temp"), WindowsPathKey::new(r"C:\TEMP"));
+    /// ```
+    pub fn new(path: impl Into<WindowsPathBuf>) -> Self {
+        Self(path.into())
+    }
+
+    /// Returns the path with its original spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPathKey;
+    ///
+    /// let key = WindowsPathKey::new(r"C:\Temp");
+    /// assert_eq!(key.as_path().as_bytes(), br"C:\Temp");
+    /// ```
+    pub fn as_path(&self) -> &WindowsPath {
+        &self.0
+    }
+
+    /// Consumes the key, returning the path with its original spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPathKey;
+    ///
+    /// let path = WindowsPathKey::new(r"C:\Temp").into_path_buf();
+    /// assert_eq!(path.as_bytes(), br"C:\Temp");
+    /// ```
+    pub fn into_path_buf(self) -> WindowsPathBuf {
+        self.0
+    }
+}
+
+impl Deref for WindowsPathKey {
+    type Target = WindowsPathKeyRef;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        WindowsPathKeyRef::new(&self.0)
+    }
+}
+
+impl Borrow<WindowsPathKeyRef> for WindowsPathKey {
+    #[inline]
+    fn borrow(&self) -> &WindowsPathKeyRef {
+        self.deref()
+    }
+}
+
+impl AsRef<WindowsPath> for WindowsPathKey {
+    #[inline]
+    fn as_ref(&self) -> &WindowsPath {
+        &self.0
+    }
+}
+
+impl From<WindowsPathBuf> for WindowsPathKey {
+    #[inline]
+    fn from(path: WindowsPathBuf) -> Self {
+        Self(path)
+    }
+}
+
+impl From<WindowsPathKey> for WindowsPathBuf {
+    #[inline]
+    fn from(key: WindowsPathKey) -> Self {
+        key.0
+    }
+}
+
+impl PartialEq for WindowsPathKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl Eq for WindowsPathKey {}
+
+impl PartialOrd for WindowsPathKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WindowsPathKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl Hash for WindowsPathKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
+impl fmt::Debug for WindowsPathKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for WindowsPathKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0.display(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn btree_map_should_find_keys_regardless_of_case() {
+        let mut map = BTreeMap::new();
+        for (i, path) in [r"C:\b", r"C:\A", r"c:\a\b", r"\\Server\Share\x"]
+            .into_iter()
+            .enumerate()
+        {
+            map.insert(WindowsPathKey::new(path), i);
+        }
+
+        assert_eq!(map.get(WindowsPathKeyRef::new(r"c:\B")), Some(&0));
+        assert_eq!(map.get(WindowsPathKeyRef::new(r"C:\a")), Some(&1));
+        assert_eq!(map.get(WindowsPathKeyRef::new(r"C:\A\B")), Some(&2));
+        assert_eq!(
+            map.get(WindowsPathKeyRef::new(r"\\server\SHARE\X")),
+            Some(&3)
+        );
+        assert_eq!(map.get(WindowsPathKeyRef::new(r"C:\c")), None);
+
+        // Ordering must agree with lookups, so iteration is sorted case-insensitively
+        let keys: Vec<_> = map.keys().map(|key| key.to_string()).collect();
+        assert_eq!(keys, [r"\\Server\Share\x", r"C:\A", r"c:\a\b", r"C:\b"]);
+    }
+}
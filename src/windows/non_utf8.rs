@@ -1,12 +1,15 @@
 mod components;
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 
 pub use components::*;
 
 use super::constants::*;
-use crate::common::CheckedPathError;
+use crate::common::{
+    replace_tail, CheckedPathError, PushBuffer, RootKind, UncPathError, WindowsPrefixError,
+};
 use crate::no_std_compat::*;
 use crate::typed::{TypedPath, TypedPathBuf};
 use crate::{private, Component, Components, Encoding, Path, PathBuf};
@@ -89,6 +92,47 @@ impl<'a> Encoding<'a> for WindowsEncoding {
         h.write_usize(bytes_hashed);
     }
 
+    fn cmp(path: &[u8], other: &[u8]) -> Ordering {
+        let is_sep = |b: &u8| *b == SEPARATOR as u8 || *b == ALT_SEPARATOR as u8;
+        let starts_with_two_seps =
+            |bytes: &[u8]| matches!(bytes, [a, b, ..] if is_sep(a) && is_sep(b));
+
+        let first_difference = match path.iter().zip(other).position(|(a, b)| a != b) {
+            None if path.len() == other.len() => return Ordering::Equal,
+            None => path.len().min(other.len()),
+            Some(i) => i,
+        };
+
+        // UNC, device, and verbatim prefixes span separators and change which bytes act as
+        // separators, so we cannot safely back up into the middle of them
+        if starts_with_two_seps(path) || starts_with_two_seps(other) {
+            return Self::components(path).cmp(Self::components(other));
+        }
+
+        // Back up to the start of the component containing the first difference, as everything
+        // before it parses into the same components for both paths
+        let start = path[..first_difference]
+            .iter()
+            .rposition(is_sep)
+            .map_or(0, |i| i + 1);
+        let (rest, other_rest) = (&path[start..], &other[start..]);
+
+        // When parsed on its own, a remainder starting with a separator, `.`, or a drive letter
+        // would yield a root, current directory, or prefix component that does not exist within
+        // the full path
+        let is_ambiguous = |bytes: &[u8]| match bytes {
+            [b, ..] if is_sep(b) || *b == b'.' => true,
+            [_, b':', ..] => true,
+            _ => false,
+        };
+
+        if is_ambiguous(rest) || is_ambiguous(other_rest) {
+            Self::components(path).cmp(Self::components(other))
+        } else {
+            Self::components(rest).cmp(Self::components(other_rest))
+        }
+    }
+
     // COMPLEX RULES OF WINDOWS PATH APPENDING
     //
     // 1. If the incoming path being pushed is absolute or has a prefix:
@@ -127,70 +171,72 @@ impl<'a> Encoding<'a> for WindowsEncoding {
     //      current path
     //    * append incoming path to current path
     fn push(current_path: &mut Vec<u8>, path: &[u8]) {
+        let pushed = Self::try_push(current_path, path);
+        debug_assert!(pushed, "a Vec can always grow");
+    }
+
+    fn try_push<B: PushBuffer>(current_path: &mut B, path: &[u8]) -> bool {
         if path.is_empty() {
-            return;
+            return true;
         }
 
         let comps = Self::components(path);
-        let cur_comps = Self::components(current_path);
+        let current = current_path.as_bytes();
+        let cur_comps = Self::components(current);
 
         if comps.is_absolute() || comps.has_prefix() {
-            current_path.clear();
-            current_path.extend_from_slice(path);
-        } else if cur_comps.has_any_verbatim_prefix() && !path.is_empty() {
-            let mut buffer: Vec<_> = Self::components(current_path).collect();
-            for c in Self::components(path) {
+            replace_tail(current_path, 0, None, path)
+        } else if cur_comps.has_any_verbatim_prefix() {
+            // Verbatim paths are not parsed by Windows, so `.` and `..` are resolved here instead
+            // by rebuilding the path one component at a time
+            let mut rebuilt = B::default();
+            let mut has_root = false;
+            for c in cur_comps {
+                has_root |= c == WindowsComponent::RootDir;
+                if !push_verbatim_component(&mut rebuilt, c) {
+                    return false;
+                }
+            }
+
+            for c in comps {
                 match c {
                     WindowsComponent::RootDir => {
-                        buffer.truncate(1);
-                        buffer.push(c);
+                        let len = Self::components(rebuilt.as_bytes())
+                            .next()
+                            .map_or(0, |c| c.as_bytes().len());
+                        rebuilt.truncate(len);
+                        has_root = true;
+                        if !push_verbatim_component(&mut rebuilt, c) {
+                            return false;
+                        }
                     }
                     WindowsComponent::CurDir => (),
-                    WindowsComponent::ParentDir => {
-                        if let Some(WindowsComponent::Normal(_)) = buffer.last() {
-                            buffer.pop();
+                    WindowsComponent::ParentDir => pop_verbatim_normal(&mut rebuilt, has_root),
+                    _ => {
+                        if !push_verbatim_component(&mut rebuilt, c) {
+                            return false;
                         }
                     }
-                    _ => buffer.push(c),
                 }
             }
 
-            let mut new_path = Vec::new();
-            let mut need_sep = false;
-
-            for c in buffer {
-                if need_sep && c != WindowsComponent::RootDir {
-                    new_path.push(SEPARATOR as u8);
-                }
-
-                new_path.extend_from_slice(c.as_bytes());
-
-                need_sep = match c {
-                    WindowsComponent::RootDir => false,
-                    WindowsComponent::Prefix(prefix) => {
-                        !matches!(prefix.kind(), WindowsPrefix::Disk(_))
-                    }
-                    _ => true,
-                };
-            }
-
-            *current_path = new_path;
+            *current_path = rebuilt;
+            true
         } else if comps.has_root() {
-            let len = Self::components(current_path).prefix_len();
-            current_path.truncate(len);
-            current_path.extend_from_slice(path);
+            let len = cur_comps.prefix_len();
+            replace_tail(current_path, len, None, path)
         } else {
             // NOTE: From std lib, there's a check that the prefix len == path len, which
             //       would imply having no other
-            let needs_sep = (!current_path.is_empty()
-                && !current_path.ends_with(&[SEPARATOR as u8]))
-                && !Self::components(current_path).is_only_disk();
+            let len = current.len();
+            let needs_sep = (!current.is_empty() && !current.ends_with(&[SEPARATOR as u8]))
+                && !cur_comps.is_only_disk();
 
             if needs_sep {
-                current_path.push(SEPARATOR as u8);
+                replace_tail(current_path, len, Some(SEPARATOR as u8), path)
+            } else {
+                replace_tail(current_path, len, None, path)
             }
-
-            current_path.extend_from_slice(path);
         }
     }
 
@@ -223,6 +269,50 @@ impl<'a> Encoding<'a> for WindowsEncoding {
         Self::push(current_path, path);
         Ok(())
     }
+
+    fn starts_with_fast(path: &[u8], base: &[u8]) -> Option<bool> {
+        let is_sep = |b: &u8| *b == SEPARATOR as u8 || *b == ALT_SEPARATOR as u8;
+        let starts_with_two_seps =
+            |bytes: &[u8]| matches!(bytes, [a, b, ..] if is_sep(a) && is_sep(b));
+
+        // UNC, device, and verbatim prefixes change which bytes act as separators and can match
+        // partially (e.g. `\\server` against `\\server\share`), so we only handle the simpler
+        // forms here and leave the rest to a component comparison
+        if starts_with_two_seps(path) || starts_with_two_seps(base) || !path.starts_with(base) {
+            return None;
+        }
+
+        // A byte prefix only lines up with the components of `path` if it ends on a component
+        // boundary, meaning nothing remains, or a separator sits on either side of the split
+        let rest = &path[base.len()..];
+        let on_boundary = base.is_empty()
+            || rest.is_empty()
+            || base.last().map_or(false, is_sep)
+            || rest.first().map_or(false, is_sep);
+
+        if on_boundary {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    fn root_kind(path: &[u8]) -> RootKind {
+        let components = Self::components(path);
+        match components.prefix().map(|prefix| prefix.kind()) {
+            None if components.has_root() => RootKind::RootRelative,
+            None => RootKind::Relative,
+            Some(WindowsPrefix::Disk(_)) if components.has_root() => RootKind::Absolute,
+            Some(WindowsPrefix::Disk(_)) => RootKind::DriveRelative,
+            Some(WindowsPrefix::UNC(..)) => RootKind::UNC,
+            Some(WindowsPrefix::DeviceNS(_)) => RootKind::Device,
+            Some(
+                WindowsPrefix::Verbatim(_)
+                | WindowsPrefix::VerbatimUNC(..)
+                | WindowsPrefix::VerbatimDisk(_),
+            ) => RootKind::Verbatim,
+        }
+    }
 }
 
 impl fmt::Debug for WindowsEncoding {
@@ -274,19 +364,408 @@ where
 }
 
 impl WindowsPath {
-    pub fn to_typed_path(&self) -> TypedPath {
+    pub fn to_typed_path(&self) -> TypedPath<'_> {
         TypedPath::windows(self)
     }
 
     pub fn to_typed_path_buf(&self) -> TypedPathBuf {
         TypedPathBuf::from_windows(self)
     }
+
+    /// Returns the [`file_name`] with any alternate data stream suffix removed.
+    ///
+    /// On NTFS, a file name can refer to an alternate data stream of the file using the syntax
+    /// `file.txt:stream` or `file.txt:stream:$DATA`. Methods such as [`extension`] are unaware of
+    /// this syntax and treat the stream as part of the file name.
+    ///
+    /// [`file_name`]: Path::file_name
+    /// [`extension`]: Path::extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPath;
+    ///
+    /// let path = WindowsPath::new(r"C:\dir\file.txt:stream:$DATA");
+    /// assert_eq!(path.file_name_without_stream(), Some(b"file.txt".as_slice()));
+    /// assert_eq!(path.extension(), Some(b"txt:stream:$DATA".as_slice()));
+    ///
+    /// let path = WindowsPath::new(r"C:\dir\file.txt");
+    /// assert_eq!(path.file_name_without_stream(), Some(b"file.txt".as_slice()));
+    /// ```
+    pub fn file_name_without_stream(&self) -> Option<&[u8]> {
+        self.file_name().map(|name| match split_stream(name) {
+            Some((name, _)) => name,
+            None => name,
+        })
+    }
+
+    /// Returns the name of the alternate data stream referenced by the [`file_name`], if any.
+    ///
+    /// The stream type, such as `$DATA`, is not included. The default stream, written as
+    /// `file.txt::$DATA`, has an empty name.
+    ///
+    /// [`file_name`]: Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPath;
+    ///
+    /// let path = WindowsPath::new(r"C:\dir\file.txt:stream:$DATA");
+    /// assert_eq!(path.stream_name(), Some(b"stream".as_slice()));
+    ///
+    /// let path = WindowsPath::new(r"C:\dir\file.txt::$DATA");
+    /// assert_eq!(path.stream_name(), Some(b"".as_slice()));
+    ///
+    /// let path = WindowsPath::new(r"C:\dir\file.txt");
+    /// assert_eq!(path.stream_name(), None);
+    /// ```
+    pub fn stream_name(&self) -> Option<&[u8]> {
+        let (_, stream) = split_stream(self.file_name()?)?;
+        Some(match split_stream(stream) {
+            Some((name, _)) => name,
+            None => stream,
+        })
+    }
+
+    /// Returns `true` if any [`Normal`] component of the path uses alternate data stream syntax,
+    /// meaning that it contains a `:`.
+    ///
+    /// Such paths are valid on NTFS but are commonly rejected by tooling, and are not considered
+    /// valid by [`Component::is_valid`].
+    ///
+    /// [`Normal`]: WindowsComponent::Normal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPath;
+    ///
+    /// assert!(WindowsPath::new(r"C:\dir\file.txt:stream").has_stream());
+    /// assert!(WindowsPath::new(r"dir:stream\file.txt").has_stream());
+    /// assert!(!WindowsPath::new(r"C:\dir\file.txt").has_stream());
+    /// ```
+    pub fn has_stream(&self) -> bool {
+        self.components().any(|component| match component {
+            WindowsComponent::Normal(name) => split_stream(name).is_some(),
+            _ => false,
+        })
+    }
+}
+
+impl WindowsPathBuf {
+    /// Creates a [`WindowsPathBuf`] pointing to the root of the drive identified by `letter`,
+    /// such as `C:\`. The drive letter is always stored in uppercase.
+    ///
+    /// Returns `None` if `letter` is not an ASCII letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPath, WindowsPathBuf};
+    ///
+    /// assert_eq!(WindowsPathBuf::drive_root('C').unwrap(), WindowsPath::new(r"C:\"));
+    /// assert_eq!(WindowsPathBuf::drive_root('d').unwrap(), WindowsPath::new(r"D:\"));
+    /// assert!(WindowsPathBuf::drive_root('C').unwrap().is_absolute());
+    /// assert_eq!(WindowsPathBuf::drive_root('1'), None);
+    /// ```
+    pub fn drive_root(letter: char) -> Option<Self> {
+        if !letter.is_ascii_alphabetic() {
+            return None;
+        }
+
+        let index = (letter.to_ascii_uppercase() as u8 - b'A') as usize;
+        Some(DRIVE_ROOTS[index].to_path_buf())
+    }
+
+    /// Creates a [`WindowsPathBuf`] pointing to the root of the administrative share for the
+    /// drive identified by `letter` on `host`, such as `\\host\C$\`. The drive letter is always
+    /// stored in uppercase.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `letter` is not an ASCII letter, or if `host` is not a valid server
+    /// name as described by [`WindowsPrefix::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPathBuf, WindowsPrefixError};
+    ///
+    /// let path = WindowsPathBuf::admin_share('c', "build01").unwrap();
+    /// assert_eq!(path.as_bytes(), br"\\build01\C$\");
+    /// assert!(path.is_absolute());
+    ///
+    /// assert_eq!(
+    ///     WindowsPathBuf::admin_share('1', "build01"),
+    ///     Err(WindowsPrefixError::InvalidDrive)
+    /// );
+    /// assert_eq!(
+    ///     WindowsPathBuf::admin_share('C', r"a\b"),
+    ///     Err(WindowsPrefixError::InvalidServer)
+    /// );
+    /// ```
+    pub fn admin_share<H: AsRef<[u8]>>(letter: char, host: H) -> Result<Self, WindowsPrefixError> {
+        if !letter.is_ascii_alphabetic() {
+            return Err(WindowsPrefixError::InvalidDrive);
+        }
+
+        let share = [letter.to_ascii_uppercase() as u8, b'$'];
+        let mut path = Self::unc_root(host.as_ref(), &share)?;
+        path.push(SEPARATOR as u8);
+        Ok(Self::from(path))
+    }
+
+    /// Creates a [`WindowsPathBuf`] pointing to `rel` within `share` on `host`, such as
+    /// `\\host\share\rel`. Domain-based DFS namespaces are UNC paths as well, with the domain as
+    /// the host and the namespace as the share.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `host` and `share` are not valid as described by
+    /// [`WindowsPrefix::validate`], or if `rel` could not be pushed onto the share with
+    /// [`push_checked`](crate::PathBuf::push_checked), such as when it is absolute or escapes the
+    /// share with `..`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{CheckedPathError, UncPathError, WindowsPathBuf, WindowsPrefixError};
+    ///
+    /// let path = WindowsPathBuf::unc("fileserver", "public", r"reports\2024.xlsx").unwrap();
+    /// assert_eq!(path.as_bytes(), br"\\fileserver\public\reports\2024.xlsx");
+    ///
+    /// let path = WindowsPathBuf::unc("corp.example.com", "dfs", "").unwrap();
+    /// assert_eq!(path.as_bytes(), br"\\corp.example.com\dfs");
+    ///
+    /// assert_eq!(
+    ///     WindowsPathBuf::unc("fileserver", "", "file.txt"),
+    ///     Err(UncPathError::InvalidPrefix(WindowsPrefixError::EmptyShare)),
+    /// );
+    /// assert_eq!(
+    ///     WindowsPathBuf::unc("fileserver", "public", r"..\private"),
+    ///     Err(UncPathError::InvalidPath(CheckedPathError::PathTraversalAttack)),
+    /// );
+    /// ```
+    pub fn unc<H, S, P>(host: H, share: S, rel: P) -> Result<Self, UncPathError>
+    where
+        H: AsRef<[u8]>,
+        S: AsRef<[u8]>,
+        P: AsRef<[u8]>,
+    {
+        let mut path = Self::from(Self::unc_root(host.as_ref(), share.as_ref())?);
+        path.push_checked(rel.as_ref())?;
+        Ok(path)
+    }
+
+    /// Validates `host` and `share`, returning the bytes of `\\host\share`.
+    fn unc_root(host: &[u8], share: &[u8]) -> Result<Vec<u8>, WindowsPrefixError> {
+        WindowsPrefix::UNC(host, share).validate()?;
+
+        let mut path = Vec::with_capacity(3 + host.len() + share.len());
+        path.extend_from_slice(br"\\");
+        path.extend_from_slice(host);
+        path.push(SEPARATOR as u8);
+        path.extend_from_slice(share);
+        Ok(path)
+    }
+
+    /// Creates a [`WindowsPathBuf`] from `s` after expanding `%NAME%` references to
+    /// environment variables, as Windows does for `REG_EXPAND_SZ` registry values.
+    ///
+    /// Each name is passed to `resolve`, which returns its value or [`None`] if it is not defined.
+    /// Like `ExpandEnvironmentStrings`, a reference that cannot be resolved is left in the path as
+    /// written, and the names of those references are returned alongside the path in the order they
+    /// were first encountered. Text between two `%`s that contains a separator is never reported,
+    /// as it cannot be the name of a variable in a path. Since Windows variable names are
+    /// case-insensitive, `resolve` should generally ignore case as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPath, WindowsPathBuf};
+    ///
+    /// let resolve = |name: &[u8]| {
+    ///     if name.eq_ignore_ascii_case(b"SystemRoot") {
+    ///         Some(&br"C:\Windows"[..])
+    ///     } else {
+    ///         None
+    ///     }
+    /// };
+    ///
+    /// let (path, unresolved) = WindowsPathBuf::from_expandable(r"%SystemRoot%\System32", resolve);
+    /// assert_eq!(path, WindowsPath::new(r"C:\Windows\System32"));
+    /// assert!(unresolved.is_empty());
+    ///
+    /// let (path, unresolved) =
+    ///     WindowsPathBuf::from_expandable(r"%APPDATA%\%systemroot%", resolve);
+    /// assert_eq!(path, WindowsPath::new(r"%APPDATA%\C:\Windows"));
+    /// assert_eq!(unresolved, [b"APPDATA".to_vec()]);
+    /// ```
+    pub fn from_expandable<S, F, V>(s: S, resolve: F) -> (Self, Vec<Vec<u8>>)
+    where
+        S: AsRef<[u8]>,
+        F: FnMut(&[u8]) -> Option<V>,
+        V: AsRef<[u8]>,
+    {
+        let (bytes, unresolved) = expand_env_vars(s.as_ref(), resolve);
+        (Self::from(bytes), unresolved)
+    }
+}
+
+/// Expands `%NAME%` references in `s` using `resolve`, returning the expanded bytes along with
+/// the names that could not be resolved.
+///
+/// An unresolved reference is kept as written, and its closing `%` may begin the next reference,
+/// matching the behavior of `ExpandEnvironmentStrings`. Names containing a separator are not
+/// reported as unresolved, as they only arise between two unrelated `%`s, like in `%A%\%B%`.
+pub(crate) fn expand_env_vars<F, V>(s: &[u8], mut resolve: F) -> (Vec<u8>, Vec<Vec<u8>>)
+where
+    F: FnMut(&[u8]) -> Option<V>,
+    V: AsRef<[u8]>,
+{
+    let mut expanded = Vec::with_capacity(s.len());
+    let mut unresolved: Vec<Vec<u8>> = Vec::new();
+    let mut rest = s;
+
+    while let Some(start) = rest.iter().position(|b| *b == b'%') {
+        expanded.extend_from_slice(&rest[..start]);
+        let after = &rest[start + 1..];
+        let end = match after.iter().position(|b| *b == b'%') {
+            Some(end) => end,
+            None => {
+                rest = &rest[start..];
+                break;
+            }
+        };
+
+        let name = &after[..end];
+        match (name.is_empty(), resolve(name)) {
+            (false, Some(value)) => {
+                expanded.extend_from_slice(value.as_ref());
+                rest = &after[end + 1..];
+            }
+            _ => {
+                // A name spanning a separator comes from stray `%`s rather than a reference
+                let is_name = !name.is_empty()
+                    && !name
+                        .iter()
+                        .any(|b| *b == SEPARATOR as u8 || *b == ALT_SEPARATOR as u8);
+                if is_name && !unresolved.iter().any(|n| n == name) {
+                    unresolved.push(name.to_vec());
+                }
+                expanded.push(b'%');
+                expanded.extend_from_slice(name);
+                rest = &after[end..];
+            }
+        }
+    }
+
+    expanded.extend_from_slice(rest);
+    (expanded, unresolved)
+}
+
+/// Splits `name` at the first `:`, which separates a file name from its alternate data stream.
+fn split_stream(name: &[u8]) -> Option<(&[u8], &[u8])> {
+    let i = name.iter().position(|b| *b == b':')?;
+    Some((&name[..i], &name[i + 1..]))
+}
+
+/// Appends `component` to a verbatim path being rebuilt by [`WindowsEncoding::try_push`],
+/// separating it from the previous component unless that was the root, returning false without
+/// changing `rebuilt` if it would not fit.
+fn push_verbatim_component<B: PushBuffer>(rebuilt: &mut B, component: WindowsComponent) -> bool {
+    let current = rebuilt.as_bytes();
+    let needs_sep = !current.is_empty()
+        && !current.ends_with(&[SEPARATOR as u8])
+        && component != WindowsComponent::RootDir;
+
+    let len = current.len();
+    if needs_sep {
+        replace_tail(rebuilt, len, Some(SEPARATOR as u8), component.as_bytes())
+    } else {
+        replace_tail(rebuilt, len, None, component.as_bytes())
+    }
+}
+
+/// Removes the last component of a verbatim path being rebuilt by [`WindowsEncoding::try_push`]
+/// if it is a normal one, along with the separator before it unless that is the root, which the
+/// path has if `has_root` is true.
+fn pop_verbatim_normal<B: PushBuffer>(rebuilt: &mut B, has_root: bool) {
+    let current = rebuilt.as_bytes();
+    let mut components = WindowsEncoding::components(current);
+    let mut len = match components.next_back() {
+        Some(WindowsComponent::Normal(name)) => current.len() - name.len(),
+        _ => return,
+    };
+
+    let root_len = components.prefix_len() + usize::from(has_root);
+    if len > root_len && current[len - 1] == SEPARATOR as u8 {
+        len -= 1;
+    }
+    rebuilt.truncate(len);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn expand_env_vars_should_match_expand_environment_strings() {
+        let resolve = |name: &[u8]| match name {
+            b"A" => Some(&b"1"[..]),
+            b"B" => Some(&b""[..]),
+            _ => None,
+        };
+        let expand = |s: &[u8]| expand_env_vars(s, resolve);
+
+        assert_eq!(expand(b"%A%%B%"), (b"1".to_vec(), vec![]));
+        assert_eq!(expand(b"100%"), (b"100%".to_vec(), vec![]));
+        assert_eq!(expand(b"%%A%"), (b"%1".to_vec(), vec![]));
+        assert_eq!(expand(b"50%X%A%"), (b"50%X1".to_vec(), vec![b"X".to_vec()]));
+        assert_eq!(
+            expand(br"%X%\%A%"),
+            (br"%X%\1".to_vec(), vec![b"X".to_vec()])
+        );
+        assert_eq!(
+            expand(b"%X%%Y%%X%"),
+            (b"%X%%Y%%X%".to_vec(), vec![b"X".to_vec(), b"Y".to_vec()])
+        );
+    }
+
+    #[test]
+    fn pushing_component_bytes_should_reproduce_path() {
+        let paths: &[&[u8]] = &[
+            b"",
+            b"file.txt",
+            br".\dir\file.txt",
+            br"..\..\dir",
+            br"\dir\file.txt",
+            b"c:",
+            b"c:dir",
+            br"c:\",
+            br"C:\dir\..\file.txt",
+            b"C:/dir/./file.txt",
+            br"\\server\share",
+            br"\\server\share\dir\file.txt",
+            br"\\?\pictures\kittens",
+            br"\\?\UNC\server\share\dir",
+            br"\\?\c:\dir",
+            br"\\.\COM42",
+            br"\\.\BrainInterface\dir",
+        ];
+
+        for path in paths {
+            let path = WindowsPath::new(path);
+            let mut rebuilt = WindowsPathBuf::new();
+            for component in path.components() {
+                rebuilt.push(component.as_bytes());
+            }
+            assert_eq!(rebuilt, path, "{:?}", path);
+        }
+    }
+
     #[test]
     fn push_checked_should_fail_if_providing_an_absolute_path() {
         // Empty current path will fail when pushing an absolute path
@@ -423,4 +902,256 @@ mod tests {
         );
         assert_eq!(current_path, br"\some\path\abc\..\def\.");
     }
+
+    #[test]
+    fn starts_with_fast_should_succeed_if_base_ends_on_a_component_boundary() {
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\a\b", b""),
+            Some(true)
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\a\b", b"C:"),
+            Some(true)
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\a\b", br"C:\"),
+            Some(true)
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\a\b", br"C:\a"),
+            Some(true)
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:/a/b", br"C:/a/"),
+            Some(true)
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"\a\b", br"\a\b"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn starts_with_fast_should_be_inconclusive_if_bytes_do_not_line_up_with_components() {
+        // Base is not a byte prefix at all
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\a\b", br"D:\a"),
+            None
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\a\b", br"C:/a"),
+            None
+        );
+
+        // Base ends in the middle of a component
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"C:\abc", br"C:\a"),
+            None
+        );
+        assert_eq!(WindowsEncoding::starts_with_fast(br"C:a", br"C:"), None);
+
+        // UNC, device, and verbatim prefixes are never handled
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"\\server\share", br"\\server"),
+            None
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"\\?\C:\a/b", br"\\?\C:\a"),
+            None
+        );
+        assert_eq!(
+            WindowsEncoding::starts_with_fast(br"\\.\COM1\a", br"\"),
+            None
+        );
+    }
+
+    #[test]
+    fn cmp_should_match_comparing_components() {
+        let paths: &[&[u8]] = &[
+            b"",
+            b".",
+            b"..",
+            br"\",
+            br"\\",
+            b"-",
+            b"a",
+            br"a\",
+            b"a/",
+            br"a\.",
+            br"a\.\b",
+            br"a\.c",
+            br"a\b",
+            br"a\\b",
+            br"a/b",
+            br"a\b\c",
+            br"a\..\b",
+            br"a\C:",
+            br"a\C:\b",
+            b"C:",
+            b"c:",
+            br"C:\",
+            br"C:\a",
+            br"C:\a\b",
+            br"C:a",
+            br"D:\a",
+            br"\\server\share",
+            br"\\server\share\a",
+            br"\\server",
+            br"\\?\C:\a",
+            br"\\?\C:\a/b",
+            br"\\?\C:\a\b",
+            br"\\.\COM1",
+            br"\a\b",
+        ];
+
+        for path in paths {
+            for other in paths {
+                assert_eq!(
+                    WindowsEncoding::cmp(path, other),
+                    WindowsEncoding::components(path).cmp(WindowsEncoding::components(other)),
+                    "{:?} <=> {:?}",
+                    String::from_utf8_lossy(path),
+                    String::from_utf8_lossy(other),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_from_should_match_normalize_for_valid_paths() {
+        let paths: &[&[u8]] = &[
+            br"",
+            br"file.txt",
+            br".\dir\file.txt",
+            br"dir\..\file.txt",
+            br"\dir\file.txt",
+            br"\..\dir",
+            br"c:",
+            br"c:dir",
+            br"c:\..\dir",
+            br"C:\dir\..\file.txt",
+            br"C:/dir/./file.txt",
+            br"\\server\share\..\dir",
+            br"\\?\c:\dir\..\file.txt",
+            br"\\.\COM42\dir\.",
+        ];
+
+        for path in paths {
+            assert_eq!(
+                WindowsPathBuf::normalized_from(path),
+                Ok(WindowsPath::new(path).normalize()),
+                "{:?}",
+                WindowsPath::new(path),
+            );
+        }
+    }
+
+    #[test]
+    fn components_with_spans_should_index_into_original_path() {
+        let paths: &[&[u8]] = &[
+            br"",
+            br".\dir\..\file.txt",
+            br"\\dir\\\file.txt\",
+            br"c:dir",
+            br"C:/dir/./..",
+            br"\\server\share\dir",
+            br"\\?\c:\dir\.\file.txt",
+            br"\\.\COM42\dir",
+        ];
+
+        for path in paths {
+            let path = WindowsPath::new(path);
+            let spans: Vec<_> = path.components_with_spans().collect();
+            assert_eq!(
+                spans.iter().map(|(c, _)| *c).collect::<Vec<_>>(),
+                path.components().collect::<Vec<_>>(),
+                "{path:?}",
+            );
+
+            for (component, range) in spans {
+                let bytes = &path.as_bytes()[range];
+                if component == WindowsComponent::RootDir {
+                    assert!(bytes == b"\\" || bytes == b"/", "{path:?}: {bytes:?}");
+                } else {
+                    assert_eq!(bytes, component.as_bytes(), "{path:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn root_kind_should_classify_prefix_and_root() {
+        let cases: &[(&[u8], RootKind)] = &[
+            (br"", RootKind::Relative),
+            (br"dir\file", RootKind::Relative),
+            (br"\dir", RootKind::RootRelative),
+            (br"/dir", RootKind::RootRelative),
+            (br"c:", RootKind::DriveRelative),
+            (br"c:dir", RootKind::DriveRelative),
+            (br"c:\dir", RootKind::Absolute),
+            (br"\\server\share", RootKind::UNC),
+            (br"\\?\dir", RootKind::Verbatim),
+            (br"\\?\UNC\server\share", RootKind::Verbatim),
+            (br"\\?\c:\dir", RootKind::Verbatim),
+            (br"\\.\COM42", RootKind::Device),
+        ];
+
+        for (path, kind) in cases {
+            let path = WindowsPath::new(path);
+            assert_eq!(path.root_kind(), *kind, "{path:?}");
+            if path.is_absolute() {
+                assert!(
+                    !matches!(
+                        kind,
+                        RootKind::Relative | RootKind::DriveRelative | RootKind::RootRelative
+                    ),
+                    "{path:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn drive_roots_should_list_every_drive_letter_in_order() {
+        for (i, (root, utf8_root)) in DRIVE_ROOTS.iter().zip(UTF8_DRIVE_ROOTS).enumerate() {
+            let letter = b'A' + i as u8;
+            assert_eq!(root.as_bytes(), [letter, b':', b'\\']);
+            assert_eq!(root.as_bytes(), utf8_root.as_str().as_bytes());
+            assert!(root.is_absolute());
+
+            let path = WindowsPathBuf::drive_root(char::from(letter.to_ascii_lowercase()));
+            assert_eq!(path.as_deref(), Some(*root));
+        }
+    }
+
+    #[test]
+    fn separator_style_should_report_the_first_separator() {
+        use crate::WindowsSeparator;
+
+        let style = |s: &[u8]| WindowsPath::new(s).separator_style();
+
+        // Mixed separators report whichever comes first
+        assert_eq!(
+            style(br"C:\Users/me\file.txt"),
+            Some(WindowsSeparator::Backslash)
+        );
+        assert_eq!(
+            style(br"C:/Users\me/file.txt"),
+            Some(WindowsSeparator::Forward)
+        );
+        assert_eq!(
+            style(br"\\?\C:/file.txt"),
+            Some(WindowsSeparator::Backslash)
+        );
+
+        // Root-only paths
+        assert_eq!(style(br"\"), Some(WindowsSeparator::Backslash));
+        assert_eq!(style(b"/"), Some(WindowsSeparator::Forward));
+        assert_eq!(style(br"C:\"), Some(WindowsSeparator::Backslash));
+        assert_eq!(style(b"C:"), None);
+
+        // Paths without any separators
+        assert_eq!(style(b""), None);
+        assert_eq!(style(b"file.txt"), None);
+    }
 }
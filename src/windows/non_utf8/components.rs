@@ -105,7 +105,7 @@ impl<'a> WindowsComponents<'a> {
     }
 
     /// Returns the prefix of the represented path's components if it has one
-    pub fn prefix(&self) -> Option<WindowsPrefixComponent> {
+    pub fn prefix(&self) -> Option<WindowsPrefixComponent<'_>> {
         match self.peek_front() {
             Some(WindowsComponent::Prefix(p)) => Some(p),
             _ => None,
@@ -119,7 +119,7 @@ impl<'a> WindowsComponents<'a> {
 
     /// Returns the kind of prefix associated with the represented path if it has one
     #[inline]
-    pub fn prefix_kind(&self) -> Option<WindowsPrefix> {
+    pub fn prefix_kind(&self) -> Option<WindowsPrefix<'_>> {
         self.prefix().map(|p| p.kind())
     }
 
@@ -127,7 +127,11 @@ impl<'a> WindowsComponents<'a> {
     pub fn has_any_verbatim_prefix(&self) -> bool {
         matches!(
             self.prefix_kind(),
-            Some(WindowsPrefix::Verbatim(_) | WindowsPrefix::UNC(..) | WindowsPrefix::Disk(_))
+            Some(
+                WindowsPrefix::Verbatim(_)
+                    | WindowsPrefix::VerbatimUNC(..)
+                    | WindowsPrefix::VerbatimDisk(_)
+            )
         )
     }
 
@@ -277,3 +281,44 @@ impl cmp::Ord for WindowsComponents<'_> {
         _self.cmp(_other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{WindowsPath, WindowsPathBuf};
+
+    #[test]
+    fn has_any_verbatim_prefix_should_only_match_verbatim_prefixes() {
+        for path in [r"\\?\pictures", r"\\?\UNC\server\share", r"\\?\C:\"] {
+            assert!(
+                WindowsPath::new(path)
+                    .components()
+                    .has_any_verbatim_prefix(),
+                "{path}"
+            );
+        }
+
+        for path in [r"C:\", "c:", r"\\server\share", r"\\.\COM1", r"\dir", "dir"] {
+            assert!(
+                !WindowsPath::new(path)
+                    .components()
+                    .has_any_verbatim_prefix(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn push_should_not_normalize_parent_dir_onto_non_verbatim_prefixes() {
+        let mut path = WindowsPathBuf::from(r"C:\dir");
+        path.push("..");
+        assert_eq!(path, WindowsPath::new(r"C:\dir\.."));
+
+        let mut path = WindowsPathBuf::from(r"\\server\share\dir");
+        path.push("..");
+        assert_eq!(path, WindowsPath::new(r"\\server\share\dir\.."));
+
+        let mut path = WindowsPathBuf::from(r"\\?\C:\dir");
+        path.push("..");
+        assert_eq!(path, WindowsPath::new(r"\\?\C:\"));
+    }
+}
@@ -430,8 +430,9 @@ mod tests {
     use std::path::Component;
 
     use super::*;
+    use crate::WindowsPrefixError;
 
-    fn make_windows_prefix_component(s: &str) -> WindowsComponent {
+    fn make_windows_prefix_component(s: &str) -> WindowsComponent<'_> {
         let component = WindowsComponent::try_from(s).unwrap();
         assert!(component.is_prefix());
         component
@@ -539,4 +540,79 @@ mod tests {
         let component = WindowsComponent::try_from(make_component(r"\\?\pictures")).unwrap();
         assert_eq!(get_prefix(component), WindowsPrefix::Verbatim(b"pictures"));
     }
+
+    #[test]
+    fn volume_guid_should_require_the_guid_format() {
+        let guid = b"26a21bda-a627-11d7-9931-806e6f6e6963".as_slice();
+        assert_eq!(
+            WindowsPrefix::DeviceNS(b"volume{26a21bda-a627-11d7-9931-806e6f6e6963}").volume_guid(),
+            Some(guid)
+        );
+        for name in [
+            b"Volume{26a21bda-a627-11d7-9931-806e6f6e6963".as_slice(),
+            b"Volume26a21bda-a627-11d7-9931-806e6f6e6963}",
+            b"Volume{26a21bda-a627-11d7-9931-806e6f6e696}",
+            b"Volume{26a21bda-a627-11d7-9931-806e6f6e6963-0}",
+            b"Volume{26a21bda-a627-11d7-9931806e6f6e6963}",
+            b"Volume{26a21bdg-a627-11d7-9931-806e6f6e6963}",
+            b"Volume{}",
+        ] {
+            assert_eq!(WindowsPrefix::Verbatim(name).volume_guid(), None);
+        }
+        assert_eq!(
+            WindowsPrefix::UNC(b"Volume{26a21bda-a627-11d7-9931-806e6f6e6963}", b"").volume_guid(),
+            None
+        );
+    }
+
+    #[test]
+    fn validate_should_check_webdav_suffixes_in_order() {
+        let validate = |server: &[u8]| WindowsPrefix::UNC(server, b"share").validate();
+
+        assert_eq!(validate(b"server@ssl"), Ok(()));
+        assert_eq!(validate(b"server@80"), Ok(()));
+        assert_eq!(validate(b"server@SSL@65535"), Ok(()));
+
+        for server in [
+            b"server@".as_slice(),
+            b"server@0",
+            b"server@65536",
+            b"server@123456",
+            b"server@443@SSL",
+            b"server@SSL@SSL",
+            b"server@SSL@443@1",
+        ] {
+            assert_eq!(
+                validate(server),
+                Err(WindowsPrefixError::InvalidServerSuffix),
+                "{server:?}"
+            );
+        }
+        assert_eq!(validate(b"@SSL"), Err(WindowsPrefixError::EmptyServer));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn from_std_prefix_should_keep_bytes_that_are_not_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Prefix;
+
+        use crate::WindowsPrefix;
+
+        let server = OsStr::from_bytes(b"serv\xffer");
+        let share = OsStr::from_bytes(b"\xfe");
+        assert_eq!(
+            WindowsPrefix::from(Prefix::UNC(server, share)),
+            WindowsPrefix::UNC(b"serv\xffer", b"\xfe")
+        );
+        assert_eq!(
+            WindowsPrefix::from(Prefix::DeviceNS(share)),
+            WindowsPrefix::DeviceNS(b"\xfe")
+        );
+        assert_eq!(
+            WindowsPrefix::from(Prefix::VerbatimDisk(b'C')),
+            WindowsPrefix::VerbatimDisk(b'C')
+        );
+    }
 }
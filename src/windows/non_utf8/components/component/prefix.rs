@@ -1,9 +1,11 @@
-use core::cmp;
 use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
+use core::{cmp, fmt};
 
+use crate::no_std_compat::*;
+use crate::windows::constants::DISALLOWED_FILENAME_BYTES;
 use crate::windows::WindowsComponents;
-use crate::ParseError;
+use crate::{ParseError, WindowsPrefixError};
 
 /// A structure wrapping a Windows path prefix as well as its unparsed string
 /// representation. Byte slice version of [`std::path::PrefixComponent`].
@@ -49,6 +51,35 @@ pub struct WindowsPrefixComponent<'a> {
 }
 
 impl<'a> WindowsPrefixComponent<'a> {
+    /// Parses the prefix at the beginning of `path`, returning it alongside the rest of the path.
+    ///
+    /// Unlike [`WindowsComponents`], this stops once the prefix has been parsed, and unlike
+    /// the [`TryFrom`] implementation, `path` may continue past the prefix. Nothing is
+    /// allocated, which makes this suitable for inspecting many paths in a hot loop.
+    ///
+    /// Returns [`None`] if `path` does not begin with a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPrefix, WindowsPrefixComponent};
+    ///
+    /// let (prefix, rest) =
+    ///     WindowsPrefixComponent::parse(br"\\server\share\dir\file.txt").unwrap();
+    /// assert_eq!(prefix.kind(), WindowsPrefix::UNC(b"server", b"share"));
+    /// assert_eq!(rest, br"\dir\file.txt");
+    ///
+    /// let (prefix, rest) = WindowsPrefixComponent::parse(b"c:file.txt").unwrap();
+    /// assert_eq!(prefix.kind(), WindowsPrefix::Disk(b'C'));
+    /// assert_eq!(rest, b"file.txt");
+    ///
+    /// assert!(WindowsPrefixComponent::parse(br"\dir\file.txt").is_none());
+    /// ```
+    pub fn parse(path: &'a [u8]) -> Option<(Self, &'a [u8])> {
+        let (rest, prefix) = super::super::parser::prefix_component(path).ok()?;
+        Some((prefix, rest))
+    }
+
     /// Returns the parsed prefix data
     ///
     /// See [`WindowsPrefix`]'s documentation for more information on the different
@@ -285,7 +316,174 @@ impl<'a> TryFrom<&'a str> for WindowsPrefix<'a> {
     }
 }
 
-impl WindowsPrefix<'_> {
+#[cfg(all(feature = "std", any(unix, typed_path_os_str_encoded_bytes)))]
+impl<'a> From<std::path::Prefix<'a>> for WindowsPrefix<'a> {
+    /// Converts a [`std::path::Prefix`] into a [`WindowsPrefix`] without loss, using the raw
+    /// bytes of each part on Unix-like platforms and their internal encoding (WTF-8 on Windows)
+    /// elsewhere.
+    ///
+    /// Compilers older than Rust 1.74 cannot access those bytes on other platforms, so there
+    /// only a [`TryFrom`] conversion is provided, which fails if any part is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsStr;
+    /// use std::path::Prefix;
+    /// use typed_path::WindowsPrefix;
+    ///
+    /// let prefix = WindowsPrefix::from(Prefix::Disk(b'C'));
+    /// assert_eq!(prefix, WindowsPrefix::Disk(b'C'));
+    ///
+    /// let prefix = WindowsPrefix::from(Prefix::UNC(OsStr::new("server"), OsStr::new("share")));
+    /// assert_eq!(prefix, WindowsPrefix::UNC(b"server", b"share"));
+    /// ```
+    fn from(prefix: std::path::Prefix<'a>) -> Self {
+        #[cfg(unix)]
+        fn to_bytes(s: &std::ffi::OsStr) -> &[u8] {
+            std::os::unix::ffi::OsStrExt::as_bytes(s)
+        }
+
+        #[cfg(not(unix))]
+        fn to_bytes(s: &std::ffi::OsStr) -> &[u8] {
+            s.as_encoded_bytes()
+        }
+
+        match prefix {
+            std::path::Prefix::Verbatim(x) => Self::Verbatim(to_bytes(x)),
+            std::path::Prefix::VerbatimUNC(x, y) => Self::VerbatimUNC(to_bytes(x), to_bytes(y)),
+            std::path::Prefix::VerbatimDisk(x) => Self::VerbatimDisk(x),
+            std::path::Prefix::DeviceNS(x) => Self::DeviceNS(to_bytes(x)),
+            std::path::Prefix::UNC(x, y) => Self::UNC(to_bytes(x), to_bytes(y)),
+            std::path::Prefix::Disk(x) => Self::Disk(x),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", not(any(unix, typed_path_os_str_encoded_bytes))))]
+impl<'a> TryFrom<std::path::Prefix<'a>> for WindowsPrefix<'a> {
+    type Error = std::path::Prefix<'a>;
+
+    /// Attempts to convert a [`std::path::Prefix`] into a [`WindowsPrefix`], returning a result
+    /// containing the new prefix when successful or the original prefix when failed
+    ///
+    /// This is only provided on compilers older than Rust 1.74, which cannot access the bytes of
+    /// an [`OsStr`](std::ffi::OsStr) on this platform. The conversion fails only when one of the
+    /// parts of the prefix is not valid UTF-8.
+    fn try_from(prefix: std::path::Prefix<'a>) -> Result<Self, Self::Error> {
+        fn to_bytes(s: &std::ffi::OsStr) -> Option<&[u8]> {
+            s.to_str().map(str::as_bytes)
+        }
+
+        match prefix {
+            std::path::Prefix::Verbatim(x) => Ok(Self::Verbatim(to_bytes(x).ok_or(prefix)?)),
+            std::path::Prefix::VerbatimUNC(x, y) => Ok(Self::VerbatimUNC(
+                to_bytes(x).ok_or(prefix)?,
+                to_bytes(y).ok_or(prefix)?,
+            )),
+            std::path::Prefix::VerbatimDisk(x) => Ok(Self::VerbatimDisk(x)),
+            std::path::Prefix::DeviceNS(x) => Ok(Self::DeviceNS(to_bytes(x).ok_or(prefix)?)),
+            std::path::Prefix::UNC(x, y) => Ok(Self::UNC(
+                to_bytes(x).ok_or(prefix)?,
+                to_bytes(y).ok_or(prefix)?,
+            )),
+            std::path::Prefix::Disk(x) => Ok(Self::Disk(x)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<WindowsPrefix<'a>> for std::path::Prefix<'a> {
+    type Error = WindowsPrefix<'a>;
+
+    /// Attempts to convert a [`WindowsPrefix`] into a [`std::path::Prefix`], returning a result
+    /// containing the new prefix when successful or the original prefix when failed
+    ///
+    /// The conversion fails only when one of the parts of the prefix is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Prefix;
+    /// use typed_path::WindowsPrefix;
+    ///
+    /// let prefix = Prefix::try_from(WindowsPrefix::VerbatimDisk(b'C')).unwrap();
+    /// assert_eq!(prefix, Prefix::VerbatimDisk(b'C'));
+    ///
+    /// let prefix = Prefix::try_from(WindowsPrefix::DeviceNS(b"COM42")).unwrap();
+    /// assert_eq!(prefix, Prefix::DeviceNS(OsStr::new("COM42")));
+    ///
+    /// assert!(Prefix::try_from(WindowsPrefix::Verbatim(&[0xff])).is_err());
+    /// ```
+    fn try_from(prefix: WindowsPrefix<'a>) -> Result<Self, Self::Error> {
+        fn to_os_str(s: &[u8]) -> Option<&std::ffi::OsStr> {
+            core::str::from_utf8(s).ok().map(std::ffi::OsStr::new)
+        }
+
+        match prefix {
+            WindowsPrefix::Verbatim(x) => Ok(Self::Verbatim(to_os_str(x).ok_or(prefix)?)),
+            WindowsPrefix::VerbatimUNC(x, y) => Ok(Self::VerbatimUNC(
+                to_os_str(x).ok_or(prefix)?,
+                to_os_str(y).ok_or(prefix)?,
+            )),
+            WindowsPrefix::VerbatimDisk(x) => Ok(Self::VerbatimDisk(x)),
+            WindowsPrefix::DeviceNS(x) => Ok(Self::DeviceNS(to_os_str(x).ok_or(prefix)?)),
+            WindowsPrefix::UNC(x, y) => Ok(Self::UNC(
+                to_os_str(x).ok_or(prefix)?,
+                to_os_str(y).ok_or(prefix)?,
+            )),
+            WindowsPrefix::Disk(x) => Ok(Self::Disk(x)),
+        }
+    }
+}
+
+impl fmt::Display for WindowsPrefix<'_> {
+    /// Formats the prefix in its canonical textual form, converting any non-UTF-8 bytes lossily
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPrefix::*;
+    ///
+    /// assert_eq!(Verbatim(b"pictures").to_string(), r"\\?\pictures");
+    /// assert_eq!(VerbatimUNC(b"server", b"share").to_string(), r"\\?\UNC\server\share");
+    /// assert_eq!(VerbatimDisk(b'C').to_string(), r"\\?\C:");
+    /// assert_eq!(DeviceNS(b"BrainInterface").to_string(), r"\\.\BrainInterface");
+    /// assert_eq!(UNC(b"server", b"share").to_string(), r"\\server\share");
+    /// assert_eq!(UNC(b"server", b"").to_string(), r"\\server");
+    /// assert_eq!(Disk(b'C').to_string(), "C:");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use self::WindowsPrefix::*;
+
+        fn write_server_share(f: &mut fmt::Formatter<'_>, x: &[u8], y: &[u8]) -> fmt::Result {
+            write!(f, "{}", String::from_utf8_lossy(x))?;
+            if !y.is_empty() {
+                write!(f, r"\{}", String::from_utf8_lossy(y))?;
+            }
+            Ok(())
+        }
+
+        match *self {
+            Verbatim(x) => write!(f, r"\\?\{}", String::from_utf8_lossy(x)),
+            VerbatimUNC(x, y) => {
+                f.write_str(r"\\?\UNC\")?;
+                write_server_share(f, x, y)
+            }
+            VerbatimDisk(x) => write!(f, r"\\?\{}:", x as char),
+            DeviceNS(x) => write!(f, r"\\.\{}", String::from_utf8_lossy(x)),
+            UNC(x, y) => {
+                f.write_str(r"\\")?;
+                write_server_share(f, x, y)
+            }
+            Disk(x) => write!(f, "{}:", x as char),
+        }
+    }
+}
+
+impl<'a> WindowsPrefix<'a> {
     /// Calculates the full byte length of the prefix
     ///
     /// # Examples
@@ -347,4 +545,217 @@ impl WindowsPrefix<'_> {
         use self::WindowsPrefix::*;
         matches!(*self, Verbatim(_) | VerbatimDisk(_) | VerbatimUNC(..))
     }
+
+    /// Returns the GUID of a volume prefix, such as `\\?\Volume{GUID}` or `\\.\Volume{GUID}`,
+    /// without the surrounding braces.
+    ///
+    /// Volume GUID paths otherwise parse as generic [`Verbatim`] or [`DeviceNS`] prefixes. The
+    /// GUID must be written as 32 hexadecimal digits in groups of 8, 4, 4, 4, and 12 separated by
+    /// `-`, and `Volume` is matched without regard to ASCII case.
+    ///
+    /// [`Verbatim`]: WindowsPrefix::Verbatim
+    /// [`DeviceNS`]: WindowsPrefix::DeviceNS
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPrefix, WindowsPrefixComponent};
+    ///
+    /// let path = br"\\?\Volume{26a21bda-a627-11d7-9931-806e6f6e6963}\Windows";
+    /// let prefix = WindowsPrefixComponent::parse(path).unwrap().0.kind();
+    /// assert_eq!(
+    ///     prefix,
+    ///     WindowsPrefix::Verbatim(b"Volume{26a21bda-a627-11d7-9931-806e6f6e6963}")
+    /// );
+    /// assert_eq!(prefix.volume_guid(), Some(b"26a21bda-a627-11d7-9931-806e6f6e6963".as_slice()));
+    /// assert!(prefix.is_volume());
+    ///
+    /// assert_eq!(WindowsPrefix::Verbatim(b"Volume{not-a-guid}").volume_guid(), None);
+    /// assert_eq!(WindowsPrefix::VerbatimDisk(b'C').volume_guid(), None);
+    /// ```
+    pub fn volume_guid(&self) -> Option<&'a [u8]> {
+        use self::WindowsPrefix::*;
+        match *self {
+            Verbatim(x) | DeviceNS(x) => parse_volume_guid(x),
+            _ => None,
+        }
+    }
+
+    /// Determines if the prefix identifies a volume by GUID. See [`volume_guid`].
+    ///
+    /// [`volume_guid`]: WindowsPrefix::volume_guid
+    #[inline]
+    pub fn is_volume(&self) -> bool {
+        self.volume_guid().is_some()
+    }
+
+    /// Returns the prefix with its drive letter converted to ASCII upper case, if it has one.
+    ///
+    /// Prefixes parsed from a path already have an upper-case drive letter, so this is only
+    /// needed for prefixes constructed by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPrefix::*;
+    ///
+    /// assert_eq!(Disk(b'c').normalize_drive(), Disk(b'C'));
+    /// assert_eq!(VerbatimDisk(b'd').normalize_drive(), VerbatimDisk(b'D'));
+    /// assert_eq!(UNC(b"server", b"share").normalize_drive(), UNC(b"server", b"share"));
+    /// ```
+    pub fn normalize_drive(self) -> Self {
+        use self::WindowsPrefix::*;
+        match self {
+            Disk(x) => Disk(x.to_ascii_uppercase()),
+            VerbatimDisk(x) => VerbatimDisk(x.to_ascii_uppercase()),
+            x => x,
+        }
+    }
+
+    /// Compares two prefixes for equality, treating drive letters that differ only in ASCII case
+    /// as equal.
+    ///
+    /// Unlike the [`PartialEq`] implementation, `c:` and `C:` are considered equal here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPrefix::*;
+    ///
+    /// assert_ne!(Disk(b'c'), Disk(b'C'));
+    /// assert!(Disk(b'c').eq_ignore_drive_case(&Disk(b'C')));
+    /// assert!(!Disk(b'c').eq_ignore_drive_case(&VerbatimDisk(b'C')));
+    /// ```
+    pub fn eq_ignore_drive_case(&self, other: &Self) -> bool {
+        self.normalize_drive() == other.normalize_drive()
+    }
+
+    /// Checks that the prefix could be written out as part of a path that Windows accepts, which
+    /// the parser does not enforce.
+    ///
+    /// * A disk prefix must have an ASCII letter as its drive.
+    /// * A UNC prefix, verbatim or not, must have a server name made up of ASCII letters,
+    ///   digits, `-`, `.`, and `_`, which may be followed by the WebDAV suffixes `@SSL` and
+    ///   `@port` in that order, as in `\\server@SSL@443\share`.
+    /// * A UNC prefix must also have a share name that does not contain any of the
+    ///   [`DISALLOWED_FILENAME_BYTES`] or control characters.
+    ///
+    /// Verbatim and device namespace prefixes are passed to Windows as written, so they are
+    /// always valid.
+    ///
+    /// [`DISALLOWED_FILENAME_BYTES`]: crate::constants::windows::DISALLOWED_FILENAME_BYTES
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPrefix::*;
+    /// use typed_path::WindowsPrefixError;
+    ///
+    /// assert_eq!(UNC(b"fileserver", b"public").validate(), Ok(()));
+    /// assert_eq!(UNC(b"docs.example.com@SSL@8443", b"DavWWWRoot").validate(), Ok(()));
+    /// assert_eq!(VerbatimUNC(b"server", b"share").validate(), Ok(()));
+    /// assert_eq!(Disk(b'C').validate(), Ok(()));
+    ///
+    /// assert_eq!(UNC(b"", b"share").validate(), Err(WindowsPrefixError::EmptyServer));
+    /// assert_eq!(
+    ///     UNC(b"file server", b"share").validate(),
+    ///     Err(WindowsPrefixError::InvalidServer)
+    /// );
+    /// assert_eq!(
+    ///     UNC(b"server@99999", b"share").validate(),
+    ///     Err(WindowsPrefixError::InvalidServerSuffix)
+    /// );
+    /// assert_eq!(UNC(b"server", b"").validate(), Err(WindowsPrefixError::EmptyShare));
+    /// assert_eq!(UNC(b"server", b"sha|re").validate(), Err(WindowsPrefixError::InvalidShare));
+    /// assert_eq!(Disk(b'1').validate(), Err(WindowsPrefixError::InvalidDrive));
+    /// ```
+    pub fn validate(&self) -> Result<(), WindowsPrefixError> {
+        use self::WindowsPrefix::*;
+        match *self {
+            Disk(x) | VerbatimDisk(x) if !x.is_ascii_alphabetic() => {
+                Err(WindowsPrefixError::InvalidDrive)
+            }
+            UNC(server, share) | VerbatimUNC(server, share) => {
+                validate_unc_server(server)?;
+                validate_unc_share(share)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the GUID within `Volume{GUID}`, checking that it has the form
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+fn parse_volume_guid(bytes: &[u8]) -> Option<&[u8]> {
+    const NAME: &[u8] = b"Volume{";
+    const GROUPS: [usize; 5] = [8, 4, 4, 4, 12];
+
+    if bytes.len() < NAME.len() || !bytes[..NAME.len()].eq_ignore_ascii_case(NAME) {
+        return None;
+    }
+    let guid = bytes[NAME.len()..].strip_suffix(b"}")?;
+
+    let mut groups = guid.split(|b| *b == b'-');
+    for len in GROUPS {
+        let group = groups.next()?;
+        if group.len() != len || !group.iter().all(u8::is_ascii_hexdigit) {
+            return None;
+        }
+    }
+
+    match groups.next() {
+        Some(_) => None,
+        None => Some(guid),
+    }
+}
+
+fn validate_unc_server(server: &[u8]) -> Result<(), WindowsPrefixError> {
+    let mut parts = server.split(|b| *b == b'@');
+    let host = parts.next().unwrap_or_default();
+
+    if host.is_empty() {
+        return Err(WindowsPrefixError::EmptyServer);
+    }
+    if !host
+        .iter()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_'))
+    {
+        return Err(WindowsPrefixError::InvalidServer);
+    }
+
+    // WebDAV servers are written as `server[@SSL][@port]`
+    let mut parts = parts.peekable();
+    if parts
+        .peek()
+        .map_or(false, |ssl| ssl.eq_ignore_ascii_case(b"SSL"))
+    {
+        parts.next();
+    }
+    if let Some(port) = parts.next() {
+        let is_port = !port.is_empty()
+            && port.len() <= 5
+            && port.iter().all(u8::is_ascii_digit)
+            && (1..=65535).contains(&port.iter().fold(0u32, |n, b| n * 10 + u32::from(b - b'0')));
+        if !is_port {
+            return Err(WindowsPrefixError::InvalidServerSuffix);
+        }
+    }
+    if parts.next().is_some() {
+        return Err(WindowsPrefixError::InvalidServerSuffix);
+    }
+
+    Ok(())
+}
+
+fn validate_unc_share(share: &[u8]) -> Result<(), WindowsPrefixError> {
+    if share.is_empty() {
+        return Err(WindowsPrefixError::EmptyShare);
+    }
+    if share
+        .iter()
+        .any(|b| b.is_ascii_control() || DISALLOWED_FILENAME_BYTES.contains(b))
+    {
+        return Err(WindowsPrefixError::InvalidShare);
+    }
+    Ok(())
 }
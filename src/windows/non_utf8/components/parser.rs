@@ -382,8 +382,7 @@ pub fn prefix_component(input: ParseInput) -> ParseResult<WindowsPrefixComponent
     ))
 }
 
-#[allow(elided_named_lifetimes)]
-fn prefix<'a>(input: ParseInput<'a>) -> ParseResult<WindowsPrefix> {
+fn prefix<'a>(input: ParseInput<'a>) -> ParseResult<'a, WindowsPrefix<'a>> {
     any_of!('a,
         prefix_verbatim_unc,
         prefix_verbatim_disk,
@@ -413,8 +412,7 @@ fn prefix_verbatim_unc(input: ParseInput) -> ParseResult<WindowsPrefix> {
 }
 
 /// Format is `\\?\PICTURES:` where the backslash is interchangeable with a forward slash
-#[allow(elided_named_lifetimes)]
-fn prefix_verbatim<'a>(input: ParseInput<'a>) -> ParseResult<WindowsPrefix> {
+fn prefix_verbatim<'a>(input: ParseInput<'a>) -> ParseResult<'a, WindowsPrefix<'a>> {
     let (input, _) = not(prefix_verbatim_disk)(input)?;
     let (input, _) = not(prefix_verbatim_unc)(input)?;
 
@@ -0,0 +1,182 @@
+use crate::no_std_compat::*;
+use crate::windows::constants::{ALT_SEPARATOR, SEPARATOR};
+use crate::{Utf8WindowsPath, Utf8WindowsPathBuf, WindowsPath, WindowsPathBuf};
+
+/// One of the two characters that separate the components of a Windows path.
+///
+/// # Examples
+///
+/// ```
+/// use typed_path::{WindowsPath, WindowsSeparator};
+///
+/// // Keep the style of the original path after rebuilding it
+/// let original = WindowsPath::new("C:/projects/app/src/main.rs");
+/// let rebuilt = original.with_file_name("lib.rs");
+/// assert_eq!(rebuilt.as_bytes(), br"C:/projects/app/src\lib.rs");
+///
+/// let style = original.separator_style().unwrap_or_default();
+/// assert_eq!(style, WindowsSeparator::Forward);
+/// assert_eq!(
+///     rebuilt.to_path_buf_with_separator(style).as_bytes(),
+///     b"C:/projects/app/src/lib.rs",
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WindowsSeparator {
+    /// The primary separator, `\`, which is also the default.
+    Backslash,
+
+    /// The alternate separator, `/`.
+    Forward,
+}
+
+impl Default for WindowsSeparator {
+    fn default() -> Self {
+        Self::Backslash
+    }
+}
+
+impl WindowsSeparator {
+    /// Returns the character for this separator.
+    pub fn as_char(self) -> char {
+        match self {
+            Self::Backslash => SEPARATOR,
+            Self::Forward => ALT_SEPARATOR,
+        }
+    }
+}
+
+impl WindowsPath {
+    /// Returns the first separator used by the path, or [`None`] if the path has no separators.
+    ///
+    /// Paths that mix both separators report whichever appears first, which is usually the
+    /// style that the author of the path intended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPath, WindowsSeparator};
+    ///
+    /// assert_eq!(
+    ///     WindowsPath::new(r"C:\Windows").separator_style(),
+    ///     Some(WindowsSeparator::Backslash)
+    /// );
+    /// assert_eq!(
+    ///     WindowsPath::new("C:/Windows").separator_style(),
+    ///     Some(WindowsSeparator::Forward)
+    /// );
+    /// assert_eq!(WindowsPath::new(r"a/b\c").separator_style(), Some(WindowsSeparator::Forward));
+    /// assert_eq!(WindowsPath::new("C:file.txt").separator_style(), None);
+    /// ```
+    pub fn separator_style(&self) -> Option<WindowsSeparator> {
+        separator_style(self.as_bytes())
+    }
+
+    /// Creates an owned [`WindowsPathBuf`] like `self` but with every separator replaced by
+    /// `separator`.
+    ///
+    /// Within a path that has a verbatim (`\\?\`) prefix, `/` is an ordinary character rather
+    /// than a separator, so such paths are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WindowsPath, WindowsSeparator};
+    ///
+    /// let path = WindowsPath::new(r"\\server\share/dir\file.txt");
+    /// assert_eq!(
+    ///     path.to_path_buf_with_separator(WindowsSeparator::Forward).as_bytes(),
+    ///     b"//server/share/dir/file.txt",
+    /// );
+    /// assert_eq!(
+    ///     path.to_path_buf_with_separator(WindowsSeparator::Backslash).as_bytes(),
+    ///     br"\\server\share\dir\file.txt",
+    /// );
+    ///
+    /// let path = WindowsPath::new(r"\\?\C:\a/b");
+    /// assert_eq!(
+    ///     path.to_path_buf_with_separator(WindowsSeparator::Forward).as_bytes(),
+    ///     path.as_bytes()
+    /// );
+    /// ```
+    pub fn to_path_buf_with_separator(&self, separator: WindowsSeparator) -> WindowsPathBuf {
+        if self.components().has_any_verbatim_prefix() {
+            return self.to_path_buf();
+        }
+        WindowsPathBuf::from(replace_separators(self.as_bytes(), separator))
+    }
+}
+
+impl Utf8WindowsPath {
+    /// Returns the first separator used by the path, or [`None`] if the path has no separators.
+    ///
+    /// See [`WindowsPath::separator_style`] for details.
+    ///
+    /// [`WindowsPath::separator_style`]: crate::WindowsPath::separator_style
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, WindowsSeparator};
+    ///
+    /// assert_eq!(
+    ///     Utf8WindowsPath::new(r"C:\Windows").separator_style(),
+    ///     Some(WindowsSeparator::Backslash)
+    /// );
+    /// assert_eq!(
+    ///     Utf8WindowsPath::new("C:/Windows").separator_style(),
+    ///     Some(WindowsSeparator::Forward)
+    /// );
+    /// assert_eq!(Utf8WindowsPath::new("file.txt").separator_style(), None);
+    /// ```
+    pub fn separator_style(&self) -> Option<WindowsSeparator> {
+        separator_style(self.as_str().as_bytes())
+    }
+
+    /// Creates an owned [`Utf8WindowsPathBuf`] like `self` but with every separator replaced by
+    /// `separator`.
+    ///
+    /// See [`WindowsPath::to_path_buf_with_separator`] for details.
+    ///
+    /// [`WindowsPath::to_path_buf_with_separator`]: crate::WindowsPath::to_path_buf_with_separator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, WindowsSeparator};
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\Users/ünïcode\file.txt");
+    /// assert_eq!(
+    ///     path.to_path_buf_with_separator(WindowsSeparator::Forward).as_str(),
+    ///     "C:/Users/ünïcode/file.txt",
+    /// );
+    /// ```
+    pub fn to_path_buf_with_separator(&self, separator: WindowsSeparator) -> Utf8WindowsPathBuf {
+        if self.components().has_any_verbatim_prefix() {
+            return self.to_path_buf();
+        }
+
+        // Separators are ASCII, so replacing them keeps the string valid UTF-8
+        let bytes = replace_separators(self.as_str().as_bytes(), separator);
+        Utf8WindowsPathBuf::from(String::from_utf8(bytes).expect("valid UTF-8"))
+    }
+}
+
+fn separator_style(bytes: &[u8]) -> Option<WindowsSeparator> {
+    bytes.iter().find_map(|b| match *b {
+        b'\\' => Some(WindowsSeparator::Backslash),
+        b'/' => Some(WindowsSeparator::Forward),
+        _ => None,
+    })
+}
+
+fn replace_separators(bytes: &[u8], separator: WindowsSeparator) -> Vec<u8> {
+    let separator = separator.as_char() as u8;
+    bytes
+        .iter()
+        .map(|b| match *b {
+            b'\\' | b'/' => separator,
+            b => b,
+        })
+        .collect()
+}
@@ -1,14 +1,18 @@
 mod components;
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::Hasher;
 
 pub use components::*;
 
-use crate::common::CheckedPathError;
+use super::constants::UTF8_DRIVE_ROOTS;
+use crate::common::{CheckedPathError, RootKind, UncPathError, WindowsPrefixError};
 use crate::no_std_compat::*;
 use crate::typed::{Utf8TypedPath, Utf8TypedPathBuf};
-use crate::{private, Encoding, Utf8Encoding, Utf8Path, Utf8PathBuf, WindowsEncoding};
+use crate::{
+    private, Encoding, Utf8Encoding, Utf8Path, Utf8PathBuf, WindowsEncoding, WindowsPathBuf,
+};
 
 /// Represents a Windows-specific [`Utf8Path`]
 pub type Utf8WindowsPath = Utf8Path<Utf8WindowsEncoding>;
@@ -37,6 +41,10 @@ impl<'a> Utf8Encoding<'a> for Utf8WindowsEncoding {
         WindowsEncoding::hash(path.as_bytes(), h);
     }
 
+    fn cmp(path: &str, other: &str) -> Ordering {
+        WindowsEncoding::cmp(path.as_bytes(), other.as_bytes())
+    }
+
     fn push(current_path: &mut String, path: &str) {
         unsafe {
             WindowsEncoding::push(current_path.as_mut_vec(), path.as_bytes());
@@ -46,6 +54,14 @@ impl<'a> Utf8Encoding<'a> for Utf8WindowsEncoding {
     fn push_checked(current_path: &mut String, path: &str) -> Result<(), CheckedPathError> {
         unsafe { WindowsEncoding::push_checked(current_path.as_mut_vec(), path.as_bytes()) }
     }
+
+    fn starts_with_fast(path: &str, base: &str) -> Option<bool> {
+        WindowsEncoding::starts_with_fast(path.as_bytes(), base.as_bytes())
+    }
+
+    fn root_kind(path: &str) -> RootKind {
+        WindowsEncoding::root_kind(path.as_bytes())
+    }
 }
 
 impl fmt::Debug for Utf8WindowsEncoding {
@@ -97,13 +113,209 @@ where
 }
 
 impl Utf8WindowsPath {
-    pub fn to_typed_path(&self) -> Utf8TypedPath {
+    pub fn to_typed_path(&self) -> Utf8TypedPath<'_> {
         Utf8TypedPath::windows(self)
     }
 
     pub fn to_typed_path_buf(&self) -> Utf8TypedPathBuf {
         Utf8TypedPathBuf::from_windows(self)
     }
+
+    /// Returns the [`file_name`] with any alternate data stream suffix removed.
+    ///
+    /// On NTFS, a file name can refer to an alternate data stream of the file using the syntax
+    /// `file.txt:stream` or `file.txt:stream:$DATA`. Methods such as [`extension`] are unaware of
+    /// this syntax and treat the stream as part of the file name.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    /// [`extension`]: Utf8Path::extension
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\dir\file.txt:stream:$DATA");
+    /// assert_eq!(path.file_name_without_stream(), Some("file.txt"));
+    /// assert_eq!(path.extension(), Some("txt:stream:$DATA"));
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\dir\file.txt");
+    /// assert_eq!(path.file_name_without_stream(), Some("file.txt"));
+    /// ```
+    pub fn file_name_without_stream(&self) -> Option<&str> {
+        self.file_name()
+            .map(|name| name.split(':').next().unwrap_or(name))
+    }
+
+    /// Returns the name of the alternate data stream referenced by the [`file_name`], if any.
+    ///
+    /// The stream type, such as `$DATA`, is not included. The default stream, written as
+    /// `file.txt::$DATA`, has an empty name.
+    ///
+    /// [`file_name`]: Utf8Path::file_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\dir\file.txt:stream:$DATA");
+    /// assert_eq!(path.stream_name(), Some("stream"));
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\dir\file.txt::$DATA");
+    /// assert_eq!(path.stream_name(), Some(""));
+    ///
+    /// let path = Utf8WindowsPath::new(r"C:\dir\file.txt");
+    /// assert_eq!(path.stream_name(), None);
+    /// ```
+    pub fn stream_name(&self) -> Option<&str> {
+        self.file_name()?.split(':').nth(1)
+    }
+
+    /// Returns `true` if any [`Normal`] component of the path uses alternate data stream syntax,
+    /// meaning that it contains a `:`.
+    ///
+    /// Such paths are valid on NTFS but are commonly rejected by tooling, and are not considered
+    /// valid by [`Utf8Component::is_valid`](crate::Utf8Component::is_valid).
+    ///
+    /// [`Normal`]: Utf8WindowsComponent::Normal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPath;
+    ///
+    /// assert!(Utf8WindowsPath::new(r"C:\dir\file.txt:stream").has_stream());
+    /// assert!(Utf8WindowsPath::new(r"dir:stream\file.txt").has_stream());
+    /// assert!(!Utf8WindowsPath::new(r"C:\dir\file.txt").has_stream());
+    /// ```
+    pub fn has_stream(&self) -> bool {
+        self.components().any(|component| match component {
+            Utf8WindowsComponent::Normal(name) => name.contains(':'),
+            _ => false,
+        })
+    }
+}
+
+impl Utf8WindowsPathBuf {
+    /// Creates a [`Utf8WindowsPathBuf`] pointing to the root of the drive identified by
+    /// `letter`, such as `C:\`. The drive letter is always stored in uppercase.
+    ///
+    /// Returns `None` if `letter` is not an ASCII letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// assert_eq!(Utf8WindowsPathBuf::drive_root('C').unwrap(), Utf8WindowsPath::new(r"C:\"));
+    /// assert_eq!(Utf8WindowsPathBuf::drive_root('d').unwrap(), Utf8WindowsPath::new(r"D:\"));
+    /// assert!(Utf8WindowsPathBuf::drive_root('C').unwrap().is_absolute());
+    /// assert_eq!(Utf8WindowsPathBuf::drive_root('é'), None);
+    /// ```
+    pub fn drive_root(letter: char) -> Option<Self> {
+        if !letter.is_ascii_alphabetic() {
+            return None;
+        }
+
+        let index = (letter.to_ascii_uppercase() as u8 - b'A') as usize;
+        Some(UTF8_DRIVE_ROOTS[index].to_path_buf())
+    }
+
+    /// Creates a [`Utf8WindowsPathBuf`] pointing to the root of the administrative share for
+    /// the drive identified by `letter` on `host`, such as `\\host\C$\`.
+    ///
+    /// See [`WindowsPathBuf::admin_share`] for details.
+    ///
+    /// [`WindowsPathBuf::admin_share`]: crate::WindowsPathBuf::admin_share
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPathBuf;
+    ///
+    /// let path = Utf8WindowsPathBuf::admin_share('D', "build01").unwrap();
+    /// assert_eq!(path.as_str(), r"\\build01\D$\");
+    /// ```
+    pub fn admin_share<H: AsRef<str>>(letter: char, host: H) -> Result<Self, WindowsPrefixError> {
+        let path = WindowsPathBuf::admin_share(letter, host.as_ref())?;
+        Ok(Self::from_validated_bytes(path.into_vec()))
+    }
+
+    /// Creates a [`Utf8WindowsPathBuf`] pointing to `rel` within `share` on `host`, such as
+    /// `\\host\share\rel`.
+    ///
+    /// See [`WindowsPathBuf::unc`] for details.
+    ///
+    /// [`WindowsPathBuf::unc`]: crate::WindowsPathBuf::unc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPathBuf;
+    ///
+    /// let path = Utf8WindowsPathBuf::unc("fileserver", "home", "alice/notes.txt").unwrap();
+    /// assert_eq!(path.as_str(), r"\\fileserver\home\alice/notes.txt");
+    /// ```
+    pub fn unc<H, S, P>(host: H, share: S, rel: P) -> Result<Self, UncPathError>
+    where
+        H: AsRef<str>,
+        S: AsRef<str>,
+        P: AsRef<str>,
+    {
+        let path = WindowsPathBuf::unc(host.as_ref(), share.as_ref(), rel.as_ref())?;
+        Ok(Self::from_validated_bytes(path.into_vec()))
+    }
+
+    /// Converts bytes assembled only from UTF-8 strings and ASCII separators.
+    fn from_validated_bytes(bytes: Vec<u8>) -> Self {
+        Self::from(String::from_utf8(bytes).expect("assembled from UTF-8 strings"))
+    }
+
+    /// Creates a [`Utf8WindowsPathBuf`] from `s` after expanding `%NAME%` references to
+    /// environment variables, as Windows does for `REG_EXPAND_SZ` registry values.
+    ///
+    /// See [`WindowsPathBuf::from_expandable`] for details.
+    ///
+    /// [`WindowsPathBuf::from_expandable`]: crate::WindowsPathBuf::from_expandable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, Utf8WindowsPathBuf};
+    ///
+    /// let resolve = |name: &str| match name.to_ascii_uppercase().as_str() {
+    ///     "PROGRAMFILES" => Some(r"C:\Program Files"),
+    ///     _ => None,
+    /// };
+    ///
+    /// let (path, unresolved) =
+    ///     Utf8WindowsPathBuf::from_expandable(r"%ProgramFiles%\%APP%\app.exe", resolve);
+    /// assert_eq!(path, Utf8WindowsPath::new(r"C:\Program Files\%APP%\app.exe"));
+    /// assert_eq!(unresolved, ["APP"]);
+    /// ```
+    pub fn from_expandable<S, F, V>(s: S, mut resolve: F) -> (Self, Vec<String>)
+    where
+        S: AsRef<str>,
+        F: FnMut(&str) -> Option<V>,
+        V: AsRef<str>,
+    {
+        // Names are delimited by ASCII `%`, so they always fall on character boundaries
+        fn as_str(name: &[u8]) -> &str {
+            core::str::from_utf8(name).expect("name is valid UTF-8")
+        }
+
+        let (bytes, unresolved) = super::non_utf8::expand_env_vars(s.as_ref().as_bytes(), |name| {
+            resolve(as_str(name)).map(|v| v.as_ref().as_bytes().to_vec())
+        });
+
+        let path = String::from_utf8(bytes).expect("expanded path is valid UTF-8");
+        let unresolved = unresolved
+            .into_iter()
+            .map(|name| String::from(as_str(&name)))
+            .collect();
+        (Self::from(path), unresolved)
+    }
 }
 
 #[cfg(test)]
@@ -246,4 +458,61 @@ mod tests {
         );
         assert_eq!(current_path, r"\some\path\abc\..\def\.");
     }
+
+    #[test]
+    fn normalized_from_should_match_normalize_for_valid_paths() {
+        let paths: &[&str] = &[
+            r"",
+            r"file.txt",
+            r".\dir\file.txt",
+            r"dir\..\file.txt",
+            r"\dir\file.txt",
+            r"\..\dir",
+            r"c:",
+            r"c:dir",
+            r"c:\..\dir",
+            r"C:\dir\..\file.txt",
+            r"C:/dir/./file.txt",
+            r"\\server\share\..\dir",
+            r"\\?\c:\dir\..\file.txt",
+            r"\\.\COM42\dir\.",
+        ];
+
+        for path in paths {
+            assert_eq!(
+                crate::Utf8WindowsPathBuf::normalized_from(path),
+                Ok(crate::Utf8WindowsPath::new(path).normalize()),
+                "{:?}",
+                path,
+            );
+        }
+    }
+
+    #[test]
+    fn separator_style_should_report_the_first_separator() {
+        use crate::WindowsSeparator;
+
+        let style = |s: &str| Utf8WindowsPath::new(s).separator_style();
+
+        // Mixed separators report whichever comes first
+        assert_eq!(
+            style(r"C:\Users/me\file.txt"),
+            Some(WindowsSeparator::Backslash)
+        );
+        assert_eq!(
+            style(r"C:/Users\me/file.txt"),
+            Some(WindowsSeparator::Forward)
+        );
+        assert_eq!(style(r"\\?\C:/file.txt"), Some(WindowsSeparator::Backslash));
+
+        // Root-only paths
+        assert_eq!(style(r"\"), Some(WindowsSeparator::Backslash));
+        assert_eq!(style("/"), Some(WindowsSeparator::Forward));
+        assert_eq!(style(r"C:\"), Some(WindowsSeparator::Backslash));
+        assert_eq!(style("C:"), None);
+
+        // Paths without any separators
+        assert_eq!(style(""), None);
+        assert_eq!(style("file.txt"), None);
+    }
 }
@@ -387,3 +387,201 @@ impl<'a> TryFrom<&'a str> for Utf8WindowsComponent<'a> {
         Ok(component)
     }
 }
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<Utf8WindowsComponent<'a>> for std::path::Component<'a> {
+    type Error = Utf8WindowsComponent<'a>;
+
+    /// Attempts to convert a [`Utf8WindowsComponent`] into a [`std::path::Component`], returning a
+    /// result containing the new path when successful or the original path when failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Component;
+    /// use typed_path::Utf8WindowsComponent;
+    ///
+    /// let component = Component::try_from(Utf8WindowsComponent::RootDir).unwrap();
+    /// assert_eq!(component, Component::RootDir);
+    ///
+    /// let component = Component::try_from(Utf8WindowsComponent::CurDir).unwrap();
+    /// assert_eq!(component, Component::CurDir);
+    ///
+    /// let component = Component::try_from(Utf8WindowsComponent::ParentDir).unwrap();
+    /// assert_eq!(component, Component::ParentDir);
+    ///
+    /// let component = Component::try_from(Utf8WindowsComponent::Normal("file.txt")).unwrap();
+    /// assert_eq!(component, Component::Normal(OsStr::new("file.txt")));
+    /// ```
+    ///
+    /// Alongside the traditional path components, the [`Component::Prefix`] variant is also
+    /// supported, but only when compiling on Windows. When on a non-Windows platform, the
+    /// conversion will always fail.
+    ///
+    /// [`Component::Prefix`]: std::path::Component::Prefix
+    ///
+    fn try_from(component: Utf8WindowsComponent<'a>) -> Result<Self, Self::Error> {
+        match component {
+            // NOTE: Standard library provides no way to construct a PrefixComponent, so, we have
+            //       to build a new path with just the prefix and then get the component
+            //
+            //       Because the prefix is not empty when being supplied to the path, we should get
+            //       back at least one component and can therefore return the unwrapped result
+            Utf8WindowsComponent::Prefix(x) => {
+                if cfg!(windows) {
+                    Ok(std::path::Path::new(x.as_str())
+                        .components()
+                        .next()
+                        .expect("Impossible: non-empty std path had no components"))
+                } else {
+                    Err(component)
+                }
+            }
+            Utf8WindowsComponent::RootDir => Ok(Self::RootDir),
+            Utf8WindowsComponent::CurDir => Ok(Self::CurDir),
+            Utf8WindowsComponent::ParentDir => Ok(Self::ParentDir),
+            Utf8WindowsComponent::Normal(x) => Ok(Self::Normal(std::ffi::OsStr::new(x))),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<std::path::Component<'a>> for Utf8WindowsComponent<'a> {
+    type Error = std::path::Component<'a>;
+
+    /// Attempts to convert a [`std::path::Component`] into a [`Utf8WindowsComponent`], returning
+    /// a result containing the new component when successful or the original component when
+    /// failed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Component;
+    /// use typed_path::Utf8WindowsComponent;
+    ///
+    /// let component = Utf8WindowsComponent::try_from(Component::RootDir).unwrap();
+    /// assert_eq!(component, Utf8WindowsComponent::RootDir);
+    ///
+    /// let component = Utf8WindowsComponent::try_from(Component::CurDir).unwrap();
+    /// assert_eq!(component, Utf8WindowsComponent::CurDir);
+    ///
+    /// let component = Utf8WindowsComponent::try_from(Component::ParentDir).unwrap();
+    /// assert_eq!(component, Utf8WindowsComponent::ParentDir);
+    ///
+    /// let component =
+    ///     Utf8WindowsComponent::try_from(Component::Normal(OsStr::new("file.txt"))).unwrap();
+    /// assert_eq!(component, Utf8WindowsComponent::Normal("file.txt"));
+    /// ```
+    ///
+    /// Alongside the traditional path components, the [`Component::Prefix`] variant is also
+    /// supported, but only when compiling on Windows. When on a non-Windows platform, the
+    /// conversion will always fail.
+    ///
+    /// [`Component::Prefix`]: std::path::Component::Prefix
+    ///
+    fn try_from(component: std::path::Component<'a>) -> Result<Self, Self::Error> {
+        match &component {
+            std::path::Component::Prefix(x) => Ok(Utf8WindowsComponent::Prefix(
+                Utf8WindowsPrefixComponent::try_from(x.as_os_str().to_str().ok_or(component)?)
+                    .map_err(|_| component)?,
+            )),
+            std::path::Component::RootDir => Ok(Self::RootDir),
+            std::path::Component::CurDir => Ok(Self::CurDir),
+            std::path::Component::ParentDir => Ok(Self::ParentDir),
+            std::path::Component::Normal(x) => Ok(Self::Normal(x.to_str().ok_or(component)?)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use std::convert::TryFrom;
+    use std::path::{Component, Path};
+
+    use super::*;
+
+    fn make_utf8_windows_prefix_component(s: &str) -> Utf8WindowsComponent<'_> {
+        let component = Utf8WindowsComponent::try_from(s).unwrap();
+        assert!(component.is_prefix());
+        component
+    }
+
+    #[test]
+    fn try_from_std_component_should_yield_nothing_for_empty_path() {
+        let components = Path::new("")
+            .components()
+            .map(Utf8WindowsComponent::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert!(components.is_empty());
+    }
+
+    #[test]
+    fn try_from_std_component_should_round_trip_every_component() {
+        let path = Path::new("/a/./b/../c.txt");
+        for component in path.components() {
+            let utf8 = Utf8WindowsComponent::try_from(component).unwrap();
+            assert_eq!(Component::try_from(utf8).unwrap(), component);
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn try_from_std_component_should_fail_for_normal_that_is_not_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let component = Component::Normal(OsStr::from_bytes(b"fo\xffo"));
+        assert_eq!(Utf8WindowsComponent::try_from(component), Err(component));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn try_from_utf8_windows_component_to_std_component_should_fail_for_prefix_on_non_windows() {
+        for s in [
+            "C:",
+            r"\\server\share",
+            r"\\.\COM42",
+            r"\\?\C:",
+            r"\\?\UNC\server\share",
+            r"\\?\pictures",
+        ] {
+            let component = make_utf8_windows_prefix_component(s);
+            assert_eq!(Component::try_from(component), Err(component));
+        }
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn try_from_std_component_should_keep_prefix_on_windows() {
+        fn get_prefix(component: Utf8WindowsComponent) -> Utf8WindowsPrefix {
+            match component {
+                Utf8WindowsComponent::Prefix(prefix) => prefix.kind(),
+                x => panic!("Wrong component: {x:?}"),
+            }
+        }
+
+        for (s, expected) in [
+            ("C:", Utf8WindowsPrefix::Disk('C')),
+            (r"\\server\share", Utf8WindowsPrefix::UNC("server", "share")),
+            (r"\\.\COM42", Utf8WindowsPrefix::DeviceNS("COM42")),
+            (r"\\?\C:", Utf8WindowsPrefix::VerbatimDisk('C')),
+            (
+                r"\\?\UNC\server\share",
+                Utf8WindowsPrefix::VerbatimUNC("server", "share"),
+            ),
+            (r"\\?\pictures", Utf8WindowsPrefix::Verbatim("pictures")),
+        ] {
+            let component = Path::new(s).components().next().unwrap();
+            let utf8 = Utf8WindowsComponent::try_from(component).unwrap();
+            assert_eq!(get_prefix(utf8), expected);
+            assert_eq!(Component::try_from(utf8).unwrap(), component);
+            assert_eq!(utf8, make_utf8_windows_prefix_component(s));
+        }
+    }
+}
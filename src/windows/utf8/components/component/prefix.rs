@@ -1,10 +1,10 @@
-use core::cmp;
 use core::convert::TryFrom;
 use core::hash::{Hash, Hasher};
 use core::str::Utf8Error;
+use core::{cmp, fmt};
 
 use crate::windows::{Utf8WindowsComponents, WindowsPrefix, WindowsPrefixComponent};
-use crate::ParseError;
+use crate::{ParseError, WindowsPrefixError};
 
 /// A structure wrapping a Windows path prefix as well as its unparsed string
 /// representation. [`str`] version of [`std::path::PrefixComponent`].
@@ -50,6 +50,34 @@ pub struct Utf8WindowsPrefixComponent<'a> {
 }
 
 impl<'a> Utf8WindowsPrefixComponent<'a> {
+    /// Parses the prefix at the beginning of `path`, returning it alongside the rest of the path.
+    ///
+    /// See [`WindowsPrefixComponent::parse`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPrefix, Utf8WindowsPrefixComponent};
+    ///
+    /// let (prefix, rest) = Utf8WindowsPrefixComponent::parse(r"\\?\C:\Windows").unwrap();
+    /// assert_eq!(prefix.kind(), Utf8WindowsPrefix::VerbatimDisk('C'));
+    /// assert_eq!(rest, r"\Windows");
+    ///
+    /// assert!(Utf8WindowsPrefixComponent::parse("Windows").is_none());
+    /// ```
+    pub fn parse(path: &'a str) -> Option<(Self, &'a str)> {
+        let (prefix, rest) = WindowsPrefixComponent::parse(path.as_bytes())?;
+
+        // SAFETY: a prefix always ends before an ASCII byte or at the end of the input, so
+        //         both halves of the split are valid UTF-8.
+        unsafe {
+            Some((
+                Self::from_utf8_unchecked(&prefix),
+                core::str::from_utf8_unchecked(rest),
+            ))
+        }
+    }
+
     /// Returns the parsed prefix data
     ///
     /// See [`Utf8WindowsPrefix`]'s documentation for more information on the different
@@ -288,6 +316,145 @@ impl<'a> TryFrom<&'a str> for Utf8WindowsPrefix<'a> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<'a> TryFrom<std::path::Prefix<'a>> for Utf8WindowsPrefix<'a> {
+    type Error = std::path::Prefix<'a>;
+
+    /// Attempts to convert a [`std::path::Prefix`] into a [`Utf8WindowsPrefix`], returning a
+    /// result containing the new prefix when successful or the original prefix when failed
+    ///
+    /// The conversion fails only when one of the parts of the prefix is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Prefix;
+    /// use typed_path::Utf8WindowsPrefix;
+    ///
+    /// let prefix = Utf8WindowsPrefix::try_from(Prefix::Disk(b'C')).unwrap();
+    /// assert_eq!(prefix, Utf8WindowsPrefix::Disk('C'));
+    ///
+    /// let prefix =
+    ///     Utf8WindowsPrefix::try_from(Prefix::UNC(OsStr::new("server"), OsStr::new("share")))
+    ///         .unwrap();
+    /// assert_eq!(prefix, Utf8WindowsPrefix::UNC("server", "share"));
+    /// ```
+    fn try_from(prefix: std::path::Prefix<'a>) -> Result<Self, Self::Error> {
+        match prefix {
+            std::path::Prefix::Verbatim(x) => Ok(Self::Verbatim(x.to_str().ok_or(prefix)?)),
+            std::path::Prefix::VerbatimUNC(x, y) => Ok(Self::VerbatimUNC(
+                x.to_str().ok_or(prefix)?,
+                y.to_str().ok_or(prefix)?,
+            )),
+            std::path::Prefix::VerbatimDisk(x) => Ok(Self::VerbatimDisk(x as char)),
+            std::path::Prefix::DeviceNS(x) => Ok(Self::DeviceNS(x.to_str().ok_or(prefix)?)),
+            std::path::Prefix::UNC(x, y) => Ok(Self::UNC(
+                x.to_str().ok_or(prefix)?,
+                y.to_str().ok_or(prefix)?,
+            )),
+            std::path::Prefix::Disk(x) => Ok(Self::Disk(x as char)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> TryFrom<Utf8WindowsPrefix<'a>> for std::path::Prefix<'a> {
+    type Error = Utf8WindowsPrefix<'a>;
+
+    /// Attempts to convert a [`Utf8WindowsPrefix`] into a [`std::path::Prefix`], returning a
+    /// result containing the new prefix when successful or the original prefix when failed
+    ///
+    /// The conversion fails only when a disk letter is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::ffi::OsStr;
+    /// use std::path::Prefix;
+    /// use typed_path::Utf8WindowsPrefix;
+    ///
+    /// let prefix = Prefix::try_from(Utf8WindowsPrefix::VerbatimDisk('C')).unwrap();
+    /// assert_eq!(prefix, Prefix::VerbatimDisk(b'C'));
+    ///
+    /// let prefix = Prefix::try_from(Utf8WindowsPrefix::DeviceNS("COM42")).unwrap();
+    /// assert_eq!(prefix, Prefix::DeviceNS(OsStr::new("COM42")));
+    ///
+    /// assert!(Prefix::try_from(Utf8WindowsPrefix::Disk('💖')).is_err());
+    /// ```
+    fn try_from(prefix: Utf8WindowsPrefix<'a>) -> Result<Self, Self::Error> {
+        fn to_drive(c: char) -> Option<u8> {
+            if c.is_ascii() {
+                Some(c as u8)
+            } else {
+                None
+            }
+        }
+
+        match prefix {
+            Utf8WindowsPrefix::Verbatim(x) => Ok(Self::Verbatim(std::ffi::OsStr::new(x))),
+            Utf8WindowsPrefix::VerbatimUNC(x, y) => Ok(Self::VerbatimUNC(
+                std::ffi::OsStr::new(x),
+                std::ffi::OsStr::new(y),
+            )),
+            Utf8WindowsPrefix::VerbatimDisk(x) => {
+                Ok(Self::VerbatimDisk(to_drive(x).ok_or(prefix)?))
+            }
+            Utf8WindowsPrefix::DeviceNS(x) => Ok(Self::DeviceNS(std::ffi::OsStr::new(x))),
+            Utf8WindowsPrefix::UNC(x, y) => {
+                Ok(Self::UNC(std::ffi::OsStr::new(x), std::ffi::OsStr::new(y)))
+            }
+            Utf8WindowsPrefix::Disk(x) => Ok(Self::Disk(to_drive(x).ok_or(prefix)?)),
+        }
+    }
+}
+
+impl fmt::Display for Utf8WindowsPrefix<'_> {
+    /// Formats the prefix in its canonical textual form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPrefix::*;
+    ///
+    /// assert_eq!(Verbatim("pictures").to_string(), r"\\?\pictures");
+    /// assert_eq!(VerbatimUNC("server", "share").to_string(), r"\\?\UNC\server\share");
+    /// assert_eq!(VerbatimDisk('C').to_string(), r"\\?\C:");
+    /// assert_eq!(DeviceNS("BrainInterface").to_string(), r"\\.\BrainInterface");
+    /// assert_eq!(UNC("server", "share").to_string(), r"\\server\share");
+    /// assert_eq!(UNC("server", "").to_string(), r"\\server");
+    /// assert_eq!(Disk('C').to_string(), "C:");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use self::Utf8WindowsPrefix::*;
+
+        fn write_server_share(f: &mut fmt::Formatter<'_>, x: &str, y: &str) -> fmt::Result {
+            f.write_str(x)?;
+            if !y.is_empty() {
+                write!(f, r"\{y}")?;
+            }
+            Ok(())
+        }
+
+        match *self {
+            Verbatim(x) => write!(f, r"\\?\{x}"),
+            VerbatimUNC(x, y) => {
+                f.write_str(r"\\?\UNC\")?;
+                write_server_share(f, x, y)
+            }
+            VerbatimDisk(x) => write!(f, r"\\?\{x}:"),
+            DeviceNS(x) => write!(f, r"\\.\{x}"),
+            UNC(x, y) => {
+                f.write_str(r"\\")?;
+                write_server_share(f, x, y)
+            }
+            Disk(x) => write!(f, "{x}:"),
+        }
+    }
+}
+
 impl<'a> Utf8WindowsPrefix<'a> {
     /// Calculates the full byte length of the prefix
     ///
@@ -351,6 +518,118 @@ impl<'a> Utf8WindowsPrefix<'a> {
         matches!(*self, Verbatim(_) | VerbatimDisk(_) | VerbatimUNC(..))
     }
 
+    /// Returns the GUID of a volume prefix, such as `\\?\Volume{GUID}` or `\\.\Volume{GUID}`,
+    /// without the surrounding braces.
+    ///
+    /// See [`WindowsPrefix::volume_guid`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPrefix, Utf8WindowsPrefixComponent};
+    ///
+    /// let path = r"\\?\Volume{26a21bda-a627-11d7-9931-806e6f6e6963}\";
+    /// let prefix = Utf8WindowsPrefixComponent::parse(path).unwrap().0.kind();
+    /// assert_eq!(prefix.volume_guid(), Some("26a21bda-a627-11d7-9931-806e6f6e6963"));
+    /// assert!(!Utf8WindowsPrefix::Verbatim("pictures").is_volume());
+    /// ```
+    pub fn volume_guid(&self) -> Option<&'a str> {
+        use self::Utf8WindowsPrefix::*;
+        let guid = match *self {
+            Verbatim(x) => WindowsPrefix::Verbatim(x.as_bytes()).volume_guid()?,
+            DeviceNS(x) => WindowsPrefix::DeviceNS(x.as_bytes()).volume_guid()?,
+            _ => return None,
+        };
+
+        // The GUID is only hexadecimal digits and hyphens
+        core::str::from_utf8(guid).ok()
+    }
+
+    /// Determines if the prefix identifies a volume by GUID. See [`volume_guid`].
+    ///
+    /// [`volume_guid`]: Utf8WindowsPrefix::volume_guid
+    #[inline]
+    pub fn is_volume(&self) -> bool {
+        self.volume_guid().is_some()
+    }
+
+    /// Returns the prefix with its drive letter converted to ASCII upper case, if it has one.
+    ///
+    /// Prefixes parsed from a path already have an upper-case drive letter, so this is only
+    /// needed for prefixes constructed by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPrefix::*;
+    ///
+    /// assert_eq!(Disk('c').normalize_drive(), Disk('C'));
+    /// assert_eq!(VerbatimDisk('d').normalize_drive(), VerbatimDisk('D'));
+    /// assert_eq!(UNC("server", "share").normalize_drive(), UNC("server", "share"));
+    /// ```
+    pub fn normalize_drive(self) -> Self {
+        use self::Utf8WindowsPrefix::*;
+        match self {
+            Disk(x) => Disk(x.to_ascii_uppercase()),
+            VerbatimDisk(x) => VerbatimDisk(x.to_ascii_uppercase()),
+            x => x,
+        }
+    }
+
+    /// Compares two prefixes for equality, treating drive letters that differ only in ASCII case
+    /// as equal.
+    ///
+    /// Unlike the [`PartialEq`] implementation, `c:` and `C:` are considered equal here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPrefix::*;
+    ///
+    /// assert_ne!(Disk('c'), Disk('C'));
+    /// assert!(Disk('c').eq_ignore_drive_case(&Disk('C')));
+    /// assert!(!Disk('c').eq_ignore_drive_case(&VerbatimDisk('C')));
+    /// ```
+    pub fn eq_ignore_drive_case(&self, other: &Self) -> bool {
+        self.normalize_drive() == other.normalize_drive()
+    }
+
+    /// Checks that the prefix could be written out as part of a path that Windows accepts, which
+    /// the parser does not enforce.
+    ///
+    /// See [`WindowsPrefix::validate`] for the rules that are checked.
+    ///
+    /// [`WindowsPrefix::validate`]: crate::WindowsPrefix::validate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPrefix::*;
+    /// use typed_path::WindowsPrefixError;
+    ///
+    /// assert_eq!(UNC("fileserver", "public").validate(), Ok(()));
+    /// assert_eq!(UNC("server@SSL", "share").validate(), Ok(()));
+    /// assert_eq!(
+    ///     UNC("server@SSL@", "share").validate(),
+    ///     Err(WindowsPrefixError::InvalidServerSuffix)
+    /// );
+    /// assert_eq!(VerbatimUNC("server", "").validate(), Err(WindowsPrefixError::EmptyShare));
+    /// assert_eq!(Disk('é').validate(), Err(WindowsPrefixError::InvalidDrive));
+    /// ```
+    pub fn validate(&self) -> Result<(), WindowsPrefixError> {
+        use self::Utf8WindowsPrefix::*;
+        let prefix = match *self {
+            Verbatim(x) => WindowsPrefix::Verbatim(x.as_bytes()),
+            VerbatimUNC(x, y) => WindowsPrefix::VerbatimUNC(x.as_bytes(), y.as_bytes()),
+            VerbatimDisk(x) if x.is_ascii() => WindowsPrefix::VerbatimDisk(x as u8),
+            DeviceNS(x) => WindowsPrefix::DeviceNS(x.as_bytes()),
+            UNC(x, y) => WindowsPrefix::UNC(x.as_bytes(), y.as_bytes()),
+            Disk(x) if x.is_ascii() => WindowsPrefix::Disk(x as u8),
+            Disk(_) | VerbatimDisk(_) => return Err(WindowsPrefixError::InvalidDrive),
+        };
+        prefix.validate()
+    }
+
     /// Converts a non-UTF-8 [`WindowsPrefix`] to a UTF-8 [`Utf8WindowsPrefix`]
     /// by checking that the prefix contains valid UTF-8.
     ///
@@ -0,0 +1,244 @@
+use super::join::{exceeds_legacy_limit, to_verbatim};
+use super::wtf8;
+use crate::no_std_compat::*;
+use crate::{Utf8WindowsPath, Utf8WindowsPathBuf, WideStringError, WindowsPath, WindowsPathBuf};
+
+impl WindowsPath {
+    /// Encodes the path as a NUL-terminated UTF-16 string, ready to be passed to Win32 functions
+    /// as a `PCWSTR`.
+    ///
+    /// The path is read as [WTF-8], the encoding the standard library uses for `OsStr` on
+    /// Windows, so unpaired surrogates produced by [`from_pcwstr`] are encoded back unchanged.
+    ///
+    /// An absolute disk or UNC path that is too long for legacy Windows APIs is first converted to
+    /// its normalized verbatim (`\\?\`) form, exactly as [`join_with`] does when
+    /// [`JoinOptions::auto_verbatim`] is enabled. Every other path is encoded as written.
+    ///
+    /// [WTF-8]: https://simonsapin.github.io/wtf-8/
+    /// [`from_pcwstr`]: WindowsPathBuf::from_pcwstr
+    /// [`join_with`]: WindowsPath::join_with
+    /// [`JoinOptions::auto_verbatim`]: crate::JoinOptions::auto_verbatim
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WideStringError::InvalidUnicode`] if the path is not valid WTF-8, and
+    /// [`WideStringError::InteriorNul`] if it contains a NUL character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{WideStringError, WindowsPath};
+    ///
+    /// let wide = WindowsPath::new(r"C:\Temp").to_wide_null().unwrap();
+    /// assert_eq!(String::from_utf16(&wide).unwrap(), "C:\\Temp\0");
+    ///
+    /// let name = "x".repeat(260);
+    /// let wide = WindowsPath::new(r"C:\Temp").join(&name).to_wide_null().unwrap();
+    /// assert!(String::from_utf16(&wide).unwrap().starts_with(r"\\?\C:\Temp\x"));
+    ///
+    /// assert_eq!(
+    ///     WindowsPath::new(b"C:\\\xff").to_wide_null(),
+    ///     Err(WideStringError::InvalidUnicode),
+    /// );
+    /// ```
+    pub fn to_wide_null(&self) -> Result<Vec<u16>, WideStringError> {
+        let bytes = self.as_bytes();
+        if bytes.contains(&0) {
+            return Err(WideStringError::InteriorNul);
+        }
+
+        if exceeds_legacy_limit(bytes) {
+            if let Some(verbatim) = to_verbatim(self) {
+                return encode_wide_null(verbatim.as_bytes());
+            }
+        }
+
+        encode_wide_null(bytes)
+    }
+}
+
+impl Utf8WindowsPath {
+    /// Encodes the path as a NUL-terminated UTF-16 string, ready to be passed to Win32 functions
+    /// as a `PCWSTR`.
+    ///
+    /// See [`WindowsPath::to_wide_null`] for how long paths are handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WideStringError::InteriorNul`] if the path contains a NUL character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{Utf8WindowsPath, WideStringError};
+    ///
+    /// let wide = Utf8WindowsPath::new(r"\\server\share\Ünïcödé").to_wide_null().unwrap();
+    /// assert_eq!(String::from_utf16(&wide).unwrap(), "\\\\server\\share\\Ünïcödé\0");
+    ///
+    /// assert_eq!(
+    ///     Utf8WindowsPath::new("C:\\a\0b").to_wide_null(),
+    ///     Err(WideStringError::InteriorNul),
+    /// );
+    /// ```
+    pub fn to_wide_null(&self) -> Result<Vec<u16>, WideStringError> {
+        WindowsPath::new(self.as_str()).to_wide_null()
+    }
+}
+
+impl WindowsPathBuf {
+    /// Creates a path by copying a NUL-terminated UTF-16 string, such as a `PCWSTR` returned by a
+    /// Win32 function.
+    ///
+    /// The string is stored as [WTF-8], so unpaired surrogates, which Windows allows in file
+    /// names, are kept and encoded back unchanged by [`to_wide_null`].
+    ///
+    /// [WTF-8]: https://simonsapin.github.io/wtf-8/
+    /// [`to_wide_null`]: WindowsPath::to_wide_null
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and point to a sequence of `u16` values terminated by a `0`, all of
+    /// which must be valid for reads for the duration of the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::WindowsPathBuf;
+    ///
+    /// let wide: Vec<u16> = "C:\\Temp\0".encode_utf16().collect();
+    /// let path = unsafe { WindowsPathBuf::from_pcwstr(wide.as_ptr()) };
+    /// assert_eq!(path.as_bytes(), br"C:\Temp");
+    ///
+    /// // An unpaired surrogate is kept as its WTF-8 encoding
+    /// let wide = [u16::from(b'a'), 0xd800, 0];
+    /// let path = unsafe { WindowsPathBuf::from_pcwstr(wide.as_ptr()) };
+    /// assert_eq!(path.as_bytes(), b"a\xed\xa0\x80");
+    /// ```
+    pub unsafe fn from_pcwstr(ptr: *const u16) -> Self {
+        Self::from(wtf8::from_wide(wide_null_slice(ptr).iter().copied()))
+    }
+}
+
+impl Utf8WindowsPathBuf {
+    /// Creates a path by copying a NUL-terminated UTF-16 string, such as a `PCWSTR` returned by a
+    /// Win32 function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WideStringError::InvalidUnicode`] if the string contains an unpaired surrogate,
+    /// which cannot be represented in UTF-8. Use [`WindowsPathBuf::from_pcwstr`] to keep it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and point to a sequence of `u16` values terminated by a `0`, all of
+    /// which must be valid for reads for the duration of the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::Utf8WindowsPathBuf;
+    ///
+    /// let wide: Vec<u16> = "C:\\Temp\0".encode_utf16().collect();
+    /// let path = unsafe { Utf8WindowsPathBuf::from_pcwstr(wide.as_ptr()) }.unwrap();
+    /// assert_eq!(path.as_str(), r"C:\Temp");
+    /// ```
+    pub unsafe fn from_pcwstr(ptr: *const u16) -> Result<Self, WideStringError> {
+        char::decode_utf16(wide_null_slice(ptr).iter().copied())
+            .collect::<Result<String, _>>()
+            .map(Self::from)
+            .map_err(|_| WideStringError::InvalidUnicode)
+    }
+}
+
+/// Encodes WTF-8 `bytes` as NUL-terminated UTF-16, failing if they are not valid WTF-8.
+fn encode_wide_null(bytes: &[u8]) -> Result<Vec<u16>, WideStringError> {
+    let mut wide = wtf8::to_wide(bytes).ok_or(WideStringError::InvalidUnicode)?;
+    wide.push(0);
+    Ok(wide)
+}
+
+/// Returns the NUL-terminated UTF-16 string at `ptr`, without its terminator, which must satisfy
+/// the safety requirements of [`WindowsPathBuf::from_pcwstr`].
+unsafe fn wide_null_slice<'a>(ptr: *const u16) -> &'a [u16] {
+    let mut len = 0;
+    while *ptr.add(len) != 0 {
+        len += 1;
+    }
+
+    core::slice::from_raw_parts(ptr, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_wide_null_should_round_trip_through_from_pcwstr() {
+        for path in [
+            r"C:\Temp\file.txt",
+            r"\\server\share\dir",
+            r"relative\ünï",
+            "",
+        ] {
+            let wide = WindowsPath::new(path).to_wide_null().unwrap();
+            assert_eq!(wide.last(), Some(&0));
+
+            let decoded = unsafe { WindowsPathBuf::from_pcwstr(wide.as_ptr()) };
+            assert_eq!(decoded.as_bytes(), path.as_bytes());
+        }
+    }
+
+    #[test]
+    fn from_pcwstr_should_round_trip_unpaired_surrogates_through_wtf8() {
+        for wide in [
+            vec![u16::from(b'a'), 0xd800, u16::from(b'b'), 0],
+            vec![0xdfff, u16::from(b'\\'), 0xdbff, 0],
+            vec![0xd83d, 0xdc96, 0xdc00, 0],
+        ] {
+            let path = unsafe { WindowsPathBuf::from_pcwstr(wide.as_ptr()) };
+            assert!(core::str::from_utf8(path.as_bytes()).is_err());
+            assert_eq!(path.to_wide_null().unwrap(), wide);
+        }
+    }
+
+    #[test]
+    fn to_wide_null_should_reject_bytes_that_are_not_wtf8() {
+        for bytes in [&b"C:\\\xff"[..], b"\xed\xa0", b"\xed\x80"] {
+            assert_eq!(
+                WindowsPath::new(bytes).to_wide_null(),
+                Err(WideStringError::InvalidUnicode)
+            );
+        }
+    }
+
+    #[test]
+    fn to_wide_null_should_only_upgrade_long_absolute_paths() {
+        let name = "x".repeat(260);
+
+        let wide = WindowsPath::new(r"\\server\share\.")
+            .join(&name)
+            .to_wide_null()
+            .unwrap();
+        let expected = format!("\\\\?\\UNC\\server\\share\\{name}\0");
+        assert_eq!(String::from_utf16(&wide).unwrap(), expected);
+
+        // Relative paths cannot be made verbatim, so they are encoded as written
+        let wide = WindowsPath::new(r"dir\.")
+            .join(&name)
+            .to_wide_null()
+            .unwrap();
+        assert_eq!(
+            String::from_utf16(&wide).unwrap(),
+            format!("dir\\.\\{name}\0")
+        );
+    }
+
+    #[test]
+    fn from_pcwstr_should_reject_unpaired_surrogates() {
+        let wide = [u16::from(b'a'), 0xd800, u16::from(b'b'), 0];
+        assert_eq!(
+            unsafe { Utf8WindowsPathBuf::from_pcwstr(wide.as_ptr()) },
+            Err(WideStringError::InvalidUnicode)
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::no_std_compat::*;
+
+/// Encodes WTF-8 `bytes`, the encoding the standard library uses for `OsStr` on Windows, as
+/// UTF-16, returning `None` if they are not valid WTF-8.
+pub(crate) fn to_wide(mut bytes: &[u8]) -> Option<Vec<u16>> {
+    let mut wide = Vec::with_capacity(bytes.len());
+    loop {
+        let valid_up_to = match core::str::from_utf8(bytes) {
+            Ok(s) => {
+                wide.extend(s.encode_utf16());
+                return Some(wide);
+            }
+            Err(x) => x.valid_up_to(),
+        };
+
+        let (valid, rest) = bytes.split_at(valid_up_to);
+        wide.extend(core::str::from_utf8(valid).ok()?.encode_utf16());
+
+        // The only sequences WTF-8 adds to UTF-8 are the three-byte encodings of surrogates
+        match rest {
+            [0xed, b1 @ 0xa0..=0xbf, b2 @ 0x80..=0xbf, rest @ ..] => {
+                wide.push(0xd000 | (u16::from(b1 & 0x3f) << 6) | u16::from(b2 & 0x3f));
+                bytes = rest;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Decodes UTF-16 as WTF-8, encoding each unpaired surrogate as if it were a code point.
+pub(crate) fn from_wide(wide: impl IntoIterator<Item = u16>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for c in char::decode_utf16(wide) {
+        match c {
+            Ok(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            Err(x) => {
+                let surrogate = x.unpaired_surrogate();
+                bytes.extend_from_slice(&[
+                    0xe0 | (surrogate >> 12) as u8,
+                    0x80 | ((surrogate >> 6) & 0x3f) as u8,
+                    0x80 | (surrogate & 0x3f) as u8,
+                ]);
+            }
+        }
+    }
+    bytes
+}